@@ -0,0 +1,188 @@
+#ifndef VRP_H
+#define VRP_H
+
+/* Generated by cbindgen from vrp-cli/src/lib.rs: do not edit manually. */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+// A version of `VrpSolveOptions` layout supported by the library.
+#define VRP_SOLVE_OPTIONS_VERSION 1
+
+// A handle which allows to cancel solving from another thread.
+typedef struct CancellationHandle CancellationHandle;
+
+// Keeps problem which is read once and can be solved multiple times.
+typedef struct ProblemHandle ProblemHandle;
+
+// Keeps problem and routing matrices passed in chunks.
+typedef struct ProblemStream ProblemStream;
+
+typedef void (*ProgressCallback)(size_t, double, size_t, uint64_t);
+
+// Solve options passed as a struct instead of json string. The `struct_size` field should be
+// set to the struct size and the `version` field to `VRP_SOLVE_OPTIONS_VERSION` the caller is
+// compiled against, use `vrp_solve_options_init` to get them together with default values.
+// New fields are only appended with a version increment and the library never reads beyond
+// `struct_size`, so it keeps reading structs of older versions: missing fields are treated as
+// zero. Zero values mean that setting is not specified and the value from config is used.
+typedef struct VrpSolveOptions {
+  // A size of the struct in bytes as known by the caller.
+  size_t struct_size;
+  // A version of struct layout.
+  uint32_t version;
+  // Max amount of generations.
+  size_t max_generations;
+  // Max running time in seconds.
+  size_t max_time;
+  // Sample size of cost variation termination, used together with threshold.
+  size_t cost_variation_sample;
+  // Threshold of cost variation termination.
+  double cost_variation_threshold;
+  // Specifies whether `seed` is used: zero seed is a valid value.
+  bool has_seed;
+  // Random seed used to get reproducible runs.
+  uint64_t seed;
+  // Amount of threads used by solver.
+  size_t parallelism;
+  // Reduces memory usage at the cost of solution quality.
+  bool low_memory;
+  // Optional cancellation handle created by `vrp_cancellation_new`: once it is cancelled,
+  // solver stops at the next generation and returns the best known solution.
+  const struct CancellationHandle *cancellation;
+  // Optional callback called from solver thread after each generation with generation,
+  // best cost, amount of unassigned jobs and elapsed milliseconds.
+  ProgressCallback progress;
+} VrpSolveOptions;
+
+typedef void (*WriteCallback)(const char*, size_t);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Creates a new cancellation handle which should be set to `cancellation` field of
+// `VrpSolveOptions` and, finally, released by `vrp_cancellation_free`.
+struct CancellationHandle *vrp_cancellation_new(void);
+
+// Requests cancellation: solver stops refinement at the next generation and returns the best
+// solution found so far. Can be called from any thread.
+void vrp_cancel(const struct CancellationHandle *handle);
+
+// Releases cancellation handle. It should not be called while solving is in progress.
+void vrp_cancellation_free(struct CancellationHandle *handle);
+
+// Returns a list of unique locations to request a routing matrix as a string which should be
+// released by `vrp_free_string`. On failure, returns null pointer and writes error details to
+// `error`: a json object with `code`, `category`, `message` and optional `errors` fields, which
+// should be released by `vrp_free_string` too. Null `error` pointer is ignored.
+char *vrp_get_routing_locations(const char *problem, char **error);
+
+// Converts problem from format specified by `format` to `pragmatic` format. Result and error
+// ownership is the same as in `vrp_get_routing_locations`.
+char *vrp_convert_to_pragmatic(const char *format,
+                               const char *const *inputs,
+                               size_t input_len,
+                               char **error);
+
+// Validates problem passed in `pragmatic` format with optional routing matrices without solving
+// it. Returns a json array of found errors with `code`, `cause`, `action` and optional `details`
+// fields, which is empty when problem is valid. Errors of malformed problem or matrices are
+// returned the same way. Result and error ownership is the same as in `vrp_get_routing_locations`,
+// `error` is written only when arguments cannot be read.
+char *vrp_validate_pragmatic(const char *problem,
+                             const char *const *matrices,
+                             size_t matrices_len,
+                             char **error);
+
+// Checks that solution in `pragmatic` format, e.g. produced by the solver and modified manually,
+// is feasible for the problem with optional routing matrices. Returns a json object with
+// `errors` and `warnings` arrays of violation descriptions: solution is feasible when `errors`
+// is empty. Time, load and distance violations which do not exceed `time_tolerance` (in seconds),
+// `load_tolerance` and `distance_tolerance` (in meters) are reported as warnings. Result and
+// error ownership is the same as in `vrp_get_routing_locations`, `error` is written only when
+// arguments cannot be read.
+char *vrp_check_solution(const char *problem,
+                         const char *const *matrices,
+                         size_t matrices_len,
+                         const char *solution,
+                         double time_tolerance,
+                         int32_t load_tolerance,
+                         double distance_tolerance,
+                         char **error);
+
+// Solves the problem kept by the handle. Works as `vrp_solve`, but serialized solution is passed
+// to `write` callback in chunks, as a pointer to bytes and their amount, while it is written
+// tour by tour, so the whole solution is never kept in memory. Chunks are not null terminated
+// and may end in the middle of utf8 character. Please note that total statistic is written
+// after tours. Returns true on success, otherwise writes error details to `error` the same way
+// as `vrp_get_routing_locations` does. Nothing is written when solving fails.
+bool vrp_solve_streamed(const struct ProblemHandle *handle,
+                        const char *config,
+                        const struct VrpSolveOptions *options,
+                        WriteCallback write,
+                        char **error);
+
+// Releases a string returned by library functions. Null pointer is ignored.
+void vrp_free_string(char *value);
+
+// Reads problem passed in `pragmatic` format with optional routing matrices and returns a handle
+// which should be passed to `vrp_solve` and, finally, released by `vrp_problem_destroy`. On
+// failure, returns null pointer and writes error details to `error` the same way as
+// `vrp_get_routing_locations` does.
+struct ProblemHandle *vrp_problem_create(const char *problem,
+                                         const char *const *matrices,
+                                         size_t matrices_len,
+                                         char **error);
+
+// Solves the problem kept by the handle with optional config and options, and returns
+// serialized solution. Null options pointer means default options. Result and error ownership
+// is the same as in `vrp_get_routing_locations`. The same handle can be solved from multiple
+// threads at the same time.
+char *vrp_solve(const struct ProblemHandle *handle,
+                const char *config,
+                const struct VrpSolveOptions *options,
+                char **error);
+
+// Releases the problem handle. It should not be called while solving is in progress.
+void vrp_problem_destroy(struct ProblemHandle *handle);
+
+// Returns library version as a static null terminated string which should not be released.
+const char *vrp_get_version(void);
+
+// Initializes solve options of `struct_size` bytes, usually `sizeof(VrpSolveOptions)`, with
+// the size, the version supported by the library and default values. Only `struct_size`
+// bytes are written. Null pointer is ignored.
+void vrp_solve_options_init(struct VrpSolveOptions *options, size_t struct_size);
+
+// Starts streaming of a problem in `pragmatic` format. Returns a handle which should be passed
+// to `vrp_problem_append` and `vrp_matrix_append` functions and, finally, to `vrp_problem_end`
+// or `vrp_problem_discard` which release it.
+struct ProblemStream *vrp_problem_begin(void);
+
+// Appends a chunk of `chunk_len` bytes to the problem. Chunk is not required to be null
+// terminated or to end at utf8 character boundary. Null handle is ignored.
+void vrp_problem_append(struct ProblemStream *stream, const char *chunk, size_t chunk_len);
+
+// Starts a new routing matrix: subsequent `vrp_matrix_append` calls append chunks to it.
+// Null handle is ignored.
+void vrp_matrix_begin(struct ProblemStream *stream);
+
+// Appends a chunk of `chunk_len` bytes to the routing matrix started by the last
+// `vrp_matrix_begin` call. Starts a new matrix if there is none. Null handle is ignored.
+void vrp_matrix_append(struct ProblemStream *stream, const char *chunk, size_t chunk_len);
+
+// Reads streamed problem and releases the stream handle. Returns a problem handle which should
+// be passed to `vrp_solve` and, finally, released by `vrp_problem_destroy`. On failure, returns
+// null pointer and writes error details to `error` the same way as `vrp_problem_create` does.
+struct ProblemHandle *vrp_problem_end(struct ProblemStream *stream, char **error);
+
+// Releases the handle without solving the problem. Null handle is ignored.
+void vrp_problem_discard(struct ProblemStream *stream);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* VRP_H */
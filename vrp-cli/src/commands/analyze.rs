@@ -0,0 +1,68 @@
+use super::*;
+use std::io::BufReader;
+use vrp_cli::extensions::analyze::write_analysis;
+
+pub const FORMAT_ARG_NAME: &str = "FORMAT";
+pub const PROBLEM_ARG_NAME: &str = "problem-file";
+pub const SOLUTION_ARG_NAME: &str = "solution-file";
+pub const OUT_FORMAT_ARG_NAME: &str = "out-format";
+pub const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_analyze_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("analyze")
+        .about("Calculates key performance indicators of the solution")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(PROBLEM_ARG_NAME)
+                .help("Sets problem file, use '-' for stdin")
+                .short("p")
+                .long(PROBLEM_ARG_NAME)
+                .alias("problem")
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(SOLUTION_ARG_NAME)
+                .help("Sets solution file, use '-' for stdin")
+                .short("s")
+                .long(SOLUTION_ARG_NAME)
+                .alias("solution")
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_FORMAT_ARG_NAME)
+                .help("Specifies analysis output format")
+                .short("f")
+                .long(OUT_FORMAT_ARG_NAME)
+                .required(false)
+                .possible_values(&["text", "json"])
+                .default_value("text"),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for analysis output, use '-' for stdout")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_analyze(matches: &ArgMatches) {
+    let problem_file = BufReader::new(open_file(matches.value_of(PROBLEM_ARG_NAME).unwrap(), "problem"));
+    let solution_file = BufReader::new(open_file(matches.value_of(SOLUTION_ARG_NAME).unwrap(), "solution"));
+    let out_format = matches.value_of(OUT_FORMAT_ARG_NAME).unwrap();
+    let out_buffer =
+        create_write_buffer(matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out analysis")));
+
+    if let Err(err) = write_analysis(problem_file, solution_file, out_format, out_buffer) {
+        exit_with_error(ErrorCategory::Input, format!("cannot analyze solution: '{}'", err))
+    }
+}
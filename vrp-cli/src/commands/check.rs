@@ -1,13 +1,17 @@
 use super::*;
 use std::io::BufReader;
-use std::process;
-use vrp_pragmatic::checker::CheckerContext;
-use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_pragmatic::checker::{CheckerConfig, CheckerContext};
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem};
 use vrp_pragmatic::format::solution::deserialize_solution;
+use vrp_pragmatic::format::FormatError;
 
 pub const FORMAT_ARG_NAME: &str = "FORMAT";
 pub const PROBLEM_ARG_NAME: &str = "problem-files";
 pub const SOLUTION_ARG_NAME: &str = "solution-file";
+pub const MATRIX_ARG_NAME: &str = "matrix";
+pub const TIME_TOLERANCE_ARG_NAME: &str = "time-tolerance";
+pub const LOAD_TOLERANCE_ARG_NAME: &str = "load-tolerance";
+pub const DISTANCE_TOLERANCE_ARG_NAME: &str = "distance-tolerance";
 
 pub fn get_check_app<'a, 'b>() -> App<'a, 'b> {
     App::new("check")
@@ -30,12 +34,42 @@ pub fn get_check_app<'a, 'b>() -> App<'a, 'b> {
         )
         .arg(
             Arg::with_name(SOLUTION_ARG_NAME)
-                .help("Sets solution file")
+                .help("Sets solution file, use '-' for stdin")
                 .short("s")
                 .long(SOLUTION_ARG_NAME)
                 .required(true)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix")
+                .short("m")
+                .long(MATRIX_ARG_NAME)
+                .multiple(true)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(TIME_TOLERANCE_ARG_NAME)
+                .help("Specifies time violation in seconds which is reported as warning, not error")
+                .long(TIME_TOLERANCE_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(LOAD_TOLERANCE_ARG_NAME)
+                .help("Specifies load violation which is reported as warning, not error")
+                .long(LOAD_TOLERANCE_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(DISTANCE_TOLERANCE_ARG_NAME)
+                .help("Specifies distance violation in meters which is reported as warning, not error")
+                .long(DISTANCE_TOLERANCE_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
 }
 
 pub fn run_check(matches: &ArgMatches) {
@@ -44,25 +78,54 @@ pub fn run_check(matches: &ArgMatches) {
         .values_of(PROBLEM_ARG_NAME)
         .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "problem"))).collect::<Vec<_>>());
     let solution_file = matches.value_of(SOLUTION_ARG_NAME).map(|path| BufReader::new(open_file(path, "solution")));
+    let matrix_files = matches
+        .values_of(MATRIX_ARG_NAME)
+        .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "routing matrix"))).collect::<Vec<_>>());
+    let config = CheckerConfig {
+        time_tolerance: parse_float_value::<f64>(matches, TIME_TOLERANCE_ARG_NAME, "time tolerance").unwrap_or(0.),
+        load_tolerance: parse_int_value::<i32>(matches, LOAD_TOLERANCE_ARG_NAME, "load tolerance").unwrap_or(0),
+        distance_tolerance: parse_float_value::<f64>(matches, DISTANCE_TOLERANCE_ARG_NAME, "distance tolerance")
+            .unwrap_or(0.),
+    };
 
     let result = match (input_format, problem_files, solution_file) {
         ("pragmatic", Some(mut problem_files), Some(solution_file)) if problem_files.len() == 1 => {
-            // TODO support matrix
             let problem_file = problem_files.swap_remove(0);
 
-            deserialize_problem(problem_file)
-                .into_iter()
-                .zip(deserialize_solution(solution_file).into_iter())
-                .map(|(problem, solution)| CheckerContext::new(problem, None, solution).check())
-                .next()
-                .expect("Cannot deserialize problem or solution")
+            let matrices = matrix_files
+                .map(|files| {
+                    files
+                        .into_iter()
+                        .map(|file| {
+                            deserialize_matrix(file).map_err(|errors| {
+                                format!("cannot read matrix: '{}'", FormatError::format_many(&errors, ","))
+                            })
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .transpose();
+
+            matrices.and_then(|matrices| {
+                let problem = deserialize_problem(problem_file)
+                    .map_err(|errors| format!("cannot read problem: '{}'", FormatError::format_many(&errors, ",")))?;
+                let solution =
+                    deserialize_solution(solution_file).map_err(|err| format!("cannot read solution: '{}'", err))?;
+
+                Ok(CheckerContext::new(problem, matrices, solution).with_config(config).get_report())
+            })
         }
         ("pragmatic", _, _) => Err("pragmatic format expects one problem and one solution file".to_string()),
         _ => Err(format!("unknown format: '{}'", input_format)),
     };
 
-    if let Err(err) = result {
-        eprintln!("{}", err);
-        process::exit(1);
+    match result {
+        Ok(report) => {
+            report.warnings.iter().for_each(|warning| eprintln!("warning: {}", warning));
+
+            if !report.errors.is_empty() {
+                exit_with_error(ErrorCategory::Validation, report.errors.join("\n"))
+            }
+        }
+        Err(err) => exit_with_error(ErrorCategory::Input, err),
     }
 }
@@ -0,0 +1,57 @@
+use super::*;
+use std::io::BufReader;
+use vrp_cli::extensions::convert::convert_problem;
+
+pub const FROM_FORMAT_ARG_NAME: &str = "from";
+pub const TO_FORMAT_ARG_NAME: &str = "to";
+pub const INPUT_ARG_NAME: &str = "input-file";
+pub const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_convert_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("convert")
+        .about("Provides the way to convert problem from one format to another")
+        .arg(
+            Arg::with_name(FROM_FORMAT_ARG_NAME)
+                .help("Specifies input format")
+                .long(FROM_FORMAT_ARG_NAME)
+                .required(true)
+                .takes_value(true)
+                .possible_values(&["pragmatic"]),
+        )
+        .arg(
+            Arg::with_name(TO_FORMAT_ARG_NAME)
+                .help("Specifies output format")
+                .long(TO_FORMAT_ARG_NAME)
+                .required(true)
+                .takes_value(true)
+                .possible_values(&["solomon"]),
+        )
+        .arg(
+            Arg::with_name(INPUT_ARG_NAME)
+                .help("Sets input file which contains a VRP definition, use '-' for stdin")
+                .short("i")
+                .long(INPUT_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for result output, use '-' for stdout")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_convert(matches: &ArgMatches) {
+    let from_format = matches.value_of(FROM_FORMAT_ARG_NAME).unwrap();
+    let to_format = matches.value_of(TO_FORMAT_ARG_NAME).unwrap();
+    let input_file = BufReader::new(open_file(matches.value_of(INPUT_ARG_NAME).unwrap(), "input"));
+    let out_buffer =
+        create_write_buffer(matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result")));
+
+    if let Err(err) = convert_problem(from_format, to_format, input_file, out_buffer) {
+        exit_with_error(ErrorCategory::Input, format!("cannot convert problem: '{}'", err))
+    }
+}
@@ -1,14 +1,15 @@
+use super::matrix::get_matrix_path;
 use super::*;
 use std::io::BufReader;
-use std::process;
 use vrp_cli::extensions::generate::generate_problem;
-use vrp_pragmatic::format::problem::serialize_problem;
+use vrp_pragmatic::format::problem::{create_approx_matrices, serialize_matrix, serialize_problem};
 
 pub const FORMAT_ARG_NAME: &str = "FORMAT";
 pub const PROTOTYPES_ARG_NAME: &str = "prototypes";
 pub const OUT_RESULT_ARG_NAME: &str = "out-result";
 pub const JOBS_SIZE_ARG_NAME: &str = "jobs-size";
 pub const AREA_SIZE_ARG_NAME: &str = "area-size";
+pub const OUT_MATRIX_ARG_NAME: &str = "out-matrix";
 
 pub fn get_generate_app<'a, 'b>() -> App<'a, 'b> {
     App::new("generate")
@@ -31,7 +32,7 @@ pub fn get_generate_app<'a, 'b>() -> App<'a, 'b> {
         )
         .arg(
             Arg::with_name(OUT_RESULT_ARG_NAME)
-                .help("Specifies path to file for result output")
+                .help("Specifies path to file for result output, use '-' for stdout")
                 .short("o")
                 .long(OUT_RESULT_ARG_NAME)
                 .required(false)
@@ -53,6 +54,14 @@ pub fn get_generate_app<'a, 'b>() -> App<'a, 'b> {
                 .required(true)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(OUT_MATRIX_ARG_NAME)
+                .help("Specifies path to approximated routing matrix output. With multiple profiles, profile name is added to file name")
+                .short("m")
+                .long(OUT_MATRIX_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
 }
 
 pub fn run_generate(matches: &ArgMatches) {
@@ -68,13 +77,22 @@ pub fn run_generate(matches: &ArgMatches) {
             let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
             let out_buffer = create_write_buffer(out_result);
             if let Err(err) = serialize_problem(out_buffer, &problem) {
-                eprintln!("Cannot serialize result problem: '{}'", err);
-                process::exit(1);
+                exit_with_error(ErrorCategory::Output, format!("cannot serialize result problem: '{}'", err))
+            }
+
+            if let Some(out_matrix) = matches.value_of(OUT_MATRIX_ARG_NAME) {
+                let matrices = create_approx_matrices(&problem);
+                let is_single = matrices.len() == 1;
+
+                matrices.iter().for_each(|matrix| {
+                    let out_path = get_matrix_path(out_matrix, matrix, is_single);
+                    let out_buffer = create_write_buffer(Some(create_file(out_path.as_str(), "out matrix")));
+                    if let Err(err) = serialize_matrix(out_buffer, matrix) {
+                        exit_with_error(ErrorCategory::Output, format!("cannot serialize matrix: '{}'", err))
+                    }
+                });
             }
         }
-        Err(err) => {
-            eprintln!("Cannot generate problem: '{}'", err);
-            process::exit(1);
-        }
+        Err(err) => exit_with_error(ErrorCategory::Input, format!("cannot generate problem: '{}'", err)),
     }
 }
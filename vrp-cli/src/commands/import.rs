@@ -1,12 +1,12 @@
 use super::*;
 use std::io::BufReader;
-use std::process;
-use vrp_cli::extensions::import::import_problem;
+use vrp_cli::extensions::import::{get_duplicate_locations, import_problem, merge_duplicate_locations};
 use vrp_pragmatic::format::problem::serialize_problem;
 
 pub const FORMAT_ARG_NAME: &str = "FORMAT";
 pub const INPUT_ARG_NAME: &str = "input-files";
 pub const OUT_RESULT_ARG_NAME: &str = "out-result";
+pub const MERGE_DUPLICATES_ARG_NAME: &str = "merge-duplicates";
 
 pub fn get_import_app<'a, 'b>() -> App<'a, 'b> {
     App::new("import")
@@ -29,12 +29,18 @@ pub fn get_import_app<'a, 'b>() -> App<'a, 'b> {
         )
         .arg(
             Arg::with_name(OUT_RESULT_ARG_NAME)
-                .help("Specifies path to file for result output")
+                .help("Specifies path to file for result output, use '-' for stdout")
                 .short("o")
                 .long(OUT_RESULT_ARG_NAME)
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(MERGE_DUPLICATES_ARG_NAME)
+                .help("Merges jobs with the same location into multi task jobs")
+                .long(MERGE_DUPLICATES_ARG_NAME)
+                .required(false),
+        )
 }
 
 pub fn run_import(matches: &ArgMatches) {
@@ -45,16 +51,31 @@ pub fn run_import(matches: &ArgMatches) {
 
     match import_problem(input_format, input_files) {
         Ok(problem) => {
+            let duplicates = get_duplicate_locations(&problem);
+            if !duplicates.is_empty() {
+                eprintln!("{} location(s) are shared by multiple jobs:", duplicates.len());
+                duplicates.iter().for_each(|duplicate| {
+                    eprintln!(
+                        "  ({}, {}): {}",
+                        duplicate.location.lat,
+                        duplicate.location.lng,
+                        duplicate.job_ids.join(", ")
+                    )
+                });
+            }
+
+            let problem = if matches.is_present(MERGE_DUPLICATES_ARG_NAME) {
+                merge_duplicate_locations(&problem)
+            } else {
+                problem
+            };
+
             let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
             let out_buffer = create_write_buffer(out_result);
             if let Err(err) = serialize_problem(out_buffer, &problem) {
-                eprintln!("Cannot serialize result problem: '{}'", err);
-                process::exit(1);
+                exit_with_error(ErrorCategory::Output, format!("cannot serialize result problem: '{}'", err))
             }
         }
-        Err(err) => {
-            eprintln!("Cannot import problem: '{}'", err);
-            process::exit(1);
-        }
+        Err(err) => exit_with_error(ErrorCategory::Input, format!("cannot import problem: '{}'", err)),
     }
 }
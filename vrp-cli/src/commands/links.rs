@@ -0,0 +1,65 @@
+use super::*;
+use std::io::BufReader;
+use vrp_pragmatic::format::solution::{create_tour_links, deserialize_solution, serialize_tour_links_as_markdown};
+
+pub const FORMAT_ARG_NAME: &str = "FORMAT";
+pub const SOLUTION_ARG_NAME: &str = "solution-file";
+pub const OUT_FORMAT_ARG_NAME: &str = "out-format";
+pub const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_links_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("links")
+        .about("Creates links to routing services which show each tour of the solution on a map")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(SOLUTION_ARG_NAME)
+                .help("Sets solution file, use '-' for stdin")
+                .short("s")
+                .long(SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_FORMAT_ARG_NAME)
+                .help("Specifies links report format")
+                .short("f")
+                .long(OUT_FORMAT_ARG_NAME)
+                .required(false)
+                .possible_values(&["json", "markdown"])
+                .default_value("json"),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for links output, use '-' for stdout")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_links(matches: &ArgMatches) {
+    let solution_file = BufReader::new(open_file(matches.value_of(SOLUTION_ARG_NAME).unwrap(), "solution"));
+    let out_format = matches.value_of(OUT_FORMAT_ARG_NAME).unwrap();
+    let out_buffer =
+        create_write_buffer(matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out links")));
+
+    let solution = deserialize_solution(solution_file)
+        .unwrap_or_else(|err| exit_with_error(ErrorCategory::Input, format!("cannot read solution: '{}'", err)));
+    let links = create_tour_links(&solution);
+
+    let result = match out_format {
+        "markdown" => serialize_tour_links_as_markdown(out_buffer, links.as_slice()).map_err(|err| err.to_string()),
+        _ => serde_json::to_writer_pretty(out_buffer, &links).map_err(|err| err.to_string()),
+    };
+
+    if let Err(err) = result {
+        exit_with_error(ErrorCategory::Output, format!("cannot write links: '{}'", err))
+    }
+}
@@ -0,0 +1,101 @@
+use super::*;
+use std::io::BufReader;
+use std::path::Path;
+use vrp_cli::extensions::matrix::create_matrices;
+use vrp_cli::get_errors_serialized;
+use vrp_pragmatic::format::problem::{deserialize_problem, serialize_matrix, Matrix};
+
+pub const FORMAT_ARG_NAME: &str = "FORMAT";
+pub const PROBLEM_ARG_NAME: &str = "problem-file";
+pub const METHOD_ARG_NAME: &str = "method";
+pub const OSRM_URL_ARG_NAME: &str = "osrm-url";
+pub const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_matrix_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("matrix")
+        .about("Creates routing matrices for problem locations")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(PROBLEM_ARG_NAME)
+                .help("Sets problem file, use '-' for stdin")
+                .short("p")
+                .long(PROBLEM_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(METHOD_ARG_NAME)
+                .help("Specifies method used to calculate routing matrix")
+                .short("m")
+                .long(METHOD_ARG_NAME)
+                .required(false)
+                .possible_values(&["haversine", "osrm"])
+                .default_value("haversine"),
+        )
+        .arg(
+            Arg::with_name(OSRM_URL_ARG_NAME)
+                .help("Specifies OSRM service url, e.g. http://localhost:5000")
+                .long(OSRM_URL_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to matrix output. With multiple profiles, profile name is added to file name")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_matrix(matches: &ArgMatches) {
+    let problem_file = BufReader::new(open_file(matches.value_of(PROBLEM_ARG_NAME).unwrap(), "problem"));
+    let method = matches.value_of(METHOD_ARG_NAME).unwrap();
+    let osrm_url = matches.value_of(OSRM_URL_ARG_NAME);
+    let out_result = matches.value_of(OUT_RESULT_ARG_NAME);
+
+    let result = deserialize_problem(problem_file)
+        .map_err(|errors| get_errors_serialized(&errors))
+        .and_then(|problem| create_matrices(&problem, method, osrm_url));
+
+    match result {
+        Ok(matrices) => {
+            let is_single = matrices.len() == 1;
+            if !is_single && out_result.map_or(false, is_std_stream) {
+                exit_with_error(ErrorCategory::Input, "cannot write multiple matrices to stdout")
+            }
+
+            matrices.iter().for_each(|matrix| {
+                let out_path = out_result.map(|path| get_matrix_path(path, matrix, is_single));
+                let out_buffer = create_write_buffer(out_path.map(|path| create_file(path.as_str(), "out matrix")));
+
+                if let Err(err) = serialize_matrix(out_buffer, matrix) {
+                    exit_with_error(ErrorCategory::Output, format!("cannot serialize matrix: '{}'", err))
+                }
+            });
+        }
+        Err(err) => exit_with_error(ErrorCategory::Input, format!("cannot create matrix: '{}'", err)),
+    }
+}
+
+pub(crate) fn get_matrix_path(path: &str, matrix: &Matrix, is_single: bool) -> String {
+    if is_single {
+        return path.to_string();
+    }
+
+    let path = Path::new(path);
+    let stem = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or("matrix");
+    let file_name = match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) => format!("{}_{}.{}", stem, matrix.profile, ext),
+        None => format!("{}_{}", stem, matrix.profile),
+    };
+
+    path.with_file_name(file_name).to_string_lossy().to_string()
+}
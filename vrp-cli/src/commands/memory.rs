@@ -0,0 +1,164 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/memory_test.rs"]
+mod memory_test;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::ops::Sub;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use vrp_core::solver::{ProgressEvent, ProgressHook};
+
+static IS_COUNTING_ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+/// A system allocator which counts allocations once counting is enabled.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count_allocation(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count_allocation(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count_allocation(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+fn count_allocation(size: usize) {
+    if IS_COUNTING_ENABLED.load(Ordering::Relaxed) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    }
+}
+
+/// Memory usage at some point of time.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MemorySnapshot {
+    /// Amount of allocations since counting is enabled.
+    pub allocations: usize,
+    /// Amount of allocated bytes since counting is enabled.
+    pub allocated: usize,
+    /// Peak resident set size in kB, if it is known.
+    pub peak_rss: Option<usize>,
+}
+
+impl MemorySnapshot {
+    /// Takes a snapshot of current memory usage.
+    pub fn take() -> Self {
+        Self {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            allocated: ALLOCATED.load(Ordering::Relaxed),
+            peak_rss: get_peak_rss(),
+        }
+    }
+}
+
+impl Sub for MemorySnapshot {
+    type Output = MemorySnapshot;
+
+    /// Returns allocations between two snapshots, peak rss is taken from the latest one.
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            allocations: self.allocations.saturating_sub(other.allocations),
+            allocated: self.allocated.saturating_sub(other.allocated),
+            peak_rss: self.peak_rss,
+        }
+    }
+}
+
+/// A generation number with memory usage after it.
+type GenerationSnapshot = (usize, MemorySnapshot);
+
+/// Tracks memory usage per solving phase: problem reading, construction of initial solutions
+/// together with the first generation, and the rest of generations.
+pub struct MemoryStats {
+    start: MemorySnapshot,
+    problem: Mutex<Option<MemorySnapshot>>,
+    generations: Mutex<Option<(GenerationSnapshot, GenerationSnapshot)>>,
+}
+
+impl MemoryStats {
+    /// Creates a new instance of `MemoryStats` and enables allocations counting.
+    pub fn new() -> Self {
+        IS_COUNTING_ENABLED.store(true, Ordering::Relaxed);
+
+        Self { start: MemorySnapshot::take(), problem: Mutex::new(None), generations: Mutex::new(None) }
+    }
+
+    /// Marks the end of problem reading phase.
+    pub fn record_problem(&self) {
+        *self.problem.lock().unwrap() = Some(MemorySnapshot::take());
+    }
+
+    /// Creates a progress hook which tracks memory usage of generations.
+    pub fn create_progress_hook(self: Arc<Self>) -> ProgressHook {
+        Arc::new(move |event: &ProgressEvent| {
+            let current = (event.generation, MemorySnapshot::take());
+            let mut generations = self.generations.lock().unwrap();
+            *generations = Some(generations.map_or((current, current), |(first, _)| (first, current)));
+        })
+    }
+
+    /// Returns report messages, one per known phase.
+    pub fn get_report(&self) -> Vec<String> {
+        let problem = *self.problem.lock().unwrap();
+        let generations = *self.generations.lock().unwrap();
+
+        let problem_phase = problem.map(|problem| ("problem", problem - self.start));
+        let construction_phase =
+            problem.and_then(|problem| generations.map(|((_, first), _)| ("construction", first - problem)));
+        let generation_phase = generations.filter(|((first, _), (last, _))| last > first).map(
+            |((first_generation, first), (last_generation, last))| {
+                let total = last - first;
+                let count = last_generation - first_generation;
+                let average = MemorySnapshot {
+                    allocations: total.allocations / count,
+                    allocated: total.allocated / count,
+                    peak_rss: total.peak_rss,
+                };
+
+                ("generation average", average)
+            },
+        );
+
+        problem_phase
+            .into_iter()
+            .chain(construction_phase)
+            .chain(generation_phase)
+            .map(|(phase, snapshot)| format_snapshot(phase, &snapshot))
+            .collect()
+    }
+}
+
+fn format_snapshot(phase: &str, snapshot: &MemorySnapshot) -> String {
+    format!(
+        "memory: {}, allocations: {}, allocated: {} bytes, peak rss: {}",
+        phase,
+        snapshot.allocations,
+        snapshot.allocated,
+        snapshot.peak_rss.map_or_else(|| "unknown".to_string(), |peak_rss| format!("{} kB", peak_rss))
+    )
+}
+
+/// Returns peak resident set size of the process in kB. Supported only on linux.
+fn get_peak_rss() -> Option<usize> {
+    std::fs::read_to_string("/proc/self/status").ok().and_then(|status| {
+        status
+            .lines()
+            .find(|line| line.starts_with("VmHWM:"))
+            .and_then(|line| line.split_whitespace().nth(1))
+            .and_then(|value| value.parse().ok())
+    })
+}
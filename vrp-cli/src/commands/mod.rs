@@ -1,32 +1,145 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/commands_test.rs"]
+mod commands_test;
+
 use clap::{App, Arg, ArgMatches, Values};
 
+pub mod analyze;
 pub mod check;
+pub mod compare;
+pub mod convert;
 pub mod generate;
 pub mod import;
+pub mod links;
+pub mod matrix;
+pub mod memory;
+pub mod reduce;
 pub mod solve;
+pub mod timeline;
 
+use flate2::read::MultiGzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::Serialize;
+use std::fmt::Display;
 use std::fs::File;
-use std::io::{stdout, BufWriter, Write};
+use std::io::{stdin, stdout, BufReader, BufWriter, Read, Write};
 use std::process;
 use std::str::FromStr;
+use vrp_cli::extensions::solve::acceptance::AcceptanceReport;
+use vrp_cli::{ApiError, ErrorCategory};
+use vrp_pragmatic::format::FormatError;
 
-fn open_file(path: &str, description: &str) -> File {
-    File::open(path).unwrap_or_else(|err| {
-        eprintln!("Cannot open {} file '{}': '{}'", description, path, err.to_string());
-        process::exit(1);
-    })
+/// A path which refers to standard input or output instead of a file.
+const STD_STREAM_PATH: &str = "-";
+
+/// An extension of files which are transparently compressed or decompressed with gzip.
+const GZIP_EXTENSION: &str = ".gz";
+
+/// A command failure which is written to stderr as a json document. Process exit code is the code
+/// of error category, so orchestration systems can distinguish bad input from solver failures.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CommandError {
+    #[serde(serialize_with = "serialize_error_category")]
+    category: ErrorCategory,
+    exit_code: i32,
+    message: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<FormatError>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    report: Option<AcceptanceReport>,
 }
 
-fn create_file(path: &str, description: &str) -> File {
-    File::create(path).unwrap_or_else(|err| {
-        eprintln!("Cannot create {} file '{}': '{}'", description, path, err.to_string());
-        process::exit(1);
-    })
+impl CommandError {
+    fn new(category: ErrorCategory, message: impl Display) -> Self {
+        Self { category, exit_code: category.code(), message: message.to_string(), errors: vec![], report: None }
+    }
+
+    /// Creates an error from acceptance report of the solution which violates thresholds.
+    fn from_acceptance_report(report: AcceptanceReport) -> Self {
+        let message = format!("solution is not accepted: {}", report.violations.join(", "));
+
+        Self { report: Some(report), ..Self::new(ErrorCategory::Acceptance, message) }
+    }
+
+    /// Prepends context description to the error message.
+    fn with_context(self, context: impl Display) -> Self {
+        Self { message: format!("{}: '{}'", context, self.message), ..self }
+    }
+
+    /// Writes error document to stderr and exits with the code of the error category.
+    fn exit(self) -> ! {
+        eprintln!("{}", serde_json::to_string(&self).unwrap_or_else(|_| self.message.clone()));
+        process::exit(self.exit_code)
+    }
+}
+
+impl From<ApiError> for CommandError {
+    fn from(error: ApiError) -> Self {
+        let message = if error.errors.is_empty() {
+            error.message
+        } else {
+            FormatError::format_many(error.errors.as_slice(), ", ")
+        };
+
+        Self { errors: error.errors, ..Self::new(error.category, message) }
+    }
+}
+
+fn serialize_error_category<S: serde::Serializer>(category: &ErrorCategory, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(category.name())
+}
+
+/// Writes error document to stderr and exits with the code of the error category.
+fn exit_with_error(category: ErrorCategory, message: impl Display) -> ! {
+    CommandError::new(category, message).exit()
+}
+
+fn is_std_stream(path: &str) -> bool {
+    path == STD_STREAM_PATH
+}
+
+fn is_gzip_file(path: &str) -> bool {
+    path.ends_with(GZIP_EXTENSION)
+}
+
+fn open_file(path: &str, description: &str) -> Box<dyn Read> {
+    if is_std_stream(path) {
+        return Box::new(stdin());
+    }
+
+    let file = File::open(path).unwrap_or_else(|err| {
+        exit_with_error(ErrorCategory::Input, format!("cannot open {} file '{}': '{}'", description, path, err))
+    });
+
+    if is_gzip_file(path) {
+        Box::new(MultiGzDecoder::new(BufReader::new(file)))
+    } else {
+        Box::new(file)
+    }
+}
+
+fn create_file(path: &str, description: &str) -> Box<dyn Write + Send> {
+    if is_std_stream(path) {
+        return Box::new(stdout());
+    }
+
+    let file = File::create(path).unwrap_or_else(|err| {
+        exit_with_error(ErrorCategory::Output, format!("cannot create {} file '{}': '{}'", description, path, err))
+    });
+
+    if is_gzip_file(path) {
+        // NOTE gzip stream is finished when encoder is dropped
+        Box::new(GzEncoder::new(file, Compression::default()))
+    } else {
+        Box::new(file)
+    }
 }
 
-fn create_write_buffer(out_file: Option<File>) -> BufWriter<Box<dyn Write>> {
+fn create_write_buffer(out_file: Option<Box<dyn Write + Send>>) -> BufWriter<Box<dyn Write>> {
     if let Some(out_file) = out_file {
-        BufWriter::new(Box::new(out_file))
+        BufWriter::new(out_file)
     } else {
         BufWriter::new(Box::new(stdout()))
     }
@@ -40,10 +153,8 @@ fn parse_float_value<T: FromStr<Err = std::num::ParseFloatError>>(
     arg_desc: &str,
 ) -> Option<T> {
     matches.value_of(arg_name).map(|arg| {
-        arg.parse::<T>().unwrap_or_else(|err| {
-            eprintln!("cannot get {}: '{}'", err.to_string(), arg_desc);
-            process::exit(1);
-        })
+        arg.parse::<T>()
+            .unwrap_or_else(|err| exit_with_error(ErrorCategory::Input, format!("cannot get {}: '{}'", arg_desc, err)))
     })
 }
 
@@ -53,9 +164,7 @@ fn parse_int_value<T: FromStr<Err = std::num::ParseIntError>>(
     arg_desc: &str,
 ) -> Option<T> {
     matches.value_of(arg_name).map(|arg| {
-        arg.parse::<T>().unwrap_or_else(|err| {
-            eprintln!("cannot get {}: '{}'", err.to_string(), arg_desc);
-            process::exit(1);
-        })
+        arg.parse::<T>()
+            .unwrap_or_else(|err| exit_with_error(ErrorCategory::Input, format!("cannot get {}: '{}'", arg_desc, err)))
     })
 }
@@ -0,0 +1,147 @@
+use super::*;
+use std::io::BufReader;
+use vrp_cli::extensions::reduce::{reduce_problem, ReduceMethod};
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, serialize_matrix, serialize_problem};
+use vrp_pragmatic::format::{FormatError, Location};
+
+pub const FORMAT_ARG_NAME: &str = "FORMAT";
+pub const PROBLEM_ARG_NAME: &str = "problem-file";
+pub const MATRIX_ARG_NAME: &str = "matrix";
+pub const JOBS_ARG_NAME: &str = "jobs";
+pub const SEED_ARG_NAME: &str = "seed";
+pub const REGION_ARG_NAME: &str = "region";
+pub const OUT_RESULT_ARG_NAME: &str = "out-result";
+pub const OUT_MATRIX_ARG_NAME: &str = "out-matrix";
+
+pub fn get_reduce_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("reduce")
+        .about("Reduces problem size keeping it valid, e.g. to get a minimal reproduction case")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(PROBLEM_ARG_NAME)
+                .help("Sets problem file, use '-' for stdin")
+                .short("p")
+                .long(PROBLEM_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix")
+                .short("m")
+                .long(MATRIX_ARG_NAME)
+                .multiple(true)
+                .number_of_values(1)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(JOBS_ARG_NAME)
+                .help("Specifies amount of randomly selected jobs to keep")
+                .long(JOBS_ARG_NAME)
+                .required_unless(REGION_ARG_NAME)
+                .conflicts_with(REGION_ARG_NAME)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(SEED_ARG_NAME)
+                .help("Specifies seed used to select jobs randomly")
+                .long(SEED_ARG_NAME)
+                .required(false)
+                .requires(JOBS_ARG_NAME)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(REGION_ARG_NAME)
+                .help("Keeps jobs inside of bounding box specified as min_lat,min_lng,max_lat,max_lng")
+                .long(REGION_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for reduced problem output, use '-' for stdout")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_MATRIX_ARG_NAME)
+                .help("Specifies path to file for reduced routing matrix output, one per each matrix in the same order")
+                .long(OUT_MATRIX_ARG_NAME)
+                .multiple(true)
+                .number_of_values(1)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_reduce(matches: &ArgMatches) {
+    let matrix_paths = matches.values_of(MATRIX_ARG_NAME).map_or_else(Vec::new, |paths: Values| paths.collect());
+    let out_matrix_paths =
+        matches.values_of(OUT_MATRIX_ARG_NAME).map_or_else(Vec::new, |paths: Values| paths.collect());
+
+    if matrix_paths.len() != out_matrix_paths.len() {
+        exit_with_error(ErrorCategory::Input, "amount of output matrix files should be equal to amount of matrix files")
+    }
+
+    let method = get_reduce_method(matches);
+
+    let problem =
+        deserialize_problem(BufReader::new(open_file(matches.value_of(PROBLEM_ARG_NAME).unwrap(), "problem")))
+            .unwrap_or_else(|errors| {
+                CommandError::from(ApiError::from_format_errors(errors)).with_context("cannot read problem").exit()
+            });
+
+    let matrices = matrix_paths
+        .iter()
+        .map(|path| {
+            deserialize_matrix(BufReader::new(open_file(path, "routing matrix"))).unwrap_or_else(|errors| {
+                exit_with_error(
+                    ErrorCategory::Input,
+                    format!("cannot read matrix: '{}'", FormatError::format_many(&errors, ",")),
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let (problem, matrices) = reduce_problem(&problem, matrices.as_slice(), &method)
+        .unwrap_or_else(|err| exit_with_error(ErrorCategory::Input, format!("cannot reduce problem: '{}'", err)));
+
+    let out_buffer =
+        create_write_buffer(matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result")));
+    serialize_problem(out_buffer, &problem).unwrap_or_else(|err| {
+        exit_with_error(ErrorCategory::Output, format!("cannot serialize reduced problem: '{}'", err))
+    });
+
+    out_matrix_paths.iter().zip(matrices.iter()).for_each(|(path, matrix)| {
+        serialize_matrix(create_write_buffer(Some(create_file(path, "out matrix"))), matrix).unwrap_or_else(|err| {
+            exit_with_error(ErrorCategory::Output, format!("cannot serialize reduced matrix: '{}'", err))
+        });
+    });
+}
+
+fn get_reduce_method(matches: &ArgMatches) -> ReduceMethod {
+    if let Some(jobs) = parse_int_value::<usize>(matches, JOBS_ARG_NAME, "amount of jobs") {
+        return ReduceMethod::Sample { jobs, seed: parse_int_value::<u64>(matches, SEED_ARG_NAME, "seed") };
+    }
+
+    let region = matches.value_of(REGION_ARG_NAME).unwrap();
+    if let [min_lat, min_lng, max_lat, max_lng] =
+        region.split(',').filter_map(|value| value.trim().parse::<f64>().ok()).collect::<Vec<_>>().as_slice()
+    {
+        ReduceMethod::Region { min: Location::new(*min_lat, *min_lng), max: Location::new(*max_lat, *max_lng) }
+    } else {
+        exit_with_error(
+            ErrorCategory::Input,
+            format!("cannot parse region '{}': expected min_lat,min_lng,max_lat,max_lng", region),
+        )
+    }
+}
@@ -1,17 +1,42 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/solve_test.rs"]
+mod solve_test;
+
+use super::memory::MemoryStats;
 use super::*;
 
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufReader, BufWriter, Write};
-use std::process;
-use std::sync::Arc;
-use vrp_cli::extensions::solve::config::create_builder_from_config_file;
-use vrp_cli::{get_errors_serialized, get_locations_serialized};
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufReader, BufWriter, Cursor, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use vrp_cli::extensions::matrix::{create_matrices, format_locations};
+use vrp_cli::extensions::plugin::add_plugin_modules;
+use vrp_cli::extensions::solve::acceptance::check_acceptance;
+use vrp_cli::extensions::solve::batch::*;
+use vrp_cli::extensions::solve::config::{read_config, Preset};
+use vrp_cli::extensions::solve::dashboard::create_dashboard_hook;
+use vrp_cli::extensions::solve::options::{apply_options_to_problem, create_builder_from_options, SolveOptions};
+use vrp_cli::extensions::solve::profiler::*;
+use vrp_cli::extensions::solve::route_summary::write_route_summary;
+use vrp_cli::extensions::solve::scenarios::{read_scenarios, solve_scenarios};
+use vrp_cli::extensions::solve::script::add_script_module;
+use vrp_cli::extensions::solve::zones::Strategy;
+use vrp_cli::{catch_panic, get_errors_serialized, get_locations_serialized};
 use vrp_core::models::{Problem, Solution};
-use vrp_core::solver::Builder;
-use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem};
-use vrp_pragmatic::format::solution::PragmaticSolution;
+use vrp_core::solver::{Builder, ImprovementHook, InsertionHeatmap, Logger, ParetoArchive, ProgressEvent};
+use vrp_core::utils::init_thread_pool;
+use vrp_pragmatic::format::problem::{
+    deserialize_matrix, deserialize_problem, serialize_matrix, serialize_problem, Matrix, PragmaticProblem,
+    Problem as ApiProblem,
+};
+use vrp_pragmatic::format::solution::{
+    create_solution, create_unassignable_jobs, read_init_solution as read_pragmatic_init_solution,
+    serialize_heatmap_as_geojson, serialize_solution, Extras, PragmaticSolution, Solution as ApiSolution,
+};
+use vrp_pragmatic::get_unique_locations;
 use vrp_scientific::common::read_init_solution;
+use vrp_scientific::common::write_dimacs_solution;
 use vrp_scientific::lilim::{LilimProblem, LilimSolution};
 use vrp_scientific::solomon::{SolomonProblem, SolomonSolution};
 
@@ -22,74 +47,128 @@ const GENERATIONS_ARG_NAME: &str = "max-generations";
 const TIME_ARG_NAME: &str = "max-time";
 const COST_VARIATION_ARG_NAME: &str = "cost-variation";
 const GEO_JSON_ARG_NAME: &str = "geo-json";
+const HEATMAP_ARG_NAME: &str = "heatmap";
 
 const INIT_SOLUTION_ARG_NAME: &str = "init-solution";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
 const GET_LOCATIONS_ARG_NAME: &str = "get-locations";
 const CONFIG_ARG_NAME: &str = "config";
+const LOW_MEMORY_ARG_NAME: &str = "low-memory";
+const SPARSE_INDEX_ARG_NAME: &str = "sparse-index";
+const SEED_ARG_NAME: &str = "seed";
+const MODE_ARG_NAME: &str = "mode";
+const GET_UNASSIGNABLE_ARG_NAME: &str = "get-unassignable";
+const PARETO_ARG_NAME: &str = "pareto";
+const SCENARIOS_ARG_NAME: &str = "scenarios";
+const PLUGIN_ARG_NAME: &str = "plugin";
+const LOG_ARG_NAME: &str = "log";
+const LOG_FILE_ARG_NAME: &str = "log-file";
+const INPUT_DIR_ARG_NAME: &str = "input-dir";
+const OUTPUT_DIR_ARG_NAME: &str = "output-dir";
+const OSRM_URL_ARG_NAME: &str = "osrm-url";
+const APPROXIMATE_MATRIX_ARG_NAME: &str = "approximate-matrix";
+const DUMP_INTERVAL_ARG_NAME: &str = "dump-interval";
+const DUMP_DIR_ARG_NAME: &str = "dump-dir";
+const PARALLELISM_ARG_NAME: &str = "parallelism";
+const TUI_ARG_NAME: &str = "tui";
+const OUTPUT_FORMAT_ARG_NAME: &str = "output-format";
+const PROVIDER_ARG_NAME: &str = "provider";
+const TAG_ARG_NAME: &str = "tag";
+const MEMORY_STATS_ARG_NAME: &str = "memory-stats";
+const PROFILE_CONSTRAINTS_ARG_NAME: &str = "profile-constraints";
+const STRATEGY_ARG_NAME: &str = "strategy";
+
+const PARETO_ARCHIVE_SIZE: usize = 10;
+const MAX_DUMPS: usize = 5;
 
-struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, String>>);
+struct ProblemReader(pub Box<dyn Fn(Box<dyn Read>, Option<Vec<Box<dyn Read>>>) -> Result<Problem, CommandError>>);
 
-struct InitSolutionReader(pub Box<dyn Fn(File, Arc<Problem>) -> Option<Solution>>);
+struct InitSolutionReader(pub Box<dyn Fn(Box<dyn Read>, Arc<Problem>) -> Option<Solution>>);
 
 struct SolutionWriter(
     pub  Box<
-        dyn Fn(&Problem, Solution, BufWriter<Box<dyn Write>>, Option<BufWriter<Box<dyn Write>>>) -> Result<(), String>,
+        dyn Fn(
+            &Problem,
+            Solution,
+            &[(String, String)],
+            BufWriter<Box<dyn Write>>,
+            Option<BufWriter<Box<dyn Write>>>,
+        ) -> Result<(), String>,
     >,
 );
 
-struct LocationWriter(pub Box<dyn Fn(File, BufWriter<Box<dyn Write>>) -> Result<(), String>>);
+struct LocationWriter(pub Box<dyn Fn(Box<dyn Read>, Option<&str>, BufWriter<Box<dyn Write>>) -> Result<(), String>>);
 
 fn get_formats<'a>() -> HashMap<&'a str, (ProblemReader, InitSolutionReader, SolutionWriter, LocationWriter)> {
     vec![
         (
             "solomon",
             (
-                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
+                ProblemReader(Box::new(|problem: Box<dyn Read>, matrices: Option<Vec<Box<dyn Read>>>| {
                     assert!(matrices.is_none());
-                    BufReader::new(problem).read_solomon()
+                    BufReader::new(problem)
+                        .read_solomon()
+                        .map_err(|err| CommandError::new(ErrorCategory::Deserialization, err))
                 })),
                 InitSolutionReader(Box::new(|file, problem| read_init_solution(BufReader::new(file), problem).ok())),
-                SolutionWriter(Box::new(|_, solution, writer, _| solution.write_solomon(writer))),
-                LocationWriter(Box::new(|_, _| unimplemented!())),
+                SolutionWriter(Box::new(|_, solution, _, writer, _| solution.write_solomon(writer))),
+                LocationWriter(Box::new(|_, _, _| unimplemented!())),
             ),
         ),
         (
             "lilim",
             (
-                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
+                ProblemReader(Box::new(|problem: Box<dyn Read>, matrices: Option<Vec<Box<dyn Read>>>| {
                     assert!(matrices.is_none());
-                    BufReader::new(problem).read_lilim()
+                    BufReader::new(problem)
+                        .read_lilim()
+                        .map_err(|err| CommandError::new(ErrorCategory::Deserialization, err))
                 })),
                 InitSolutionReader(Box::new(|_file, _problem| None)),
-                SolutionWriter(Box::new(|_, solution, writer, _| solution.write_lilim(writer))),
-                LocationWriter(Box::new(|_, _| unimplemented!())),
+                SolutionWriter(Box::new(|_, solution, _, writer, _| solution.write_lilim(writer))),
+                LocationWriter(Box::new(|_, _, _| unimplemented!())),
             ),
         ),
         (
             "pragmatic",
             (
-                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
+                ProblemReader(Box::new(|problem: Box<dyn Read>, matrices: Option<Vec<Box<dyn Read>>>| {
                     if let Some(matrices) = matrices {
                         let matrices = matrices.into_iter().map(|m| BufReader::new(m)).collect();
                         (BufReader::new(problem), matrices).read_pragmatic()
                     } else {
-                        println!("configured to use single approximated routing matrix");
+                        eprintln!("configured to use single approximated routing matrix");
                         BufReader::new(problem).read_pragmatic()
                     }
-                    .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n"))
+                    .map_err(|errors| ApiError::from_format_errors(errors).into())
                 })),
-                InitSolutionReader(Box::new(|_file, _problem| None)),
-                SolutionWriter(Box::new(|problem, solution, default_writer, geojson_writer| {
+                InitSolutionReader(Box::new(|file, problem| {
+                    read_pragmatic_init_solution(BufReader::new(file), problem)
+                        .map_err(|err| eprintln!("cannot read initial solution: '{}'", err))
+                        .ok()
+                })),
+                SolutionWriter(Box::new(|problem, solution, tags, default_writer, geojson_writer| {
                     geojson_writer
                         .map_or(Ok(()), |geojson_writer| solution.write_geo_json(problem, geojson_writer))
-                        .and_then(|_| solution.write_pragmatic_json(problem, default_writer))
+                        .and_then(|_| {
+                            if tags.is_empty() {
+                                solution.write_pragmatic_json(problem, default_writer)
+                            } else {
+                                let extras = Extras { performance: vec![], tags: Some(tags.iter().cloned().collect()) };
+                                let solution =
+                                    ApiSolution { extras: Some(extras), ..create_solution(problem, &solution) };
+                                serialize_solution(default_writer, &solution).map_err(|err| err.to_string())
+                            }
+                        })
                 })),
-                LocationWriter(Box::new(|problem, writer| {
+                LocationWriter(Box::new(|problem, provider, writer| {
                     let mut writer = writer;
                     deserialize_problem(BufReader::new(problem))
                         .map_err(|errors| get_errors_serialized(&errors))
-                        .and_then(|problem| get_locations_serialized(&problem))
+                        .and_then(|problem| match provider {
+                            Some(provider) => format_locations(&get_unique_locations(&problem), provider),
+                            None => get_locations_serialized(&problem),
+                        })
                         .and_then(|locations| writer.write_all(locations.as_bytes()).map_err(|err| err.to_string()))
                 })),
             ),
@@ -109,10 +188,16 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .possible_values(&["solomon", "lilim", "pragmatic"])
                 .index(1),
         )
-        .arg(Arg::with_name(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+        .arg(
+            Arg::with_name(PROBLEM_ARG_NAME)
+                .help("Sets the problem file to use, use '-' for stdin")
+                .required_unless(INPUT_DIR_ARG_NAME)
+                .conflicts_with(INPUT_DIR_ARG_NAME)
+                .index(2),
+        )
         .arg(
             Arg::with_name(GENERATIONS_ARG_NAME)
-                .help("Specifies maximum number of generations")
+                .help("Specifies maximum number of generations, overrides config and preset settings")
                 .short("n")
                 .long(GENERATIONS_ARG_NAME)
                 .required(false)
@@ -120,7 +205,7 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
         )
         .arg(
             Arg::with_name(TIME_ARG_NAME)
-                .help("Specifies max time algorithm run in seconds")
+                .help("Specifies max time algorithm run in seconds, overrides config and preset settings")
                 .short("t")
                 .long(TIME_ARG_NAME)
                 .required(false)
@@ -144,16 +229,37 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
         )
         .arg(
             Arg::with_name(MATRIX_ARG_NAME)
-                .help("Specifies path to file with routing matrix")
+                .help("Specifies path to file with routing matrix, use '-' for stdin")
                 .short("m")
                 .long(MATRIX_ARG_NAME)
                 .multiple(true)
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(OSRM_URL_ARG_NAME)
+                .help("Specifies OSRM service url to request routing matrices from, e.g. http://localhost:5000 (pragmatic only)")
+                .long(OSRM_URL_ARG_NAME)
+                .required(false)
+                .conflicts_with(MATRIX_ARG_NAME)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(APPROXIMATE_MATRIX_ARG_NAME)
+                .help(
+                    "Approximates routing matrices using great-circle distances and optional speed in km/h, \
+                     e.g. --approximate-matrix=40 (pragmatic only)",
+                )
+                .long(APPROXIMATE_MATRIX_ARG_NAME)
+                .required(false)
+                .conflicts_with_all(&[MATRIX_ARG_NAME, OSRM_URL_ARG_NAME])
+                .takes_value(true)
+                .min_values(0)
+                .require_equals(true),
+        )
         .arg(
             Arg::with_name(OUT_RESULT_ARG_NAME)
-                .help("Specifies path to file for result output")
+                .help("Specifies path to file for result output, use '-' for stdout")
                 .short("o")
                 .long(OUT_RESULT_ARG_NAME)
                 .required(false)
@@ -166,11 +272,46 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .long(GET_LOCATIONS_ARG_NAME)
                 .required(false),
         )
+        .arg(
+            Arg::with_name(PROVIDER_ARG_NAME)
+                .help("Formats unique locations as a request to routing matrix service of the provider (pragmatic only)")
+                .long(PROVIDER_ARG_NAME)
+                .required(false)
+                .requires(GET_LOCATIONS_ARG_NAME)
+                .possible_values(&["osrm", "here", "google"])
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name(GEO_JSON_ARG_NAME)
                 .help("Specifies path to solution output in geo json format")
                 .short("g")
                 .long(GEO_JSON_ARG_NAME)
+                .visible_alias("geojson")
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUTPUT_FORMAT_ARG_NAME)
+                .help(
+                    "Specifies solution output format: full json, per route summary in csv (pragmatic only) or \
+                     DIMACS challenge submission format (solomon and lilim only)",
+                )
+                .long(OUTPUT_FORMAT_ARG_NAME)
+                .required(false)
+                .possible_values(&["json", "csv", "dimacs"])
+                .default_value("json"),
+        )
+        .arg(
+            Arg::with_name(HEATMAP_ARG_NAME)
+                .help("Specifies path to insertion failures heatmap output in geo json format (pragmatic only)")
+                .long(HEATMAP_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(PARETO_ARG_NAME)
+                .help("Specifies path to output of non-dominated solutions over cost, tours and unassigned jobs (pragmatic only)")
+                .long(PARETO_ARG_NAME)
                 .required(false)
                 .takes_value(true),
         )
@@ -182,6 +323,162 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(LOW_MEMORY_ARG_NAME)
+                .help("Reduces memory usage on large problems at the cost of solution quality")
+                .long(LOW_MEMORY_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name(SPARSE_INDEX_ARG_NAME)
+                .help(
+                    "Specifies sparse neighbourhood index used in low memory mode in form \"jobs_threshold,neighbours\"",
+                )
+                .long(SPARSE_INDEX_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(SEED_ARG_NAME)
+                .help("Specifies random seed to get reproducible results, overrides seed from config")
+                .long(SEED_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(PARALLELISM_ARG_NAME)
+                .help("Specifies amount of threads used by solver, by default all available cores are used")
+                .long(PARALLELISM_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(MODE_ARG_NAME)
+                .help("Specifies solving quality preset: quick returns a plan fast without full refinement")
+                .long(MODE_ARG_NAME)
+                .required(false)
+                .possible_values(&["quick", "normal", "thorough"])
+                .default_value("normal"),
+        )
+        .arg(
+            Arg::with_name(STRATEGY_ARG_NAME)
+                .help("Specifies solving strategy: cluster-first assigns jobs to vehicles before sequencing them (pragmatic only)")
+                .long(STRATEGY_ARG_NAME)
+                .required(false)
+                .possible_values(&["default", "cluster-first"])
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(GET_UNASSIGNABLE_ARG_NAME)
+                .help("Returns list of jobs which cannot be assigned to any vehicle without solving (pragmatic only)")
+                .long(GET_UNASSIGNABLE_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name(PLUGIN_ARG_NAME)
+                .help("Specifies path to shared library with custom constraints")
+                .long(PLUGIN_ARG_NAME)
+                .multiple(true)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(LOG_ARG_NAME)
+                .help("Prints generation number, best cost, unassigned jobs and elapsed time to stderr after each generation")
+                .long(LOG_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name(TUI_ARG_NAME)
+                .help("Shows live dashboard with solver progress in terminal (stderr) instead of log messages")
+                .long(TUI_ARG_NAME)
+                .required(false)
+                .conflicts_with(LOG_ARG_NAME),
+        )
+        .arg(
+            Arg::with_name(LOG_FILE_ARG_NAME)
+                .help("Specifies path to file for progress log output instead of stderr")
+                .long(LOG_FILE_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(INPUT_DIR_ARG_NAME)
+                .help("Specifies path to directory with problem files to be solved one by one")
+                .long(INPUT_DIR_ARG_NAME)
+                .required(false)
+                .requires(OUTPUT_DIR_ARG_NAME)
+                .conflicts_with_all(&[
+                    INIT_SOLUTION_ARG_NAME,
+                    MATRIX_ARG_NAME,
+                    OSRM_URL_ARG_NAME,
+                    APPROXIMATE_MATRIX_ARG_NAME,
+                    OUT_RESULT_ARG_NAME,
+                    GET_LOCATIONS_ARG_NAME,
+                    GEO_JSON_ARG_NAME,
+                    HEATMAP_ARG_NAME,
+                    PARETO_ARG_NAME,
+                    GET_UNASSIGNABLE_ARG_NAME,
+                    SCENARIOS_ARG_NAME,
+                    OUTPUT_FORMAT_ARG_NAME,
+                    MEMORY_STATS_ARG_NAME,
+                    PROFILE_CONSTRAINTS_ARG_NAME,
+                ])
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUTPUT_DIR_ARG_NAME)
+                .help("Specifies path to directory for solutions of problems from input directory and their summary")
+                .long(OUTPUT_DIR_ARG_NAME)
+                .required(false)
+                .requires(INPUT_DIR_ARG_NAME)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(DUMP_INTERVAL_ARG_NAME)
+                .help("Specifies amount of generations between dumps of the best known solution (pragmatic only)")
+                .long(DUMP_INTERVAL_ARG_NAME)
+                .required(false)
+                .requires(DUMP_DIR_ARG_NAME)
+                .conflicts_with_all(&[INPUT_DIR_ARG_NAME, SCENARIOS_ARG_NAME])
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(DUMP_DIR_ARG_NAME)
+                .help("Specifies path to directory where intermediate solutions are dumped")
+                .long(DUMP_DIR_ARG_NAME)
+                .required(false)
+                .requires(DUMP_INTERVAL_ARG_NAME)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(TAG_ARG_NAME)
+                .help("Specifies tag in key=value format which is added to progress log and solution extras (pragmatic only)")
+                .long(TAG_ARG_NAME)
+                .required(false)
+                .multiple(true)
+                .number_of_values(1)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(MEMORY_STATS_ARG_NAME)
+                .help("Reports allocations and peak memory usage per solving phase to progress log or stderr")
+                .long(MEMORY_STATS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name(PROFILE_CONSTRAINTS_ARG_NAME)
+                .help("Reports time spent per constraint module ranked from the slowest to progress log or stderr")
+                .long(PROFILE_CONSTRAINTS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name(SCENARIOS_ARG_NAME)
+                .help("Specifies path to file with fleet variations to solve and compare with the original plan (pragmatic only)")
+                .long(SCENARIOS_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
 }
 
 /// Runs solver commands.
@@ -189,32 +486,215 @@ pub fn run_solve(matches: &ArgMatches) {
     let formats = get_formats();
 
     // required
-    let problem_path = matches.value_of(PROBLEM_ARG_NAME).unwrap();
     let problem_format = matches.value_of(FORMAT_ARG_NAME).unwrap();
-    let problem_file = open_file(problem_path, "problem");
 
     // optional
-    let max_generations = parse_int_value::<usize>(matches, GENERATIONS_ARG_NAME, "max generations");
-    let max_time = parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time");
-
-    let cost_variation = matches.value_of(COST_VARIATION_ARG_NAME).map(|arg| {
-        if let [sample, threshold] =
-            arg.split(',').filter_map(|line| line.parse::<f64>().ok()).collect::<Vec<_>>().as_slice()
-        {
-            (*sample as usize, *threshold)
-        } else {
-            eprintln!("cannot parse cost variation");
-            process::exit(1);
+    let options = get_solve_options(matches);
+    if let Some(parallelism) = options.parallelism {
+        if parallelism == 0 {
+            exit_with_error(ErrorCategory::Input, "parallelism should be greater than zero")
         }
-    });
+        init_thread_pool(parallelism).unwrap_or_else(|err| exit_with_error(ErrorCategory::Input, err));
+    }
+
     let init_solution = matches.value_of(INIT_SOLUTION_ARG_NAME).map(|path| open_file(path, "init solution"));
-    let config = matches.value_of(CONFIG_ARG_NAME).map(|path| open_file(path, "config"));
+    let config = matches.value_of(CONFIG_ARG_NAME).map(|path| {
+        read_config(BufReader::new(open_file(path, "config")))
+            .unwrap_or_else(|err| exit_with_error(ErrorCategory::Input, format!("cannot read config: '{}'", err)))
+    });
     let matrix_files = matches
         .values_of(MATRIX_ARG_NAME)
         .map(|paths: Values| paths.map(|path| open_file(path, "routing matrix")).collect());
     let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out solution"));
+    // NOTE solver messages are sent to stderr when result is written to stdout, so it can be piped
+    let is_out_stdout =
+        matches.value_of(OUT_RESULT_ARG_NAME).map_or(true, is_std_stream) && !matches.is_present(INPUT_DIR_ARG_NAME);
+    let is_csv_output_set = matches.value_of(OUTPUT_FORMAT_ARG_NAME) == Some("csv");
+    if is_csv_output_set && problem_format != "pragmatic" {
+        exit_with_error(ErrorCategory::Input, "csv output format is supported only for pragmatic format")
+    }
+    let is_dimacs_output_set = matches.value_of(OUTPUT_FORMAT_ARG_NAME) == Some("dimacs");
+    if is_dimacs_output_set && problem_format == "pragmatic" {
+        exit_with_error(ErrorCategory::Input, "dimacs output format is supported only for solomon and lilim formats")
+    }
     let out_geojson = matches.value_of(GEO_JSON_ARG_NAME).map(|path| create_file(path, "out geojson"));
+    let is_low_memory_set = options.low_memory.unwrap_or(false);
+    let out_heatmap = matches.value_of(HEATMAP_ARG_NAME).map(|path| {
+        if problem_format != "pragmatic" {
+            exit_with_error(ErrorCategory::Input, "heatmap is supported only for pragmatic format")
+        }
+        if is_low_memory_set {
+            exit_with_error(ErrorCategory::Input, "heatmap is not supported in low memory mode")
+        }
+        create_file(path, "out heatmap")
+    });
+    let out_pareto = matches.value_of(PARETO_ARG_NAME).map(|path| {
+        if problem_format != "pragmatic" {
+            exit_with_error(ErrorCategory::Input, "pareto output is supported only for pragmatic format")
+        }
+        if is_low_memory_set {
+            exit_with_error(ErrorCategory::Input, "pareto output is not supported in low memory mode")
+        }
+        create_file(path, "out pareto")
+    });
+    let plugin_paths = matches.values_of(PLUGIN_ARG_NAME).map_or_else(Vec::new, |paths: Values| paths.collect());
     let is_get_locations_set = matches.is_present(GET_LOCATIONS_ARG_NAME);
+    if options.strategy == Some(Strategy::ClusterFirst) && problem_format != "pragmatic" {
+        exit_with_error(ErrorCategory::Input, "cluster-first strategy is supported only for pragmatic format")
+    }
+    let is_get_unassignable_set = matches.is_present(GET_UNASSIGNABLE_ARG_NAME);
+    if is_get_unassignable_set && problem_format != "pragmatic" {
+        exit_with_error(ErrorCategory::Input, "unassignable jobs analysis is supported only for pragmatic format")
+    }
+    let scenarios = matches.value_of(SCENARIOS_ARG_NAME).map(|path| {
+        if problem_format != "pragmatic" {
+            exit_with_error(ErrorCategory::Input, "scenarios are supported only for pragmatic format")
+        }
+        read_scenarios(BufReader::new(open_file(path, "scenarios")))
+            .unwrap_or_else(|err| exit_with_error(ErrorCategory::Input, format!("cannot read scenarios: '{}'", err)))
+    });
+    let dump = parse_int_value::<usize>(matches, DUMP_INTERVAL_ARG_NAME, "dump interval").map(|interval| {
+        if problem_format != "pragmatic" {
+            exit_with_error(
+                ErrorCategory::Input,
+                "dump of intermediate solutions is supported only for pragmatic format",
+            )
+        }
+        if interval == 0 {
+            exit_with_error(ErrorCategory::Input, "dump interval should be greater than zero")
+        }
+        let dump_dir = Path::new(matches.value_of(DUMP_DIR_ARG_NAME).unwrap()).to_path_buf();
+        std::fs::create_dir_all(&dump_dir).unwrap_or_else(|err| {
+            exit_with_error(
+                ErrorCategory::Output,
+                format!("cannot create dump directory '{}': '{}'", dump_dir.display(), err),
+            )
+        });
+        (interval, dump_dir)
+    });
+    let tags = get_tags(matches);
+    let progress_logger: Option<Logger> = match (matches.is_present(LOG_ARG_NAME), matches.value_of(LOG_FILE_ARG_NAME))
+    {
+        (_, Some(path)) => {
+            let log_file = Mutex::new(create_file(path, "log file"));
+            Some(Arc::new(move |msg: String| {
+                let _ = writeln!(log_file.lock().unwrap(), "{}", msg);
+            }))
+        }
+        (true, None) => Some(Arc::new(|msg: String| eprintln!("{}", msg))),
+        (false, None) => None,
+    };
+    let progress_logger = progress_logger.map(|logger| add_tags_to_logger(logger, tags.as_slice()));
+    let memory_stats =
+        if matches.is_present(MEMORY_STATS_ARG_NAME) { Some(Arc::new(MemoryStats::new())) } else { None };
+    let is_profile_constraints_set = matches.is_present(PROFILE_CONSTRAINTS_ARG_NAME);
+    let is_tui_set = matches.is_present(TUI_ARG_NAME);
+    let create_builder = || -> Builder {
+        // NOTE options from arguments override the ones from config or preset
+        let builder = create_builder_from_options(config.as_ref(), &options).unwrap_or_else(|err| {
+            exit_with_error(ErrorCategory::Input, format!("cannot create solver from config: '{}'", err))
+        });
+
+        let builder =
+            if is_out_stdout { builder.with_logger(Arc::new(|msg: String| eprintln!("{}", msg))) } else { builder };
+        let builder = if is_tui_set { builder.with_logger(Arc::new(|_: String| {})) } else { builder };
+
+        let progress_hooks = if is_tui_set { Some(create_dashboard_hook(std::io::stderr())) } else { None }
+            .into_iter()
+            .chain(memory_stats.clone().map(|memory_stats| memory_stats.create_progress_hook()))
+            .collect::<Vec<_>>();
+        let builder = if progress_hooks.is_empty() {
+            builder
+        } else {
+            builder.with_progress_hook(Arc::new(move |event: &ProgressEvent| {
+                progress_hooks.iter().for_each(|hook| hook(event))
+            }))
+        };
+
+        match progress_logger.as_ref() {
+            Some(logger) => builder.with_progress_logger(logger.clone()),
+            None => builder,
+        }
+    };
+
+    let add_modules = |problem: Problem| {
+        add_plugin_modules(problem, plugin_paths.as_slice())
+            .and_then(|problem| match config.as_ref().and_then(|config| config.script()) {
+                Some(script) => add_script_module(
+                    problem,
+                    script,
+                    progress_logger.clone().unwrap_or_else(|| Arc::new(|msg| eprintln!("{}", msg))),
+                ),
+                None => Ok(problem),
+            })
+            .map_err(|err| CommandError::new(ErrorCategory::Input, err))
+    };
+
+    if let (Some(input_dir), Some(output_dir)) =
+        (matches.value_of(INPUT_DIR_ARG_NAME), matches.value_of(OUTPUT_DIR_ARG_NAME))
+    {
+        let (problem_reader, _, solution_writer, _) = formats
+            .get(problem_format)
+            .unwrap_or_else(|| exit_with_error(ErrorCategory::Input, format!("unknown format: '{}'", problem_format)));
+
+        solve_batch(Path::new(input_dir), Path::new(output_dir), |path| {
+            let problem_file = get_problem_input(open_file(path.to_str().unwrap_or_default(), "problem"), &options)?;
+            let problem = problem_reader.0(problem_file, None)
+                .and_then(|problem| add_modules(problem))
+                .map_err(|err| err.message)?;
+            let problem = Arc::new(problem);
+
+            let (solution, cost) = create_builder()
+                .with_problem(problem.clone())
+                .build()
+                .and_then(|solver| catch_panic(|| solver.solve()))?;
+            let (tours, unassigned) = (solution.routes.len(), solution.unassigned.len());
+
+            let out_path = Path::new(output_dir).join(path.file_name().unwrap_or_default());
+            let out_buffer =
+                create_write_buffer(Some(create_file(out_path.to_str().unwrap_or_default(), "out solution")));
+            solution_writer.0(&problem, solution, tags.as_slice(), out_buffer, None)?;
+
+            Ok((cost, tours, unassigned))
+        });
+
+        return;
+    }
+
+    let problem_path = matches.value_of(PROBLEM_ARG_NAME).unwrap();
+    let problem_file = get_problem_input(open_file(problem_path, "problem"), &options)
+        .unwrap_or_else(|err| exit_with_error(ErrorCategory::Input, format!("cannot apply solve strategy: '{}'", err)));
+
+    let (problem_file, matrix_files) = match matches.value_of(OSRM_URL_ARG_NAME) {
+        Some(_) if problem_format != "pragmatic" => {
+            exit_with_error(ErrorCategory::Input, "osrm routing matrices are supported only for pragmatic format")
+        }
+        Some(osrm_url) => get_matrix_inputs(problem_file, |problem| create_matrices(problem, "osrm", Some(osrm_url)))
+            .unwrap_or_else(|err| {
+                exit_with_error(ErrorCategory::Input, format!("cannot get routing matrices from OSRM: '{}'", err))
+            }),
+        None => (problem_file, matrix_files),
+    };
+
+    let (problem_file, matrix_files) = if matches.is_present(APPROXIMATE_MATRIX_ARG_NAME) {
+        if problem_format != "pragmatic" {
+            exit_with_error(
+                ErrorCategory::Input,
+                "approximated routing matrices are supported only for pragmatic format",
+            )
+        }
+
+        let speed = parse_float_value::<f64>(matches, APPROXIMATE_MATRIX_ARG_NAME, "approximation speed");
+        if speed.iter().any(|&speed| speed <= 0.) {
+            exit_with_error(ErrorCategory::Input, "approximation speed should be greater than zero")
+        }
+
+        get_matrix_inputs(problem_file, |problem| get_approx_matrices(problem, speed)).unwrap_or_else(|err| {
+            exit_with_error(ErrorCategory::Input, format!("cannot approximate routing matrices: '{}'", err))
+        })
+    } else {
+        (problem_file, matrix_files)
+    };
 
     match formats.get(problem_format) {
         Some((problem_reader, init_reader, solution_writer, locations_writer)) => {
@@ -222,50 +702,384 @@ pub fn run_solve(matches: &ArgMatches) {
             let geo_buffer = out_geojson.map(|geojson| create_write_buffer(Some(geojson)));
 
             if is_get_locations_set {
-                locations_writer.0(problem_file, out_buffer).unwrap_or_else(|err| {
-                    eprintln!("cannot get locations '{}'", err);
-                    process::exit(1);
+                locations_writer.0(problem_file, matches.value_of(PROVIDER_ARG_NAME), out_buffer).unwrap_or_else(
+                    |err| exit_with_error(ErrorCategory::Input, format!("cannot get locations '{}'", err)),
+                );
+            } else if let Some(scenarios) = scenarios {
+                let problem = deserialize_problem(BufReader::new(problem_file)).unwrap_or_else(|errors| {
+                    CommandError::from(ApiError::from_format_errors(errors)).with_context("cannot read problem").exit()
+                });
+                let matrices = matrix_files.map(|files: Vec<Box<dyn Read>>| {
+                    files
+                        .into_iter()
+                        .map(|file| {
+                            deserialize_matrix(BufReader::new(file)).unwrap_or_else(|errors| {
+                                CommandError::from(ApiError::from_format_errors(errors))
+                                    .with_context("cannot read matrix")
+                                    .exit()
+                            })
+                        })
+                        .collect()
                 });
+
+                solve_scenarios(&problem, matrices, &scenarios, &create_builder)
+                    .and_then(|report| serde_json::to_writer_pretty(out_buffer, &report).map_err(|err| err.to_string()))
+                    .unwrap_or_else(|err| {
+                        exit_with_error(ErrorCategory::Solver, format!("cannot solve scenarios: '{}'", err))
+                    });
             } else {
-                match problem_reader.0(problem_file, matrix_files) {
+                match problem_reader.0(problem_file, matrix_files).and_then(|problem| add_modules(problem)) {
+                    Ok(problem) if is_get_unassignable_set => {
+                        serde_json::to_writer_pretty(out_buffer, &create_unassignable_jobs(Arc::new(problem)))
+                            .unwrap_or_else(|err| {
+                                exit_with_error(
+                                    ErrorCategory::Output,
+                                    format!("cannot write unassignable jobs: '{}'", err),
+                                )
+                            });
+                    }
                     Ok(problem) => {
+                        if let Some(memory_stats) = memory_stats.as_ref() {
+                            memory_stats.record_problem();
+                        }
+
+                        let (problem, profiler) = if is_profile_constraints_set {
+                            enable_constraint_profiling(problem)
+                                .map(|(problem, profiler)| (problem, Some(profiler)))
+                                .unwrap_or_else(|err| exit_with_error(ErrorCategory::Solver, err))
+                        } else {
+                            (problem, None)
+                        };
+
                         let problem = Arc::new(problem);
                         let solution = init_solution.and_then(|file| init_reader.0(file, problem.clone()));
 
-                        let builder = if let Some(config) = config {
-                            create_builder_from_config_file(BufReader::new(config)).unwrap_or_else(|err| {
-                                eprintln!("cannot read config: '{}'", err);
-                                process::exit(1);
-                            })
+                        let builder = create_builder();
+
+                        let heatmap = Arc::new(InsertionHeatmap::default());
+                        let builder = if out_heatmap.is_some() {
+                            builder.with_insertion_heatmap(heatmap.clone())
                         } else {
-                            Builder::default()
-                                .with_max_generations(max_generations)
-                                .with_max_time(max_time)
-                                .with_cost_variation(cost_variation)
+                            builder
                         };
 
-                        let (solution, _) = builder
+                        let pareto = Arc::new(ParetoArchive::new(PARETO_ARCHIVE_SIZE));
+                        let builder =
+                            if out_pareto.is_some() { builder.with_pareto_archive(pareto.clone()) } else { builder };
+
+                        let builder = match dump.as_ref() {
+                            Some((interval, dump_dir)) => {
+                                builder.with_interval_hook(*interval, get_dump_hook(problem.clone(), dump_dir.clone()))
+                            }
+                            None => builder,
+                        };
+
+                        let (solution, cost) = builder
                             .with_problem(problem.clone())
                             .with_solutions(solution.map_or_else(|| vec![], |s| vec![Arc::new(s)]))
                             .build()
-                            .and_then(|solver| solver.solve())
+                            .and_then(|solver| catch_panic(|| solver.solve()))
                             .unwrap_or_else(|err| {
-                                eprintln!("cannot find any solution: '{}'", err);
-                                process::exit(1);
+                                exit_with_error(ErrorCategory::Solver, format!("cannot find any solution: '{}'", err))
                             });
 
-                        solution_writer.0(&problem, solution, out_buffer, geo_buffer).unwrap()
-                    }
-                    Err(error) => {
-                        eprintln!("cannot read {} problem from '{}': '{}'", problem_format, problem_path, error);
-                        process::exit(1);
+                        memory_stats
+                            .as_ref()
+                            .map(|memory_stats| memory_stats.get_report())
+                            .into_iter()
+                            .chain(
+                                profiler.map(|profiler| {
+                                    create_constraint_profile_report(profiler.get_profiles().as_slice())
+                                }),
+                            )
+                            .flatten()
+                            .for_each(|msg| match progress_logger.as_ref() {
+                                Some(logger) => logger(msg),
+                                None => eprintln!("{}", msg),
+                            });
+
+                        let acceptance = config
+                            .as_ref()
+                            .and_then(|config| config.acceptance())
+                            .map(|acceptance| check_acceptance(acceptance, &solution, cost));
+
+                        if let Some(out_heatmap) = out_heatmap {
+                            serialize_heatmap_as_geojson(create_write_buffer(Some(out_heatmap)), &problem, &heatmap)
+                                .unwrap_or_else(|err| {
+                                    exit_with_error(ErrorCategory::Output, format!("cannot write heatmap: '{}'", err))
+                                });
+                        }
+
+                        if let Some(out_pareto) = out_pareto {
+                            let solutions = pareto
+                                .solutions()
+                                .iter()
+                                .map(|(_, solution)| create_solution(problem.as_ref(), solution.as_ref()))
+                                .collect::<Vec<_>>();
+                            serde_json::to_writer_pretty(create_write_buffer(Some(out_pareto)), &solutions)
+                                .unwrap_or_else(|err| {
+                                    exit_with_error(
+                                        ErrorCategory::Output,
+                                        format!("cannot write pareto solutions: '{}'", err),
+                                    )
+                                });
+                        }
+
+                        if is_csv_output_set {
+                            geo_buffer
+                                .map_or(Ok(()), |geo_buffer| solution.write_geo_json(&problem, geo_buffer))
+                                .and_then(|_| write_route_summary(out_buffer, &create_solution(&problem, &solution)))
+                                .unwrap_or_else(|err| {
+                                    exit_with_error(
+                                        ErrorCategory::Output,
+                                        format!("cannot write route summary: '{}'", err),
+                                    )
+                                });
+                        } else if is_dimacs_output_set {
+                            write_dimacs_solution(out_buffer, &problem, &solution).unwrap_or_else(|err| {
+                                exit_with_error(ErrorCategory::Output, format!("cannot write solution: '{}'", err))
+                            });
+                        } else {
+                            solution_writer.0(&problem, solution, tags.as_slice(), out_buffer, geo_buffer)
+                                .unwrap_or_else(|err| {
+                                    exit_with_error(ErrorCategory::Output, format!("cannot write solution: '{}'", err))
+                                })
+                        }
+
+                        // NOTE solution is written anyway, so it can be inspected
+                        if let Some(report) = acceptance.filter(|report| !report.is_accepted()) {
+                            CommandError::from_acceptance_report(report).exit()
+                        }
                     }
+                    Err(error) => error
+                        .with_context(format!("cannot read {} problem from '{}'", problem_format, problem_path))
+                        .exit(),
                 };
             }
         }
-        None => {
-            eprintln!("unknown format: '{}'", problem_format);
-            process::exit(1);
+        None => exit_with_error(ErrorCategory::Input, format!("unknown format: '{}'", problem_format)),
+    }
+}
+
+/// Returns solve options specified by command line arguments.
+fn get_solve_options(matches: &ArgMatches) -> SolveOptions {
+    let cost_variation = matches.value_of(COST_VARIATION_ARG_NAME).map(|arg| {
+        if let [sample, threshold] =
+            arg.split(',').filter_map(|line| line.parse::<f64>().ok()).collect::<Vec<_>>().as_slice()
+        {
+            (*sample as usize, *threshold)
+        } else {
+            exit_with_error(ErrorCategory::Input, "cannot parse cost variation")
+        }
+    });
+
+    let sparse_index = matches.value_of(SPARSE_INDEX_ARG_NAME).map(|arg| {
+        if let [threshold, neighbours] =
+            arg.split(',').filter_map(|line| line.parse::<usize>().ok()).collect::<Vec<_>>().as_slice()
+        {
+            (*threshold, *neighbours)
+        } else {
+            exit_with_error(ErrorCategory::Input, "cannot parse sparse index")
+        }
+    });
+
+    let mode = matches
+        .value_of(MODE_ARG_NAME)
+        .map(|mode| mode.parse::<Preset>().unwrap_or_else(|err| exit_with_error(ErrorCategory::Input, err)));
+
+    let strategy = matches
+        .value_of(STRATEGY_ARG_NAME)
+        .map(|strategy| strategy.parse::<Strategy>().unwrap_or_else(|err| exit_with_error(ErrorCategory::Input, err)));
+
+    SolveOptions {
+        max_generations: parse_int_value::<usize>(matches, GENERATIONS_ARG_NAME, "max generations"),
+        max_time: parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time"),
+        cost_variation,
+        seed: parse_int_value::<u64>(matches, SEED_ARG_NAME, "seed"),
+        parallelism: parse_int_value::<usize>(matches, PARALLELISM_ARG_NAME, "parallelism"),
+        mode,
+        low_memory: Some(matches.is_present(LOW_MEMORY_ARG_NAME)),
+        sparse_index,
+        strategy,
+    }
+}
+
+/// Returns tags specified by command line arguments in the order of their appearance.
+fn get_tags(matches: &ArgMatches) -> Vec<(String, String)> {
+    matches.values_of(TAG_ARG_NAME).map_or_else(Vec::new, |tags: Values| {
+        tags.map(|tag| match tag.splitn(2, '=').collect::<Vec<_>>().as_slice() {
+            [key, value] if !key.is_empty() => (key.to_string(), value.to_string()),
+            _ => exit_with_error(ErrorCategory::Input, format!("cannot parse tag '{}': expected key=value", tag)),
+        })
+        .collect()
+    })
+}
+
+/// Returns a logger which appends tags to each message, so log records can be grouped by them.
+fn add_tags_to_logger(logger: Logger, tags: &[(String, String)]) -> Logger {
+    if tags.is_empty() {
+        return logger;
+    }
+
+    let suffix = tags.iter().map(|(key, value)| format!(", {}: {}", key, value)).collect::<String>();
+
+    Arc::new(move |msg: String| logger(format!("{}{}", msg, suffix)))
+}
+
+/// Solves all problems from the input directory one by one within the same process and writes
+/// results summary to the output directory.
+fn solve_batch<F>(input_dir: &Path, output_dir: &Path, solve_problem: F)
+where
+    F: Fn(&Path) -> Result<(f64, usize, usize), String>,
+{
+    let files = get_batch_files(input_dir).unwrap_or_else(|err| exit_with_error(ErrorCategory::Input, err));
+
+    std::fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        exit_with_error(
+            ErrorCategory::Output,
+            format!("cannot create output directory '{}': '{}'", output_dir.display(), err),
+        )
+    });
+
+    let results = files
+        .iter()
+        .map(|path| {
+            let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default().to_string();
+            let start = Instant::now();
+
+            eprintln!("solving '{}'", name);
+
+            let result = solve_problem(path.as_path());
+            let elapsed = start.elapsed().as_secs_f64();
+
+            match result {
+                Ok((cost, tours, unassigned)) => BatchResult {
+                    name,
+                    cost: Some(cost),
+                    tours: Some(tours),
+                    unassigned: Some(unassigned),
+                    elapsed,
+                    error: None,
+                },
+                Err(err) => {
+                    eprintln!("cannot solve '{}': '{}'", name, err);
+                    BatchResult { name, cost: None, tours: None, unassigned: None, elapsed, error: Some(err) }
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let summary_path = output_dir.join(BATCH_SUMMARY_FILE_NAME);
+    let summary_buffer = create_write_buffer(Some(create_file(summary_path.to_str().unwrap_or_default(), "summary")));
+    write_batch_summary(summary_buffer, results.as_slice())
+        .unwrap_or_else(|err| exit_with_error(ErrorCategory::Output, format!("cannot write batch summary: '{}'", err)));
+}
+
+/// Returns problem input modified according to solve options, e.g. with jobs pre-assigned to
+/// vehicles when cluster-first strategy is used.
+fn get_problem_input(mut problem_file: Box<dyn Read>, options: &SolveOptions) -> Result<Box<dyn Read>, String> {
+    if options.strategy != Some(Strategy::ClusterFirst) {
+        return Ok(problem_file);
+    }
+
+    let mut problem_data = vec![];
+    problem_file.read_to_end(&mut problem_data).map_err(|err| format!("cannot read problem: '{}'", err))?;
+
+    let problem = deserialize_problem(BufReader::new(problem_data.as_slice()))
+        .map_err(|errors| get_errors_serialized(&errors))
+        .and_then(|problem| apply_options_to_problem(problem, options))?;
+
+    let mut problem_data = vec![];
+    serialize_problem(BufWriter::new(&mut problem_data), &problem).map_err(|err| err.to_string())?;
+
+    Ok(Box::new(Cursor::new(problem_data)))
+}
+
+/// Creates routing matrices for problem locations, e.g. by requesting them from OSRM service, and
+/// returns problem and matrices as inputs for problem reader.
+fn get_matrix_inputs<F>(
+    mut problem_file: Box<dyn Read>,
+    create_matrices: F,
+) -> Result<(Box<dyn Read>, Option<Vec<Box<dyn Read>>>), String>
+where
+    F: Fn(&ApiProblem) -> Result<Vec<Matrix>, String>,
+{
+    let mut problem_data = vec![];
+    problem_file.read_to_end(&mut problem_data).map_err(|err| format!("cannot read problem: '{}'", err))?;
+
+    let problem = deserialize_problem(BufReader::new(problem_data.as_slice()))
+        .map_err(|errors| get_errors_serialized(&errors))?;
+
+    let matrix_files = create_matrices(&problem)?
+        .iter()
+        .map(|matrix| {
+            let mut matrix_data = vec![];
+            serialize_matrix(BufWriter::new(&mut matrix_data), matrix).map_err(|err| err.to_string())?;
+            Ok(Box::new(Cursor::new(matrix_data)) as Box<dyn Read>)
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok((Box::new(Cursor::new(problem_data)), Some(matrix_files)))
+}
+
+/// Returns haversine approximated routing matrices. If speed in km/h is specified, it overrides
+/// speeds of all profiles.
+fn get_approx_matrices(problem: &ApiProblem, speed: Option<f64>) -> Result<Vec<Matrix>, String> {
+    let mut problem = problem.clone();
+    if let Some(speed) = speed {
+        problem.fleet.profiles.iter_mut().for_each(|profile| profile.speed = Some(speed / 3.6));
+    }
+
+    create_matrices(&problem, "haversine", None)
+}
+
+/// Returns a hook which writes solution in pragmatic format to the dump directory. Only the latest
+/// `MAX_DUMPS` dumps are kept.
+fn get_dump_hook(problem: Arc<Problem>, dump_dir: PathBuf) -> ImprovementHook {
+    let dumps = Mutex::new(VecDeque::default());
+
+    Arc::new(move |solution: &Solution, _, generation| {
+        let path = dump_dir.join(format!("solution_{}.json", generation));
+        let mut dumps = dumps.lock().unwrap();
+
+        let result = write_dump(path.as_path(), &mut dumps, MAX_DUMPS, |file| {
+            solution.write_pragmatic_json(&problem, BufWriter::new(file))
+        });
+
+        if let Err(err) = result {
+            eprintln!("cannot dump solution to '{}': '{}'", path.display(), err);
+        }
+    })
+}
+
+/// Writes a dump to temporary file first and renames it to given path, so a dump is never seen
+/// partially written. Removes the oldest dumps from previously written ones to keep at most
+/// `max_dumps` of them.
+fn write_dump<F>(path: &Path, dumps: &mut VecDeque<PathBuf>, max_dumps: usize, writer: F) -> Result<(), String>
+where
+    F: FnOnce(File) -> Result<(), String>,
+{
+    let tmp_path = path.with_extension("json.tmp");
+
+    let result = File::create(&tmp_path)
+        .map_err(|err| err.to_string())
+        .and_then(writer)
+        .and_then(|_| std::fs::rename(&tmp_path, path).map_err(|err| err.to_string()));
+
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp_path);
+        return result;
+    }
+
+    dumps.retain(|dump| dump != path);
+    dumps.push_back(path.to_path_buf());
+
+    while dumps.len() > max_dumps {
+        if let Some(dump) = dumps.pop_front() {
+            std::fs::remove_file(&dump)
+                .map_err(|err| format!("cannot remove old dump '{}': '{}'", dump.display(), err))?;
         }
     }
+
+    Ok(())
 }
@@ -0,0 +1,84 @@
+use super::*;
+use std::io::BufReader;
+use vrp_cli::extensions::timeline::{write_timeline, TimeFormat};
+
+pub const FORMAT_ARG_NAME: &str = "FORMAT";
+pub const PROBLEM_ARG_NAME: &str = "problem-file";
+pub const SOLUTION_ARG_NAME: &str = "solution-file";
+pub const OUT_FORMAT_ARG_NAME: &str = "out-format";
+pub const OUT_RESULT_ARG_NAME: &str = "out-result";
+pub const LOCALE_ARG_NAME: &str = "locale";
+pub const TIMEZONE_ARG_NAME: &str = "timezone";
+
+pub fn get_timeline_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("timeline")
+        .about("Creates a timeline of vehicle activities from the solution")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(PROBLEM_ARG_NAME)
+                .help("Sets problem file, use '-' for stdin")
+                .short("p")
+                .long(PROBLEM_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(SOLUTION_ARG_NAME)
+                .help("Sets solution file, use '-' for stdin")
+                .short("s")
+                .long(SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_FORMAT_ARG_NAME)
+                .help("Specifies timeline output format")
+                .short("f")
+                .long(OUT_FORMAT_ARG_NAME)
+                .required(false)
+                .possible_values(&["json", "svg"])
+                .default_value("json"),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for timeline output, use '-' for stdout")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(LOCALE_ARG_NAME)
+                .help("Specifies locale of time in svg output, e.g. en-US, en-GB, de-DE")
+                .long(LOCALE_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(TIMEZONE_ARG_NAME)
+                .help("Specifies timezone of time in svg output as offset from UTC, e.g. +02:00")
+                .long(TIMEZONE_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_timeline(matches: &ArgMatches) {
+    let problem_file = BufReader::new(open_file(matches.value_of(PROBLEM_ARG_NAME).unwrap(), "problem"));
+    let solution_file = BufReader::new(open_file(matches.value_of(SOLUTION_ARG_NAME).unwrap(), "solution"));
+    let out_format = matches.value_of(OUT_FORMAT_ARG_NAME).unwrap();
+    let time_format = TimeFormat::new(matches.value_of(LOCALE_ARG_NAME), matches.value_of(TIMEZONE_ARG_NAME))
+        .unwrap_or_else(|err| exit_with_error(ErrorCategory::Input, err));
+    let out_buffer =
+        create_write_buffer(matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out timeline")));
+
+    if let Err(err) = write_timeline(problem_file, solution_file, out_format, &time_format, out_buffer) {
+        exit_with_error(ErrorCategory::Input, format!("cannot create timeline: '{}'", err))
+    }
+}
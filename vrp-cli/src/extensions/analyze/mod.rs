@@ -0,0 +1,31 @@
+//! Contains logic to calculate key performance indicators (KPIs) of the solution.
+
+mod model;
+pub use self::model::*;
+
+mod text;
+pub use self::text::write_kpi_text;
+
+use crate::get_errors_serialized;
+use std::io::{BufReader, BufWriter, Read, Write};
+use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+/// Reads problem and solution in pragmatic format and writes solution KPIs in given format.
+pub fn write_analysis<R: Read, W: Write>(
+    problem: BufReader<R>,
+    solution: BufReader<R>,
+    out_format: &str,
+    writer: BufWriter<W>,
+) -> Result<(), String> {
+    let problem = deserialize_problem(problem).map_err(|errors| get_errors_serialized(&errors))?;
+    let solution = deserialize_solution(solution).map_err(|err| format!("cannot read solution: '{}'", err))?;
+
+    let kpi = create_solution_kpi(&problem, &solution)?;
+
+    match out_format {
+        "json" => serde_json::to_writer_pretty(writer, &kpi).map_err(|err| err.to_string()),
+        "text" => write_kpi_text(writer, &kpi).map_err(|err| err.to_string()),
+        _ => Err(format!("unknown analysis format: '{}'", out_format)),
+    }
+}
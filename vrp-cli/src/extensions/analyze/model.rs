@@ -0,0 +1,157 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/model_test.rs"]
+mod model_test;
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use vrp_pragmatic::format::problem::Problem;
+use vrp_pragmatic::format::solution::{Solution, Tour};
+
+/// Key performance indicators of a single tour.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourKpi {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle type id.
+    pub type_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Tour cost.
+    pub cost: f64,
+    /// Tour distance.
+    pub distance: i32,
+    /// Tour duration.
+    pub duration: i32,
+    /// Driving time.
+    pub driving: i32,
+    /// Waiting time.
+    pub waiting: i32,
+    /// Amount of served jobs.
+    pub jobs: usize,
+    /// Max ratio of vehicle load to its capacity over the tour.
+    pub load_factor: f64,
+}
+
+/// Key performance indicators of the solution.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SolutionKpi {
+    /// Total cost.
+    pub cost: f64,
+    /// Total distance.
+    pub distance: i32,
+    /// Total duration.
+    pub duration: i32,
+    /// Total driving time.
+    pub driving: i32,
+    /// Total serving time.
+    pub serving: i32,
+    /// Total waiting time.
+    pub waiting: i32,
+    /// Total break time.
+    pub break_time: i32,
+    /// Amount of tours.
+    pub tours: usize,
+    /// Amount of vehicles used in the solution.
+    pub vehicles_used: usize,
+    /// Amount of vehicles available in the problem.
+    pub vehicles_available: usize,
+    /// Ratio of used vehicles to available ones.
+    pub vehicle_utilization: f64,
+    /// Average load factor of tours.
+    pub load_factor: f64,
+    /// Amount of served jobs.
+    pub assigned: usize,
+    /// Amount of unassigned jobs.
+    pub unassigned: usize,
+    /// Tour indicators.
+    pub tour_kpis: Vec<TourKpi>,
+}
+
+/// Creates solution KPIs from problem and its solution in pragmatic format.
+pub fn create_solution_kpi(problem: &Problem, solution: &Solution) -> Result<SolutionKpi, String> {
+    let capacities = problem
+        .fleet
+        .vehicles
+        .iter()
+        .map(|vehicle| (vehicle.type_id.clone(), vehicle.capacity.clone()))
+        .collect::<HashMap<_, _>>();
+
+    let tour_kpis = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            capacities
+                .get(&tour.type_id)
+                .ok_or_else(|| format!("unknown vehicle type: '{}'", tour.type_id))
+                .map(|capacity| create_tour_kpi(tour, capacity.as_slice()))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let vehicles_used = solution.tours.iter().map(|tour| tour.vehicle_id.as_str()).collect::<HashSet<_>>().len();
+    let vehicles_available = problem.fleet.vehicles.iter().map(|vehicle| vehicle.vehicle_ids.len()).sum::<usize>();
+    let statistic = &solution.statistic;
+
+    Ok(SolutionKpi {
+        cost: statistic.cost,
+        distance: statistic.distance,
+        duration: statistic.duration,
+        driving: statistic.times.driving,
+        serving: statistic.times.serving,
+        waiting: statistic.times.waiting,
+        break_time: statistic.times.break_time,
+        tours: solution.tours.len(),
+        vehicles_used,
+        vehicles_available,
+        vehicle_utilization: get_ratio(vehicles_used as f64, vehicles_available as f64),
+        load_factor: get_ratio(tour_kpis.iter().map(|tour| tour.load_factor).sum::<f64>(), tour_kpis.len() as f64),
+        assigned: tour_kpis.iter().map(|tour| tour.jobs).sum(),
+        unassigned: solution.unassigned.len(),
+        tour_kpis,
+    })
+}
+
+fn create_tour_kpi(tour: &Tour, capacity: &[i32]) -> TourKpi {
+    let jobs = tour
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "replacement" | "service"))
+        .map(|activity| activity.job_id.as_str())
+        .collect::<HashSet<_>>()
+        .len();
+
+    let load_factor = tour
+        .stops
+        .iter()
+        .flat_map(|stop| {
+            stop.load
+                .iter()
+                .zip(capacity.iter())
+                .filter(|(_, &capacity)| capacity > 0)
+                .map(|(&load, &capacity)| load as f64 / capacity as f64)
+        })
+        .fold(0., f64::max);
+
+    TourKpi {
+        vehicle_id: tour.vehicle_id.clone(),
+        type_id: tour.type_id.clone(),
+        shift_index: tour.shift_index,
+        cost: tour.statistic.cost,
+        distance: tour.statistic.distance,
+        duration: tour.statistic.duration,
+        driving: tour.statistic.times.driving,
+        waiting: tour.statistic.times.waiting,
+        jobs,
+        load_factor,
+    }
+}
+
+fn get_ratio(value: f64, total: f64) -> f64 {
+    if total > 0. {
+        value / total
+    } else {
+        0.
+    }
+}
@@ -0,0 +1,79 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/text_test.rs"]
+mod text_test;
+
+use super::SolutionKpi;
+use std::io::{BufWriter, Error, Write};
+
+/// Writes solution KPIs in human readable form: a summary followed by a table with tour indicators.
+pub fn write_kpi_text<W: Write>(mut writer: BufWriter<W>, kpi: &SolutionKpi) -> Result<(), Error> {
+    let summary = vec![
+        ("cost", format!("{:.2}", kpi.cost)),
+        ("distance", kpi.distance.to_string()),
+        ("duration", kpi.duration.to_string()),
+        ("driving time", kpi.driving.to_string()),
+        ("serving time", kpi.serving.to_string()),
+        ("waiting time", kpi.waiting.to_string()),
+        ("break time", kpi.break_time.to_string()),
+        ("tours", kpi.tours.to_string()),
+        (
+            "vehicles used",
+            format!(
+                "{} of {} ({})",
+                kpi.vehicles_used,
+                kpi.vehicles_available,
+                format_percent(kpi.vehicle_utilization)
+            ),
+        ),
+        ("load factor", format_percent(kpi.load_factor)),
+        ("assigned jobs", kpi.assigned.to_string()),
+        ("unassigned jobs", kpi.unassigned.to_string()),
+    ];
+
+    for (name, value) in summary {
+        writeln!(writer, "{:<17}{}", format!("{}:", name), value)?;
+    }
+
+    if kpi.tour_kpis.is_empty() {
+        return Ok(());
+    }
+
+    let width = kpi.tour_kpis.iter().map(|tour| tour.vehicle_id.len()).max().unwrap_or(0).max("vehicle".len()) + 2;
+
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "{:<width$}{:>6}{:>6}{:>10}{:>10}{:>10}{:>10}{:>8}",
+        "vehicle",
+        "shift",
+        "jobs",
+        "distance",
+        "duration",
+        "driving",
+        "waiting",
+        "load",
+        width = width
+    )?;
+
+    for tour in kpi.tour_kpis.iter() {
+        writeln!(
+            writer,
+            "{:<width$}{:>6}{:>6}{:>10}{:>10}{:>10}{:>10}{:>8}",
+            tour.vehicle_id,
+            tour.shift_index,
+            tour.jobs,
+            tour.distance,
+            tour.duration,
+            tour.driving,
+            tour.waiting,
+            format_percent(tour.load_factor),
+            width = width
+        )?;
+    }
+
+    Ok(())
+}
+
+fn format_percent(value: f64) -> String {
+    format!("{:.1}%", value * 100.)
+}
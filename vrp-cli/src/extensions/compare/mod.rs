@@ -0,0 +1,29 @@
+//! Contains logic to compare two solutions of the same problem.
+
+mod model;
+pub use self::model::*;
+
+mod text;
+pub use self::text::write_diff_text;
+
+use std::io::{BufReader, BufWriter, Read, Write};
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+/// Reads two solutions in pragmatic format and writes their difference in given format.
+pub fn write_comparison<R: Read, W: Write>(
+    baseline: BufReader<R>,
+    solution: BufReader<R>,
+    out_format: &str,
+    writer: BufWriter<W>,
+) -> Result<(), String> {
+    let baseline = deserialize_solution(baseline).map_err(|err| format!("cannot read baseline solution: '{}'", err))?;
+    let solution = deserialize_solution(solution).map_err(|err| format!("cannot read solution: '{}'", err))?;
+
+    let diff = create_solution_diff(&baseline, &solution);
+
+    match out_format {
+        "json" => serde_json::to_writer_pretty(writer, &diff).map_err(|err| err.to_string()),
+        "text" => write_diff_text(writer, &diff).map_err(|err| err.to_string()),
+        _ => Err(format!("unknown comparison format: '{}'", out_format)),
+    }
+}
@@ -0,0 +1,167 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/compare/model_test.rs"]
+mod model_test;
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use vrp_pragmatic::format::solution::{Solution, Tour};
+
+/// A value of the indicator in baseline and compared solutions.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct ValueDiff {
+    /// A value in baseline solution.
+    pub baseline: f64,
+    /// A value in compared solution.
+    pub solution: f64,
+}
+
+impl ValueDiff {
+    /// Returns difference between compared and baseline values.
+    pub fn delta(&self) -> f64 {
+        self.solution - self.baseline
+    }
+}
+
+/// A job which is served by different tours in baseline and compared solutions.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JobMove {
+    /// Job id.
+    pub job_id: String,
+    /// A tour in baseline solution.
+    pub from: String,
+    /// A tour in compared solution.
+    pub to: String,
+}
+
+/// A tour which serves the same jobs in different order.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct OrderChange {
+    /// A tour key.
+    pub tour: String,
+    /// Job ids in order of baseline solution.
+    pub baseline: Vec<String>,
+    /// Job ids in order of compared solution.
+    pub solution: Vec<String>,
+}
+
+/// A difference between two solutions of the same problem.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SolutionDiff {
+    /// Total cost.
+    pub cost: ValueDiff,
+    /// Total distance.
+    pub distance: ValueDiff,
+    /// Total duration.
+    pub duration: ValueDiff,
+    /// Amount of tours.
+    pub tours: ValueDiff,
+    /// Amount of unassigned jobs.
+    pub unassigned: ValueDiff,
+    /// Jobs which are served by different tours.
+    pub moved_jobs: Vec<JobMove>,
+    /// Tours which serve jobs common for both solutions in different order.
+    pub order_changes: Vec<OrderChange>,
+    /// Jobs which are unassigned in baseline solution, but assigned in compared one.
+    pub newly_assigned: Vec<String>,
+    /// Jobs which are assigned in baseline solution, but unassigned in compared one.
+    pub newly_unassigned: Vec<String>,
+}
+
+/// Creates a difference between baseline and compared solutions. Tours are matched by vehicle id
+/// and shift index.
+pub fn create_solution_diff(baseline: &Solution, solution: &Solution) -> SolutionDiff {
+    let baseline_jobs = get_job_tours(baseline);
+    let solution_jobs = get_job_tours(solution);
+
+    let mut moved_jobs = baseline_jobs
+        .iter()
+        .filter_map(|(job_id, from)| {
+            solution_jobs.get(job_id).filter(|to| *to != from).map(|to| JobMove {
+                job_id: job_id.clone(),
+                from: from.clone(),
+                to: to.clone(),
+            })
+        })
+        .collect::<Vec<_>>();
+    moved_jobs.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+
+    let solution_tours = solution.tours.iter().map(|tour| (get_tour_key(tour), tour)).collect::<HashMap<_, _>>();
+    let order_changes = baseline
+        .tours
+        .iter()
+        .filter_map(|baseline_tour| {
+            let key = get_tour_key(baseline_tour);
+            let solution_tour = solution_tours.get(&key)?;
+
+            let baseline_ids = get_job_ids(baseline_tour).collect::<HashSet<_>>();
+            let solution_ids = get_job_ids(solution_tour).collect::<HashSet<_>>();
+
+            let baseline_order =
+                get_job_ids(baseline_tour).filter(|id| solution_ids.contains(id)).map(String::from).collect::<Vec<_>>();
+            let solution_order =
+                get_job_ids(solution_tour).filter(|id| baseline_ids.contains(id)).map(String::from).collect::<Vec<_>>();
+
+            if baseline_order != solution_order {
+                Some(OrderChange { tour: key, baseline: baseline_order, solution: solution_order })
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let baseline_unassigned = get_unassigned(baseline);
+    let solution_unassigned = get_unassigned(solution);
+
+    SolutionDiff {
+        cost: create_value_diff(baseline.statistic.cost, solution.statistic.cost),
+        distance: create_value_diff(baseline.statistic.distance as f64, solution.statistic.distance as f64),
+        duration: create_value_diff(baseline.statistic.duration as f64, solution.statistic.duration as f64),
+        tours: create_value_diff(baseline.tours.len() as f64, solution.tours.len() as f64),
+        unassigned: create_value_diff(baseline.unassigned.len() as f64, solution.unassigned.len() as f64),
+        moved_jobs,
+        order_changes,
+        newly_assigned: get_difference(&baseline_unassigned, &solution_unassigned),
+        newly_unassigned: get_difference(&solution_unassigned, &baseline_unassigned),
+    }
+}
+
+fn create_value_diff(baseline: f64, solution: f64) -> ValueDiff {
+    ValueDiff { baseline, solution }
+}
+
+fn get_tour_key(tour: &Tour) -> String {
+    format!("{}/{}", tour.vehicle_id, tour.shift_index)
+}
+
+fn get_job_ids(tour: &Tour) -> impl Iterator<Item = &str> + '_ {
+    tour.stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "replacement" | "service"))
+        .map(|activity| activity.job_id.as_str())
+}
+
+fn get_job_tours(solution: &Solution) -> HashMap<String, String> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            let key = get_tour_key(tour);
+            get_job_ids(tour).map(move |job_id| (job_id.to_string(), key.clone()))
+        })
+        .collect()
+}
+
+fn get_unassigned(solution: &Solution) -> HashSet<String> {
+    solution.unassigned.iter().map(|job| job.job_id.clone()).collect()
+}
+
+/// Returns sorted items from the first set which are not present in the second one.
+fn get_difference(first: &HashSet<String>, second: &HashSet<String>) -> Vec<String> {
+    let mut items = first.difference(second).cloned().collect::<Vec<_>>();
+    items.sort();
+
+    items
+}
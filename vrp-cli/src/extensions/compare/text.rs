@@ -0,0 +1,60 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/compare/text_test.rs"]
+mod text_test;
+
+use super::{SolutionDiff, ValueDiff};
+use std::io::{BufWriter, Error, Write};
+
+/// Writes solutions difference in human readable form: a summary with indicator deltas followed
+/// by moved jobs, order changes within tours and changes of unassigned jobs.
+pub fn write_diff_text<W: Write>(mut writer: BufWriter<W>, diff: &SolutionDiff) -> Result<(), Error> {
+    let summary = vec![
+        ("cost", format_value(&diff.cost, 2)),
+        ("distance", format_value(&diff.distance, 0)),
+        ("duration", format_value(&diff.duration, 0)),
+        ("tours", format_value(&diff.tours, 0)),
+        ("unassigned jobs", format_value(&diff.unassigned, 0)),
+        ("moved jobs", diff.moved_jobs.len().to_string()),
+        ("reordered tours", diff.order_changes.len().to_string()),
+    ];
+
+    for (name, value) in summary {
+        writeln!(writer, "{:<17}{}", format!("{}:", name), value)?;
+    }
+
+    if !diff.moved_jobs.is_empty() {
+        writeln!(writer)?;
+        writeln!(writer, "moved jobs:")?;
+        for job_move in diff.moved_jobs.iter() {
+            writeln!(writer, "  {}: {} -> {}", job_move.job_id, job_move.from, job_move.to)?;
+        }
+    }
+
+    if !diff.order_changes.is_empty() {
+        writeln!(writer)?;
+        writeln!(writer, "reordered tours:")?;
+        for change in diff.order_changes.iter() {
+            writeln!(writer, "  {}: {} -> {}", change.tour, change.baseline.join(" "), change.solution.join(" "))?;
+        }
+    }
+
+    let assignment_changes = [("newly assigned", &diff.newly_assigned), ("newly unassigned", &diff.newly_unassigned)];
+    for (name, jobs) in assignment_changes.iter() {
+        if !jobs.is_empty() {
+            writeln!(writer)?;
+            writeln!(writer, "{}: {}", name, jobs.join(" "))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn format_value(value: &ValueDiff, precision: usize) -> String {
+    format!(
+        "{:.precision$} -> {:.precision$} ({:+.precision$})",
+        value.baseline,
+        value.solution,
+        value.delta(),
+        precision = precision
+    )
+}
@@ -0,0 +1,24 @@
+//! Contains logic to convert problems between supported formats.
+
+mod solomon;
+pub use self::solomon::write_solomon_problem;
+
+use crate::get_errors_serialized;
+use std::io::{BufReader, BufWriter, Read, Write};
+use vrp_pragmatic::format::problem::deserialize_problem;
+
+/// Reads problem in one format and writes it in another one.
+pub fn convert_problem<R: Read, W: Write>(
+    from_format: &str,
+    to_format: &str,
+    reader: BufReader<R>,
+    writer: BufWriter<W>,
+) -> Result<(), String> {
+    match (from_format, to_format) {
+        ("pragmatic", "solomon") => {
+            let problem = deserialize_problem(reader).map_err(|errors| get_errors_serialized(&errors))?;
+            write_solomon_problem(writer, &problem)
+        }
+        _ => Err(format!("conversion from '{}' to '{}' is not supported", from_format, to_format)),
+    }
+}
@@ -0,0 +1,132 @@
+//! Converts pragmatic problem to solomon format.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/convert/solomon_test.rs"]
+mod solomon_test;
+
+use chrono::DateTime;
+use std::io::{BufWriter, Write};
+use vrp_pragmatic::format::problem::*;
+use vrp_pragmatic::format::Location;
+
+/// Writes pragmatic problem in solomon format. Only problems which can be expressed as CVRPTW are
+/// supported: one vehicle type with one shift which starts and ends at the same depot, single
+/// dimensional capacity, delivery or service jobs with one place and at most one time window.
+/// Location latitude and longitude are used as integer x and y coordinates, times are converted
+/// to seconds relative to the vehicle shift start. Customers are numbered in order of plan jobs.
+pub fn write_solomon_problem<W: Write>(mut writer: BufWriter<W>, problem: &Problem) -> Result<(), String> {
+    if problem.plan.relations.as_ref().map_or(false, |relations| !relations.is_empty()) {
+        return Err("solomon format does not support relations".to_string());
+    }
+
+    let (number, capacity, depot, start_time) = get_vehicle_line(problem)?;
+    let customers = problem
+        .plan
+        .jobs
+        .iter()
+        .enumerate()
+        .map(|(idx, job)| get_customer_line(idx + 1, job, start_time, depot[5]))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    writer.write_all(b"pragmatic\n\nVEHICLE\nNUMBER     CAPACITY\n").map_err(|err| err.to_string())?;
+    writer.write_all(format!("{:>5}{:>13}\n\n", number, capacity).as_bytes()).map_err(|err| err.to_string())?;
+    writer
+        .write_all(b"CUSTOMER\nCUST NO.  XCOORD.   YCOORD.    DEMAND   READY TIME  DUE DATE   SERVICE   TIME\n\n")
+        .map_err(|err| err.to_string())?;
+
+    std::iter::once(&depot).chain(customers.iter()).try_for_each(|line| {
+        let line = line.iter().map(|value| format!("{:>10}", value)).collect::<Vec<_>>().concat();
+        writer.write_all(format!("{}\n", line).as_bytes()).map_err(|err| err.to_string())
+    })
+}
+
+/// Returns vehicle number, capacity, depot line and absolute shift start time.
+fn get_vehicle_line(problem: &Problem) -> Result<(usize, i32, Vec<i64>, i64), String> {
+    let vehicle = match problem.fleet.vehicles.as_slice() {
+        [vehicle] => vehicle,
+        _ => return Err("solomon format supports only one vehicle type".to_string()),
+    };
+
+    let shift = match vehicle.shifts.as_slice() {
+        [shift] => shift,
+        _ => return Err(format!("vehicle type '{}' should have exactly one shift", vehicle.type_id)),
+    };
+
+    let capacity = match vehicle.capacity.as_slice() {
+        [capacity] => *capacity,
+        _ => return Err(format!("vehicle type '{}' should have single dimensional capacity", vehicle.type_id)),
+    };
+
+    if shift.breaks.is_some() || shift.reloads.is_some() || vehicle.skills.is_some() || vehicle.limits.is_some() {
+        return Err("solomon format does not support vehicle breaks, reloads, skills or limits".to_string());
+    }
+
+    let end = match shift.end.as_ref() {
+        Some(end) if is_same_location(&end.location, &shift.start.location) => end,
+        _ => return Err(format!("vehicle type '{}' should return to its start location", vehicle.type_id)),
+    };
+
+    let start_time = parse_time(&shift.start.time)?;
+    let end_time = parse_time(&end.time)? - start_time;
+    let (x, y) = get_coordinates(&shift.start.location)?;
+
+    Ok((vehicle.vehicle_ids.len(), capacity, vec![0, x, y, 0, 0, end_time, 0], start_time))
+}
+
+fn get_customer_line(number: usize, job: &Job, shift_start: i64, shift_end: i64) -> Result<Vec<i64>, String> {
+    let (task, is_service) = match (&job.pickups, &job.deliveries, &job.replacements, &job.services) {
+        (None, Some(tasks), None, None) if tasks.len() == 1 => (tasks.first().unwrap(), false),
+        (None, None, None, Some(tasks)) if tasks.len() == 1 => (tasks.first().unwrap(), true),
+        _ => return Err(format!("job '{}' should have exactly one delivery or service task", job.id)),
+    };
+
+    if job.skills.is_some() || job.priority.is_some() {
+        return Err(format!("job '{}' has skills or priority which are not supported", job.id));
+    }
+
+    let place = match task.places.as_slice() {
+        [place] => place,
+        _ => return Err(format!("job '{}' should have exactly one place", job.id)),
+    };
+
+    let demand = match (is_service, task.demand.as_ref().map(|demand| demand.as_slice())) {
+        (true, None) => 0,
+        (false, Some([demand])) => *demand as i64,
+        _ => return Err(format!("job '{}' should have single dimensional delivery demand", job.id)),
+    };
+
+    let (ready, due) = match place.times.as_ref().map(|times| times.as_slice()) {
+        None => (0, shift_end),
+        Some([tw]) if tw.len() == 2 => (parse_time(&tw[0])? - shift_start, parse_time(&tw[1])? - shift_start),
+        _ => return Err(format!("job '{}' should have at most one time window", job.id)),
+    };
+
+    let (x, y) = get_coordinates(&place.location)?;
+    let service = get_integer(place.duration).ok_or_else(|| format!("job '{}' has non integer duration", job.id))?;
+
+    Ok(vec![number as i64, x, y, demand, ready, due, service])
+}
+
+fn get_coordinates(location: &Location) -> Result<(i64, i64), String> {
+    get_integer(location.lat)
+        .zip(get_integer(location.lng))
+        .ok_or_else(|| format!("location ({}, {}) should have integer coordinates", location.lat, location.lng))
+}
+
+fn get_integer(value: f64) -> Option<i64> {
+    if value.fract() == 0. {
+        Some(value as i64)
+    } else {
+        None
+    }
+}
+
+fn is_same_location(a: &Location, b: &Location) -> bool {
+    (a.lat - b.lat).abs() < f64::EPSILON && (a.lng - b.lng).abs() < f64::EPSILON
+}
+
+fn parse_time(time: &str) -> Result<i64, String> {
+    DateTime::parse_from_rfc3339(time)
+        .map(|time| time.timestamp())
+        .map_err(|err| format!("cannot parse time: '{}'", err))
+}
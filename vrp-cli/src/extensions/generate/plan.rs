@@ -2,6 +2,7 @@
 #[path = "../../../tests/unit/extensions/generate/plan_test.rs"]
 mod plan_test;
 
+use vrp_core::utils::geo::{get_bounding_box, get_bounding_box_from_center};
 use vrp_core::utils::{DefaultRandom, Random};
 use vrp_pragmatic::format::problem::{Job, JobPlace, JobTask, Plan, Problem};
 use vrp_pragmatic::format::Location;
@@ -68,60 +69,31 @@ pub fn generate_plan(problem_proto: &Problem, job_size: usize, area_size: Option
                 services: generate_tasks(&job_proto.services, true),
                 priority: job_proto.priority,
                 skills: job_proto.skills.clone(),
+                position: job_proto.position,
             }
         })
         .collect();
 
-    Ok(Plan { jobs, relations: None })
+    Ok(Plan { jobs, relations: None, forbidden_arcs: None })
 }
 
 fn get_bounding_box_from_plan(plan: &Plan) -> (Location, Location) {
-    let mut lat_min = std::f64::MAX;
-    let mut lat_max = std::f64::MIN;
-    let mut lng_min = std::f64::MAX;
-    let mut lng_max = std::f64::MIN;
+    let points =
+        get_plan_places(&plan).map(|job_place| (job_place.location.lat, job_place.location.lng)).collect::<Vec<_>>();
 
-    get_plan_places(&plan).map(|job_place| &job_place.location).cloned().for_each(|Location { lat, lng }| {
-        lat_min = lat_min.min(lat);
-        lat_max = lat_max.max(lat);
-
-        lng_min = lng_min.min(lng);
-        lng_max = lng_max.max(lng);
-    });
+    let ((lat_min, lng_min), (lat_max, lng_max)) =
+        get_bounding_box(points.iter()).unwrap_or(((std::f64::MAX, std::f64::MAX), (std::f64::MIN, std::f64::MIN)));
 
     (Location { lat: lat_min, lng: lng_min }, Location { lat: lat_max, lng: lng_max })
 }
 
 fn get_bounding_box_from_size(plan: &Plan, area_size: f64) -> (Location, Location) {
-    const WGS84_A: f64 = 6378137.0;
-    const WGS84_B: f64 = 6356752.3;
-    let deg_to_rad = |deg| std::f64::consts::PI * deg / 180.;
-    let rad_to_deg = |rad| 180. * rad / std::f64::consts::PI;
-
     let (min, max) = get_bounding_box_from_plan(plan);
-    let center_lat = min.lat + (max.lat - min.lat) / 2.;
-    let center_lng = min.lng + (max.lng - min.lng) / 2.;
-
-    let lat = deg_to_rad(center_lat);
-    let lng = deg_to_rad(center_lng);
-
-    // NOTE copied from pragmatic
-    let an = WGS84_A * WGS84_A * lat.cos();
-    let bn = WGS84_B * WGS84_B * lat.sin();
-    let ad = WGS84_A * lat.cos();
-    let bd = WGS84_B * lat.sin();
+    let center = (min.lat + (max.lat - min.lat) / 2., min.lng + (max.lng - min.lng) / 2.);
 
-    let half_size = area_size;
+    let ((lat_min, lng_min), (lat_max, lng_max)) = get_bounding_box_from_center(&center, area_size);
 
-    let radius = ((an * an + bn * bn) / (ad * ad + bd * bd)).sqrt();
-    let pradius = radius * lat.cos();
-
-    let lat_min = rad_to_deg(lat - half_size / radius);
-    let lat_max = rad_to_deg(lat + half_size / radius);
-    let lon_min = rad_to_deg(lng - half_size / pradius);
-    let lon_max = rad_to_deg(lng + half_size / pradius);
-
-    (Location { lat: lat_min, lng: lon_min }, Location { lat: lat_max, lng: lon_max })
+    (Location { lat: lat_min, lng: lng_min }, Location { lat: lat_max, lng: lng_max })
 }
 
 fn get_plan_time_windows(plan: &Plan) -> Vec<Vec<Vec<String>>> {
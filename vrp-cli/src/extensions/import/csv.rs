@@ -97,6 +97,7 @@ fn read_jobs<R: Read>(reader: BufReader<R>) -> Result<Vec<Job>, Box<dyn Error>>
             services: get_tasks(&tasks, Box::new(|j| j.demand == 0)),
             priority: None,
             skills: None,
+            position: None,
         })
         .collect();
 
@@ -115,14 +116,16 @@ fn read_vehicles<R: Read>(reader: BufReader<R>) -> Result<Vec<VehicleType>, Box<
                 profile: vehicle.profile,
                 costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
                 shifts: vec![VehicleShift {
-                    start: VehiclePlace { time: vehicle.tw_start, location: depot_location.clone() },
-                    end: Some(VehiclePlace { time: vehicle.tw_end, location: depot_location }),
+                    start: VehiclePlace { time: vehicle.tw_start, location: depot_location.clone(), gate: None },
+                    end: Some(VehiclePlace { time: vehicle.tw_end, location: depot_location, gate: None }),
                     breaks: None,
                     reloads: None,
                 }],
                 capacity: vec![vehicle.capacity],
                 skills: None,
                 limits: None,
+                unavailable: None,
+                positions: None,
             }
         })
         .collect();
@@ -149,10 +152,20 @@ pub fn read_csv_problem<R1: Read, R2: Read>(
     let profiles = vehicles.iter().map(|v| v.profile.clone()).collect::<HashSet<_>>();
 
     Ok(Problem {
-        plan: Plan { jobs, relations: None },
+        plan: Plan { jobs, relations: None, forbidden_arcs: None },
         fleet: Fleet {
             vehicles,
-            profiles: profiles.into_iter().map(|p| Profile { name: p.clone(), profile_type: p, speed: None }).collect(),
+            profiles: profiles
+                .into_iter()
+                .map(|p| Profile {
+                    name: p.clone(),
+                    profile_type: p,
+                    speed: None,
+                    overrides: None,
+                    multipliers: None,
+                    straight_line: None,
+                })
+                .collect(),
         },
         objectives: None,
         config: None,
@@ -0,0 +1,248 @@
+//! Contains logic to detect and merge jobs which share the same location.
+
+#[cfg(all(test, feature = "importers"))]
+#[path = "../../../tests/unit/extensions/import/duplicates_test.rs"]
+mod duplicates_test;
+
+use chrono::DateTime;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use vrp_pragmatic::format::problem::*;
+use vrp_pragmatic::format::Location;
+
+/// Specifies a location shared by multiple jobs.
+#[derive(Clone, Debug)]
+pub struct DuplicateLocation {
+    /// A shared location.
+    pub location: Location,
+    /// Ids of jobs which have at least one place at the location.
+    pub job_ids: Vec<String>,
+}
+
+/// Returns locations which are used by more than one job in order of their first appearance.
+pub fn get_duplicate_locations(problem: &Problem) -> Vec<DuplicateLocation> {
+    let groups = group_by(problem.plan.jobs.iter(), |job| {
+        let mut keys = HashSet::new();
+        get_tasks(job)
+            .flat_map(|(_, task)| task.places.iter())
+            .filter(|place| keys.insert(get_location_key(&place.location)))
+            .map(|place| (get_location_key(&place.location), place.location.clone()))
+            .collect()
+    });
+
+    groups
+        .into_iter()
+        .filter(|(_, jobs)| jobs.len() > 1)
+        .map(|(location, jobs)| DuplicateLocation {
+            location,
+            job_ids: jobs.into_iter().map(|job| job.id.clone()).collect(),
+        })
+        .collect()
+}
+
+/// Merges jobs which have the same location into multi task jobs. Only jobs with exactly one task
+/// of the same type, one place, the same skills, priority and position are merged. Jobs used in relations or
+/// forbidden arcs are left untouched. Jobs are merged only while their total demand fits capacity of
+/// at least one vehicle type and their time windows have a common interval, otherwise they are split
+/// into multiple merged jobs. Merged job gets id of the first job in the group, original job ids are
+/// kept as task tags, unless a task has its own tag already.
+pub fn merge_duplicate_locations(problem: &Problem) -> Problem {
+    let excluded = problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter().flat_map(|relation| relation.jobs.iter()))
+        .chain(problem.plan.forbidden_arcs.iter().flat_map(|arcs| arcs.iter().flat_map(|arc| vec![&arc.from, &arc.to])))
+        .cloned()
+        .collect::<HashSet<_>>();
+
+    let groups = group_by(problem.plan.jobs.iter(), |job| {
+        let mut tasks = get_tasks(job);
+        let key = match (tasks.next(), tasks.next()) {
+            (Some((task_type, task)), None) if task.places.len() == 1 && !excluded.contains(&job.id) => {
+                let location = get_location_key(&task.places[0].location);
+                Some((location, task_type, job.priority, job.skills.clone(), job.position))
+            }
+            _ => None,
+        };
+
+        // NOTE jobs which cannot be merged get unique key
+        vec![(key.ok_or_else(|| job.id.clone()), ())]
+    });
+
+    let capacities = problem.fleet.vehicles.iter().map(|vehicle| vehicle.capacity.as_slice()).collect::<Vec<_>>();
+
+    let jobs = groups
+        .into_iter()
+        .flat_map(|(_, jobs)| split_by_feasibility(jobs, capacities.as_slice()))
+        .map(|jobs| match jobs.as_slice() {
+            [job] => (*job).clone(),
+            _ => merge_jobs(jobs.as_slice()),
+        })
+        .collect();
+
+    Problem { plan: Plan { jobs, ..problem.plan.clone() }, ..problem.clone() }
+}
+
+/// Time windows as intervals of unix timestamps, `None` means that time is not restricted.
+type TimeIntervals = Option<Vec<(i64, i64)>>;
+
+/// Splits jobs which share the same location into subgroups which can be merged: total demand
+/// of a subgroup fits capacity of at least one vehicle type and time windows of its jobs overlap.
+/// Jobs are assigned to the first subgroup they fit into, so order of jobs is preserved.
+fn split_by_feasibility<'a>(jobs: Vec<&'a Job>, capacities: &[&[i32]]) -> Vec<Vec<&'a Job>> {
+    if jobs.len() < 2 {
+        return vec![jobs];
+    }
+
+    let fits_capacity = |demand: &[i32]| {
+        capacities.iter().any(|capacity| {
+            demand.iter().enumerate().all(|(idx, &value)| value <= capacity.get(idx).cloned().unwrap_or(0))
+        })
+    };
+
+    let mut subgroups: Vec<(Vec<&'a Job>, Vec<i32>, TimeIntervals)> = vec![];
+
+    jobs.into_iter().for_each(|job| {
+        let (_, task) = get_tasks(job).next().unwrap();
+        let demand = task.demand.clone().unwrap_or_default();
+        // NOTE time windows which cannot be parsed are treated as empty, so such job is never merged
+        let times = get_time_intervals(&task.places[0]).unwrap_or_else(|_| Some(vec![]));
+
+        let subgroup = subgroups.iter_mut().find_map(|(jobs, total_demand, total_times)| {
+            let merged_demand = add_demand(total_demand.as_slice(), demand.as_slice());
+            let merged_times = intersect_times(total_times, &times)?;
+
+            if fits_capacity(merged_demand.as_slice()) {
+                Some((jobs, total_demand, total_times, merged_demand, merged_times))
+            } else {
+                None
+            }
+        });
+
+        match subgroup {
+            Some((jobs, total_demand, total_times, merged_demand, merged_times)) => {
+                jobs.push(job);
+                *total_demand = merged_demand;
+                *total_times = merged_times;
+            }
+            None => subgroups.push((vec![job], demand, times)),
+        }
+    });
+
+    subgroups.into_iter().map(|(jobs, _, _)| jobs).collect()
+}
+
+fn add_demand(left: &[i32], right: &[i32]) -> Vec<i32> {
+    (0..left.len().max(right.len()))
+        .map(|idx| left.get(idx).cloned().unwrap_or(0) + right.get(idx).cloned().unwrap_or(0))
+        .collect()
+}
+
+fn get_time_intervals(place: &JobPlace) -> Result<TimeIntervals, String> {
+    let parse_time =
+        |time: &String| DateTime::parse_from_rfc3339(time).map(|time| time.timestamp()).map_err(|err| err.to_string());
+
+    place.times.as_ref().map_or(Ok(None), |times| {
+        times
+            .iter()
+            .map(|tw| match tw.as_slice() {
+                [start, end] => Ok((parse_time(start)?, parse_time(end)?)),
+                _ => Err("invalid time window".to_string()),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some)
+    })
+}
+
+/// Returns intersection of two sets of time windows or `None` if they have no common interval.
+fn intersect_times(left: &TimeIntervals, right: &TimeIntervals) -> Option<TimeIntervals> {
+    match (left, right) {
+        (None, None) => Some(None),
+        (Some(times), None) | (None, Some(times)) => Some(Some(times.clone())).filter(|_| !times.is_empty()),
+        (Some(left), Some(right)) => {
+            let times = left
+                .iter()
+                .flat_map(|&(left_start, left_end)| {
+                    right
+                        .iter()
+                        .map(move |&(right_start, right_end)| (left_start.max(right_start), left_end.min(right_end)))
+                })
+                .filter(|(start, end)| start <= end)
+                .collect::<Vec<_>>();
+
+            if times.is_empty() {
+                None
+            } else {
+                Some(Some(times))
+            }
+        }
+    }
+}
+
+fn merge_jobs(jobs: &[&Job]) -> Job {
+    let first = jobs.first().unwrap();
+
+    let tasks = jobs
+        .iter()
+        .flat_map(|job| {
+            get_tasks(job).map(move |(_, task)| JobTask {
+                tag: task.tag.clone().or_else(|| Some(job.id.clone())),
+                ..task.clone()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let (task_type, _) = get_tasks(first).next().unwrap();
+
+    Job {
+        id: first.id.clone(),
+        pickups: if task_type == "pickup" { Some(tasks.clone()) } else { None },
+        deliveries: if task_type == "delivery" { Some(tasks.clone()) } else { None },
+        replacements: if task_type == "replacement" { Some(tasks.clone()) } else { None },
+        services: if task_type == "service" { Some(tasks) } else { None },
+        priority: first.priority,
+        skills: first.skills.clone(),
+        position: first.position,
+    }
+}
+
+fn get_tasks(job: &Job) -> impl Iterator<Item = (&'static str, &JobTask)> + '_ {
+    with_type("pickup", &job.pickups)
+        .chain(with_type("delivery", &job.deliveries))
+        .chain(with_type("replacement", &job.replacements))
+        .chain(with_type("service", &job.services))
+}
+
+fn with_type<'a>(
+    task_type: &'static str,
+    tasks: &'a Option<Vec<JobTask>>,
+) -> impl Iterator<Item = (&'static str, &'a JobTask)> {
+    tasks.iter().flat_map(move |tasks| tasks.iter().map(move |task| (task_type, task)))
+}
+
+fn get_location_key(location: &Location) -> (u64, u64) {
+    (location.lat.to_bits(), location.lng.to_bits())
+}
+
+/// Groups jobs by keys preserving order of first key appearance. Each job can have multiple keys.
+fn group_by<'a, K, V, F>(jobs: impl Iterator<Item = &'a Job>, get_keys: F) -> Vec<(V, Vec<&'a Job>)>
+where
+    K: Eq + Hash,
+    F: Fn(&'a Job) -> Vec<(K, V)>,
+{
+    let mut indices = HashMap::new();
+    let mut groups: Vec<(V, Vec<&'a Job>)> = vec![];
+
+    jobs.for_each(|job| {
+        get_keys(job).into_iter().for_each(|(key, value)| {
+            let idx = *indices.entry(key).or_insert_with(|| {
+                groups.push((value, vec![]));
+                groups.len() - 1
+            });
+            groups[idx].1.push(job);
+        });
+    });
+
+    groups
+}
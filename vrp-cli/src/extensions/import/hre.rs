@@ -357,6 +357,7 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                         services: None,
                         priority: job.priority.as_ref().map(|p| *p),
                         skills: job.skills.clone(),
+                        position: None,
                     },
                     hre::JobVariant::Multi(job) => Job {
                         id: job.id.clone(),
@@ -366,6 +367,7 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                         services: None,
                         priority: job.priority.as_ref().map(|p| *p),
                         skills: job.skills.clone(),
+                        position: None,
                     },
                 })
                 .collect(),
@@ -384,6 +386,7 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                     })
                     .collect()
             }),
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: hre_problem
@@ -406,11 +409,13 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                             start: VehiclePlace {
                                 time: shift.start.time.clone(),
                                 location: to_loc(&shift.start.location),
+                                gate: None,
                             },
-                            end: shift
-                                .end
-                                .as_ref()
-                                .map(|end| VehiclePlace { time: end.time.clone(), location: to_loc(&end.location) }),
+                            end: shift.end.as_ref().map(|end| VehiclePlace {
+                                time: end.time.clone(),
+                                location: to_loc(&end.location),
+                                gate: None,
+                            }),
                             breaks: shift.breaks.as_ref().map(|breaks| {
                                 breaks
                                     .iter()
@@ -441,13 +446,22 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                         shift_time: l.shift_time.clone(),
                         allowed_areas: None,
                     }),
+                    unavailable: None,
+                    positions: None,
                 })
                 .collect(),
             profiles: hre_problem
                 .fleet
                 .profiles
                 .iter()
-                .map(|p| Profile { name: p.name.clone(), profile_type: p.profile_type.clone(), speed: None })
+                .map(|p| Profile {
+                    name: p.name.clone(),
+                    profile_type: p.profile_type.clone(),
+                    speed: None,
+                    overrides: None,
+                    multipliers: None,
+                    straight_line: None,
+                })
                 .collect(),
         },
         objectives: None,
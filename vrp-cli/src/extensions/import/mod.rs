@@ -1,23 +1,42 @@
+#[cfg(feature = "importers")]
 mod csv;
+#[cfg(feature = "importers")]
 use self::csv::read_csv_problem;
+#[cfg(feature = "importers")]
 mod hre;
+#[cfg(feature = "importers")]
 use self::hre::read_hre_problem;
+mod duplicates;
+pub use self::duplicates::{get_duplicate_locations, merge_duplicate_locations, DuplicateLocation};
 
 use std::io::{BufReader, Read};
 use vrp_pragmatic::format::problem::Problem;
 
+/// Imports problem from given format:
+/// * `csv`: two files with jobs and vehicles
+/// * `hre`: one file in hre json format
+///
+/// Both formats require `importers` feature.
 pub fn import_problem<R: Read>(input_format: &str, readers: Option<Vec<BufReader<R>>>) -> Result<Problem, String> {
     match (input_format, readers) {
+        #[cfg(not(feature = "importers"))]
+        ("csv", _) | ("hre", _) => {
+            Err(format!("{} format is not supported: build with 'importers' feature", input_format))
+        }
+        #[cfg(feature = "importers")]
         ("csv", Some(mut readers)) if readers.len() == 2 => {
             let jobs = readers.swap_remove(0);
             let vehicles = readers.swap_remove(0);
             read_csv_problem(jobs, vehicles).map_err(|err| format!("cannot read csv: {}", err))
         }
+        #[cfg(feature = "importers")]
         ("csv", _) => Err("csv format expects two files with jobs and vehicles as an input".to_string()),
+        #[cfg(feature = "importers")]
         ("hre", Some(mut readers)) if readers.len() == 1 => {
             let problem = readers.swap_remove(0);
             read_hre_problem(problem).map_err(|err| format!("cannot read problem from hre json: '{}'", err))
         }
+        #[cfg(feature = "importers")]
         ("hre", _) => Err("hre format expects one input file".to_string()),
         _ => Err(format!("unknown format: '{}'", input_format)),
     }
@@ -0,0 +1,27 @@
+//! Contains logic to create routing matrices for problem locations.
+
+#[cfg(all(feature = "osrm", not(target_arch = "wasm32")))]
+mod osrm;
+#[cfg(all(feature = "osrm", not(target_arch = "wasm32")))]
+pub use self::osrm::create_osrm_matrices;
+
+mod provider;
+pub use self::provider::format_locations;
+
+use vrp_pragmatic::format::problem::{create_approx_matrices, Matrix, Problem};
+
+/// Creates routing matrices for each profile of the problem using given method:
+/// * `haversine`: great-circle distances and profile speed
+/// * `osrm`: distances and durations requested from OSRM table service at `osrm_url`, requires
+///   `osrm` feature which is not available in webassembly build
+pub fn create_matrices(problem: &Problem, method: &str, osrm_url: Option<&str>) -> Result<Vec<Matrix>, String> {
+    match (method, osrm_url) {
+        ("haversine", _) => Ok(create_approx_matrices(problem)),
+        #[cfg(all(feature = "osrm", not(target_arch = "wasm32")))]
+        ("osrm", Some(osrm_url)) => create_osrm_matrices(problem, osrm_url),
+        #[cfg(not(all(feature = "osrm", not(target_arch = "wasm32"))))]
+        ("osrm", Some(_)) => Err("osrm method is not supported: build with 'osrm' feature".to_string()),
+        ("osrm", None) => Err("osrm method requires OSRM service url".to_string()),
+        _ => Err(format!("unknown matrix method: '{}'", method)),
+    }
+}
@@ -0,0 +1,185 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/matrix/osrm_test.rs"]
+mod osrm_test;
+
+use super::provider::get_osrm_coordinates;
+use serde::Deserialize;
+use std::io::Read;
+use std::ops::Range;
+use std::time::Duration;
+use vrp_pragmatic::format::problem::{Matrix, Problem};
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::get_unique_locations;
+
+/// An error code used to mark locations which are not reachable according to OSRM.
+const UNREACHABLE_ERROR_CODE: i64 = 1;
+
+/// Max amount of coordinates in one table request. It is the default limit of OSRM server.
+const MAX_TABLE_SIZE: usize = 100;
+
+/// Max time to establish connection with OSRM server.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Max time to wait for response data from OSRM server.
+const READ_TIMEOUT: Duration = Duration::from_secs(120);
+
+#[derive(Deserialize)]
+struct TableResponse {
+    code: String,
+    message: Option<String>,
+    durations: Option<Vec<Vec<Option<f64>>>>,
+    distances: Option<Vec<Vec<Option<f64>>>>,
+}
+
+/// Table values of source and destination locations as flatten durations and distances.
+type TableValues = (Vec<Option<f64>>, Vec<Option<f64>>);
+
+/// Creates routing matrices for each profile of the problem using OSRM table service. Profile type
+/// is used as OSRM profile name. Big matrices are requested in blocks which fit into the default
+/// table size limit of OSRM server.
+pub fn create_osrm_matrices(problem: &Problem, url: &str) -> Result<Vec<Matrix>, String> {
+    create_osrm_matrices_with_table_size(problem, url, MAX_TABLE_SIZE)
+}
+
+fn create_osrm_matrices_with_table_size(
+    problem: &Problem,
+    url: &str,
+    max_table_size: usize,
+) -> Result<Vec<Matrix>, String> {
+    let agent = ureq::AgentBuilder::new().timeout_connect(CONNECT_TIMEOUT).timeout_read(READ_TIMEOUT).build();
+    let url = url.trim_end_matches('/');
+    let locations = get_unique_locations(problem);
+    let size = locations.len();
+    let block_size = (max_table_size / 2).max(1);
+
+    problem
+        .fleet
+        .profiles
+        .iter()
+        .map(|profile| {
+            let mut durations = vec![None; size * size];
+            let mut distances = vec![None; size * size];
+
+            for source_start in (0..size).step_by(block_size) {
+                for destination_start in (0..size).step_by(block_size) {
+                    let sources = source_start..(source_start + block_size).min(size);
+                    let destinations = destination_start..(destination_start + block_size).min(size);
+
+                    let path = get_table_path(profile.profile_type.as_str(), &locations, &sources, &destinations);
+                    let body = send_get_request(&agent, format!("{}{}", url, path).as_str())?;
+                    let (block_durations, block_distances) =
+                        parse_table_response(body.as_slice(), sources.len(), destinations.len())?;
+
+                    sources.clone().enumerate().for_each(|(row, from)| {
+                        destinations.clone().enumerate().for_each(|(column, to)| {
+                            let idx = row * destinations.len() + column;
+                            durations[from * size + to] = block_durations[idx];
+                            distances[from * size + to] = block_distances[idx];
+                        })
+                    });
+                }
+            }
+
+            let (travel_times, distances, error_codes) = create_matrix_values(durations, distances);
+
+            Ok(Matrix { profile: profile.name.clone(), timestamp: None, travel_times, distances, error_codes })
+        })
+        .collect()
+}
+
+/// Returns table service path for given block of source and destination locations. When blocks are
+/// different, coordinates of destinations follow coordinates of sources.
+fn get_table_path(
+    profile: &str,
+    locations: &[Location],
+    sources: &Range<usize>,
+    destinations: &Range<usize>,
+) -> String {
+    let join = |indices: Range<usize>| indices.map(|idx| idx.to_string()).collect::<Vec<_>>().join(";");
+
+    let (coordinates, destination_indices) = if sources == destinations {
+        (get_osrm_coordinates(&locations[sources.clone()]), join(0..sources.len()))
+    } else {
+        let coordinates = locations[sources.clone()]
+            .iter()
+            .chain(locations[destinations.clone()].iter())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        (get_osrm_coordinates(coordinates.as_slice()), join(sources.len()..sources.len() + destinations.len()))
+    };
+
+    format!(
+        "/table/v1/{}/{}?sources={}&destinations={}&annotations=duration,distance",
+        profile,
+        coordinates,
+        join(0..sources.len()),
+        destination_indices
+    )
+}
+
+/// Parses OSRM table response into flatten durations and distances of given amount of sources and
+/// destinations.
+fn parse_table_response(body: &[u8], sources: usize, destinations: usize) -> Result<TableValues, String> {
+    let response = serde_json::from_slice::<TableResponse>(body)
+        .map_err(|err| format!("cannot parse OSRM response: '{}'", err))?;
+
+    if response.code != "Ok" {
+        return Err(format!(
+            "OSRM returned '{}' code: '{}'",
+            response.code,
+            response.message.unwrap_or_else(|| "no message".to_string())
+        ));
+    }
+
+    let flatten = |values: Option<Vec<Vec<Option<f64>>>>, name: &str| {
+        let values = values
+            .ok_or_else(|| format!("OSRM response has no {}", name))?
+            .into_iter()
+            .flat_map(|row| row.into_iter())
+            .collect::<Vec<_>>();
+
+        if values.len() == sources * destinations {
+            Ok(values)
+        } else {
+            Err(format!("OSRM response has unexpected amount of {}: {}", name, values.len()))
+        }
+    };
+
+    Ok((flatten(response.durations, "durations")?, flatten(response.distances, "distances")?))
+}
+
+/// Creates matrix durations, distances and optional error codes from table values.
+fn create_matrix_values(
+    durations: Vec<Option<f64>>,
+    distances: Vec<Option<f64>>,
+) -> (Vec<i64>, Vec<i64>, Option<Vec<i64>>) {
+    let to_values = |values: &[Option<f64>]| values.iter().map(|v| v.map_or(0, |v| v.round() as i64)).collect();
+    let error_codes = durations
+        .iter()
+        .zip(distances.iter())
+        .map(|pair| match pair {
+            (Some(_), Some(_)) => 0,
+            _ => UNREACHABLE_ERROR_CODE,
+        })
+        .collect::<Vec<_>>();
+    let error_codes = if error_codes.iter().any(|&code| code != 0) { Some(error_codes) } else { None };
+
+    (to_values(durations.as_slice()), to_values(distances.as_slice()), error_codes)
+}
+
+/// Sends http GET request and returns response body as bytes.
+fn send_get_request(agent: &ureq::Agent, url: &str) -> Result<Vec<u8>, String> {
+    let response = match agent.get(url).set("Accept", "application/json").call() {
+        Ok(response) => response,
+        // NOTE OSRM returns error details in json body together with 400 status code
+        Err(ureq::Error::Status(400, response)) => response,
+        Err(ureq::Error::Status(status, _)) => return Err(format!("unexpected http status: '{}'", status)),
+        Err(err) => return Err(format!("cannot send request to '{}': '{}'", url, err)),
+    };
+
+    let mut body = vec![];
+    response.into_reader().read_to_end(&mut body).map_err(|err| format!("cannot read response: '{}'", err))?;
+
+    Ok(body)
+}
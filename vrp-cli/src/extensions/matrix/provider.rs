@@ -0,0 +1,51 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/matrix/provider_test.rs"]
+mod provider_test;
+
+use serde::Serialize;
+use vrp_pragmatic::format::Location;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HereMatrixRequest<'a> {
+    origins: &'a [Location],
+    region_definition: HereRegionDefinition,
+    matrix_attributes: Vec<&'static str>,
+}
+
+#[derive(Serialize)]
+struct HereRegionDefinition {
+    #[serde(rename = "type")]
+    region_type: &'static str,
+}
+
+/// Formats locations as a request to routing matrix service of the given provider:
+/// * `osrm`: coordinates url fragment of table service, e.g. `13.3,52.5;13.4,52.6`
+/// * `here`: request body of HERE Matrix Routing API v8
+/// * `google`: `origins` and `destinations` url parameters of Google Distance Matrix API
+pub fn format_locations(locations: &[Location], provider: &str) -> Result<String, String> {
+    match provider {
+        "osrm" => Ok(get_osrm_coordinates(locations)),
+        "here" => serde_json::to_string_pretty(&HereMatrixRequest {
+            origins: locations,
+            region_definition: HereRegionDefinition { region_type: "world" },
+            matrix_attributes: vec!["travelTimes", "distances"],
+        })
+        .map_err(|err| err.to_string()),
+        "google" => {
+            let coordinates = locations
+                .iter()
+                .map(|location| format!("{},{}", location.lat, location.lng))
+                .collect::<Vec<_>>()
+                .join("|");
+
+            Ok(format!("origins={}&destinations={}", coordinates, coordinates))
+        }
+        _ => Err(format!("unknown routing provider: '{}'", provider)),
+    }
+}
+
+/// Returns locations as OSRM coordinates: `lng,lat` pairs separated by semicolon.
+pub(crate) fn get_osrm_coordinates(locations: &[Location]) -> String {
+    locations.iter().map(|location| format!("{},{}", location.lng, location.lat)).collect::<Vec<_>>().join(";")
+}
@@ -1,3 +1,16 @@
+pub mod analyze;
+pub mod compare;
+#[cfg(not(all(target_arch = "wasm32", feature = "wasm-slim")))]
+pub mod convert;
 pub mod generate;
+#[cfg(not(all(target_arch = "wasm32", feature = "wasm-slim")))]
 pub mod import;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod matrix;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod plugin;
+#[cfg(not(all(target_arch = "wasm32", feature = "wasm-slim")))]
+pub mod reduce;
 pub mod solve;
+#[cfg(not(all(target_arch = "wasm32", feature = "wasm-slim")))]
+pub mod timeline;
@@ -0,0 +1,228 @@
+//! Contains logic to load constraints from plugins: shared libraries with C ABI interface.
+//!
+//! A plugin exports `vrp_register_module` function which fills [`PluginModule`] with plugin
+//! callbacks and returns zero on success:
+//!
+//! ```c
+//! int32_t vrp_register_module(PluginModule* module);
+//! ```
+//!
+//! Callbacks are called from multiple threads, so they have to be thread safe.
+
+#[cfg(test)]
+#[path = "../../tests/unit/extensions/plugin_test.rs"]
+mod plugin_test;
+
+use libloading::{Library, Symbol};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Cost, IdDimension};
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::Activity;
+use vrp_core::models::Problem;
+
+/// A name of function exported by plugin library.
+pub const PLUGIN_REGISTER_FN_NAME: &str = "vrp_register_module";
+
+/// Activity data passed to plugin callbacks.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PluginActivity {
+    /// Location index.
+    pub location: usize,
+    /// Activity duration.
+    pub duration: f64,
+    /// Start of activity time window.
+    pub tw_start: f64,
+    /// End of activity time window.
+    pub tw_end: f64,
+    /// Arrival time. It is not yet known for target activity.
+    pub arrival: f64,
+    /// Departure time. It is not yet known for target activity.
+    pub departure: f64,
+}
+
+/// Activity insertion context passed to plugin callbacks.
+#[repr(C)]
+#[derive(Debug)]
+pub struct PluginActivityContext {
+    /// Vehicle id as utf8 bytes, not null terminated.
+    pub vehicle_id: *const u8,
+    /// Length of vehicle id.
+    pub vehicle_id_len: usize,
+    /// Previous activity.
+    pub prev: PluginActivity,
+    /// Target activity which is inserted.
+    pub target: PluginActivity,
+    /// Next activity. Null if tour is open and target activity is inserted last.
+    pub next: *const PluginActivity,
+}
+
+/// Evaluates activity insertion: returns `false` if insertion is not allowed.
+pub type PluginHardActivityFn = extern "C" fn(ctx: *const PluginActivityContext) -> bool;
+
+/// Estimates activity insertion: returns penalty which is added to insertion cost.
+pub type PluginSoftActivityFn = extern "C" fn(ctx: *const PluginActivityContext) -> f64;
+
+/// Specifies plugin callbacks. Any of them can be omitted by setting it to null.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PluginModule {
+    /// Violation code reported for jobs which cannot be assigned due to plugin hard constraint.
+    pub code: i32,
+    /// Hard activity constraint callback.
+    pub hard_activity: Option<PluginHardActivityFn>,
+    /// Soft activity constraint callback.
+    pub soft_activity: Option<PluginSoftActivityFn>,
+}
+
+type PluginRegisterFn = unsafe extern "C" fn(module: *mut PluginModule) -> i32;
+
+/// Loads constraint module from plugin library.
+pub fn load_plugin_module(path: &str) -> Result<Box<dyn ConstraintModule + Send + Sync>, String> {
+    let library = Library::new(path).map_err(|err| format!("cannot load plugin '{}': '{}'", path, err))?;
+
+    let mut module = PluginModule { code: 0, hard_activity: None, soft_activity: None };
+
+    let result = unsafe {
+        let register: Symbol<PluginRegisterFn> = library
+            .get(PLUGIN_REGISTER_FN_NAME.as_bytes())
+            .map_err(|err| format!("cannot find '{}' in plugin '{}': '{}'", PLUGIN_REGISTER_FN_NAME, path, err))?;
+
+        register(&mut module)
+    };
+
+    if result != 0 {
+        return Err(format!("plugin '{}' failed to register module with code: {}", path, result));
+    }
+
+    Ok(Box::new(PluginConstraintModule::new(module, Some(Arc::new(library)))))
+}
+
+/// Adds constraint modules loaded from plugin libraries to the problem.
+pub fn add_plugin_modules(problem: Problem, paths: &[&str]) -> Result<Problem, String> {
+    if paths.is_empty() {
+        return Ok(problem);
+    }
+
+    let mut problem = problem;
+    let constraint = Arc::get_mut(&mut problem.constraint)
+        .ok_or_else(|| "cannot add plugin modules: constraint pipeline is already in use".to_string())?;
+
+    paths.iter().try_for_each(|path| {
+        constraint.add_module(load_plugin_module(path)?);
+        Ok::<_, String>(())
+    })?;
+
+    Ok(problem)
+}
+
+/// A constraint module which delegates activity checks to plugin callbacks.
+struct PluginConstraintModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl PluginConstraintModule {
+    /// Creates a new instance of `PluginConstraintModule`. Library, if present, is kept loaded
+    /// as long as module's constraints are alive.
+    fn new(module: PluginModule, library: Option<Arc<Library>>) -> Self {
+        let hard = module.hard_activity.map(|callback| {
+            ConstraintVariant::HardActivity(Arc::new(PluginHardActivityConstraint {
+                code: module.code,
+                callback,
+                _library: library.clone(),
+            }))
+        });
+
+        let soft = module.soft_activity.map(|callback| {
+            ConstraintVariant::SoftActivity(Arc::new(PluginSoftActivityConstraint {
+                callback,
+                _library: library.clone(),
+            }))
+        });
+
+        Self { constraints: hard.into_iter().chain(soft.into_iter()).collect(), keys: vec![] }
+    }
+}
+
+impl ConstraintModule for PluginConstraintModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_ctx: &mut RouteContext, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct PluginHardActivityConstraint {
+    code: i32,
+    callback: PluginHardActivityFn,
+    _library: Option<Arc<Library>>,
+}
+
+impl HardActivityConstraint for PluginHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let is_allowed = with_plugin_context(route_ctx, activity_ctx, |ctx| (self.callback)(ctx));
+
+        if is_allowed {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
+
+struct PluginSoftActivityConstraint {
+    callback: PluginSoftActivityFn,
+    _library: Option<Arc<Library>>,
+}
+
+impl SoftActivityConstraint for PluginSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        with_plugin_context(route_ctx, activity_ctx, |ctx| (self.callback)(ctx))
+    }
+}
+
+fn with_plugin_context<T>(
+    route_ctx: &RouteContext,
+    activity_ctx: &ActivityContext,
+    func: impl FnOnce(*const PluginActivityContext) -> T,
+) -> T {
+    let vehicle_id = route_ctx.route.actor.vehicle.dimens.get_id().map_or("", |id| id.as_str());
+    let next = activity_ctx.next.map(|next| create_plugin_activity(next));
+
+    let ctx = PluginActivityContext {
+        vehicle_id: vehicle_id.as_ptr(),
+        vehicle_id_len: vehicle_id.len(),
+        prev: create_plugin_activity(activity_ctx.prev),
+        target: create_plugin_activity(activity_ctx.target),
+        next: next.as_ref().map_or(std::ptr::null(), |next| next as *const PluginActivity),
+    };
+
+    func(&ctx)
+}
+
+fn create_plugin_activity(activity: &Activity) -> PluginActivity {
+    PluginActivity {
+        location: activity.place.location,
+        duration: activity.place.duration,
+        tw_start: activity.place.time.start,
+        tw_end: activity.place.time.end,
+        arrival: activity.schedule.arrival,
+        departure: activity.schedule.departure,
+    }
+}
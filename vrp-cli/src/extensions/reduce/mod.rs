@@ -0,0 +1,172 @@
+//! Contains logic to reduce problem size while keeping it valid, e.g. to get a minimal
+//! reproduction case of solver issue.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/reduce/reduce_test.rs"]
+mod reduce_test;
+
+use std::collections::{HashMap, HashSet};
+use vrp_core::utils::{shuffle, DefaultRandom, Random};
+use vrp_pragmatic::format::problem::*;
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::get_unique_locations;
+
+/// Specifies the way jobs are selected for the reduced problem.
+#[derive(Clone, Debug)]
+pub enum ReduceMethod {
+    /// Keeps given amount of randomly selected jobs.
+    Sample {
+        /// Amount of jobs to keep.
+        jobs: usize,
+        /// A seed used to get reproducible selection.
+        seed: Option<u64>,
+    },
+    /// Keeps jobs which have all places inside of bounding box.
+    Region {
+        /// Min latitude and longitude.
+        min: Location,
+        /// Max latitude and longitude.
+        max: Location,
+    },
+}
+
+/// Reduces problem by keeping only jobs selected by given method. Fleet is preserved as is,
+/// relations and forbidden arcs are updated to refer to the kept jobs only. Routing matrices,
+/// if specified, are reduced to the locations used by the reduced problem.
+pub fn reduce_problem(
+    problem: &Problem,
+    matrices: &[Matrix],
+    method: &ReduceMethod,
+) -> Result<(Problem, Vec<Matrix>), String> {
+    let kept = select_jobs(problem, method);
+    if kept.is_empty() {
+        return Err("no jobs left in reduced problem".to_string());
+    }
+
+    let jobs = problem.plan.jobs.iter().filter(|job| kept.contains(&job.id)).cloned().collect();
+    let is_kept = |id: &String| kept.contains(id) || is_special_job_id(id);
+
+    let relations = problem.plan.relations.as_ref().map(|relations| {
+        relations
+            .iter()
+            .filter(|relation| relation.jobs.iter().any(|id| kept.contains(id)))
+            .map(|relation| Relation {
+                jobs: relation.jobs.iter().filter(|id| is_kept(id)).cloned().collect(),
+                ..relation.clone()
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let forbidden_arcs = problem.plan.forbidden_arcs.as_ref().map(|arcs| {
+        arcs.iter().filter(|arc| kept.contains(&arc.from) && kept.contains(&arc.to)).cloned().collect::<Vec<_>>()
+    });
+
+    let reduced = Problem {
+        plan: Plan {
+            jobs,
+            relations: relations.filter(|relations| !relations.is_empty()),
+            forbidden_arcs: forbidden_arcs.filter(|arcs| !arcs.is_empty()),
+        },
+        ..problem.clone()
+    };
+
+    let matrices = reduce_matrices(problem, &reduced, matrices)?;
+
+    Ok((reduced, matrices))
+}
+
+fn select_jobs(problem: &Problem, method: &ReduceMethod) -> HashSet<String> {
+    match method {
+        ReduceMethod::Sample { jobs, seed } => {
+            let random: Box<dyn Random> = match seed {
+                Some(seed) => Box::new(DefaultRandom::new_with_seed(*seed)),
+                None => Box::new(DefaultRandom::default()),
+            };
+
+            let mut ids = problem.plan.jobs.iter().map(|job| job.id.clone()).collect::<Vec<_>>();
+            shuffle(ids.as_mut_slice(), random.as_ref());
+
+            ids.into_iter().take(*jobs).collect()
+        }
+        ReduceMethod::Region { min, max } => {
+            let is_inside = |location: &Location| {
+                location.lat >= min.lat && location.lat <= max.lat && location.lng >= min.lng && location.lng <= max.lng
+            };
+
+            problem
+                .plan
+                .jobs
+                .iter()
+                .filter(|job| get_places(job).all(|place| is_inside(&place.location)))
+                .map(|job| job.id.clone())
+                .collect()
+        }
+    }
+}
+
+fn reduce_matrices(original: &Problem, reduced: &Problem, matrices: &[Matrix]) -> Result<Vec<Matrix>, String> {
+    let original_indices = get_unique_locations(original)
+        .iter()
+        .enumerate()
+        .map(|(idx, location)| (get_location_key(location), idx))
+        .collect::<HashMap<_, _>>();
+    let original_size = original_indices.len();
+
+    let indices = get_unique_locations(reduced)
+        .iter()
+        .map(|location| {
+            original_indices
+                .get(&get_location_key(location))
+                .cloned()
+                .ok_or_else(|| format!("cannot find location ({}, {}) in original problem", location.lat, location.lng))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    matrices
+        .iter()
+        .map(|matrix| {
+            if matrix.travel_times.len() != original_size * original_size
+                || matrix.distances.len() != original_size * original_size
+            {
+                return Err(format!(
+                    "routing matrix for profile '{}' does not match problem: expected {} locations",
+                    matrix.profile, original_size
+                ));
+            }
+
+            let reduce = |values: &Vec<i64>| {
+                indices
+                    .iter()
+                    .flat_map(|from| indices.iter().map(move |to| values[from * original_size + to]))
+                    .collect::<Vec<_>>()
+            };
+
+            Ok(Matrix {
+                profile: matrix.profile.clone(),
+                timestamp: matrix.timestamp.clone(),
+                travel_times: reduce(&matrix.travel_times),
+                distances: reduce(&matrix.distances),
+                error_codes: matrix.error_codes.as_ref().map(reduce),
+            })
+        })
+        .collect()
+}
+
+fn get_places(job: &Job) -> impl Iterator<Item = &JobPlace> {
+    job.pickups
+        .iter()
+        .chain(job.deliveries.iter())
+        .chain(job.replacements.iter())
+        .chain(job.services.iter())
+        .flat_map(|tasks| tasks.iter())
+        .flat_map(|task| task.places.iter())
+}
+
+fn get_location_key(location: &Location) -> (u64, u64) {
+    (location.lat.to_bits(), location.lng.to_bits())
+}
+
+/// Returns true if job id refers to a vehicle activity in relation, not to a job.
+fn is_special_job_id(id: &str) -> bool {
+    matches!(id, "departure" | "arrival" | "break" | "reload")
+}
@@ -0,0 +1,86 @@
+//! Contains logic to check the final solution against acceptance thresholds specified in config.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/acceptance_test.rs"]
+mod acceptance_test;
+
+use super::config::AcceptanceConfig;
+use serde::Serialize;
+use vrp_core::models::Solution;
+
+/// Contains solution metrics checked against acceptance thresholds and violated thresholds.
+#[derive(Clone, Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptanceReport {
+    /// Amount of unassigned jobs.
+    pub unassigned: usize,
+    /// Solution cost.
+    pub cost: f64,
+    /// Share of total routes duration which is not spent on waiting.
+    pub utilization: f64,
+    /// Descriptions of violated thresholds.
+    pub violations: Vec<String>,
+}
+
+impl AcceptanceReport {
+    /// Returns true if solution meets all thresholds.
+    pub fn is_accepted(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Checks solution with its cost against acceptance thresholds.
+pub fn check_acceptance(config: &AcceptanceConfig, solution: &Solution, cost: f64) -> AcceptanceReport {
+    create_acceptance_report(config, solution.unassigned.len(), cost, get_utilization(solution))
+}
+
+fn create_acceptance_report(
+    config: &AcceptanceConfig,
+    unassigned: usize,
+    cost: f64,
+    utilization: f64,
+) -> AcceptanceReport {
+    let violations = config
+        .max_unassigned
+        .filter(|&max_unassigned| unassigned > max_unassigned)
+        .map(|max_unassigned| format!("unassigned jobs: {} > {}", unassigned, max_unassigned))
+        .into_iter()
+        .chain(
+            config
+                .max_cost
+                .filter(|&max_cost| cost > max_cost)
+                .map(|max_cost| format!("cost: {} > {}", cost, max_cost)),
+        )
+        .chain(
+            config
+                .min_utilization
+                .filter(|&min_utilization| utilization < min_utilization)
+                .map(|min_utilization| format!("utilization: {} < {}", utilization, min_utilization)),
+        )
+        .collect();
+
+    AcceptanceReport { unassigned, cost, utilization, violations }
+}
+
+/// Returns share of total routes duration which is spent on driving and serving jobs.
+fn get_utilization(solution: &Solution) -> f64 {
+    let (duration, waiting) = solution.routes.iter().fold((0., 0.), |(duration, waiting), route| {
+        let tour = &route.tour;
+        let route_duration = match (tour.start(), tour.end()) {
+            (Some(start), Some(end)) => end.schedule.arrival - start.schedule.departure,
+            _ => 0.,
+        };
+        let route_waiting = tour
+            .all_activities()
+            .map(|activity| (activity.place.time.start - activity.schedule.arrival).max(0.))
+            .sum::<f64>();
+
+        (duration + route_duration, waiting + route_waiting)
+    });
+
+    if duration > 0. {
+        (duration - waiting) / duration
+    } else {
+        0.
+    }
+}
@@ -0,0 +1,57 @@
+//! Contains logic to solve multiple problems from a directory and summarize results.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/batch_test.rs"]
+mod batch_test;
+
+use serde::Serialize;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// A name of summary file written to the output directory.
+pub const BATCH_SUMMARY_FILE_NAME: &str = "summary.csv";
+
+/// A result of solving a single problem from the batch.
+#[derive(Clone, Serialize, Debug, PartialEq)]
+pub struct BatchResult {
+    /// Problem file name.
+    pub name: String,
+    /// Total cost.
+    pub cost: Option<f64>,
+    /// Amount of tours.
+    pub tours: Option<usize>,
+    /// Amount of unassigned jobs.
+    pub unassigned: Option<usize>,
+    /// Elapsed time in seconds.
+    pub elapsed: f64,
+    /// An error message if problem cannot be solved.
+    pub error: Option<String>,
+}
+
+/// Returns problem files from the input directory sorted by name. Hidden files and the summary
+/// file are skipped.
+pub fn get_batch_files(input_dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut files = std::fs::read_dir(input_dir)
+        .map_err(|err| format!("cannot read input directory '{}': '{}'", input_dir.display(), err))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .map_or(false, |name| !name.starts_with('.') && name != BATCH_SUMMARY_FILE_NAME)
+        })
+        .collect::<Vec<_>>();
+
+    files.sort();
+
+    Ok(files)
+}
+
+/// Writes batch results in csv format.
+pub fn write_batch_summary<W: Write>(writer: BufWriter<W>, results: &[BatchResult]) -> Result<(), String> {
+    let mut writer = csv::Writer::from_writer(writer);
+
+    results.iter().try_for_each(|result| writer.serialize(result)).map_err(|err| err.to_string())?;
+
+    writer.flush().map_err(|err| err.to_string())
+}
@@ -4,17 +4,73 @@ mod config_test;
 
 extern crate serde_json;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::io::{BufReader, Read};
+use std::str::FromStr;
 use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionCache;
 use vrp_core::solver::mutation::*;
 use vrp_core::solver::Builder;
+use vrp_core::utils::DefaultRandom;
 
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Default, Deserialize, Debug)]
 pub struct Config {
+    preset: Option<Preset>,
     population: Option<PopulationConfig>,
     mutation: Option<MutationConfig>,
     termination: Option<TerminationConfig>,
+    random: Option<RandomConfig>,
+    script: Option<ScriptConfig>,
+    acceptance: Option<AcceptanceConfig>,
+}
+
+impl Config {
+    /// Returns script settings if they are specified.
+    pub fn script(&self) -> Option<&ScriptConfig> {
+        self.script.as_ref()
+    }
+
+    /// Returns solution acceptance thresholds if they are specified.
+    pub fn acceptance(&self) -> Option<&AcceptanceConfig> {
+        self.acceptance.as_ref()
+    }
+
+    /// Returns config with given preset if it has no preset specified.
+    pub fn with_default_preset(mut self, preset: Preset) -> Self {
+        if self.preset.is_none() {
+            self.preset = Some(preset);
+        }
+        self
+    }
+}
+
+/// Specifies named quality preset which bundles termination, population and operator settings,
+/// so they can be used without knowledge of metaheuristic internals.
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq)]
+pub enum Preset {
+    /// Builds a plan fast using light ruin and recreate operators without full refinement,
+    /// e.g. for interactive use.
+    #[serde(rename = "quick")]
+    Quick,
+    /// Uses default settings which are a trade-off between speed and quality.
+    #[serde(rename = "normal")]
+    Normal,
+    /// Runs longer with bigger population and large neighbourhood operators to get better quality.
+    #[serde(rename = "thorough")]
+    Thorough,
+}
+
+impl FromStr for Preset {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "quick" => Ok(Preset::Quick),
+            "normal" => Ok(Preset::Normal),
+            "thorough" => Ok(Preset::Thorough),
+            _ => Err(format!("unknown preset: '{}'", value)),
+        }
+    }
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -56,7 +112,7 @@ pub enum RuinMethod {
 #[serde(tag = "type")]
 pub enum RecreateMethod {
     #[serde(rename(deserialize = "cheapest"))]
-    Cheapest { weight: usize },
+    Cheapest { weight: usize, cache_size: Option<usize> },
     #[serde(rename(deserialize = "regret"))]
     Regret { weight: usize, start: usize, end: usize },
     #[serde(rename(deserialize = "blinks"))]
@@ -65,6 +121,10 @@ pub enum RecreateMethod {
     Gaps { weight: usize, min: usize },
     #[serde(rename(deserialize = "nearest"))]
     Nearest { weight: usize },
+    #[serde(rename(deserialize = "priority"))]
+    Priority { weight: usize },
+    #[serde(rename(deserialize = "time-buckets"))]
+    TimeBuckets { weight: usize, bucket_size: f64 },
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -89,6 +149,31 @@ pub struct VariationConfig {
     cv: f64,
 }
 
+/// Specifies random generator settings.
+#[derive(Clone, Deserialize, Debug)]
+pub struct RandomConfig {
+    /// A seed used to get reproducible runs.
+    seed: Option<u64>,
+}
+
+/// Specifies a script with soft constraints.
+#[derive(Clone, Deserialize, Debug)]
+pub struct ScriptConfig {
+    /// A path to the script file.
+    pub path: String,
+}
+
+/// Specifies thresholds which the final solution has to meet to be accepted.
+#[derive(Clone, Deserialize, Debug, Default)]
+pub struct AcceptanceConfig {
+    /// Max amount of unassigned jobs.
+    pub max_unassigned: Option<usize>,
+    /// Max solution cost.
+    pub max_cost: Option<f64>,
+    /// Min share of total routes duration which is not spent on waiting, a value from 0 to 1.
+    pub min_utilization: Option<f64>,
+}
+
 fn configure_from_population(mut builder: Builder, population_config: &Option<PopulationConfig>) -> Builder {
     if let Some(config) = population_config {
         if let Some(methods) = &config.initial_methods {
@@ -138,21 +223,38 @@ fn configure_from_termination(mut builder: Builder, termination_config: &Option<
     builder
 }
 
-fn create_recreate_method(method: &RecreateMethod) -> (Box<dyn Recreate>, usize) {
+fn configure_from_random(mut builder: Builder, random_config: &Option<RandomConfig>) -> Builder {
+    if let Some(seed) = random_config.as_ref().and_then(|config| config.seed) {
+        builder = builder.with_random(Arc::new(DefaultRandom::new_with_seed(seed)));
+    }
+
+    builder
+}
+
+fn create_recreate_method(method: &RecreateMethod) -> (Box<dyn Recreate + Send + Sync>, usize) {
     match method {
-        RecreateMethod::Cheapest { weight } => (Box::new(RecreateWithCheapest::default()), *weight),
+        RecreateMethod::Cheapest { weight, cache_size } => match cache_size {
+            Some(size) => {
+                (Box::new(RecreateWithCheapest::new_with_cache(Arc::new(InsertionCache::new(*size)))), *weight)
+            }
+            None => (Box::new(RecreateWithCheapest::default()), *weight),
+        },
         RecreateMethod::Regret { weight, start, end } => (Box::new(RecreateWithRegret::new(*start, *end)), *weight),
         RecreateMethod::Blinks { weight } => (Box::new(RecreateWithBlinks::<i32>::default()), *weight),
         RecreateMethod::Gaps { weight, min } => (Box::new(RecreateWithGaps::new(*min)), *weight),
         RecreateMethod::Nearest { weight } => (Box::new(RecreateWithNearestNeighbor::default()), *weight),
+        RecreateMethod::Priority { weight } => (Box::new(RecreateWithPriority::default()), *weight),
+        RecreateMethod::TimeBuckets { weight, bucket_size } => {
+            (Box::new(RecreateWithTimeBuckets::new(*bucket_size)), *weight)
+        }
     }
 }
 
-fn create_ruin_group(group: &RuinMethodGroup) -> (Vec<(Arc<dyn Ruin>, f64)>, usize) {
+fn create_ruin_group(group: &RuinMethodGroup) -> (Vec<(Arc<dyn Ruin + Send + Sync>, f64)>, usize) {
     (group.methods.iter().map(|r| create_ruin_method(r)).collect(), group.weight)
 }
 
-fn create_ruin_method(method: &RuinMethod) -> (Arc<dyn Ruin>, f64) {
+fn create_ruin_method(method: &RuinMethod) -> (Arc<dyn Ruin + Send + Sync>, f64) {
     match method {
         RuinMethod::AdjustedString { probability, lmax, cavg, alpha } => {
             (Arc::new(AdjustedStringRemoval::new(*lmax, *cavg, *alpha)), *probability)
@@ -182,10 +284,70 @@ pub fn create_builder_from_config_file<R: Read>(reader: BufReader<R>) -> Result<
     read_config(reader).and_then(|config| create_builder_from_config(&config))
 }
 
-/// Creates a solver `Builder` from config.
+/// Creates a solver `Builder` from preset.
+pub fn create_builder_from_preset(preset: &Preset) -> Builder {
+    let builder = match preset {
+        Preset::Quick => Builder::default().with_quick_mode(true),
+        Preset::Normal => Builder::default(),
+        Preset::Thorough => Builder::default()
+            .with_initial_size(4)
+            .with_population_size(8)
+            .with_offspring_size(8)
+            .with_elite_size(4)
+            .with_max_generations(Some(5000))
+            .with_max_time(Some(900)),
+    };
+
+    configure_from_mutation(builder, &create_mutation_from_preset(preset))
+}
+
+/// Creates ruin and recreate operator mix of the preset. Returns none for default operators.
+fn create_mutation_from_preset(preset: &Preset) -> Option<MutationConfig> {
+    let string_removal =
+        |probability: f64, cavg: usize| RuinMethod::AdjustedString { probability, lmax: 30, cavg, alpha: 0.01 };
+    let neighbour_removal =
+        |probability: f64, min: usize, max: usize| RuinMethod::Neighbour { probability, min, max, threshold: 0.5 };
+    let random_removal =
+        |probability: f64, max: usize| RuinMethod::RandomJob { probability, min: 1, max, threshold: 0.2 };
+    let route_removal = |probability: f64| RuinMethod::RandomRoute { probability, min: 1, max: 8, threshold: 0.2 };
+    let group = |weight: usize, methods: Vec<RuinMethod>| RuinMethodGroup { methods, weight };
+
+    match preset {
+        Preset::Quick => Some(MutationConfig::RuinRecreate {
+            ruins: vec![
+                group(100, vec![string_removal(1., 10), random_removal(0.05, 10)]),
+                group(20, vec![neighbour_removal(1., 5, 15)]),
+            ],
+            recreates: vec![
+                RecreateMethod::Cheapest { weight: 100, cache_size: None },
+                RecreateMethod::Regret { weight: 20, start: 2, end: 3 },
+            ],
+        }),
+        Preset::Normal => None,
+        Preset::Thorough => Some(MutationConfig::RuinRecreate {
+            ruins: vec![
+                group(100, vec![string_removal(1., 15), route_removal(0.05), random_removal(0.05, 20)]),
+                group(50, vec![neighbour_removal(1., 15, 30), route_removal(0.05), random_removal(0.05, 20)]),
+                group(20, vec![string_removal(1., 120)]),
+                group(20, vec![neighbour_removal(1., 30, 120)]),
+                group(10, vec![RuinMethod::WorstJob { probability: 1., min: 1, max: 8, threshold: 32, skip: 4 }]),
+            ],
+            recreates: vec![
+                RecreateMethod::Cheapest { weight: 100, cache_size: None },
+                RecreateMethod::Regret { weight: 100, start: 2, end: 8 },
+                RecreateMethod::Blinks { weight: 50 },
+                RecreateMethod::Gaps { weight: 20, min: 2 },
+                RecreateMethod::Nearest { weight: 10 },
+            ],
+        }),
+    }
+}
+
+/// Creates a solver `Builder` from config. Settings specified in config override preset ones.
 pub fn create_builder_from_config(config: &Config) -> Result<Builder, String> {
-    let mut builder = Builder::default();
+    let mut builder = create_builder_from_preset(config.preset.as_ref().unwrap_or(&Preset::Normal));
 
+    builder = configure_from_random(builder, &config.random);
     builder = configure_from_population(builder, &config.population);
     builder = configure_from_mutation(builder, &config.mutation);
     builder = configure_from_termination(builder, &config.termination);
@@ -0,0 +1,68 @@
+//! Contains logic to render solver progress as a live dashboard in terminal.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/dashboard_test.rs"]
+mod dashboard_test;
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use vrp_core::solver::{ProgressEvent, ProgressHook};
+
+/// Minimum interval between dashboard redraws in seconds.
+const REFRESH_INTERVAL: f64 = 0.25;
+
+/// Creates a progress hook which redraws dashboard in place using ANSI escape codes. Redraws are
+/// throttled, so the dashboard does not slow down the solver.
+pub fn create_dashboard_hook<W: Write + Send + 'static>(writer: W) -> ProgressHook {
+    // NOTE keeps writer, elapsed time of the last redraw and amount of drawn lines
+    let state = Mutex::new((writer, None::<f64>, 0_usize));
+
+    Arc::new(move |event: &ProgressEvent| {
+        let mut state = state.lock().unwrap();
+        let (writer, last_redraw, drawn_lines) = &mut *state;
+
+        if last_redraw.map_or(false, |last_redraw| event.elapsed - last_redraw < REFRESH_INTERVAL) {
+            return;
+        }
+
+        let lines = render_dashboard(event);
+        let clear = if *drawn_lines > 0 { format!("\x1b[{}A\x1b[J", drawn_lines) } else { String::new() };
+        let _ = writeln!(writer, "{}{}", clear, lines.join("\n"));
+        let _ = writer.flush();
+
+        *last_redraw = Some(event.elapsed);
+        *drawn_lines = lines.len();
+    })
+}
+
+/// Renders solver progress as dashboard lines.
+pub fn render_dashboard(event: &ProgressEvent) -> Vec<String> {
+    vec![
+        ("generation", format!("{} ({:.2} gen/sec)", event.generation, event.speed)),
+        ("elapsed", format!("{:.1}s", event.elapsed)),
+        ("best cost", format!("{:.2}", event.best_cost)),
+        ("worst cost", format!("{:.2}", event.worst_cost)),
+        ("tours", event.tours.to_string()),
+        ("unassigned", event.unassigned.to_string()),
+        ("ruin usage", format_usage(event.ruin_usage.as_slice())),
+        ("recreate usage", format_usage(event.recreate_usage.as_slice())),
+    ]
+    .into_iter()
+    .map(|(name, value)| format!("{:<16}{}", format!("{}:", name), value))
+    .collect()
+}
+
+fn format_usage(usage: &[usize]) -> String {
+    let total = usage.iter().sum::<usize>();
+
+    if total == 0 {
+        return "-".to_string();
+    }
+
+    usage
+        .iter()
+        .enumerate()
+        .map(|(idx, &count)| format!("#{} {:.0}%", idx, count as f64 / total as f64 * 100.))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
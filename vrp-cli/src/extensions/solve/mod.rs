@@ -1 +1,13 @@
+pub mod acceptance;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod batch;
 pub mod config;
+pub mod dashboard;
+pub mod options;
+pub mod pool;
+pub mod profiler;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod route_summary;
+pub mod scenarios;
+pub mod script;
+pub mod zones;
@@ -0,0 +1,88 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/options_test.rs"]
+mod options_test;
+
+use super::config::{create_builder_from_config, create_builder_from_preset, Config, Preset};
+use super::zones::{assign_zones, Strategy};
+use serde::{Deserialize, Serialize};
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::solver::Builder;
+use vrp_core::utils::DefaultRandom;
+use vrp_pragmatic::format::problem::Problem;
+
+/// Specifies solver options which override settings from config or preset. The same options are
+/// accepted by command line interface (as arguments with kebab case names), C interface and
+/// webassembly build, so they stay in sync.
+#[derive(Clone, Default, Deserialize, Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SolveOptions {
+    /// Max amount of generations.
+    pub max_generations: Option<usize>,
+    /// Max running time in seconds.
+    pub max_time: Option<usize>,
+    /// Cost variation termination as sample size and threshold.
+    pub cost_variation: Option<(usize, f64)>,
+    /// Random seed used to get reproducible runs.
+    pub seed: Option<u64>,
+    /// Amount of threads used by solver.
+    pub parallelism: Option<usize>,
+    /// Quality preset used when no config is specified.
+    pub mode: Option<Preset>,
+    /// Reduces memory usage at the cost of solution quality.
+    pub low_memory: Option<bool>,
+    /// Sparse neighbourhood index limits used in low memory mode as amount of jobs and amount of
+    /// neighbours kept per job.
+    pub sparse_index: Option<(usize, usize)>,
+    /// Solving strategy, e.g. cluster-first route-second (pragmatic only).
+    pub strategy: Option<Strategy>,
+}
+
+/// Reads solve options from reader.
+pub fn read_options<R: Read>(reader: BufReader<R>) -> Result<SolveOptions, String> {
+    serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize solve options: '{}'", err))
+}
+
+/// Returns a problem modified according to solve options: when cluster-first strategy is used,
+/// jobs are pre-assigned to vehicles.
+pub fn apply_options_to_problem(problem: Problem, options: &SolveOptions) -> Result<Problem, String> {
+    match options.strategy {
+        Some(Strategy::ClusterFirst) => assign_zones(&problem),
+        _ => Ok(problem),
+    }
+}
+
+/// Creates a solver `Builder` from optional config and solve options. Options override settings
+/// from config, mode is used only when config has no preset.
+pub fn create_builder_from_options(config: Option<&Config>, options: &SolveOptions) -> Result<Builder, String> {
+    if options.parallelism == Some(0) {
+        return Err("parallelism should be greater than zero".to_string());
+    }
+
+    let builder = match config {
+        Some(config) => match options.mode.as_ref() {
+            Some(mode) => create_builder_from_config(&config.clone().with_default_preset(mode.clone()))?,
+            None => create_builder_from_config(config)?,
+        },
+        None => create_builder_from_preset(options.mode.as_ref().unwrap_or(&Preset::Normal)),
+    };
+
+    let builder = match options.seed {
+        Some(seed) => builder.with_random(Arc::new(DefaultRandom::new_with_seed(seed))),
+        None => builder,
+    };
+
+    let builder =
+        if options.max_generations.is_some() { builder.with_max_generations(options.max_generations) } else { builder };
+    let builder = if options.max_time.is_some() { builder.with_max_time(options.max_time) } else { builder };
+    let builder =
+        if options.cost_variation.is_some() { builder.with_cost_variation(options.cost_variation) } else { builder };
+
+    let builder =
+        if options.sparse_index.is_some() { builder.with_sparse_index(options.sparse_index) } else { builder };
+
+    Ok(match options.low_memory {
+        Some(low_memory) => builder.with_low_memory_mode(low_memory),
+        None => builder,
+    })
+}
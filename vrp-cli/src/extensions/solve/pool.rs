@@ -0,0 +1,96 @@
+//! Contains logic to solve the same problem with different routing matrices concurrently.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/pool_test.rs"]
+mod pool_test;
+
+use crate::catch_panic;
+use std::sync::{Arc, RwLock};
+use vrp_core::solver::{Builder, PreparedProblem};
+use vrp_pragmatic::format::problem::{Matrix, PragmaticProblem, Problem};
+use vrp_pragmatic::format::solution::{create_solution, Solution};
+
+/// A function which creates solver builder for each solve request.
+pub type BuilderFactory = Arc<dyn Fn() -> Builder + Send + Sync>;
+
+/// A default amount of prepared problems kept by the pool.
+const DEFAULT_CACHE_SIZE: usize = 4;
+
+/// Keeps deserialized problem definition and solves it with routing matrices specified per
+/// request, e.g. in server mode when the same plan is requested with different traffic data.
+/// The pool is `Send + Sync`, so it can be shared between threads and used concurrently.
+///
+/// Problem preparation (validation, job index with neighbour lists, constraint pipeline) depends
+/// on routing matrices, so the pool keeps prepared problems for the most recently prepared matrices
+/// and reuses them for subsequent requests with the same matrices.
+pub struct SolverPool {
+    problem: Arc<Problem>,
+    create_builder: BuilderFactory,
+    prepared: RwLock<Vec<(Vec<Matrix>, PreparedProblem)>>,
+    cache_size: usize,
+}
+
+impl SolverPool {
+    /// Creates a new instance of `SolverPool`.
+    pub fn new(problem: Problem, create_builder: BuilderFactory) -> Self {
+        Self::new_with_cache_size(problem, create_builder, DEFAULT_CACHE_SIZE)
+    }
+
+    /// Creates a new instance of `SolverPool` which keeps up to `cache_size` prepared problems.
+    pub fn new_with_cache_size(problem: Problem, create_builder: BuilderFactory, cache_size: usize) -> Self {
+        Self {
+            problem: Arc::new(problem),
+            create_builder,
+            prepared: RwLock::new(vec![]),
+            cache_size: cache_size.max(1),
+        }
+    }
+
+    /// Returns problem definition used by the pool.
+    pub fn problem(&self) -> &Problem {
+        self.problem.as_ref()
+    }
+
+    /// Returns problem prepared for given routing matrices. A cached one is returned if the same
+    /// matrices were used before, otherwise the problem is prepared and cached.
+    pub fn prepare(&self, matrices: Vec<Matrix>) -> Result<PreparedProblem, String> {
+        if let Some(prepared) = self.find_prepared(&matrices) {
+            return Ok(prepared);
+        }
+
+        // NOTE concurrent requests with the same new matrices can prepare the problem twice,
+        // but the lock is not held during preparation, so other requests are not blocked
+        let prepared = PreparedProblem::new(
+            (self.problem.as_ref(), matrices.clone())
+                .read_pragmatic()
+                .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\n"))?,
+        );
+
+        let mut cache = self.prepared.write().map_err(|_| "cannot access prepared problems".to_string())?;
+        if !cache.iter().any(|(cached, _)| *cached == matrices) {
+            if cache.len() >= self.cache_size {
+                cache.remove(0);
+            }
+            cache.push((matrices, prepared.clone()));
+        }
+
+        Ok(prepared)
+    }
+
+    /// Solves the problem using given routing matrices.
+    pub fn solve(&self, matrices: Vec<Matrix>) -> Result<Solution, String> {
+        let prepared = self.prepare(matrices)?;
+        let problem = prepared.problem();
+
+        let (solution, _) = catch_panic(|| prepared.solve((self.create_builder)()))?;
+
+        Ok(create_solution(problem.as_ref(), &solution))
+    }
+
+    fn find_prepared(&self, matrices: &[Matrix]) -> Option<PreparedProblem> {
+        self.prepared
+            .read()
+            .ok()
+            .and_then(|cache| cache.iter().find(|(cached, _)| cached.as_slice() == matrices).map(|(_, p)| p.clone()))
+    }
+}
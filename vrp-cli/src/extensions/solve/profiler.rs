@@ -0,0 +1,42 @@
+//! Contains logic to profile constraint modules and report time spent per module.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/profiler_test.rs"]
+mod profiler_test;
+
+use std::sync::Arc;
+use vrp_core::construction::constraints::{ConstraintModuleProfile, ConstraintProfiler};
+use vrp_core::models::Problem;
+
+/// Enables profiling of all constraint modules of the problem. Should be called after all custom
+/// modules are added.
+pub fn enable_constraint_profiling(problem: Problem) -> Result<(Problem, Arc<ConstraintProfiler>), String> {
+    let mut problem = problem;
+    let profiler = Arc::get_mut(&mut problem.constraint)
+        .ok_or_else(|| "cannot enable constraint profiling: constraint pipeline is already in use".to_string())?
+        .enable_profiling();
+
+    Ok((problem, profiler))
+}
+
+/// Creates a ranked report, one line per constraint module, the slowest module first.
+pub fn create_constraint_profile_report(profiles: &[ConstraintModuleProfile]) -> Vec<String> {
+    let total_time = profiles.iter().map(|profile| profile.total_time()).sum::<f64>();
+
+    profiles
+        .iter()
+        .enumerate()
+        .map(|(idx, profile)| {
+            let share = if total_time > 0. { profile.total_time() / total_time * 100. } else { 0. };
+            format!(
+                "constraint profile: #{} {}, evaluations: {}, evaluation time: {:.3}ms, state time: {:.3}ms, share: {:.1}%",
+                idx + 1,
+                profile.name,
+                profile.evaluations,
+                profile.evaluation_time * 1000.,
+                profile.state_time * 1000.,
+                share
+            )
+        })
+        .collect()
+}
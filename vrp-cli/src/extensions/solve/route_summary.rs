@@ -0,0 +1,102 @@
+//! Contains logic to write pragmatic solution as a flat per route summary in csv format.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/route_summary_test.rs"]
+mod route_summary_test;
+
+use serde::Serialize;
+use std::io::{BufWriter, Write};
+use vrp_pragmatic::format::solution::{Solution, Tour, UnassignedJob};
+
+/// A single row of the route summary: either a route or an unassigned job.
+#[derive(Clone, Serialize, Debug, PartialEq)]
+pub struct RouteSummaryRow {
+    /// A row kind: `route` or `unassigned`.
+    pub kind: String,
+    /// Vehicle id.
+    pub vehicle_id: Option<String>,
+    /// Vehicle type id.
+    pub type_id: Option<String>,
+    /// Shift index.
+    pub shift_index: Option<usize>,
+    /// Amount of stops where at least one job activity is performed.
+    pub stops: Option<usize>,
+    /// Total route distance.
+    pub distance: Option<i32>,
+    /// Total route duration.
+    pub duration: Option<i32>,
+    /// Total route cost.
+    pub cost: Option<f64>,
+    /// Maximum vehicle load per capacity dimension, separated by semicolon.
+    pub load_peak: Option<String>,
+    /// Unassigned job id.
+    pub job_id: Option<String>,
+    /// Unassigned job reasons, separated by semicolon.
+    pub reasons: Option<String>,
+}
+
+/// Creates route summary rows: one row per tour followed by one row per unassigned job.
+pub fn create_route_summary(solution: &Solution) -> Vec<RouteSummaryRow> {
+    solution.tours.iter().map(create_route_row).chain(solution.unassigned.iter().map(create_unassigned_row)).collect()
+}
+
+/// Writes route summary of the solution in csv format.
+pub fn write_route_summary<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), String> {
+    let mut writer = csv::Writer::from_writer(writer);
+
+    create_route_summary(solution).iter().try_for_each(|row| writer.serialize(row)).map_err(|err| err.to_string())?;
+
+    writer.flush().map_err(|err| err.to_string())
+}
+
+fn create_route_row(tour: &Tour) -> RouteSummaryRow {
+    let stops = tour
+        .stops
+        .iter()
+        .filter(|stop| {
+            stop.activities
+                .iter()
+                .any(|activity| activity.activity_type != "departure" && activity.activity_type != "arrival")
+        })
+        .count();
+
+    let load_peak = tour.stops.iter().fold(Vec::<i32>::new(), |mut peak, stop| {
+        peak.resize(peak.len().max(stop.load.len()), 0);
+        peak.iter_mut().zip(stop.load.iter()).for_each(|(peak, load)| *peak = (*peak).max(*load));
+        peak
+    });
+
+    RouteSummaryRow {
+        kind: "route".to_string(),
+        vehicle_id: Some(tour.vehicle_id.clone()),
+        type_id: Some(tour.type_id.clone()),
+        shift_index: Some(tour.shift_index),
+        stops: Some(stops),
+        distance: Some(tour.statistic.distance),
+        duration: Some(tour.statistic.duration),
+        cost: Some(tour.statistic.cost),
+        load_peak: Some(join(load_peak.iter())),
+        job_id: None,
+        reasons: None,
+    }
+}
+
+fn create_unassigned_row(job: &UnassignedJob) -> RouteSummaryRow {
+    RouteSummaryRow {
+        kind: "unassigned".to_string(),
+        vehicle_id: None,
+        type_id: None,
+        shift_index: None,
+        stops: None,
+        distance: None,
+        duration: None,
+        cost: None,
+        load_peak: None,
+        job_id: Some(job.job_id.clone()),
+        reasons: Some(join(job.reasons.iter().map(|reason| &reason.description))),
+    }
+}
+
+fn join<T: ToString>(values: impl Iterator<Item = T>) -> String {
+    values.map(|value| value.to_string()).collect::<Vec<_>>().join(";")
+}
@@ -0,0 +1,150 @@
+//! Contains logic to solve the same plan with fleet variations (scenarios) and compare results.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/scenarios_test.rs"]
+mod scenarios_test;
+
+use crate::catch_panic;
+use serde::{Deserialize, Serialize};
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::solver::Builder;
+use vrp_pragmatic::format::problem::{Matrix, PragmaticProblem, Problem};
+use vrp_pragmatic::format::solution::create_solution;
+
+/// Specifies a list of scenarios.
+#[derive(Clone, Deserialize, Debug)]
+pub struct Scenarios {
+    /// Scenarios to be solved.
+    pub scenarios: Vec<Scenario>,
+}
+
+/// Specifies a scenario as a named list of fleet changes applied to the original problem.
+#[derive(Clone, Deserialize, Debug)]
+pub struct Scenario {
+    /// Scenario name.
+    pub name: String,
+    /// Fleet changes.
+    pub changes: Vec<FleetChange>,
+}
+
+/// Specifies change of amount of vehicles of the given type.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FleetChange {
+    /// Vehicle type id.
+    pub type_id: String,
+    /// Amount of vehicles to add (positive) or to remove (negative).
+    pub delta: i32,
+}
+
+/// A result of solving a scenario.
+#[derive(Clone, Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScenarioResult {
+    /// Scenario name.
+    pub name: String,
+    /// Total cost.
+    pub cost: f64,
+    /// Amount of tours.
+    pub tours: usize,
+    /// Amount of unassigned jobs.
+    pub unassigned: usize,
+    /// Cost difference comparing to the baseline.
+    pub marginal_cost: f64,
+}
+
+/// A report of solving all scenarios.
+#[derive(Clone, Serialize, Debug)]
+pub struct ScenariosReport {
+    /// A result of the original problem.
+    pub baseline: ScenarioResult,
+    /// Results of scenarios.
+    pub scenarios: Vec<ScenarioResult>,
+}
+
+/// Reads scenarios from reader.
+pub fn read_scenarios<R: Read>(reader: BufReader<R>) -> Result<Scenarios, String> {
+    serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize scenarios: '{}'", err))
+}
+
+/// Returns a copy of the problem with fleet changes applied. Added vehicles get generated ids,
+/// removed vehicles are taken from the end of the vehicle type's id list.
+pub fn apply_fleet_changes(problem: &Problem, changes: &[FleetChange]) -> Result<Problem, String> {
+    let mut problem = problem.clone();
+
+    for change in changes.iter() {
+        let vehicle = problem
+            .fleet
+            .vehicles
+            .iter_mut()
+            .find(|vehicle| vehicle.type_id == change.type_id)
+            .ok_or_else(|| format!("unknown vehicle type: '{}'", change.type_id))?;
+
+        if change.delta >= 0 {
+            let start = vehicle.vehicle_ids.len();
+            vehicle.vehicle_ids.extend(
+                (0..change.delta as usize).map(|idx| format!("{}_scenario_{}", change.type_id, start + idx + 1)),
+            );
+        } else {
+            let amount = change.delta.abs() as usize;
+            if amount >= vehicle.vehicle_ids.len() {
+                return Err(format!("cannot remove all vehicles of type: '{}'", change.type_id));
+            }
+            vehicle.vehicle_ids.truncate(vehicle.vehicle_ids.len() - amount);
+        }
+    }
+
+    Ok(problem)
+}
+
+/// Solves the original problem and each scenario using solvers created by `create_builder`
+/// and reports marginal cost of each scenario comparing to the original problem.
+pub fn solve_scenarios(
+    problem: &Problem,
+    matrices: Option<Vec<Matrix>>,
+    scenarios: &Scenarios,
+    create_builder: &dyn Fn() -> Builder,
+) -> Result<ScenariosReport, String> {
+    let baseline = solve_scenario("baseline", problem.clone(), matrices.clone(), create_builder, None)?;
+
+    let scenarios = scenarios
+        .scenarios
+        .iter()
+        .map(|scenario| {
+            let problem = apply_fleet_changes(problem, scenario.changes.as_slice())?;
+            solve_scenario(scenario.name.as_str(), problem, matrices.clone(), create_builder, Some(baseline.cost))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(ScenariosReport { baseline, scenarios })
+}
+
+fn solve_scenario(
+    name: &str,
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    create_builder: &dyn Fn() -> Builder,
+    baseline_cost: Option<f64>,
+) -> Result<ScenarioResult, String> {
+    let problem = Arc::new(
+        if let Some(matrices) = matrices { (problem, matrices).read_pragmatic() } else { problem.read_pragmatic() }
+            .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\n"))?,
+    );
+
+    let (solution, _) = create_builder()
+        .with_problem(problem.clone())
+        .build()
+        .and_then(|solver| catch_panic(|| solver.solve()))
+        .map_err(|err| format!("cannot solve scenario '{}': '{}'", name, err))?;
+
+    let solution = create_solution(problem.as_ref(), &solution);
+
+    Ok(ScenarioResult {
+        name: name.to_string(),
+        cost: solution.statistic.cost,
+        tours: solution.tours.len(),
+        unassigned: solution.unassigned.len(),
+        marginal_cost: baseline_cost.map_or(0., |baseline_cost| solution.statistic.cost - baseline_cost),
+    })
+}
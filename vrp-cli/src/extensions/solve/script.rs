@@ -0,0 +1,225 @@
+//! Contains logic to define soft constraints in a script referenced by the solver config.
+//!
+//! With `scripting` feature enabled, a script written in [rhai](https://rhai.rs) language can define
+//! `activity_penalty(ctx)` and/or `route_penalty(ctx)` functions which return penalty added to
+//! activity or route insertion cost. The script is evaluated in a sandbox: it has no access to
+//! file system or environment and its amount of operations, call depth and data sizes are limited.
+//! If the script fails at runtime, e.g. exceeds the limits, it is disabled for the rest of the search
+//! and the error is reported to the logger.
+
+#[cfg(all(test, feature = "scripting"))]
+#[path = "../../../tests/unit/extensions/solve/script_test.rs"]
+mod script_test;
+
+use super::config::ScriptConfig;
+use vrp_core::models::Problem;
+use vrp_core::solver::Logger;
+
+/// Adds soft constraints defined in the script to the problem. Runtime script errors are reported
+/// to the logger.
+#[cfg(feature = "scripting")]
+pub fn add_script_module(problem: Problem, config: &ScriptConfig, logger: Logger) -> Result<Problem, String> {
+    let script = std::fs::read_to_string(config.path.as_str())
+        .map_err(|err| format!("cannot read script '{}': '{}'", config.path, err))?;
+
+    self::rhai_impl::add_script_module(problem, script.as_str(), logger)
+}
+
+/// Adds soft constraints defined in the script to the problem. Runtime script errors are reported
+/// to the logger.
+#[cfg(not(feature = "scripting"))]
+pub fn add_script_module(_problem: Problem, config: &ScriptConfig, _logger: Logger) -> Result<Problem, String> {
+    Err(format!("cannot use script '{}': scripting feature is not enabled", config.path))
+}
+
+#[cfg(feature = "scripting")]
+mod rhai_impl {
+    use rhai::{Dynamic, Engine, Map, Scope, AST};
+    use std::ops::Deref;
+    use std::slice::Iter;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use vrp_core::construction::constraints::*;
+    use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+    use vrp_core::models::common::{Cost, IdDimension};
+    use vrp_core::models::problem::Job;
+    use vrp_core::models::solution::Activity;
+    use vrp_core::models::Problem;
+    use vrp_core::solver::Logger;
+
+    const ACTIVITY_PENALTY_FN_NAME: &str = "activity_penalty";
+    const ROUTE_PENALTY_FN_NAME: &str = "route_penalty";
+
+    const MAX_OPERATIONS: u64 = 10_000;
+    const MAX_CALL_LEVELS: usize = 16;
+    const MAX_EXPR_DEPTH: usize = 32;
+    const MAX_DATA_SIZE: usize = 1024;
+
+    pub(super) fn add_script_module(problem: Problem, script: &str, logger: Logger) -> Result<Problem, String> {
+        let module = create_script_module(script, logger)?;
+
+        let mut problem = problem;
+        Arc::get_mut(&mut problem.constraint)
+            .ok_or_else(|| "cannot add script module: constraint pipeline is already in use".to_string())?
+            .add_module(Box::new(module));
+
+        Ok(problem)
+    }
+
+    pub(super) fn create_script_module(script: &str, logger: Logger) -> Result<ScriptModule, String> {
+        let engine = create_engine();
+        let ast = engine.compile(script).map_err(|err| format!("cannot compile script: '{}'", err))?;
+
+        let has_function = |name: &str| ast.iter_functions().any(|f| f.name == name && f.params.len() == 1);
+        let (has_activity, has_route) = (has_function(ACTIVITY_PENALTY_FN_NAME), has_function(ROUTE_PENALTY_FN_NAME));
+
+        if !has_activity && !has_route {
+            return Err(format!(
+                "script should define '{}(ctx)' or '{}(ctx)' function",
+                ACTIVITY_PENALTY_FN_NAME, ROUTE_PENALTY_FN_NAME
+            ));
+        }
+
+        let script = Arc::new(Script { engine, ast, logger, is_disabled: AtomicBool::new(false) });
+
+        let activity = if has_activity {
+            Some(ConstraintVariant::SoftActivity(Arc::new(ScriptSoftActivityConstraint { script: script.clone() })))
+        } else {
+            None
+        };
+
+        let route = if has_route {
+            Some(ConstraintVariant::SoftRoute(Arc::new(ScriptSoftRouteConstraint { script })))
+        } else {
+            None
+        };
+
+        Ok(ScriptModule { constraints: activity.into_iter().chain(route.into_iter()).collect(), keys: vec![] })
+    }
+
+    fn create_engine() -> Engine {
+        let mut engine = Engine::new();
+
+        engine
+            .set_max_operations(MAX_OPERATIONS)
+            .set_max_call_levels(MAX_CALL_LEVELS)
+            .set_max_expr_depths(MAX_EXPR_DEPTH, MAX_EXPR_DEPTH)
+            .set_max_string_size(MAX_DATA_SIZE)
+            .set_max_array_size(MAX_DATA_SIZE)
+            .set_max_map_size(MAX_DATA_SIZE);
+
+        engine
+    }
+
+    struct Script {
+        engine: Engine,
+        ast: AST,
+        logger: Logger,
+        is_disabled: AtomicBool,
+    }
+
+    impl Script {
+        /// Calls script function and returns its result as penalty. If script fails, it is disabled
+        /// and zero penalty is returned for this and all subsequent calls.
+        fn call(&self, name: &str, ctx: Map) -> Cost {
+            if self.is_disabled.load(Ordering::Relaxed) {
+                return 0.;
+            }
+
+            self.evaluate(name, ctx).unwrap_or_else(|err| {
+                if !self.is_disabled.swap(true, Ordering::Relaxed) {
+                    self.logger.deref()(format!("script is disabled: {}", err));
+                }
+
+                0.
+            })
+        }
+
+        fn evaluate(&self, name: &str, ctx: Map) -> Result<Cost, String> {
+            let result = self
+                .engine
+                .call_fn::<Dynamic>(&mut Scope::new(), &self.ast, name, (ctx,))
+                .map_err(|err| format!("cannot evaluate script function '{}': '{}'", name, err))?;
+
+            result
+                .as_float()
+                .or_else(|_| result.as_int().map(|value| value as Cost))
+                .map_err(|type_name| format!("script function '{}' returned '{}' instead of number", name, type_name))
+        }
+    }
+
+    pub(super) struct ScriptModule {
+        constraints: Vec<ConstraintVariant>,
+        keys: Vec<i32>,
+    }
+
+    impl ConstraintModule for ScriptModule {
+        fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_ctx: &mut RouteContext, _job: &Job) {}
+
+        fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+        fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+        fn state_keys(&self) -> Iter<i32> {
+            self.keys.iter()
+        }
+
+        fn get_constraints(&self) -> Iter<ConstraintVariant> {
+            self.constraints.iter()
+        }
+    }
+
+    struct ScriptSoftActivityConstraint {
+        script: Arc<Script>,
+    }
+
+    impl SoftActivityConstraint for ScriptSoftActivityConstraint {
+        fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+            let mut ctx = Map::new();
+            ctx.insert("vehicle_id".into(), get_vehicle_id(route_ctx));
+            ctx.insert("prev".into(), create_activity_map(activity_ctx.prev));
+            ctx.insert("target".into(), create_activity_map(activity_ctx.target));
+            ctx.insert("next".into(), activity_ctx.next.map_or(Dynamic::UNIT, |next| create_activity_map(next)));
+
+            self.script.call(ACTIVITY_PENALTY_FN_NAME, ctx)
+        }
+    }
+
+    struct ScriptSoftRouteConstraint {
+        script: Arc<Script>,
+    }
+
+    impl SoftRouteConstraint for ScriptSoftRouteConstraint {
+        fn estimate_job(&self, _solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> Cost {
+            let mut ctx = Map::new();
+            ctx.insert("vehicle_id".into(), get_vehicle_id(route_ctx));
+            ctx.insert("job_id".into(), job.dimens().get_id().map_or(Dynamic::UNIT, |id| id.clone().into()));
+            ctx.insert("jobs".into(), (route_ctx.route.tour.job_count() as i64).into());
+
+            self.script.call(ROUTE_PENALTY_FN_NAME, ctx)
+        }
+    }
+
+    fn get_vehicle_id(route_ctx: &RouteContext) -> Dynamic {
+        route_ctx.route.actor.vehicle.dimens.get_id().map_or(Dynamic::UNIT, |id| id.clone().into())
+    }
+
+    fn create_activity_map(activity: &Activity) -> Dynamic {
+        let mut map = Map::new();
+        map.insert("location".into(), (activity.place.location as i64).into());
+        map.insert("duration".into(), activity.place.duration.into());
+        map.insert("tw_start".into(), activity.place.time.start.into());
+        map.insert("tw_end".into(), activity.place.time.end.into());
+        map.insert("arrival".into(), activity.schedule.arrival.into());
+        map.insert("departure".into(), activity.schedule.departure.into());
+        map.insert(
+            "job_id".into(),
+            activity
+                .retrieve_job()
+                .and_then(|job| job.dimens().get_id().cloned())
+                .map_or(Dynamic::UNIT, |id| id.into()),
+        );
+
+        map.into()
+    }
+}
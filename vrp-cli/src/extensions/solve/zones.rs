@@ -0,0 +1,207 @@
+//! Contains logic to pre-assign jobs to vehicles (route zones) before solving, so solver only
+//! sequences jobs within each assignment (cluster-first route-second).
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/zones_test.rs"]
+mod zones_test;
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Arc;
+use vrp_core::algorithms::clustering::create_sweep_clusters;
+use vrp_core::construction::constraints::{Demand, DemandDimension};
+use vrp_core::models::common::{Dimensions, IdDimension};
+use vrp_core::models::problem::{Job as CoreJob, Multi, Single};
+use vrp_pragmatic::format::problem::{Job, JobTask, Problem, Relation, RelationType, VehicleType};
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::MultiDimensionalCapacity;
+
+/// Specifies solving strategy.
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq)]
+pub enum Strategy {
+    /// Solver assigns jobs to vehicles and sequences them at the same time.
+    #[serde(rename = "default")]
+    Default,
+    /// Jobs are assigned to vehicles by capacitated clustering first, then solver only sequences
+    /// them within each assignment.
+    #[serde(rename = "cluster-first")]
+    ClusterFirst,
+}
+
+impl FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "default" => Ok(Strategy::Default),
+            "cluster-first" => Ok(Strategy::ClusterFirst),
+            _ => Err(format!("unknown strategy: '{}'", value)),
+        }
+    }
+}
+
+/// Returns a copy of the problem where jobs are assigned to vehicles using capacitated sweep
+/// clustering around the center of vehicle start locations. Each assignment is added as a relation
+/// of `any` type, so jobs are served only by the assigned vehicle. Jobs and vehicles which are
+/// already used in relations are left untouched, as well as jobs which require skills missing
+/// on the vehicle or do not fit into any cluster.
+pub fn assign_zones(problem: &Problem) -> Result<Problem, String> {
+    let mut problem = problem.clone();
+    let relations = problem.plan.relations.clone().unwrap_or_default();
+
+    let used_jobs = relations.iter().flat_map(|relation| relation.jobs.iter().cloned()).collect::<HashSet<_>>();
+    let used_vehicles = relations.iter().map(|relation| relation.vehicle_id.clone()).collect::<HashSet<_>>();
+
+    let vehicles = problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| {
+            vehicle.vehicle_ids.iter().filter(|id| !used_vehicles.contains(*id)).map(move |id| (id, vehicle))
+        })
+        .collect::<Vec<_>>();
+
+    if vehicles.is_empty() {
+        return Err("cannot assign zones: no vehicles without relations".to_string());
+    }
+
+    let starts = problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter().map(|shift| &shift.start.location))
+        .collect::<Vec<_>>();
+    let center = (
+        starts.iter().map(|location| location.lng).sum::<f64>() / starts.len() as f64,
+        starts.iter().map(|location| location.lat).sum::<f64>() / starts.len() as f64,
+    );
+
+    let jobs = problem.plan.jobs.iter().filter(|job| !used_jobs.contains(&job.id)).collect::<Vec<_>>();
+    let locations = jobs
+        .iter()
+        .filter_map(|job| get_job_location(job).map(|location| (job.id.clone(), (location.lng, location.lat))))
+        .collect::<HashMap<_, _>>();
+    let core_jobs = jobs.iter().map(|job| create_core_job(job)).collect::<Vec<_>>();
+
+    // NOTE sweep clustering uses the same capacity for all clusters, so the smallest capacity
+    // among vehicles is used to make sure that any cluster fits into any vehicle
+    let capacity = MultiDimensionalCapacity::new(get_min_capacity(vehicles.iter().map(|(_, vehicle)| *vehicle)));
+    let clusters = create_sweep_clusters(core_jobs.as_slice(), center, capacity, &|job| {
+        job.dimens().get_id().and_then(|id| locations.get(id)).cloned()
+    });
+
+    let job_index = jobs.iter().map(|job| (job.id.clone(), *job)).collect::<HashMap<_, _>>();
+    let new_relations = vehicles
+        .iter()
+        .zip(clusters.clusters)
+        .map(|((vehicle_id, vehicle), cluster)| {
+            let jobs = cluster
+                .iter()
+                .filter_map(|job| job.dimens().get_id().and_then(|id| job_index.get(id)))
+                .filter(|job| has_skills(vehicle, job))
+                .map(|job| job.id.clone())
+                .collect::<Vec<_>>();
+
+            (vehicle_id, jobs)
+        })
+        .filter(|(_, jobs)| !jobs.is_empty())
+        .map(|(vehicle_id, jobs)| Relation {
+            type_field: RelationType::Any,
+            jobs,
+            vehicle_id: vehicle_id.to_string(),
+            shift_index: None,
+        });
+
+    problem.plan.relations = Some(relations.into_iter().chain(new_relations).collect());
+
+    Ok(problem)
+}
+
+fn has_skills(vehicle: &VehicleType, job: &Job) -> bool {
+    job.skills.as_ref().map_or(true, |required| {
+        required.iter().all(|skill| vehicle.skills.as_ref().map_or(false, |skills| skills.contains(skill)))
+    })
+}
+
+/// Returns the smallest capacity of given vehicles per dimension.
+fn get_min_capacity<'a>(vehicles: impl Iterator<Item = &'a VehicleType>) -> Vec<i32> {
+    vehicles
+        .fold(None, |acc: Option<Vec<i32>>, vehicle| {
+            Some(acc.map_or_else(
+                || vehicle.capacity.clone(),
+                |acc| {
+                    let size = acc.len().max(vehicle.capacity.len());
+                    (0..size)
+                        .map(|idx| (*acc.get(idx).unwrap_or(&0)).min(*vehicle.capacity.get(idx).unwrap_or(&0)))
+                        .collect()
+                },
+            ))
+        })
+        .unwrap_or_default()
+}
+
+/// Creates a core job which keeps only job id and demand of its tasks, so clustering can
+/// calculate its total demand. Demand is defined the same way as in the pragmatic format.
+fn create_core_job(job: &Job) -> CoreJob {
+    let create_dimens = |demand: Option<Demand<MultiDimensionalCapacity>>| {
+        let mut dimens = Dimensions::default();
+        dimens.set_id(job.id.as_str());
+        if let Some(demand) = demand {
+            dimens.set_demand(demand);
+        }
+        dimens
+    };
+
+    let task_size = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().map_or(0, |tasks| tasks.len());
+    let is_static_demand = task_size(&job.pickups) == 0 || task_size(&job.deliveries) == 0;
+    let empty = MultiDimensionalCapacity::default;
+    let get_demand = |task: &JobTask, is_static_demand: bool| {
+        let capacity = MultiDimensionalCapacity::new(task.demand.clone().unwrap_or_default());
+        if is_static_demand {
+            (capacity, empty())
+        } else {
+            (empty(), capacity)
+        }
+    };
+
+    let mut singles = job
+        .pickups
+        .iter()
+        .flatten()
+        .map(|task| Demand { pickup: get_demand(task, is_static_demand), delivery: (empty(), empty()) })
+        .chain(
+            job.deliveries
+                .iter()
+                .flatten()
+                .map(|task| Demand { pickup: (empty(), empty()), delivery: get_demand(task, is_static_demand) }),
+        )
+        .chain(
+            job.replacements
+                .iter()
+                .flatten()
+                .map(|task| Demand { pickup: get_demand(task, true), delivery: get_demand(task, true) }),
+        )
+        .map(|demand| Arc::new(Single { places: vec![], dimens: create_dimens(Some(demand)) }))
+        .collect::<Vec<_>>();
+
+    match singles.len() {
+        0 => CoreJob::Single(Arc::new(Single { places: vec![], dimens: create_dimens(None) })),
+        1 => CoreJob::Single(singles.remove(0)),
+        _ => CoreJob::Multi(Arc::new(Multi::new(singles, create_dimens(None)))),
+    }
+}
+
+/// Returns location of the first place of the first job task.
+fn get_job_location(job: &Job) -> Option<&Location> {
+    get_job_tasks(job).next().and_then(|task| task.places.first()).map(|place| &place.location)
+}
+
+fn get_job_tasks(job: &Job) -> impl Iterator<Item = &JobTask> {
+    job.pickups
+        .iter()
+        .chain(job.deliveries.iter())
+        .chain(job.replacements.iter())
+        .chain(job.services.iter())
+        .flat_map(|tasks| tasks.iter())
+}
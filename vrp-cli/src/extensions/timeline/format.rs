@@ -0,0 +1,79 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/timeline/format_test.rs"]
+mod format_test;
+
+use chrono::{FixedOffset, Offset, TimeZone, Utc};
+
+/// Specifies how time and duration are formatted in human-readable exports. Machine-readable
+/// exports always use RFC3339 format in UTC.
+pub struct TimeFormat {
+    offset: FixedOffset,
+    pattern: &'static str,
+}
+
+impl Default for TimeFormat {
+    fn default() -> Self {
+        Self { offset: Utc.fix(), pattern: "%Y-%m-%d %H:%M" }
+    }
+}
+
+impl TimeFormat {
+    /// Creates a new instance of `TimeFormat` from locale, e.g. `en-US` or `de-DE`, and timezone
+    /// specified as a fixed offset from UTC, e.g. `+02:00` or `-05:30`.
+    pub fn new(locale: Option<&str>, timezone: Option<&str>) -> Result<Self, String> {
+        let pattern = match locale {
+            None => Self::default().pattern,
+            Some("en-US") => "%m/%d/%Y %I:%M %p",
+            Some("en-GB") | Some("fr-FR") | Some("es-ES") | Some("it-IT") => "%d/%m/%Y %H:%M",
+            Some("de-DE") | Some("ru-RU") => "%d.%m.%Y %H:%M",
+            Some(locale) => return Err(format!("unsupported locale: '{}'", locale)),
+        };
+
+        let offset = match timezone {
+            None => Self::default().offset,
+            Some(timezone) => parse_offset(timezone)?,
+        };
+
+        Ok(Self { offset, pattern })
+    }
+
+    /// Formats timestamp in seconds as local time.
+    pub fn time(&self, timestamp: f64) -> Result<String, String> {
+        self.offset
+            .timestamp_opt(timestamp as i64, 0)
+            .single()
+            .map(|time| time.format(self.pattern).to_string())
+            .ok_or_else(|| format!("cannot format time: timestamp '{}' is out of range", timestamp))
+    }
+
+    /// Formats duration in seconds as hours, minutes and seconds.
+    pub fn duration(&self, duration: f64) -> String {
+        let duration = duration.max(0.).round() as i64;
+        let (hours, minutes, seconds) = (duration / 3600, duration % 3600 / 60, duration % 60);
+
+        match (hours, minutes) {
+            (0, 0) => format!("{}s", seconds),
+            (0, _) => format!("{}m {:02}s", minutes, seconds),
+            _ => format!("{}h {:02}m", hours, minutes),
+        }
+    }
+}
+
+fn parse_offset(timezone: &str) -> Result<FixedOffset, String> {
+    let error = || format!("cannot parse timezone '{}': expected offset like '+02:00'", timezone);
+
+    let (sign, value) = match timezone.chars().next() {
+        Some('+') => (1, &timezone[1..]),
+        Some('-') => (-1, &timezone[1..]),
+        _ => return Err(error()),
+    };
+
+    let parts = value.split(':').map(|part| part.parse::<i32>().map_err(|_| error())).collect::<Result<Vec<_>, _>>()?;
+
+    match parts.as_slice() {
+        [hours, minutes] if *hours <= 14 && *minutes < 60 => {
+            FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or_else(error)
+        }
+        _ => Err(error()),
+    }
+}
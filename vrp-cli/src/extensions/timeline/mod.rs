@@ -0,0 +1,36 @@
+//! Contains logic to create a timeline (Gantt-style) representation of the solution.
+
+mod format;
+pub use self::format::TimeFormat;
+
+mod model;
+pub use self::model::*;
+
+mod svg;
+pub use self::svg::write_timeline_svg;
+
+use crate::get_errors_serialized;
+use std::io::{BufReader, BufWriter, Read, Write};
+use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+/// Reads problem and solution in pragmatic format and writes their timeline in given format.
+/// Time format is applied only to human-readable svg output: json output keeps RFC3339 in UTC.
+pub fn write_timeline<R: Read, W: Write>(
+    problem: BufReader<R>,
+    solution: BufReader<R>,
+    out_format: &str,
+    time_format: &TimeFormat,
+    writer: BufWriter<W>,
+) -> Result<(), String> {
+    let problem = deserialize_problem(problem).map_err(|errors| get_errors_serialized(&errors))?;
+    let solution = deserialize_solution(solution).map_err(|err| format!("cannot read solution: '{}'", err))?;
+
+    let timeline = create_timeline(&problem, &solution)?;
+
+    match out_format {
+        "json" => serde_json::to_writer_pretty(writer, &timeline).map_err(|err| err.to_string()),
+        "svg" => write_timeline_svg(writer, &timeline, time_format).map_err(|err| err.to_string()),
+        _ => Err(format!("unknown timeline format: '{}'", out_format)),
+    }
+}
@@ -0,0 +1,201 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/timeline/model_test.rs"]
+mod model_test;
+
+use chrono::{DateTime, SecondsFormat, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use vrp_pragmatic::format::problem::{JobTask, Problem};
+use vrp_pragmatic::format::solution::{Activity, Solution, Stop, Tour};
+use vrp_pragmatic::format::Location;
+
+/// A timeline segment: a continuous period of time when vehicle does one kind of work.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Segment {
+    /// Segment type: driving, waiting, serving or break.
+    #[serde(rename(serialize = "type", deserialize = "type"))]
+    pub segment_type: String,
+    /// Job id or vehicle activity type, e.g. departure, break. Absent for driving.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+    /// Start time specified in RFC3339 format.
+    pub start: String,
+    /// End time specified in RFC3339 format.
+    pub end: String,
+}
+
+/// A timeline of the vehicle shift.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourTimeline {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Segments ordered by time.
+    pub segments: Vec<Segment>,
+}
+
+/// A timeline of the whole solution.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct Timeline {
+    /// Timelines of all tours.
+    pub tours: Vec<TourTimeline>,
+}
+
+/// Keeps service durations of activities known from the problem: an activity's interval in the
+/// solution includes waiting time, so the duration is needed to split it into waiting and serving.
+struct Durations {
+    jobs: HashMap<(String, String), Vec<(Location, f64)>>,
+    vehicles: HashMap<(String, usize), Vec<(String, f64)>>,
+}
+
+/// Creates a timeline from problem and its solution in pragmatic format.
+pub fn create_timeline(problem: &Problem, solution: &Solution) -> Result<Timeline, String> {
+    let durations = Durations::new(problem);
+
+    let tours =
+        solution.tours.iter().map(|tour| create_tour_timeline(tour, &durations)).collect::<Result<Vec<_>, String>>()?;
+
+    Ok(Timeline { tours })
+}
+
+fn create_tour_timeline(tour: &Tour, durations: &Durations) -> Result<TourTimeline, String> {
+    let mut segments = vec![];
+    let mut last_departure: Option<f64> = None;
+
+    for stop in tour.stops.iter() {
+        let (arrival, departure) = (parse_time(&stop.time.arrival)?, parse_time(&stop.time.departure)?);
+
+        if let Some(last_departure) = last_departure {
+            add_segment(&mut segments, "driving", None, last_departure, arrival)?;
+        }
+
+        for activity in stop.activities.iter() {
+            let (start, end) = match &activity.time {
+                Some(time) => (parse_time(&time.start)?, parse_time(&time.end)?),
+                None => (arrival, departure),
+            };
+
+            let duration = durations.get(tour, stop, activity, end - start).unwrap_or(end - start).min(end - start);
+            let segment_type = if activity.activity_type == "break" { "break" } else { "serving" };
+            let job_id = Some(activity.job_id.clone());
+
+            add_segment(&mut segments, "waiting", job_id.clone(), start, end - duration)?;
+            add_segment(&mut segments, segment_type, job_id, end - duration, end)?;
+        }
+
+        last_departure = Some(departure);
+    }
+
+    Ok(TourTimeline { vehicle_id: tour.vehicle_id.clone(), shift_index: tour.shift_index, segments })
+}
+
+fn add_segment(
+    segments: &mut Vec<Segment>,
+    segment_type: &str,
+    job_id: Option<String>,
+    start: f64,
+    end: f64,
+) -> Result<(), String> {
+    if end > start {
+        segments.push(Segment {
+            segment_type: segment_type.to_string(),
+            job_id,
+            start: format_time(start)?,
+            end: format_time(end)?,
+        });
+    }
+
+    Ok(())
+}
+
+impl Durations {
+    fn new(problem: &Problem) -> Self {
+        let jobs = problem.plan.jobs.iter().fold(HashMap::new(), |mut acc, job| {
+            let tasks = vec![
+                ("pickup", &job.pickups),
+                ("delivery", &job.deliveries),
+                ("replacement", &job.replacements),
+                ("service", &job.services),
+            ];
+
+            tasks.into_iter().for_each(|(activity_type, tasks): (&str, &Option<Vec<JobTask>>)| {
+                let places = tasks.iter().flat_map(|tasks| tasks.iter()).flat_map(|task| task.places.iter());
+                acc.entry((job.id.clone(), activity_type.to_string()))
+                    .or_insert_with(Vec::new)
+                    .extend(places.map(|place| (place.location.clone(), place.duration)));
+            });
+
+            acc
+        });
+
+        let vehicles = problem.fleet.vehicles.iter().fold(HashMap::new(), |mut acc, vehicle| {
+            vehicle.shifts.iter().enumerate().for_each(|(shift_index, shift)| {
+                let breaks = shift.breaks.iter().flat_map(|breaks| breaks.iter()).map(|b| ("break", b.duration));
+                let reloads = shift.reloads.iter().flat_map(|reloads| reloads.iter()).map(|r| ("reload", r.duration));
+
+                vehicle.vehicle_ids.iter().for_each(|vehicle_id| {
+                    acc.insert(
+                        (vehicle_id.clone(), shift_index),
+                        breaks
+                            .clone()
+                            .chain(reloads.clone())
+                            .map(|(activity_type, duration)| (activity_type.to_string(), duration))
+                            .collect(),
+                    );
+                });
+            });
+
+            acc
+        });
+
+        Self { jobs, vehicles }
+    }
+
+    /// Returns service duration of the activity. Jobs are matched by location, breaks and reloads
+    /// are matched by the longest duration which fits into activity interval.
+    fn get(&self, tour: &Tour, stop: &Stop, activity: &Activity, interval: f64) -> Option<f64> {
+        // NOTE departure and arrival have no service time, so their whole interval is waiting
+        if activity.activity_type == "departure" || activity.activity_type == "arrival" {
+            return Some(0.);
+        }
+
+        let location = activity.location.as_ref().unwrap_or(&stop.location);
+
+        self.jobs
+            .get(&(activity.job_id.clone(), activity.activity_type.clone()))
+            .and_then(|places| {
+                places
+                    .iter()
+                    .find(|(place, _)| place.lat == location.lat && place.lng == location.lng)
+                    .or_else(|| places.first())
+                    .map(|(_, d)| *d)
+            })
+            .or_else(|| {
+                self.vehicles.get(&(tour.vehicle_id.clone(), tour.shift_index)).and_then(|activities| {
+                    activities
+                        .iter()
+                        .filter(|(activity_type, duration)| {
+                            *activity_type == activity.activity_type && *duration <= interval
+                        })
+                        .map(|(_, duration)| *duration)
+                        .fold(None, |acc: Option<f64>, duration| Some(acc.map_or(duration, |acc| acc.max(duration))))
+                })
+            })
+    }
+}
+
+pub(crate) fn parse_time(time: &str) -> Result<f64, String> {
+    DateTime::parse_from_rfc3339(time)
+        .map(|time| time.timestamp() as f64)
+        .map_err(|err| format!("cannot parse time '{}': '{}'", time, err))
+}
+
+fn format_time(time: f64) -> Result<String, String> {
+    Utc.timestamp_opt(time as i64, 0)
+        .single()
+        .map(|time| time.to_rfc3339_opts(SecondsFormat::Secs, true))
+        .ok_or_else(|| format!("cannot format time: timestamp '{}' is out of range", time))
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/timeline/svg_test.rs"]
+mod svg_test;
+
+use super::*;
+use std::io::{BufWriter, Error, ErrorKind, Write};
+
+const LABEL_WIDTH: f64 = 160.;
+const CHART_WIDTH: f64 = 1000.;
+const ROW_HEIGHT: f64 = 24.;
+const ROW_GAP: f64 = 8.;
+
+/// Writes timeline as a simple svg Gantt chart: one row per tour, one rectangle per segment.
+/// Times and durations are shown using given time format.
+pub fn write_timeline_svg<W: Write>(
+    mut writer: BufWriter<W>,
+    timeline: &Timeline,
+    time_format: &TimeFormat,
+) -> Result<(), Error> {
+    let segments =
+        timeline.tours.iter().flat_map(|tour| tour.segments.iter()).filter_map(parse_interval).collect::<Vec<_>>();
+
+    let start = segments.iter().map(|(start, _)| *start).fold(f64::MAX, f64::min);
+    let end = segments.iter().map(|(_, end)| *end).fold(f64::MIN, f64::max);
+    let scale = if end > start { CHART_WIDTH / (end - start) } else { 0. };
+
+    let height = timeline.tours.len() as f64 * (ROW_HEIGHT + ROW_GAP) + ROW_GAP;
+    writeln!(
+        writer,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-family="sans-serif" font-size="12">"#,
+        LABEL_WIDTH + CHART_WIDTH,
+        height
+    )?;
+
+    for (idx, tour) in timeline.tours.iter().enumerate() {
+        let y = ROW_GAP + idx as f64 * (ROW_HEIGHT + ROW_GAP);
+
+        writeln!(
+            writer,
+            r#"  <text x="4" y="{}" dominant-baseline="middle">{} ({})</text>"#,
+            y + ROW_HEIGHT / 2.,
+            escape(&tour.vehicle_id),
+            tour.shift_index
+        )?;
+
+        for segment in tour.segments.iter() {
+            if let Some((segment_start, segment_end)) = parse_interval(segment) {
+                let format_time =
+                    |time: f64| time_format.time(time).map_err(|err| Error::new(ErrorKind::InvalidData, err));
+
+                writeln!(
+                    writer,
+                    r#"  <rect x="{:.2}" y="{}" width="{:.2}" height="{}" fill="{}"><title>{}{}: {} - {} ({})</title></rect>"#,
+                    LABEL_WIDTH + (segment_start - start) * scale,
+                    y,
+                    (segment_end - segment_start) * scale,
+                    ROW_HEIGHT,
+                    get_color(segment.segment_type.as_str()),
+                    segment.segment_type,
+                    segment.job_id.as_ref().map_or_else(String::new, |job_id| format!(" {}", escape(job_id))),
+                    format_time(segment_start)?,
+                    format_time(segment_end)?,
+                    time_format.duration(segment_end - segment_start)
+                )?;
+            }
+        }
+    }
+
+    writeln!(writer, "</svg>")?;
+
+    Ok(())
+}
+
+fn parse_interval(segment: &Segment) -> Option<(f64, f64)> {
+    match (parse_time(&segment.start), parse_time(&segment.end)) {
+        (Ok(start), Ok(end)) => Some((start, end)),
+        _ => None,
+    }
+}
+
+fn get_color(segment_type: &str) -> &'static str {
+    match segment_type {
+        "driving" => "#4e79a7",
+        "waiting" => "#bab0ac",
+        "serving" => "#59a14f",
+        "break" => "#f28e2b",
+        _ => "#000000",
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
@@ -7,85 +7,318 @@ mod features;
 
 pub mod extensions;
 
-use crate::extensions::import::import_problem;
-use crate::extensions::solve::config::{create_builder_from_config, read_config};
-use std::io::{BufReader, BufWriter};
+use crate::extensions::solve::config::read_config;
+use crate::extensions::solve::options::{create_builder_from_options, SolveOptions};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::ops::Deref;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
-use vrp_core::models::Problem as CoreProblem;
-use vrp_pragmatic::format::problem::{serialize_problem, PragmaticProblem, Problem};
+use vrp_core::models::{Problem as CoreProblem, Solution as CoreSolution};
+use vrp_core::solver::ProgressHook;
+use vrp_core::utils::run_with_threads;
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem, Problem};
 use vrp_pragmatic::format::solution::PragmaticSolution;
 use vrp_pragmatic::format::FormatError;
 use vrp_pragmatic::get_unique_locations;
+use vrp_pragmatic::validation::ValidationContext;
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+#[path = "../tests/unit/interop_test.rs"]
+mod interop_test;
 
 #[cfg(not(target_arch = "wasm32"))]
 mod interop {
     use super::*;
+    use crate::extensions::import::import_problem;
+    use crate::extensions::solve::options::{apply_options_to_problem, read_options};
+    use crate::extensions::solve::zones::Strategy;
     use std::ffi::{CStr, CString};
+    use std::mem;
     use std::os::raw::c_char;
+    use std::ptr;
     use std::slice;
-    use vrp_pragmatic::format::problem::deserialize_problem;
+    use std::sync::atomic::Ordering;
+    use vrp_core::solver::ProgressEvent;
+    use vrp_pragmatic::checker::{CheckerConfig, CheckerContext};
+    use vrp_pragmatic::format::problem::serialize_problem;
+    use vrp_pragmatic::format::solution::deserialize_solution;
 
     type Callback = extern "C" fn(*const c_char);
+    type Utf16Callback = extern "C" fn(*const u16, usize);
+    type ErrorCallback = extern "C" fn(*const InteropError);
+    type ProgressCallback = Option<extern "C" fn(usize, f64, usize, u64)>;
+    type WriteCallback = extern "C" fn(*const c_char, usize);
+
+    /// An error passed to `failure` callback of functions which report structured errors.
+    /// Pointers are valid only during the callback call.
+    #[repr(C)]
+    pub struct InteropError {
+        /// A stable numeric code of error category.
+        pub code: i32,
+        /// A name of error category: `input`, `deserialization`, `validation` or `solver`.
+        pub category: *const c_char,
+        /// Error details serialized as json object with `message` and optional `errors` fields.
+        pub details: *const c_char,
+    }
+
+    fn to_string(pointer: *const c_char) -> Result<String, String> {
+        if pointer.is_null() {
+            return Err("cannot read string: null pointer".to_string());
+        }
 
-    fn to_string(pointer: *const c_char) -> String {
         let slice = unsafe { CStr::from_ptr(pointer).to_bytes() };
-        std::str::from_utf8(slice).unwrap().to_string()
+        std::str::from_utf8(slice)
+            .map(|value| value.to_string())
+            .map_err(|err| format!("cannot read string: '{}'", err))
+    }
+
+    fn to_strings(pointers: *const *const c_char, len: usize) -> Result<Vec<String>, String> {
+        if pointers.is_null() || len == 0 {
+            return Ok(vec![]);
+        }
+
+        let pointers = unsafe { slice::from_raw_parts(pointers, len) };
+        pointers.iter().map(|pointer| to_string(*pointer)).collect()
+    }
+
+    /// Converts length passed as signed integer by legacy functions, negative values mean no items.
+    fn to_len(len: i32) -> usize {
+        len.max(0) as usize
+    }
+
+    fn to_string_utf16(pointer: *const u16, len: usize) -> Result<String, String> {
+        if pointer.is_null() {
+            return Err("cannot read utf16 string: null pointer".to_string());
+        }
+
+        let slice = unsafe { slice::from_raw_parts(pointer, len) };
+        String::from_utf16(slice).map_err(|err| format!("cannot read utf16 string: '{}'", err))
+    }
+
+    fn to_strings_utf16(pointers: *const *const u16, lens: *const usize, len: usize) -> Result<Vec<String>, String> {
+        if pointers.is_null() || lens.is_null() || len == 0 {
+            return Ok(vec![]);
+        }
+
+        let pointers = unsafe { slice::from_raw_parts(pointers, len) };
+        let lens = unsafe { slice::from_raw_parts(lens, len) };
+
+        pointers.iter().zip(lens.iter()).map(|(pointer, len)| to_string_utf16(*pointer, *len)).collect()
+    }
+
+    fn call_back_utf16(result: Result<String, String>, success: Utf16Callback, failure: Utf16Callback) {
+        let (callback, value) = match result {
+            Ok(ok) => (success, ok),
+            Err(err) => (failure, err),
+        };
+
+        let value = value.encode_utf16().collect::<Vec<_>>();
+        callback(value.as_ptr(), value.len());
     }
 
     fn call_back(result: Result<String, String>, success: Callback, failure: Callback) {
-        match result {
-            Ok(ok) => {
-                let ok = CString::new(ok.as_bytes()).unwrap();
-                success(ok.as_ptr());
+        let (callback, value) = match result.map(CString::new) {
+            Ok(Ok(ok)) => (success, ok),
+            Ok(Err(err)) => {
+                (failure, to_c_string(format!("cannot pass result with nul byte at {}", err.nul_position())))
             }
+            Err(err) => (failure, to_c_string(err)),
+        };
+
+        callback(value.as_ptr());
+    }
+
+    fn to_c_result(result: Result<String, ApiError>) -> Result<CString, ApiError> {
+        result.and_then(|value| {
+            CString::new(value).map_err(|err| {
+                ApiError::new(
+                    ErrorCategory::Solver,
+                    format!("cannot pass result with nul byte at {}", err.nul_position()),
+                )
+            })
+        })
+    }
+
+    fn call_back_with_error(result: Result<String, ApiError>, success: Callback, failure: ErrorCallback) {
+        let err = match to_c_result(result) {
+            Ok(ok) => return success(ok.as_ptr()),
+            Err(err) => err,
+        };
+
+        let category = to_c_string(err.category.name().to_string());
+        let details = to_c_string(serde_json::to_string(&err).unwrap_or_else(|_| err.message.clone()));
+
+        failure(&InteropError { code: err.category.code(), category: category.as_ptr(), details: details.as_ptr() });
+    }
+
+    /// An error serialized with its category for functions which return owned strings.
+    #[derive(Serialize)]
+    struct OwnedError<'a> {
+        code: i32,
+        category: &'static str,
+        #[serde(flatten)]
+        error: &'a ApiError,
+    }
+
+    /// Returns result value as a string owned by the caller or null pointer. In the latter case,
+    /// error details are written to `error` unless it is null.
+    fn into_owned(result: Result<String, ApiError>, error: *mut *mut c_char) -> *mut c_char {
+        match to_c_result(result) {
+            Ok(ok) => ok.into_raw(),
             Err(err) => {
-                let error = CString::new(err.as_bytes()).unwrap();
-                failure(error.as_ptr());
+                write_error(err, error);
+                ptr::null_mut()
             }
-        };
+        }
+    }
+
+    /// Writes error details as a string owned by the caller to `error` unless it is null.
+    fn write_error(err: ApiError, error: *mut *mut c_char) {
+        if !error.is_null() {
+            let details = OwnedError { code: err.category.code(), category: err.category.name(), error: &err };
+            let details = serde_json::to_string(&details).unwrap_or_else(|_| err.message.clone());
+            unsafe { *error = to_c_string(details).into_raw() };
+        }
+    }
+
+    fn to_options(pointer: *const c_char) -> Result<SolveOptions, String> {
+        if pointer.is_null() {
+            return Ok(SolveOptions::default());
+        }
+
+        to_string(pointer).and_then(|options| read_options(BufReader::new(options.as_bytes())))
     }
 
+    fn input_error(err: String) -> ApiError {
+        ApiError::new(ErrorCategory::Input, err)
+    }
+
+    fn create_progress_hook(progress: extern "C" fn(usize, f64, usize, u64)) -> ProgressHook {
+        Arc::new(move |event: &ProgressEvent| {
+            progress(event.generation, event.best_cost, event.unassigned, (event.elapsed * 1000.) as u64)
+        })
+    }
+
+    fn to_c_string(value: String) -> CString {
+        CString::new(value.replace('\0', "")).expect("string without nul bytes")
+    }
+
+    fn try_solve(
+        problem: Result<String, String>,
+        matrices: Result<Vec<String>, String>,
+        config: Result<String, String>,
+        options: Result<SolveOptions, String>,
+        on_improvement: Option<SolutionHook>,
+        on_progress: Option<ProgressHook>,
+        cancellation: Option<Arc<AtomicBool>>,
+    ) -> Result<String, ApiError> {
+        let (problem, matrices, config, options) = (
+            problem.map_err(input_error)?,
+            matrices.map_err(input_error)?,
+            config.map_err(input_error)?,
+            options.map_err(input_error)?,
+        );
+
+        let problem = apply_strategy(problem, &options)?;
+
+        read_problem(problem, matrices).and_then(|problem| {
+            try_get_solution_serialized(
+                &Arc::new(problem),
+                &config,
+                &options,
+                on_improvement,
+                on_progress,
+                cancellation,
+            )
+        })
+    }
+
+    fn read_problem(problem: String, matrices: Vec<String>) -> Result<CoreProblem, ApiError> {
+        if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
+            .map_err(ApiError::from_format_errors)
+    }
+
+    fn solve(
+        problem: Result<String, String>,
+        matrices: Result<Vec<String>, String>,
+        config: Result<String, String>,
+        options: Result<SolveOptions, String>,
+        on_improvement: Option<SolutionHook>,
+    ) -> Result<String, String> {
+        try_solve(problem, matrices, config, options, on_improvement, None, None).map_err(|err| err.message)
+    }
+
+    /// Applies solve strategy to the problem passed as serialized string.
+    fn apply_strategy(problem: String, options: &SolveOptions) -> Result<String, ApiError> {
+        if options.strategy != Some(Strategy::ClusterFirst) {
+            return Ok(problem);
+        }
+
+        let problem = deserialize_problem(BufReader::new(problem.as_bytes())).map_err(ApiError::from_format_errors)?;
+        let problem = apply_options_to_problem(problem, options).map_err(input_error)?;
+
+        let mut buffer = String::new();
+        let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+        serialize_problem(writer, &problem).map_err(|err| input_error(err.to_string()))?;
+
+        Ok(buffer)
+    }
+
+    fn get_locations(problem: Result<String, String>) -> Result<String, ApiError> {
+        let problem = problem.map_err(input_error)?;
+
+        deserialize_problem(BufReader::new(problem.as_bytes())).map_err(ApiError::from_format_errors).and_then(
+            |problem| get_locations_serialized(&problem).map_err(|err| ApiError::new(ErrorCategory::Solver, err)),
+        )
+    }
+
+    // NOTE functions without `vrp_` prefix which pass results to callbacks are legacy ones: they are
+    // kept for existing consumers, but not declared in `vrp.h` header and not extended anymore.
+
     /// Returns a list of unique locations to request a routing matrix.
     /// Problem should be passed in `pragmatic` format.
     #[no_mangle]
     extern "C" fn get_routing_locations(problem: *const c_char, success: Callback, failure: Callback) {
-        let problem = to_string(problem);
-        let problem = BufReader::new(problem.as_bytes());
-        let result = deserialize_problem(problem)
-            .map_err(|errors| get_errors_serialized(&errors))
-            .and_then(|problem| get_locations_serialized(&problem));
+        let result = catch_panic(|| {
+            let problem = to_string(problem)?;
+            deserialize_problem(BufReader::new(problem.as_bytes()))
+                .map_err(|errors| get_errors_serialized(&errors))
+                .and_then(|problem| get_locations_serialized(&problem))
+        });
 
         call_back(result, success, failure);
     }
 
+    fn convert(format: Result<String, String>, inputs: Result<Vec<String>, String>) -> Result<String, String> {
+        let (format, inputs) = (format?, inputs?);
+        let readers = inputs.iter().map(|p| BufReader::new(p.as_bytes())).collect::<Vec<_>>();
+
+        let problem = import_problem(format.as_str(), Some(readers))?;
+
+        let mut buffer = String::new();
+        let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+        serialize_problem(writer, &problem).map_err(|err| err.to_string())?;
+
+        Ok(buffer)
+    }
+
     /// Converts problem from format specified by `format` to `pragmatic` format.
     #[no_mangle]
     extern "C" fn convert_to_pragmatic(
         format: *const c_char,
         inputs: *const *const c_char,
-        input_len: *const i32,
+        input_len: i32,
         success: Callback,
         failure: Callback,
     ) {
-        let format = to_string(format);
-        let inputs = unsafe { slice::from_raw_parts(inputs, input_len as usize).to_vec() };
-        let inputs = inputs.iter().map(|p| to_string(*p)).collect::<Vec<_>>();
-        let readers = inputs.iter().map(|p| BufReader::new(p.as_bytes())).collect::<Vec<_>>();
-
-        match import_problem(format.as_str(), Some(readers)) {
-            Ok(problem) => {
-                let mut buffer = String::new();
-                let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
-                serialize_problem(writer, &problem).unwrap();
-                let problem = CString::new(buffer.as_bytes()).unwrap();
+        let result = catch_panic(|| convert(to_string(format), to_strings(inputs, to_len(input_len))));
 
-                success(problem.as_ptr());
-            }
-            Err(err) => {
-                let error = CString::new(err.as_bytes()).unwrap();
-                failure(error.as_ptr());
-            }
-        }
+        call_back(result, success, failure);
     }
 
     /// Solves Vehicle Routing Problem passed in `pragmatic` format.
@@ -93,22 +326,691 @@ mod interop {
     extern "C" fn solve_pragmatic(
         problem: *const c_char,
         matrices: *const *const c_char,
-        matrices_len: *const i32,
+        matrices_len: i32,
         config: *const c_char,
         success: Callback,
         failure: Callback,
     ) {
-        let problem = to_string(problem);
-        let matrices = unsafe { slice::from_raw_parts(matrices, matrices_len as usize).to_vec() };
-        let matrices = matrices.iter().map(|m| to_string(*m)).collect::<Vec<_>>();
-        let config = to_string(config);
+        let result = catch_panic(|| {
+            solve(
+                to_string(problem),
+                to_strings(matrices, to_len(matrices_len)),
+                to_string(config),
+                Ok(SolveOptions::default()),
+                None,
+            )
+        });
 
-        let result = if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
-            .map_err(|errors| get_errors_serialized(&errors))
-            .and_then(|problem| get_solution_serialized(&Arc::new(problem), &config));
+        call_back(result, success, failure);
+    }
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. In addition to `solve_pragmatic`,
+    /// calls `improvement` callback with serialized solution every time a new best one is found.
+    #[no_mangle]
+    extern "C" fn solve_pragmatic_with_hook(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: i32,
+        config: *const c_char,
+        improvement: Callback,
+        success: Callback,
+        failure: Callback,
+    ) {
+        let on_improvement: SolutionHook = Arc::new(move |solution: String| {
+            if let Ok(solution) = CString::new(solution) {
+                improvement(solution.as_ptr());
+            }
+        });
+
+        let result = catch_panic(|| {
+            solve(
+                to_string(problem),
+                to_strings(matrices, to_len(matrices_len)),
+                to_string(config),
+                Ok(SolveOptions::default()),
+                Some(on_improvement),
+            )
+        });
 
         call_back(result, success, failure);
     }
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. In addition to `solve_pragmatic`,
+    /// accepts solve options in json format which override config settings, e.g. max time, seed or
+    /// amount of threads. The same options are accepted by command line interface as arguments.
+    #[no_mangle]
+    extern "C" fn solve_pragmatic_with_options(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: i32,
+        config: *const c_char,
+        options: *const c_char,
+        success: Callback,
+        failure: Callback,
+    ) {
+        let result = catch_panic(|| {
+            let options = to_string(options).and_then(|options| read_options(BufReader::new(options.as_bytes())));
+            solve(to_string(problem), to_strings(matrices, to_len(matrices_len)), to_string(config), options, None)
+        });
+
+        call_back(result, success, failure);
+    }
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. Works as `solve_pragmatic_with_options`,
+    /// but `failure` callback receives a structured error, so the host can distinguish malformed input,
+    /// deserialization and validation errors from solver failures.
+    #[no_mangle]
+    pub(crate) extern "C" fn solve_pragmatic_with_errors(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: i32,
+        config: *const c_char,
+        options: *const c_char,
+        success: Callback,
+        failure: ErrorCallback,
+    ) {
+        let result = catch_api_panic(|| {
+            try_solve(
+                to_string(problem),
+                to_strings(matrices, to_len(matrices_len)),
+                to_string(config),
+                to_options(options),
+                None,
+                None,
+                None,
+            )
+        });
+
+        call_back_with_error(result, success, failure);
+    }
+
+    /// A handle which allows to cancel solving from another thread.
+    pub struct CancellationHandle {
+        is_cancelled: Arc<AtomicBool>,
+    }
+
+    /// Creates a new cancellation handle which should be set to `cancellation` field of
+    /// `VrpSolveOptions` and, finally, released by `vrp_cancellation_free`.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_cancellation_new() -> *mut CancellationHandle {
+        Box::into_raw(Box::new(CancellationHandle { is_cancelled: Arc::new(AtomicBool::new(false)) }))
+    }
+
+    /// Requests cancellation: solver stops refinement at the next generation and returns the best
+    /// solution found so far. Can be called from any thread.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_cancel(handle: *const CancellationHandle) {
+        if let Some(handle) = unsafe { handle.as_ref() } {
+            handle.is_cancelled.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Releases cancellation handle. It should not be called while solving is in progress.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_cancellation_free(handle: *mut CancellationHandle) {
+        if !handle.is_null() {
+            drop(unsafe { Box::from_raw(handle) });
+        }
+    }
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. Works as `solve_pragmatic_with_errors`,
+    /// but solving can be stopped by calling `vrp_cancel` with the given `cancellation` handle from
+    /// another thread. Null handle means that solving cannot be cancelled.
+    #[no_mangle]
+    extern "C" fn solve_pragmatic_with_cancellation(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: i32,
+        config: *const c_char,
+        options: *const c_char,
+        cancellation: *const CancellationHandle,
+        success: Callback,
+        failure: ErrorCallback,
+    ) {
+        let cancellation = unsafe { cancellation.as_ref() }.map(|handle| handle.is_cancelled.clone());
+
+        let result = catch_api_panic(|| {
+            try_solve(
+                to_string(problem),
+                to_strings(matrices, to_len(matrices_len)),
+                to_string(config),
+                to_options(options),
+                None,
+                None,
+                cancellation,
+            )
+        });
+
+        call_back_with_error(result, success, failure);
+    }
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. Works as `solve_pragmatic_with_cancellation`,
+    /// but calls `progress` callback after each generation with generation number, cost of the best
+    /// solution, amount of its unassigned jobs and elapsed time in milliseconds. The callback is
+    /// called from solver thread, so it should return quickly. Null callback is ignored.
+    #[no_mangle]
+    extern "C" fn solve_pragmatic_with_progress(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: i32,
+        config: *const c_char,
+        options: *const c_char,
+        cancellation: *const CancellationHandle,
+        progress: ProgressCallback,
+        success: Callback,
+        failure: ErrorCallback,
+    ) {
+        let cancellation = unsafe { cancellation.as_ref() }.map(|handle| handle.is_cancelled.clone());
+        let on_progress = progress.map(create_progress_hook);
+
+        let result = catch_api_panic(|| {
+            try_solve(
+                to_string(problem),
+                to_strings(matrices, to_len(matrices_len)),
+                to_string(config),
+                to_options(options),
+                None,
+                on_progress,
+                cancellation,
+            )
+        });
+
+        call_back_with_error(result, success, failure);
+    }
+
+    /// Returns a list of unique locations to request a routing matrix. Works as `get_routing_locations`,
+    /// but `failure` callback receives a structured error.
+    #[no_mangle]
+    extern "C" fn get_routing_locations_with_errors(problem: *const c_char, success: Callback, failure: ErrorCallback) {
+        let result = catch_api_panic(|| get_locations(to_string(problem)));
+
+        call_back_with_error(result, success, failure);
+    }
+
+    /// Returns a list of unique locations to request a routing matrix. Works as `get_routing_locations`,
+    /// but problem is passed as utf16 string of `problem_len` code units which is not required to be
+    /// null terminated. Callbacks receive utf16 string and its length.
+    #[no_mangle]
+    extern "C" fn get_routing_locations_utf16(
+        problem: *const u16,
+        problem_len: usize,
+        success: Utf16Callback,
+        failure: Utf16Callback,
+    ) {
+        let result = catch_panic(|| {
+            let problem = to_string_utf16(problem, problem_len)?;
+            deserialize_problem(BufReader::new(problem.as_bytes()))
+                .map_err(|errors| get_errors_serialized(&errors))
+                .and_then(|problem| get_locations_serialized(&problem))
+        });
+
+        call_back_utf16(result, success, failure);
+    }
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. Works as `solve_pragmatic`, but
+    /// all strings are passed as utf16 strings with explicit length in code units, so they are not
+    /// required to be null terminated. Callbacks receive utf16 string and its length.
+    #[no_mangle]
+    extern "C" fn solve_pragmatic_utf16(
+        problem: *const u16,
+        problem_len: usize,
+        matrices: *const *const u16,
+        matrices_lens: *const usize,
+        matrices_len: usize,
+        config: *const u16,
+        config_len: usize,
+        success: Utf16Callback,
+        failure: Utf16Callback,
+    ) {
+        let result = catch_panic(|| {
+            solve(
+                to_string_utf16(problem, problem_len),
+                to_strings_utf16(matrices, matrices_lens, matrices_len),
+                to_string_utf16(config, config_len),
+                Ok(SolveOptions::default()),
+                None,
+            )
+        });
+
+        call_back_utf16(result, success, failure);
+    }
+
+    /// Returns a list of unique locations to request a routing matrix as a string which should be
+    /// released by `vrp_free_string`. On failure, returns null pointer and writes error details to
+    /// `error`: a json object with `code`, `category`, `message` and optional `errors` fields, which
+    /// should be released by `vrp_free_string` too. Null `error` pointer is ignored.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_get_routing_locations(problem: *const c_char, error: *mut *mut c_char) -> *mut c_char {
+        into_owned(catch_api_panic(|| get_locations(to_string(problem))), error)
+    }
+
+    /// Converts problem from format specified by `format` to `pragmatic` format. Result and error
+    /// ownership is the same as in `vrp_get_routing_locations`.
+    #[no_mangle]
+    extern "C" fn vrp_convert_to_pragmatic(
+        format: *const c_char,
+        inputs: *const *const c_char,
+        input_len: usize,
+        error: *mut *mut c_char,
+    ) -> *mut c_char {
+        let result = catch_api_panic(|| convert(to_string(format), to_strings(inputs, input_len)).map_err(input_error));
+
+        into_owned(result, error)
+    }
+
+    /// Validates problem passed in `pragmatic` format with optional routing matrices without solving
+    /// it. Returns a json array of found errors with `code`, `cause`, `action` and optional `details`
+    /// fields, which is empty when problem is valid. Errors of malformed problem or matrices are
+    /// returned the same way. Result and error ownership is the same as in `vrp_get_routing_locations`,
+    /// `error` is written only when arguments cannot be read.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_validate_pragmatic(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: usize,
+        error: *mut *mut c_char,
+    ) -> *mut c_char {
+        let result = catch_api_panic(|| {
+            let (problem, matrices) =
+                (to_string(problem).map_err(input_error)?, to_strings(matrices, matrices_len).map_err(input_error)?);
+
+            serde_json::to_string(&get_validation_errors(&problem, &matrices))
+                .map_err(|err| ApiError::new(ErrorCategory::Solver, err.to_string()))
+        });
+
+        into_owned(result, error)
+    }
+
+    fn check_solution(
+        problem: Result<String, String>,
+        matrices: Result<Vec<String>, String>,
+        solution: Result<String, String>,
+        config: CheckerConfig,
+    ) -> Result<String, ApiError> {
+        let (problem, matrices, solution) =
+            (problem.map_err(input_error)?, matrices.map_err(input_error)?, solution.map_err(input_error)?);
+
+        let problem = deserialize_problem(BufReader::new(problem.as_bytes())).map_err(ApiError::from_format_errors)?;
+        let matrices = matrices
+            .iter()
+            .map(|matrix| deserialize_matrix(BufReader::new(matrix.as_bytes())))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ApiError::from_format_errors)?;
+        let solution = deserialize_solution(BufReader::new(solution.as_bytes()))
+            .map_err(|err| ApiError::new(ErrorCategory::Deserialization, format!("cannot read solution: '{}'", err)))?;
+
+        let matrices = if matrices.is_empty() { None } else { Some(matrices) };
+        let report = CheckerContext::new(problem, matrices, solution).with_config(config).get_report();
+
+        serde_json::to_string(&report).map_err(|err| ApiError::new(ErrorCategory::Solver, err.to_string()))
+    }
+
+    /// Checks that solution in `pragmatic` format, e.g. produced by the solver and modified manually,
+    /// is feasible for the problem with optional routing matrices. Returns a json object with
+    /// `errors` and `warnings` arrays of violation descriptions: solution is feasible when `errors`
+    /// is empty. Time, load and distance violations which do not exceed `time_tolerance` (in seconds),
+    /// `load_tolerance` and `distance_tolerance` (in meters) are reported as warnings. Result and
+    /// error ownership is the same as in `vrp_get_routing_locations`, `error` is written only when
+    /// arguments cannot be read.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_check_solution(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: usize,
+        solution: *const c_char,
+        time_tolerance: f64,
+        load_tolerance: i32,
+        distance_tolerance: f64,
+        error: *mut *mut c_char,
+    ) -> *mut c_char {
+        let result = catch_api_panic(|| {
+            check_solution(
+                to_string(problem),
+                to_strings(matrices, matrices_len),
+                to_string(solution),
+                CheckerConfig { time_tolerance, load_tolerance, distance_tolerance },
+            )
+        });
+
+        into_owned(result, error)
+    }
+
+    /// Passes written bytes to the callback.
+    struct CallbackWriter {
+        write: WriteCallback,
+    }
+
+    impl Write for CallbackWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            (self.write)(buf.as_ptr() as *const c_char, buf.len());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Solves the problem kept by the handle. Works as `vrp_solve`, but serialized solution is passed
+    /// to `write` callback in chunks, as a pointer to bytes and their amount, while it is written
+    /// tour by tour, so the whole solution is never kept in memory. Chunks are not null terminated
+    /// and may end in the middle of utf8 character. Please note that total statistic is written
+    /// after tours. Returns true on success, otherwise writes error details to `error` the same way
+    /// as `vrp_get_routing_locations` does. Nothing is written when solving fails.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_solve_streamed(
+        handle: *const ProblemHandle,
+        config: *const c_char,
+        options: *const VrpSolveOptions,
+        write: WriteCallback,
+        error: *mut *mut c_char,
+    ) -> bool {
+        let result = catch_api_panic(|| {
+            try_solve_handle(handle, config, options, |problem, config, options, on_progress, cancellation| {
+                let writer = BufWriter::new(CallbackWriter { write });
+                try_write_solution(problem, config, options, on_progress, cancellation, writer)
+            })
+        });
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                write_error(err, error);
+                false
+            }
+        }
+    }
+
+    /// Releases a string returned by library functions. Null pointer is ignored.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_free_string(value: *mut c_char) {
+        if !value.is_null() {
+            drop(unsafe { CString::from_raw(value) });
+        }
+    }
+
+    /// Keeps problem which is read once and can be solved multiple times.
+    pub struct ProblemHandle {
+        problem: Arc<CoreProblem>,
+    }
+
+    /// Reads problem passed in `pragmatic` format with optional routing matrices and returns a handle
+    /// which should be passed to `vrp_solve` and, finally, released by `vrp_problem_destroy`. On
+    /// failure, returns null pointer and writes error details to `error` the same way as
+    /// `vrp_get_routing_locations` does.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_problem_create(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: usize,
+        error: *mut *mut c_char,
+    ) -> *mut ProblemHandle {
+        let result = catch_api_panic(|| {
+            let (problem, matrices) =
+                (to_string(problem).map_err(input_error)?, to_strings(matrices, matrices_len).map_err(input_error)?);
+
+            read_problem(problem, matrices)
+        });
+
+        into_handle(result, error)
+    }
+
+    fn into_handle(result: Result<CoreProblem, ApiError>, error: *mut *mut c_char) -> *mut ProblemHandle {
+        match result {
+            Ok(problem) => Box::into_raw(Box::new(ProblemHandle { problem: Arc::new(problem) })),
+            Err(err) => {
+                write_error(err, error);
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// Reads solve arguments and passes them to `solve` function together with the problem kept by
+    /// the handle.
+    fn try_solve_handle<T>(
+        handle: *const ProblemHandle,
+        config: *const c_char,
+        options: *const VrpSolveOptions,
+        solve: impl FnOnce(
+            &Arc<CoreProblem>,
+            &String,
+            &SolveOptions,
+            Option<ProgressHook>,
+            Option<Arc<AtomicBool>>,
+        ) -> Result<T, ApiError>,
+    ) -> Result<T, ApiError> {
+        let handle = unsafe { handle.as_ref() }.ok_or_else(|| input_error("null problem handle".to_string()))?;
+        let (config, options) =
+            (to_string(config).map_err(input_error)?, read_solve_options(options).map_err(input_error)?);
+
+        let cancellation = options
+            .as_ref()
+            .and_then(|options| unsafe { options.cancellation.as_ref() })
+            .map(|handle| handle.is_cancelled.clone());
+        let on_progress = options.as_ref().and_then(|options| options.progress).map(create_progress_hook);
+        let options = options.as_ref().map(to_solve_options).unwrap_or_default();
+
+        solve(&handle.problem, &config, &options, on_progress, cancellation)
+    }
+
+    /// Solves the problem kept by the handle with optional config and options, and returns
+    /// serialized solution. Null options pointer means default options. Result and error ownership
+    /// is the same as in `vrp_get_routing_locations`. The same handle can be solved from multiple
+    /// threads at the same time.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_solve(
+        handle: *const ProblemHandle,
+        config: *const c_char,
+        options: *const VrpSolveOptions,
+        error: *mut *mut c_char,
+    ) -> *mut c_char {
+        let result = catch_api_panic(|| {
+            try_solve_handle(handle, config, options, |problem, config, options, on_progress, cancellation| {
+                try_get_solution_serialized(problem, config, options, None, on_progress, cancellation)
+            })
+        });
+
+        into_owned(result, error)
+    }
+
+    /// Releases the problem handle. It should not be called while solving is in progress.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_problem_destroy(handle: *mut ProblemHandle) {
+        if !handle.is_null() {
+            drop(unsafe { Box::from_raw(handle) });
+        }
+    }
+
+    /// A version of `VrpSolveOptions` layout supported by the library.
+    pub const VRP_SOLVE_OPTIONS_VERSION: u32 = 1;
+
+    /// Solve options passed as a struct instead of json string. The `struct_size` field should be
+    /// set to the struct size and the `version` field to `VRP_SOLVE_OPTIONS_VERSION` the caller is
+    /// compiled against, use `vrp_solve_options_init` to get them together with default values.
+    /// New fields are only appended with a version increment and the library never reads beyond
+    /// `struct_size`, so it keeps reading structs of older versions: missing fields are treated as
+    /// zero. Zero values mean that setting is not specified and the value from config is used.
+    #[repr(C)]
+    pub struct VrpSolveOptions {
+        /// A size of the struct in bytes as known by the caller.
+        pub struct_size: usize,
+        /// A version of struct layout.
+        pub version: u32,
+        /// Max amount of generations.
+        pub max_generations: usize,
+        /// Max running time in seconds.
+        pub max_time: usize,
+        /// Sample size of cost variation termination, used together with threshold.
+        pub cost_variation_sample: usize,
+        /// Threshold of cost variation termination.
+        pub cost_variation_threshold: f64,
+        /// Specifies whether `seed` is used: zero seed is a valid value.
+        pub has_seed: bool,
+        /// Random seed used to get reproducible runs.
+        pub seed: u64,
+        /// Amount of threads used by solver.
+        pub parallelism: usize,
+        /// Reduces memory usage at the cost of solution quality.
+        pub low_memory: bool,
+        /// Optional cancellation handle created by `vrp_cancellation_new`: once it is cancelled,
+        /// solver stops at the next generation and returns the best known solution.
+        pub cancellation: *const CancellationHandle,
+        /// Optional callback called from solver thread after each generation with generation,
+        /// best cost, amount of unassigned jobs and elapsed milliseconds.
+        pub progress: ProgressCallback,
+    }
+
+    /// A size of the struct header which is the same for all versions: `struct_size` and `version`.
+    const SOLVE_OPTIONS_HEADER_SIZE: usize = mem::size_of::<usize>() + mem::size_of::<u32>();
+
+    /// Copies options passed by the caller reading not more than `struct_size` bytes, so fields
+    /// unknown to the caller are zero.
+    pub(crate) fn read_solve_options(options: *const VrpSolveOptions) -> Result<Option<VrpSolveOptions>, String> {
+        if options.is_null() {
+            return Ok(None);
+        }
+
+        let struct_size = unsafe { ptr::read_unaligned(options as *const usize) };
+        if struct_size < SOLVE_OPTIONS_HEADER_SIZE {
+            return Err(format!("invalid solve options struct size: {}", struct_size));
+        }
+
+        let mut result = unsafe { mem::zeroed::<VrpSolveOptions>() };
+        let size = struct_size.min(mem::size_of::<VrpSolveOptions>());
+        unsafe { ptr::copy_nonoverlapping(options as *const u8, &mut result as *mut VrpSolveOptions as *mut u8, size) };
+
+        if result.version == 0 || result.version > VRP_SOLVE_OPTIONS_VERSION {
+            return Err(format!(
+                "unsupported solve options version: {}, expected up to {}",
+                result.version, VRP_SOLVE_OPTIONS_VERSION
+            ));
+        }
+
+        Ok(Some(result))
+    }
+
+    pub(crate) fn to_solve_options(options: &VrpSolveOptions) -> SolveOptions {
+        let non_zero = |value: usize| if value > 0 { Some(value) } else { None };
+
+        SolveOptions {
+            max_generations: non_zero(options.max_generations),
+            max_time: non_zero(options.max_time),
+            cost_variation: non_zero(options.cost_variation_sample)
+                .map(|sample| (sample, options.cost_variation_threshold)),
+            seed: if options.has_seed { Some(options.seed) } else { None },
+            parallelism: non_zero(options.parallelism),
+            low_memory: if options.low_memory { Some(true) } else { None },
+            ..SolveOptions::default()
+        }
+    }
+
+    /// Returns library version as a static null terminated string which should not be released.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_get_version() -> *const c_char {
+        concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr() as *const c_char
+    }
+
+    /// Initializes solve options of `struct_size` bytes, usually `sizeof(VrpSolveOptions)`, with
+    /// the size, the version supported by the library and default values. Only `struct_size`
+    /// bytes are written. Null pointer is ignored.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_solve_options_init(options: *mut VrpSolveOptions, struct_size: usize) {
+        if options.is_null() || struct_size < SOLVE_OPTIONS_HEADER_SIZE {
+            return;
+        }
+
+        let mut defaults = unsafe { mem::zeroed::<VrpSolveOptions>() };
+        defaults.struct_size = struct_size;
+        defaults.version = VRP_SOLVE_OPTIONS_VERSION;
+
+        let size = struct_size.min(mem::size_of::<VrpSolveOptions>());
+        unsafe { ptr::copy_nonoverlapping(&defaults as *const VrpSolveOptions as *const u8, options as *mut u8, size) };
+    }
+
+    /// Keeps problem and routing matrices passed in chunks.
+    pub struct ProblemStream {
+        problem: ChunkedReader,
+        matrices: Vec<ChunkedReader>,
+    }
+
+    fn append_chunk(reader: &mut ChunkedReader, chunk: *const c_char, chunk_len: usize) {
+        if chunk.is_null() || chunk_len == 0 {
+            return;
+        }
+
+        let chunk = unsafe { slice::from_raw_parts(chunk as *const u8, chunk_len) };
+        reader.append(chunk.to_vec());
+    }
+
+    /// Starts streaming of a problem in `pragmatic` format. Returns a handle which should be passed
+    /// to `vrp_problem_append` and `vrp_matrix_append` functions and, finally, to `vrp_problem_end`
+    /// or `vrp_problem_discard` which release it.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_problem_begin() -> *mut ProblemStream {
+        Box::into_raw(Box::new(ProblemStream { problem: ChunkedReader::default(), matrices: vec![] }))
+    }
+
+    /// Appends a chunk of `chunk_len` bytes to the problem. Chunk is not required to be null
+    /// terminated or to end at utf8 character boundary. Null handle is ignored.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_problem_append(stream: *mut ProblemStream, chunk: *const c_char, chunk_len: usize) {
+        if let Some(stream) = unsafe { stream.as_mut() } {
+            append_chunk(&mut stream.problem, chunk, chunk_len);
+        }
+    }
+
+    /// Starts a new routing matrix: subsequent `vrp_matrix_append` calls append chunks to it.
+    /// Null handle is ignored.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_matrix_begin(stream: *mut ProblemStream) {
+        if let Some(stream) = unsafe { stream.as_mut() } {
+            stream.matrices.push(ChunkedReader::default());
+        }
+    }
+
+    /// Appends a chunk of `chunk_len` bytes to the routing matrix started by the last
+    /// `vrp_matrix_begin` call. Starts a new matrix if there is none. Null handle is ignored.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_matrix_append(stream: *mut ProblemStream, chunk: *const c_char, chunk_len: usize) {
+        if let Some(stream) = unsafe { stream.as_mut() } {
+            if stream.matrices.is_empty() {
+                stream.matrices.push(ChunkedReader::default());
+            }
+            append_chunk(stream.matrices.last_mut().unwrap(), chunk, chunk_len);
+        }
+    }
+
+    /// Reads streamed problem and releases the stream handle. Returns a problem handle which should
+    /// be passed to `vrp_solve` and, finally, released by `vrp_problem_destroy`. On failure, returns
+    /// null pointer and writes error details to `error` the same way as `vrp_problem_create` does.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_problem_end(
+        stream: *mut ProblemStream,
+        error: *mut *mut c_char,
+    ) -> *mut ProblemHandle {
+        let result = catch_api_panic(|| {
+            if stream.is_null() {
+                return Err(input_error("cannot read problem stream: null pointer".to_string()));
+            }
+
+            let stream = unsafe { Box::from_raw(stream) };
+            let problem = BufReader::new(stream.problem);
+            let matrices = stream.matrices.into_iter().map(BufReader::new).collect::<Vec<_>>();
+
+            if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
+                .map_err(ApiError::from_format_errors)
+        });
+
+        into_handle(result, error)
+    }
+
+    /// Releases the handle without solving the problem. Null handle is ignored.
+    #[no_mangle]
+    pub(crate) extern "C" fn vrp_problem_discard(stream: *mut ProblemStream) {
+        if !stream.is_null() {
+            drop(unsafe { Box::from_raw(stream) });
+        }
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -119,6 +1021,7 @@ mod wasm {
     use wasm_bindgen::prelude::*;
 
     use super::*;
+    use crate::extensions::solve::options::apply_options_to_problem;
     use vrp_pragmatic::format::problem::Matrix;
 
     /// Returns a list of unique locations to request a routing matrix.
@@ -133,8 +1036,13 @@ mod wasm {
     }
 
     /// Converts problem from format specified by `format` to `pragmatic` format.
+    /// Not available when built with `wasm-slim` feature.
+    #[cfg(not(feature = "wasm-slim"))]
     #[wasm_bindgen]
     pub fn convert_to_pragmatic(format: &str, inputs: &JsValue) -> Result<JsValue, JsValue> {
+        use crate::extensions::import::import_problem;
+        use vrp_pragmatic::format::problem::serialize_problem;
+
         let inputs: Vec<String> = inputs.into_serde().map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
 
         let readers = inputs.iter().map(|input| BufReader::new(input.as_bytes())).collect();
@@ -143,7 +1051,7 @@ mod wasm {
             Ok(problem) => {
                 let mut buffer = String::new();
                 let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
-                serialize_problem(writer, &problem).unwrap();
+                serialize_problem(writer, &problem).map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
 
                 Ok(JsValue::from_str(buffer.as_str()))
             }
@@ -151,13 +1059,35 @@ mod wasm {
         }
     }
 
-    /// Solves Vehicle Routing Problem passed in `pragmatic` format.
+    /// Wraps JS function to be used as improvement hook.
+    struct JsHook(js_sys::Function);
+
+    // NOTE webassembly module runs in a single thread, so the function is never shared between threads
+    unsafe impl Send for JsHook {}
+    unsafe impl Sync for JsHook {}
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. Optional `on_improvement`
+    /// function is called with serialized solution every time a new best one is found. Optional
+    /// `options` override config settings, the same options are accepted by command line interface.
     #[wasm_bindgen]
-    pub fn solve_pragmatic(problem: &JsValue, matrices: &JsValue, config: &JsValue) -> Result<JsValue, JsValue> {
+    pub fn solve_pragmatic(
+        problem: &JsValue,
+        matrices: &JsValue,
+        config: &JsValue,
+        on_improvement: Option<js_sys::Function>,
+        options: Option<JsValue>,
+    ) -> Result<JsValue, JsValue> {
         let problem: Problem = problem.into_serde().map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
 
         let matrices: Vec<Matrix> = matrices.into_serde().map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
 
+        let options: SolveOptions = match options {
+            Some(options) => options.into_serde().map_err(|err| JsValue::from_str(err.to_string().as_str()))?,
+            None => SolveOptions::default(),
+        };
+
+        let problem = apply_options_to_problem(problem, &options).map_err(|err| JsValue::from_str(err.as_str()))?;
+
         let problem = Arc::new(
             if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }.map_err(
                 |errors| {
@@ -171,48 +1101,323 @@ mod wasm {
             .into_serde()
             .map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
 
-        get_solution_serialized(&problem, &config_str)
+        let on_improvement = on_improvement.map(|function| {
+            let hook = JsHook(function);
+            Arc::new(move |solution: String| {
+                let _ = hook.0.call1(&JsValue::NULL, &JsValue::from_str(solution.as_str()));
+            }) as SolutionHook
+        });
+
+        get_solution_serialized_with_options(&problem, &config_str, &options, on_improvement)
             .map(|problem| JsValue::from_str(problem.as_str()))
             .map_err(|err| JsValue::from_str(err.as_str()))
     }
 }
 
+/// Validates problem in `pragmatic` format with optional routing matrices without solving it and
+/// returns all found errors. If problem or matrices cannot be deserialized, their errors are
+/// returned instead.
+pub fn get_validation_errors(problem: &str, matrices: &[String]) -> Vec<FormatError> {
+    let problem = match deserialize_problem(BufReader::new(problem.as_bytes())) {
+        Ok(problem) => problem,
+        Err(errors) => return errors,
+    };
+
+    let matrices = matrices
+        .iter()
+        .map(|matrix| deserialize_matrix(BufReader::new(matrix.as_bytes())))
+        .collect::<Result<Vec<_>, _>>();
+
+    match matrices {
+        Ok(matrices) => ValidationContext::new(&problem, if matrices.is_empty() { None } else { Some(&matrices) })
+            .validate()
+            .err()
+            .unwrap_or_default(),
+        Err(errors) => errors,
+    }
+}
+
 pub fn get_locations_serialized(problem: &Problem) -> Result<String, String> {
     // TODO validate the problem?
 
-    let locations = get_unique_locations(&problem);
-    let mut buffer = String::new();
-    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
-    serde_json::to_writer_pretty(writer, &locations).map_err(|err| err.to_string())?;
+    catch_panic(|| {
+        let locations = get_unique_locations(&problem);
+        let mut buffer = String::new();
+        let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+        serde_json::to_writer_pretty(writer, &locations).map_err(|err| err.to_string())?;
 
-    Ok(buffer)
+        Ok(buffer)
+    })
 }
 
-pub fn get_solution_serialized(problem: &Arc<CoreProblem>, config_str: &String) -> Result<String, String> {
-    let config = read_config(BufReader::new(config_str.as_bytes())).map_err(|err| {
-        FormatError::new(
-            "E0004".to_string(),
-            "cannot read config".to_string(),
-            format!("check config definition. Error: '{}'", err),
-        )
-        .to_json()
-    })?;
-
-    let (solution, _) = create_builder_from_config(&config)
-        .and_then(|builder| builder.with_problem(problem.clone()).build())
-        .and_then(|solver| solver.solve())
-        .or_else(|err| {
-            Err(FormatError::new(
+/// Runs action and converts a panic into an error, so it does not kill the process which embeds
+/// the solver. Please note that panics cannot be caught when compiled with `panic = "abort"`, e.g.
+/// in webassembly build.
+pub fn catch_panic<T, F: FnOnce() -> Result<T, String>>(action: F) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(action)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown reason".to_string());
+
+        Err(format!("solver panicked: '{}'", message))
+    })
+}
+
+/// A category of library or command line error, so hosts can distinguish bad input from solver
+/// failures. The category code is also used as process exit code of the command line interface.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ErrorCategory {
+    /// Invalid arguments, e.g. null pointers, malformed strings, options or config.
+    Input,
+    /// Problem or routing matrix cannot be deserialized.
+    Deserialization,
+    /// Problem can be deserialized, but violates problem definition rules.
+    Validation,
+    /// Solver cannot find a solution or fails unexpectedly.
+    Solver,
+    /// Result cannot be written.
+    Output,
+    /// Solution does not meet acceptance thresholds.
+    Acceptance,
+}
+
+impl ErrorCategory {
+    /// Returns a category name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ErrorCategory::Input => "input",
+            ErrorCategory::Deserialization => "deserialization",
+            ErrorCategory::Validation => "validation",
+            ErrorCategory::Solver => "solver",
+            ErrorCategory::Output => "output",
+            ErrorCategory::Acceptance => "acceptance",
+        }
+    }
+
+    /// Returns a stable numeric code of the category.
+    pub fn code(&self) -> i32 {
+        match self {
+            ErrorCategory::Input => 1,
+            ErrorCategory::Deserialization => 2,
+            ErrorCategory::Validation => 3,
+            ErrorCategory::Solver => 4,
+            ErrorCategory::Output => 5,
+            ErrorCategory::Acceptance => 6,
+        }
+    }
+}
+
+/// A library error with its category. Serialized form is used as error details in C interface.
+#[derive(Clone, Debug, Serialize)]
+pub struct ApiError {
+    /// An error category.
+    #[serde(skip)]
+    pub category: ErrorCategory,
+    /// An error message.
+    pub message: String,
+    /// Pragmatic format errors which caused the error, if any.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FormatError>,
+}
+
+impl ApiError {
+    /// Creates a new instance of `ApiError` with the message only.
+    pub fn new(category: ErrorCategory, message: String) -> Self {
+        Self { category, message, errors: vec![] }
+    }
+
+    /// Creates an error from pragmatic format errors: the ones with `E1xxx` codes are caused by
+    /// validation rules, `E0003` is caused by solver, `E0004` by config, all others by malformed
+    /// problem or routing matrix.
+    pub fn from_format_errors(errors: Vec<FormatError>) -> Self {
+        let category = if errors.iter().any(|err| err.code.starts_with("E1")) {
+            ErrorCategory::Validation
+        } else if errors.iter().any(|err| err.code == "E0003") {
+            ErrorCategory::Solver
+        } else if errors.iter().any(|err| err.code == "E0004") {
+            ErrorCategory::Input
+        } else {
+            ErrorCategory::Deserialization
+        };
+
+        Self { category, message: get_errors_serialized(&errors), errors }
+    }
+
+    /// Creates an error from a single pragmatic format error keeping its json as the message.
+    fn from_format_error(error: FormatError) -> Self {
+        let message = error.to_json();
+
+        Self { message, ..Self::from_format_errors(vec![error]) }
+    }
+}
+
+/// Runs action the same way as `catch_panic` does, but reports a panic as a solver error.
+pub fn catch_api_panic<T, F: FnOnce() -> Result<T, ApiError>>(action: F) -> Result<T, ApiError> {
+    catch_panic(|| Ok(action())).map_err(|err| ApiError::new(ErrorCategory::Solver, err)).and_then(|result| result)
+}
+
+/// A reader over data passed in multiple chunks, so huge payloads do not need to be kept in one
+/// contiguous buffer. Chunks are released as soon as they are read.
+#[derive(Default)]
+pub struct ChunkedReader {
+    chunks: VecDeque<Vec<u8>>,
+    position: usize,
+}
+
+impl ChunkedReader {
+    /// Appends a chunk of data to the end.
+    pub fn append(&mut self, chunk: Vec<u8>) {
+        self.chunks.push_back(chunk);
+    }
+}
+
+impl Read for ChunkedReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while let Some(chunk) = self.chunks.front() {
+            if self.position < chunk.len() {
+                let size = buf.len().min(chunk.len() - self.position);
+                buf[..size].copy_from_slice(&chunk[self.position..self.position + size]);
+                self.position += size;
+
+                return Ok(size);
+            }
+
+            self.chunks.pop_front();
+            self.position = 0;
+        }
+
+        Ok(0)
+    }
+}
+
+/// A hook which receives a new best solution serialized in `pragmatic` format.
+pub type SolutionHook = Arc<dyn Fn(String) + Send + Sync>;
+
+pub fn get_solution_serialized(
+    problem: &Arc<CoreProblem>,
+    config_str: &String,
+    on_improvement: Option<SolutionHook>,
+) -> Result<String, String> {
+    get_solution_serialized_with_options(problem, config_str, &SolveOptions::default(), on_improvement)
+}
+
+/// Solves the problem using config and solve options which override config settings. Returns
+/// solution serialized in `pragmatic` format.
+pub fn get_solution_serialized_with_options(
+    problem: &Arc<CoreProblem>,
+    config_str: &String,
+    options: &SolveOptions,
+    on_improvement: Option<SolutionHook>,
+) -> Result<String, String> {
+    try_get_solution_serialized(problem, config_str, options, on_improvement, None, None).map_err(|err| err.message)
+}
+
+/// Solves the problem the same way as `get_solution_serialized_with_options` does, but returns
+/// an error with its category. Optional progress hook is called after each generation. Optional
+/// cancellation flag stops solving when it is set, so the best known solution is returned.
+pub fn try_get_solution_serialized(
+    problem: &Arc<CoreProblem>,
+    config_str: &String,
+    options: &SolveOptions,
+    on_improvement: Option<SolutionHook>,
+    on_progress: Option<ProgressHook>,
+    cancellation: Option<Arc<AtomicBool>>,
+) -> Result<String, ApiError> {
+    let solution = try_solve_problem(problem, config_str, options, on_improvement, on_progress, cancellation)?;
+
+    serialize_solution(problem, &solution).map_err(|err| ApiError::new(ErrorCategory::Solver, err))
+}
+
+/// Solves the problem the same way as `try_get_solution_serialized` does, but writes solution to
+/// the writer tour by tour instead of building the whole string in memory, so it is suitable for
+/// problems with huge solutions. Please note that total statistic is written after tours.
+pub fn try_write_solution<W: Write>(
+    problem: &Arc<CoreProblem>,
+    config_str: &String,
+    options: &SolveOptions,
+    on_progress: Option<ProgressHook>,
+    cancellation: Option<Arc<AtomicBool>>,
+    writer: BufWriter<W>,
+) -> Result<(), ApiError> {
+    let solution = try_solve_problem(problem, config_str, options, None, on_progress, cancellation)?;
+
+    solution.write_pragmatic_json_streamed(problem, writer).map_err(|err| ApiError::new(ErrorCategory::Solver, err))
+}
+
+fn try_solve_problem(
+    problem: &Arc<CoreProblem>,
+    config_str: &String,
+    options: &SolveOptions,
+    on_improvement: Option<SolutionHook>,
+    on_progress: Option<ProgressHook>,
+    cancellation: Option<Arc<AtomicBool>>,
+) -> Result<CoreSolution, ApiError> {
+    let config = read_config(BufReader::new(config_str.as_bytes()))
+        .map_err(|err| create_config_error("cannot read config", err))?;
+
+    let builder = create_builder_from_options(Some(&config), options)
+        .map_err(|err| create_config_error("cannot create solver from config", err))?;
+
+    let builder = match on_improvement {
+        Some(on_improvement) => {
+            let hook_problem = problem.clone();
+            builder.with_improvement_hook(Arc::new(move |solution, _, _| {
+                if let Ok(solution) = serialize_solution(&hook_problem, solution) {
+                    on_improvement.deref()(solution);
+                }
+            }))
+        }
+        None => builder,
+    };
+
+    let builder = match on_progress {
+        Some(on_progress) => builder.with_progress_hook(on_progress),
+        None => builder,
+    };
+
+    let builder = match cancellation {
+        Some(is_cancelled) => builder.with_cancellation(is_cancelled),
+        None => builder,
+    };
+
+    let solve = || {
+        let solver = builder
+            .with_problem(problem.clone())
+            .build()
+            .map_err(|err| create_config_error("cannot create solver from config", err))?;
+
+        catch_panic(|| solver.solve()).map_err(|err| {
+            ApiError::from_format_error(FormatError::new(
                 "E0003".to_string(),
                 "cannot find any solution".to_string(),
                 format!("please submit a bug and share original problem and routing matrix. Error: '{}'", err),
-            )
-            .to_json())
-        })?;
+            ))
+        })
+    };
+
+    let (solution, _) = match options.parallelism {
+        Some(threads) => run_with_threads(threads, solve).map_err(|err| ApiError::new(ErrorCategory::Input, err))??,
+        None => solve()?,
+    };
+
+    Ok(solution)
+}
+
+/// Creates an input error caused by invalid config or solve options.
+fn create_config_error(cause: &str, err: impl Display) -> ApiError {
+    ApiError::from_format_error(FormatError::new(
+        "E0004".to_string(),
+        cause.to_string(),
+        format!("check config definition. Error: '{}'", err),
+    ))
+}
 
+fn serialize_solution(problem: &CoreProblem, solution: &CoreSolution) -> Result<String, String> {
     let mut buffer = String::new();
     let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
-    solution.write_pragmatic_json(&problem, writer)?;
+    solution.write_pragmatic_json(problem, writer)?;
 
     Ok(buffer)
 }
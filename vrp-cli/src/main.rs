@@ -4,6 +4,10 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod commands;
 
+#[cfg(not(target_arch = "wasm32"))]
+#[global_allocator]
+static ALLOCATOR: commands::memory::CountingAllocator = commands::memory::CountingAllocator;
+
 fn main() {
     cli::run_app()
 }
@@ -13,8 +17,15 @@ mod cli {
     extern crate clap;
     use super::commands::import::{get_import_app, run_import};
     use super::commands::solve::{get_solve_app, run_solve};
+    use crate::commands::analyze::{get_analyze_app, run_analyze};
     use crate::commands::check::{get_check_app, run_check};
+    use crate::commands::compare::{get_compare_app, run_compare};
+    use crate::commands::convert::{get_convert_app, run_convert};
     use crate::commands::generate::{get_generate_app, run_generate};
+    use crate::commands::links::{get_links_app, run_links};
+    use crate::commands::matrix::{get_matrix_app, run_matrix};
+    use crate::commands::reduce::{get_reduce_app, run_reduce};
+    use crate::commands::timeline::{get_timeline_app, run_timeline};
     use clap::{crate_version, App};
     use std::process;
 
@@ -27,6 +38,13 @@ mod cli {
             .subcommand(get_import_app())
             .subcommand(get_check_app())
             .subcommand(get_generate_app())
+            .subcommand(get_timeline_app())
+            .subcommand(get_links_app())
+            .subcommand(get_matrix_app())
+            .subcommand(get_analyze_app())
+            .subcommand(get_convert_app())
+            .subcommand(get_compare_app())
+            .subcommand(get_reduce_app())
             .get_matches();
 
         match matches.subcommand() {
@@ -34,6 +52,13 @@ mod cli {
             ("import", Some(import_matches)) => run_import(import_matches),
             ("check", Some(check_matches)) => run_check(check_matches),
             ("generate", Some(generate_matches)) => run_generate(generate_matches),
+            ("timeline", Some(timeline_matches)) => run_timeline(timeline_matches),
+            ("links", Some(links_matches)) => run_links(links_matches),
+            ("matrix", Some(matrix_matches)) => run_matrix(matrix_matches),
+            ("analyze", Some(analyze_matches)) => run_analyze(analyze_matches),
+            ("convert", Some(convert_matches)) => run_convert(convert_matches),
+            ("compare", Some(compare_matches)) => run_compare(compare_matches),
+            ("reduce", Some(reduce_matches)) => run_reduce(reduce_matches),
             ("", None) => {
                 eprintln!("No subcommand was used. Use -h to print help information.");
                 process::exit(1);
@@ -1 +1,3 @@
 mod generate;
+mod solve;
+mod stream;
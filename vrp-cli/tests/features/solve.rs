@@ -0,0 +1,191 @@
+use crate::extensions::solve::options::SolveOptions;
+use crate::{get_solution_serialized, get_solution_serialized_with_options, try_get_solution_serialized};
+use crate::{ApiError, ErrorCategory};
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use vrp_pragmatic::format::problem::PragmaticProblem;
+use vrp_pragmatic::format::FormatError;
+
+#[test]
+fn can_call_improvement_hook_with_serialized_solution() {
+    let reader = BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap());
+    let problem = Arc::new(reader.read_pragmatic().unwrap_or_else(|_| panic!("cannot read problem")));
+    let config = r#"{ "termination": { "max_generations": 10 } }"#.to_string();
+    let improvements = Arc::new(Mutex::new(Vec::<String>::new()));
+    let hook_improvements = improvements.clone();
+
+    let solution = get_solution_serialized(
+        &problem,
+        &config,
+        Some(Arc::new(move |solution| hook_improvements.lock().unwrap().push(solution))),
+    )
+    .unwrap();
+
+    let improvements = improvements.lock().unwrap();
+    assert!(!improvements.is_empty());
+    assert!(improvements.iter().all(|improvement| improvement.contains("\"tours\"")));
+    assert_eq!(improvements.last().unwrap(), &solution);
+}
+
+#[test]
+fn can_return_error_when_solver_panics() {
+    let reader = BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap());
+    let problem = Arc::new(reader.read_pragmatic().unwrap_or_else(|_| panic!("cannot read problem")));
+    let config = r#"{ "termination": { "max_generations": 10 } }"#.to_string();
+
+    let result = get_solution_serialized(&problem, &config, Some(Arc::new(|_| panic!("hook failure"))));
+
+    let err = result.err().unwrap();
+    assert!(err.contains("E0003"));
+    assert!(err.contains("solver panicked: 'hook failure'"));
+}
+
+#[test]
+fn can_override_config_with_solve_options() {
+    let reader = BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap());
+    let problem = Arc::new(reader.read_pragmatic().unwrap_or_else(|_| panic!("cannot read problem")));
+    let config = r#"{ "termination": { "max_generations": 100000, "max_time": 300 } }"#.to_string();
+    let options =
+        SolveOptions { max_generations: Some(20), seed: Some(42), parallelism: Some(1), ..SolveOptions::default() };
+
+    let solution = get_solution_serialized_with_options(&problem, &config, &options, None).unwrap();
+
+    assert!(solution.contains("\"tours\""));
+}
+
+fn create_multi_job_problem(jobs: usize, pickups: usize) -> String {
+    let location = |idx: usize| serde_json::json!({ "lat": 52.5 + (idx % 7) as f64 / 100., "lng": 13.3 + (idx % 11) as f64 / 100. });
+    let task = |idx: usize, demand: usize| serde_json::json!({ "places": [{ "location": location(idx), "duration": 60 }], "demand": [demand] });
+
+    let jobs = (0..jobs)
+        .map(|job| {
+            serde_json::json!({
+                "id": format!("job{}", job),
+                "pickups": (0..pickups).map(|pickup| task(job * pickups + pickup, 1)).collect::<Vec<_>>(),
+                "deliveries": [task(job * 3, pickups)]
+            })
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::json!({
+        "plan": { "jobs": jobs },
+        "fleet": {
+            "vehicles": [{
+                "typeId": "vehicle",
+                "vehicleIds": ["vehicle_1", "vehicle_2"],
+                "profile": "car",
+                "costs": { "fixed": 10., "distance": 0.001, "time": 0.001 },
+                "shifts": [{ "start": { "time": "2020-01-01T00:00:00Z", "location": location(0) } }],
+                "capacity": [10]
+            }],
+            "profiles": [{ "name": "car", "type": "car" }]
+        }
+    })
+    .to_string()
+}
+
+#[test]
+fn can_get_same_solution_with_same_seed() {
+    // NOTE multi jobs have more permutations than they sample on each insertion
+    let problem = create_multi_job_problem(8, 4);
+    let problem = Arc::new(problem.read_pragmatic().unwrap_or_else(|_| panic!("cannot read problem")));
+    let config = "{}".to_string();
+    let options =
+        SolveOptions { max_generations: Some(20), seed: Some(42), parallelism: Some(1), ..SolveOptions::default() };
+
+    let solve = || get_solution_serialized_with_options(&problem, &config, &options, None).unwrap();
+
+    assert_eq!(solve(), solve());
+}
+
+#[test]
+fn can_return_input_error_with_zero_parallelism() {
+    let reader = BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap());
+    let problem = Arc::new(reader.read_pragmatic().unwrap_or_else(|_| panic!("cannot read problem")));
+    let config = r#"{ "termination": { "max_generations": 10 } }"#.to_string();
+    let options = SolveOptions { parallelism: Some(0), ..SolveOptions::default() };
+
+    let result = get_solution_serialized_with_options(&problem, &config, &options, None);
+    let err = try_get_solution_serialized(&problem, &config, &options, None, None, None).err().unwrap();
+
+    assert!(result.is_err());
+    assert_eq!(err.category, ErrorCategory::Input);
+    assert_eq!(err.errors.first().map(|err| err.code.as_str()), Some("E0004"));
+}
+
+#[test]
+fn can_return_input_error_with_invalid_config() {
+    let reader = BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap());
+    let problem = Arc::new(reader.read_pragmatic().unwrap_or_else(|_| panic!("cannot read problem")));
+    let config = "{".to_string();
+
+    let err = try_get_solution_serialized(&problem, &config, &SolveOptions::default(), None, None, None).err().unwrap();
+
+    assert_eq!(err.category, ErrorCategory::Input);
+    assert_eq!(err.errors.first().map(|err| err.code.as_str()), Some("E0004"));
+    assert_eq!(get_solution_serialized(&problem, &config, None).err().unwrap(), err.message);
+}
+
+#[test]
+fn can_categorize_format_errors() {
+    let create_error = |code: &str| FormatError::new(code.to_string(), "cause".to_string(), "action".to_string());
+
+    for (codes, expected) in vec![
+        (vec!["E0000"], ErrorCategory::Deserialization),
+        (vec!["E0001", "E0002"], ErrorCategory::Deserialization),
+        (vec!["E1003", "E1104"], ErrorCategory::Validation),
+        (vec!["E0003"], ErrorCategory::Solver),
+        (vec!["E0004"], ErrorCategory::Input),
+    ] {
+        let err = ApiError::from_format_errors(codes.iter().map(|code| create_error(code)).collect());
+
+        assert_eq!(err.category, expected);
+        assert_eq!(err.errors.len(), codes.len());
+    }
+}
+
+#[test]
+fn can_return_best_known_solution_when_cancelled() {
+    let reader = BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap());
+    let problem = Arc::new(reader.read_pragmatic().unwrap_or_else(|_| panic!("cannot read problem")));
+    let config = r#"{ "termination": { "max_generations": 100000, "max_time": 300 } }"#.to_string();
+    let is_cancelled = Arc::new(AtomicBool::new(false));
+    let hook_cancelled = is_cancelled.clone();
+
+    let solution = try_get_solution_serialized(
+        &problem,
+        &config,
+        &SolveOptions::default(),
+        Some(Arc::new(move |_| hook_cancelled.store(true, Ordering::Relaxed))),
+        None,
+        Some(is_cancelled),
+    )
+    .unwrap();
+
+    assert!(solution.contains("\"tours\""));
+}
+
+#[test]
+fn can_call_progress_hook_after_each_generation() {
+    let reader = BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap());
+    let problem = Arc::new(reader.read_pragmatic().unwrap_or_else(|_| panic!("cannot read problem")));
+    let config = r#"{ "termination": { "max_generations": 10 } }"#.to_string();
+    let generations = Arc::new(Mutex::new(Vec::<usize>::new()));
+    let hook_generations = generations.clone();
+
+    try_get_solution_serialized(
+        &problem,
+        &config,
+        &SolveOptions::default(),
+        None,
+        Some(Arc::new(move |event| hook_generations.lock().unwrap().push(event.generation))),
+        None,
+    )
+    .unwrap();
+
+    let generations = generations.lock().unwrap();
+    assert!(!generations.is_empty());
+    assert!(generations.windows(2).all(|pair| pair[0] < pair[1]));
+}
@@ -0,0 +1,33 @@
+use crate::ChunkedReader;
+use std::io::{BufReader, Read};
+use vrp_pragmatic::format::problem::PragmaticProblem;
+
+fn create_reader(data: &[u8], chunk_size: usize) -> ChunkedReader {
+    let mut reader = ChunkedReader::default();
+    data.chunks(chunk_size).for_each(|chunk| reader.append(chunk.to_vec()));
+
+    reader
+}
+
+#[test]
+fn can_read_data_from_chunks() {
+    let data = "chunked data with multibyte characters: äöü".as_bytes();
+
+    for chunk_size in vec![1, 3, 7, data.len(), data.len() + 1] {
+        let mut result = String::new();
+        create_reader(data, chunk_size).read_to_string(&mut result).unwrap();
+
+        assert_eq!(result.as_bytes(), data);
+    }
+}
+
+#[test]
+fn can_read_problem_and_matrix_from_chunks() {
+    let problem = std::fs::read("../examples/data/pragmatic/simple.basic.problem.json").unwrap();
+    let matrix = std::fs::read("../examples/data/pragmatic/simple.basic.matrix.json").unwrap();
+
+    let problem = BufReader::new(create_reader(problem.as_slice(), 64));
+    let matrices = vec![BufReader::new(create_reader(matrix.as_slice(), 64))];
+
+    assert!((problem, matrices).read_pragmatic().is_ok());
+}
@@ -0,0 +1,76 @@
+use super::*;
+
+fn write_and_read(path: &std::path::Path) -> (Vec<u8>, String) {
+    let path = path.to_str().unwrap();
+    let content = r#"{ "plan": { "jobs": [] } }"#;
+
+    let mut writer = create_write_buffer(Some(create_file(path, "test")));
+    writer.write_all(content.as_bytes()).unwrap();
+    drop(writer);
+
+    let mut read_content = String::new();
+    open_file(path, "test").read_to_string(&mut read_content).unwrap();
+
+    (std::fs::read(path).unwrap(), read_content)
+}
+
+#[test]
+fn can_write_and_read_gzip_file() {
+    let path = std::env::temp_dir().join(format!("vrp_cli_commands_test_{}.json.gz", process::id()));
+
+    let (raw, content) = write_and_read(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(&raw[..2], &[0x1f, 0x8b]);
+    assert_eq!(content, r#"{ "plan": { "jobs": [] } }"#);
+}
+
+#[test]
+fn can_write_and_read_plain_file() {
+    let path = std::env::temp_dir().join(format!("vrp_cli_commands_test_{}.json", process::id()));
+
+    let (raw, content) = write_and_read(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(String::from_utf8(raw).unwrap(), content);
+    assert_eq!(content, r#"{ "plan": { "jobs": [] } }"#);
+}
+
+#[test]
+fn can_create_command_error_from_format_errors() {
+    let create_error = |codes: &[&str]| {
+        CommandError::from(ApiError::from_format_errors(
+            codes
+                .iter()
+                .map(|code| FormatError::new(code.to_string(), "cause".to_string(), "action".to_string()))
+                .collect(),
+        ))
+    };
+
+    assert_eq!(create_error(&["E0000"]).category, ErrorCategory::Deserialization);
+    assert_eq!(create_error(&["E0000"]).exit_code, 2);
+    assert_eq!(create_error(&["E0004"]).category, ErrorCategory::Input);
+    assert_eq!(create_error(&["E0004"]).exit_code, 1);
+    assert_eq!(create_error(&["E1301", "E1302"]).category, ErrorCategory::Validation);
+    assert_eq!(create_error(&["E0003"]).category, ErrorCategory::Solver);
+    assert_eq!(create_error(&["E0003"]).exit_code, 4);
+}
+
+#[test]
+fn can_serialize_command_error() {
+    let error = CommandError::new(ErrorCategory::Output, "cannot write").with_context("cannot write solution");
+
+    assert_eq!(
+        serde_json::to_string(&error).unwrap(),
+        r#"{"category":"output","exitCode":5,"message":"cannot write solution: 'cannot write'"}"#
+    );
+
+    let error = CommandError::from(ApiError::from_format_errors(vec![FormatError::new(
+        "E1301".to_string(),
+        "cause".to_string(),
+        "action".to_string(),
+    )]));
+    let json = serde_json::to_string(&error).unwrap();
+
+    assert!(json.starts_with(r#"{"category":"validation","exitCode":3,"message":"E1301, cause: 'cause', action: 'action'.","errors":[{"code":"E1301""#));
+}
@@ -0,0 +1,59 @@
+use super::*;
+
+#[test]
+fn can_count_allocations() {
+    let memory_stats = MemoryStats::new();
+
+    let before = MemorySnapshot::take();
+    let data = vec![0_u8; 1024];
+    let after = MemorySnapshot::take();
+    drop(data);
+
+    let diff = after - before;
+    assert!(diff.allocations > 0);
+    assert!(diff.allocated >= 1024);
+
+    memory_stats.record_problem();
+    let report = memory_stats.get_report();
+    assert_eq!(report.len(), 1);
+    assert!(report[0].starts_with("memory: problem, allocations: "));
+}
+
+#[test]
+fn can_report_generation_average() {
+    let memory_stats = Arc::new(MemoryStats::new());
+    let create_event = |generation: usize| ProgressEvent {
+        generation,
+        elapsed: 0.,
+        speed: 0.,
+        best_cost: 0.,
+        worst_cost: 0.,
+        tours: 0,
+        unassigned: 0,
+        ruin_usage: vec![],
+        recreate_usage: vec![],
+    };
+
+    memory_stats.record_problem();
+    let hook = memory_stats.clone().create_progress_hook();
+    (0..3).for_each(|generation| hook(&create_event(generation)));
+
+    let report = memory_stats.get_report();
+    assert_eq!(report.len(), 3);
+    assert!(report[1].starts_with("memory: construction, "));
+    assert!(report[2].starts_with("memory: generation average, "));
+}
+
+#[test]
+fn can_subtract_snapshots() {
+    let first = MemorySnapshot { allocations: 10, allocated: 100, peak_rss: Some(1) };
+    let second = MemorySnapshot { allocations: 15, allocated: 180, peak_rss: Some(2) };
+
+    assert_eq!(second - first, MemorySnapshot { allocations: 5, allocated: 80, peak_rss: Some(2) });
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn can_get_peak_rss() {
+    assert!(get_peak_rss().unwrap() > 0);
+}
@@ -0,0 +1,161 @@
+use super::*;
+use serde_json::Value;
+
+fn to_kebab_case(name: &str) -> String {
+    name.chars().fold(String::new(), |mut acc, ch| {
+        if ch.is_uppercase() {
+            acc.push('-');
+        }
+        acc.push(ch.to_ascii_lowercase());
+        acc
+    })
+}
+
+#[test]
+fn can_pass_all_solve_options_as_arguments() {
+    let options = SolveOptions {
+        max_generations: Some(10),
+        max_time: Some(20),
+        cost_variation: Some((100, 0.1)),
+        seed: Some(42),
+        parallelism: Some(2),
+        mode: Some(Preset::Thorough),
+        low_memory: Some(true),
+        sparse_index: Some((1000, 100)),
+        strategy: Some(Strategy::ClusterFirst),
+    };
+    let args = match serde_json::to_value(&options).unwrap() {
+        Value::Object(fields) => fields
+            .into_iter()
+            .flat_map(|(name, value)| {
+                let name = format!("--{}", to_kebab_case(name.as_str()));
+                match value {
+                    Value::Bool(_) => vec![name],
+                    Value::String(value) => vec![name, value],
+                    Value::Array(values) => {
+                        vec![name, values.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(",")]
+                    }
+                    value => vec![name, value.to_string()],
+                }
+            })
+            .collect::<Vec<_>>(),
+        _ => unreachable!(),
+    };
+
+    let matches = get_solve_app()
+        .get_matches_from_safe(
+            vec!["solve".to_string(), "pragmatic".to_string(), "problem.json".to_string()].into_iter().chain(args),
+        )
+        .unwrap_or_else(|err| panic!("solve option is not supported by command line: {}", err));
+
+    assert_eq!(get_solve_options(&matches), options);
+}
+
+#[test]
+fn can_get_tags_in_order_of_appearance() {
+    let matches = get_solve_app()
+        .get_matches_from_safe(vec![
+            "solve",
+            "pragmatic",
+            "problem.json",
+            "--tag",
+            "experiment=baseline",
+            "--tag",
+            "region=eu=west",
+        ])
+        .unwrap();
+
+    assert_eq!(
+        get_tags(&matches),
+        vec![("experiment".to_string(), "baseline".to_string()), ("region".to_string(), "eu=west".to_string())]
+    );
+}
+
+#[test]
+fn can_add_tags_to_logger() {
+    let messages = Arc::new(Mutex::new(vec![]));
+    let logger: Logger = {
+        let messages = messages.clone();
+        Arc::new(move |msg: String| messages.lock().unwrap().push(msg))
+    };
+    let tags = vec![("experiment".to_string(), "baseline".to_string()), ("region".to_string(), "eu".to_string())];
+
+    add_tags_to_logger(logger, tags.as_slice())("generation: 1".to_string());
+
+    assert_eq!(messages.lock().unwrap().as_slice(), &["generation: 1, experiment: baseline, region: eu".to_string()]);
+}
+
+#[test]
+fn can_get_approx_matrices_with_speed() {
+    let file = std::fs::File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap();
+    let problem = deserialize_problem(BufReader::new(file)).ok().unwrap();
+
+    let matrices = get_approx_matrices(&problem, Some(36.)).unwrap();
+
+    assert_eq!(matrices.len(), problem.fleet.profiles.len());
+    matrices.iter().for_each(|matrix| {
+        let distances = matrix.distances.iter();
+        let durations = matrix.travel_times.iter();
+        distances.zip(durations).for_each(|(distance, duration)| assert!((distance / 10 - duration).abs() <= 1));
+    });
+}
+
+#[test]
+fn can_parse_approximate_matrix_speed_only_with_equals() {
+    let get_matches =
+        |args: Vec<&str>| get_solve_app().get_matches_from_safe(vec!["solve", "pragmatic"].into_iter().chain(args));
+
+    let matches = get_matches(vec!["--approximate-matrix", "problem.json"]).unwrap();
+    assert!(matches.is_present(APPROXIMATE_MATRIX_ARG_NAME));
+    assert_eq!(matches.value_of(APPROXIMATE_MATRIX_ARG_NAME), None);
+    assert_eq!(matches.value_of(PROBLEM_ARG_NAME), Some("problem.json"));
+
+    let matches = get_matches(vec!["problem.json", "--approximate-matrix=40"]).unwrap();
+    assert_eq!(matches.value_of(APPROXIMATE_MATRIX_ARG_NAME), Some("40"));
+
+    assert!(get_matches(vec!["problem.json", "--approximate-matrix=40", "--matrix", "matrix.json"]).is_err());
+}
+
+#[test]
+fn can_keep_only_latest_dumps() {
+    let dump_dir = std::env::temp_dir().join(format!("vrp_dumps_{}", std::process::id()));
+    std::fs::create_dir_all(&dump_dir).unwrap();
+    let mut dumps = VecDeque::default();
+
+    (1..=5).for_each(|generation| {
+        let path = dump_dir.join(format!("solution_{}.json", generation));
+        write_dump(path.as_path(), &mut dumps, 2, |mut file| {
+            file.write_all(generation.to_string().as_bytes()).map_err(|err| err.to_string())
+        })
+        .unwrap();
+    });
+    let mut files = std::fs::read_dir(&dump_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+    files.sort();
+    std::fs::remove_dir_all(&dump_dir).unwrap();
+
+    assert_eq!(files, vec!["solution_4.json".to_string(), "solution_5.json".to_string()]);
+    assert_eq!(dumps.len(), 2);
+}
+
+#[test]
+fn can_keep_previous_dump_when_writing_fails() {
+    let dump_dir = std::env::temp_dir().join(format!("vrp_failed_dumps_{}", std::process::id()));
+    std::fs::create_dir_all(&dump_dir).unwrap();
+    let mut dumps = VecDeque::default();
+
+    let first = dump_dir.join("solution_1.json");
+    write_dump(first.as_path(), &mut dumps, 1, |_| Ok(())).unwrap();
+    let second = dump_dir.join("solution_2.json");
+    let result = write_dump(second.as_path(), &mut dumps, 1, |_| Err("failed".to_string()));
+    let files = std::fs::read_dir(&dump_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+    std::fs::remove_dir_all(&dump_dir).unwrap();
+
+    assert_eq!(result, Err("failed".to_string()));
+    assert_eq!(files, vec!["solution_1.json".to_string()]);
+}
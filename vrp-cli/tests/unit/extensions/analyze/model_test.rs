@@ -0,0 +1,50 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+fn read_simple_example() -> (Problem, Solution) {
+    let problem = File::open("../examples/data/pragmatic/simple.basic.problem.json").expect("cannot read problem");
+    let solution = File::open("../examples/data/pragmatic/simple.basic.solution.json").expect("cannot read solution");
+
+    (
+        deserialize_problem(BufReader::new(problem)).ok().unwrap(),
+        deserialize_solution(BufReader::new(solution)).ok().unwrap(),
+    )
+}
+
+#[test]
+fn can_create_solution_kpi() {
+    let (problem, solution) = read_simple_example();
+
+    let kpi = create_solution_kpi(&problem, &solution).unwrap();
+
+    assert_eq!(kpi.distance, 11357);
+    assert_eq!(kpi.duration, 2277);
+    assert_eq!(kpi.driving, 1137);
+    assert_eq!(kpi.serving, 1140);
+    assert_eq!(kpi.waiting, 0);
+    assert_eq!(kpi.tours, 1);
+    assert_eq!((kpi.vehicles_used, kpi.vehicles_available), (1, 1));
+    assert_eq!(kpi.vehicle_utilization, 1.);
+    assert_eq!(kpi.assigned, 3);
+    assert_eq!(kpi.unassigned, 0);
+
+    assert_eq!(kpi.tour_kpis.len(), 1);
+    let tour = kpi.tour_kpis.first().unwrap();
+    assert_eq!(tour.vehicle_id, "vehicle_1");
+    assert_eq!(tour.jobs, 3);
+    assert_eq!(tour.load_factor, 0.2);
+    assert_eq!(kpi.load_factor, 0.2);
+}
+
+#[test]
+fn can_return_error_for_unknown_vehicle_type() {
+    let (problem, mut solution) = read_simple_example();
+    solution.tours[0].type_id = "unknown".to_string();
+
+    let result = create_solution_kpi(&problem, &solution);
+
+    assert_eq!(result.err(), Some("unknown vehicle type: 'unknown'".to_string()));
+}
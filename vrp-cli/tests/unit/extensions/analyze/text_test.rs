@@ -0,0 +1,44 @@
+use super::*;
+use crate::extensions::analyze::TourKpi;
+
+#[test]
+fn can_write_kpi_as_text() {
+    let kpi = SolutionKpi {
+        cost: 35.2146,
+        distance: 11357,
+        duration: 2277,
+        driving: 1137,
+        serving: 1140,
+        waiting: 0,
+        break_time: 0,
+        tours: 1,
+        vehicles_used: 1,
+        vehicles_available: 2,
+        vehicle_utilization: 0.5,
+        load_factor: 0.2,
+        assigned: 3,
+        unassigned: 0,
+        tour_kpis: vec![TourKpi {
+            vehicle_id: "vehicle_1".to_string(),
+            type_id: "vehicle".to_string(),
+            shift_index: 0,
+            cost: 35.2146,
+            distance: 11357,
+            duration: 2277,
+            driving: 1137,
+            waiting: 0,
+            jobs: 3,
+            load_factor: 0.2,
+        }],
+    };
+    let mut buffer = String::new();
+
+    write_kpi_text(unsafe { BufWriter::new(buffer.as_mut_vec()) }, &kpi).unwrap();
+
+    let lines = buffer.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "cost:            35.21");
+    assert_eq!(lines[8], "vehicles used:   1 of 2 (50.0%)");
+    assert_eq!(lines[9], "load factor:     20.0%");
+    assert_eq!(lines[13], "vehicle     shift  jobs  distance  duration   driving   waiting    load");
+    assert_eq!(lines[14], "vehicle_1       0     3     11357      2277      1137         0   20.0%");
+}
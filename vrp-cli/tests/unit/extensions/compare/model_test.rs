@@ -0,0 +1,76 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::solution::{deserialize_solution, UnassignedJob, UnassignedJobReason};
+
+fn read_simple_solution() -> Solution {
+    let solution = File::open("../examples/data/pragmatic/simple.basic.solution.json").expect("cannot read solution");
+
+    deserialize_solution(BufReader::new(solution)).ok().unwrap()
+}
+
+fn create_unassigned_job(job_id: &str) -> UnassignedJob {
+    UnassignedJob {
+        job_id: job_id.to_string(),
+        reasons: vec![UnassignedJobReason { code: 0, description: "unknown".to_string() }],
+    }
+}
+
+#[test]
+fn can_compare_same_solutions() {
+    let solution = read_simple_solution();
+
+    let diff = create_solution_diff(&solution, &solution);
+
+    assert_eq!(diff.cost.delta(), 0.);
+    assert_eq!(diff.tours, ValueDiff { baseline: 1., solution: 1. });
+    assert!(diff.moved_jobs.is_empty());
+    assert!(diff.order_changes.is_empty());
+    assert!(diff.newly_assigned.is_empty());
+    assert!(diff.newly_unassigned.is_empty());
+}
+
+#[test]
+fn can_detect_order_change() {
+    let baseline = read_simple_solution();
+    let mut solution = baseline.clone();
+    solution.tours[0].stops.swap(1, 2);
+    solution.statistic.cost += 10.;
+
+    let diff = create_solution_diff(&baseline, &solution);
+
+    assert_eq!(diff.cost.delta(), 10.);
+    assert!(diff.moved_jobs.is_empty());
+    assert_eq!(
+        diff.order_changes,
+        vec![OrderChange {
+            tour: "vehicle_1/0".to_string(),
+            baseline: vec!["job1".to_string(), "job3".to_string(), "job2".to_string(), "job3".to_string()],
+            solution: vec!["job3".to_string(), "job2".to_string(), "job1".to_string(), "job3".to_string()],
+        }]
+    );
+}
+
+#[test]
+fn can_detect_moved_jobs_and_unassigned_changes() {
+    let mut baseline = read_simple_solution();
+    baseline.unassigned = vec![create_unassigned_job("job4")];
+    let mut solution = read_simple_solution();
+    let mut new_tour = solution.tours[0].clone();
+    new_tour.vehicle_id = "vehicle_2".to_string();
+    new_tour.stops = vec![new_tour.stops[0].clone(), new_tour.stops[1].clone(), new_tour.stops[4].clone()];
+    solution.tours[0].stops.remove(1);
+    solution.tours.push(new_tour);
+    solution.unassigned = vec![create_unassigned_job("job5")];
+
+    let diff = create_solution_diff(&baseline, &solution);
+
+    assert_eq!(diff.tours.delta(), 1.);
+    assert_eq!(
+        diff.moved_jobs,
+        vec![JobMove { job_id: "job1".to_string(), from: "vehicle_1/0".to_string(), to: "vehicle_2/0".to_string() }]
+    );
+    assert!(diff.order_changes.is_empty());
+    assert_eq!(diff.newly_assigned, vec!["job4".to_string()]);
+    assert_eq!(diff.newly_unassigned, vec!["job5".to_string()]);
+}
@@ -0,0 +1,38 @@
+use super::*;
+use crate::extensions::compare::{JobMove, OrderChange};
+
+#[test]
+fn can_write_diff_as_text() {
+    let diff = SolutionDiff {
+        cost: ValueDiff { baseline: 35.2146, solution: 30.1 },
+        distance: ValueDiff { baseline: 11357., solution: 12000. },
+        duration: ValueDiff { baseline: 2277., solution: 2277. },
+        tours: ValueDiff { baseline: 1., solution: 2. },
+        unassigned: ValueDiff { baseline: 1., solution: 0. },
+        moved_jobs: vec![JobMove {
+            job_id: "job1".to_string(),
+            from: "vehicle_1/0".to_string(),
+            to: "vehicle_2/0".to_string(),
+        }],
+        order_changes: vec![OrderChange {
+            tour: "vehicle_1/0".to_string(),
+            baseline: vec!["job2".to_string(), "job3".to_string()],
+            solution: vec!["job3".to_string(), "job2".to_string()],
+        }],
+        newly_assigned: vec!["job4".to_string()],
+        newly_unassigned: vec![],
+    };
+    let mut buffer = String::new();
+
+    write_diff_text(unsafe { BufWriter::new(buffer.as_mut_vec()) }, &diff).unwrap();
+
+    let lines = buffer.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "cost:            35.21 -> 30.10 (-5.11)");
+    assert_eq!(lines[1], "distance:        11357 -> 12000 (+643)");
+    assert_eq!(lines[2], "duration:        2277 -> 2277 (+0)");
+    assert_eq!(lines[5], "moved jobs:      1");
+    assert_eq!(lines[9], "  job1: vehicle_1/0 -> vehicle_2/0");
+    assert_eq!(lines[12], "  vehicle_1/0: job2 job3 -> job3 job2");
+    assert_eq!(lines[14], "newly assigned: job4");
+    assert_eq!(lines.len(), 15);
+}
@@ -0,0 +1,120 @@
+use super::*;
+use std::io::BufReader;
+use vrp_scientific::solomon::SolomonProblem;
+
+fn create_location(x: f64, y: f64) -> Location {
+    Location { lat: x, lng: y }
+}
+
+fn create_delivery(id: &str, location: (f64, f64), demand: i32, times: Option<(&str, &str)>) -> Job {
+    Job {
+        id: id.to_string(),
+        pickups: None,
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace {
+                location: create_location(location.0, location.1),
+                duration: 10.,
+                times: times.map(|(start, end)| vec![vec![start.to_string(), end.to_string()]]),
+            }],
+            demand: Some(vec![demand]),
+            tag: None,
+        }]),
+        replacements: None,
+        services: None,
+        priority: None,
+        skills: None,
+        position: None,
+    }
+}
+
+fn create_test_problem(jobs: Vec<Job>) -> Problem {
+    let depot =
+        VehiclePlace { time: "2020-07-04T08:00:00Z".to_string(), location: create_location(0., 0.), gate: None };
+
+    Problem {
+        plan: Plan { jobs, relations: None, forbidden_arcs: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                type_id: "vehicle".to_string(),
+                vehicle_ids: vec!["vehicle_1".to_string(), "vehicle_2".to_string()],
+                profile: "car".to_string(),
+                costs: VehicleCosts { fixed: None, distance: 1., time: 0. },
+                shifts: vec![VehicleShift {
+                    start: depot.clone(),
+                    end: Some(VehiclePlace { time: "2020-07-04T09:00:00Z".to_string(), ..depot }),
+                    breaks: None,
+                    reloads: None,
+                }],
+                capacity: vec![10],
+                skills: None,
+                limits: None,
+                unavailable: None,
+                positions: None,
+            }],
+            profiles: vec![Profile {
+                name: "car".to_string(),
+                profile_type: "car".to_string(),
+                speed: None,
+                overrides: None,
+                multipliers: None,
+                straight_line: None,
+            }],
+        },
+        objectives: None,
+        config: None,
+    }
+}
+
+fn write_to_string(problem: &Problem) -> Result<String, String> {
+    let mut buffer = vec![];
+    write_solomon_problem(BufWriter::new(&mut buffer), problem)?;
+
+    Ok(String::from_utf8(buffer).unwrap())
+}
+
+#[test]
+fn can_write_solomon_problem() {
+    let problem = create_test_problem(vec![
+        create_delivery("job1", (1., 2.), 3, Some(("2020-07-04T08:10:00Z", "2020-07-04T08:20:00Z"))),
+        create_delivery("job2", (3., 4.), 5, None),
+    ]);
+
+    let result = write_to_string(&problem).unwrap();
+
+    let lines = result.lines().skip(9).map(|line| line.split_whitespace().collect::<Vec<_>>()).collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        vec![
+            vec!["0", "0", "0", "0", "0", "3600", "0"],
+            vec!["1", "1", "2", "3", "600", "1200", "10"],
+            vec!["2", "3", "4", "5", "0", "3600", "10"],
+        ]
+    );
+
+    let core_problem = BufReader::new(result.as_bytes()).read_solomon().unwrap();
+    assert_eq!(core_problem.jobs.size(), 2);
+    assert_eq!(core_problem.fleet.actors.len(), 2);
+}
+
+#[test]
+fn can_detect_unsupported_problem() {
+    let delivery = || create_delivery("job1", (1., 2.), 3, None);
+    let mut two_types = create_test_problem(vec![]);
+    two_types.fleet.vehicles.push(two_types.fleet.vehicles[0].clone());
+
+    let cases = vec![
+        (create_test_problem(vec![create_delivery("job1", (1.5, 2.), 3, None)]), "integer coordinates"),
+        (create_test_problem(vec![Job { skills: Some(vec![]), ..delivery() }]), "skills"),
+        (
+            create_test_problem(vec![Job { pickups: delivery().deliveries, ..delivery() }]),
+            "one delivery or service task",
+        ),
+        (two_types, "only one vehicle type"),
+    ];
+
+    cases.into_iter().for_each(|(problem, expected)| {
+        let result = write_to_string(&problem);
+
+        assert!(result.err().unwrap().contains(expected));
+    });
+}
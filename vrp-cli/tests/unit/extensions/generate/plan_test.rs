@@ -9,6 +9,7 @@ fn create_empty_job() -> Job {
         services: None,
         priority: None,
         skills: None,
+        position: None,
     }
 }
 
@@ -37,6 +38,7 @@ fn can_generate_bounding_box() {
             create_job_with_location(1., 2.),
         ],
         relations: None,
+        forbidden_arcs: None,
     };
 
     let (Location { lat: min_lat, lng: min_lng }, Location { lat: max_lat, lng: max_lng }) =
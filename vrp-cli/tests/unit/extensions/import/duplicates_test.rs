@@ -0,0 +1,126 @@
+use super::*;
+use crate::extensions::import::import_problem;
+use std::io::BufReader;
+
+fn create_problem(jobs_csv: &str) -> Problem {
+    let vehicles_csv = r"
+ID,LAT,LNG,CAPACITY,TW_START,TW_END,AMOUNT,PROFILE
+vehicle1,52.4664,13.4023,40,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,10,car
+";
+
+    import_problem("csv", Some(vec![BufReader::new(jobs_csv.as_bytes()), BufReader::new(vehicles_csv.as_bytes())]))
+        .unwrap()
+}
+
+fn get_job_ids(problem: &Problem) -> Vec<String> {
+    let mut ids = problem.plan.jobs.iter().map(|job| job.id.clone()).collect::<Vec<_>>();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn can_report_duplicate_locations() {
+    let problem = create_problem(
+        r"
+ID,LAT,LNG,DEMAND,DURATION,TW_START,TW_END
+job1,52.52599,13.45413,-2,5,,
+job2,52.5225,13.4095,1,3,,
+job2,52.52599,13.45413,-1,3,,
+job3,52.52599,13.45413,-3,5,,
+job4,52.5316,13.3884,-3,5,,
+",
+    );
+
+    let duplicates = get_duplicate_locations(&problem);
+
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].location.lat, 52.52599);
+    let mut job_ids = duplicates[0].job_ids.clone();
+    job_ids.sort();
+    assert_eq!(job_ids, vec!["job1", "job2", "job3"]);
+}
+
+#[test]
+fn can_merge_duplicate_locations() {
+    let problem = create_problem(
+        r"
+ID,LAT,LNG,DEMAND,DURATION,TW_START,TW_END
+job1,52.52599,13.45413,-2,5,,
+job2,52.5225,13.4095,1,3,,
+job2,52.52599,13.45413,-1,3,,
+job3,52.52599,13.45413,-3,5,,
+job4,52.52599,13.45413,3,5,,
+job5,52.5316,13.3884,-3,5,,
+",
+    );
+
+    let problem = merge_duplicate_locations(&problem);
+
+    assert_eq!(problem.plan.jobs.len(), 4);
+    let merged = problem.plan.jobs.iter().find(|job| job.id == "job1" || job.id == "job3").unwrap();
+    let mut tags = merged.deliveries.as_ref().unwrap().iter().map(|task| task.tag.clone().unwrap()).collect::<Vec<_>>();
+    tags.sort();
+    assert_eq!(tags, vec!["job1", "job3"]);
+}
+
+#[test]
+fn can_skip_merging_jobs_in_relations() {
+    let mut problem = create_problem(
+        r"
+ID,LAT,LNG,DEMAND,DURATION,TW_START,TW_END
+job1,52.52599,13.45413,-2,5,,
+job2,52.52599,13.45413,-1,3,,
+",
+    );
+    problem.plan.forbidden_arcs = Some(vec![ForbiddenArc { from: "job1".to_string(), to: "job2".to_string() }]);
+
+    let problem = merge_duplicate_locations(&problem);
+
+    assert_eq!(get_job_ids(&problem), vec!["job1", "job2"]);
+}
+
+#[test]
+fn can_split_merged_jobs_by_vehicle_capacity() {
+    let mut problem = create_problem(
+        r"
+ID,LAT,LNG,DEMAND,DURATION,TW_START,TW_END
+job1,52.52599,13.45413,-20,5,,
+job2,52.52599,13.45413,-15,5,,
+job3,52.52599,13.45413,-10,5,,
+job4,52.52599,13.45413,-5,5,,
+",
+    );
+    problem.plan.jobs.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let problem = merge_duplicate_locations(&problem);
+
+    assert_eq!(get_job_ids(&problem), vec!["job1", "job3"]);
+    let merged = problem.plan.jobs.iter().find(|job| job.id == "job1").unwrap();
+    let tags = merged.deliveries.as_ref().unwrap().iter().map(|task| task.tag.clone().unwrap()).collect::<Vec<_>>();
+    assert_eq!(tags, vec!["job1", "job2", "job4"]);
+}
+
+#[test]
+fn can_split_merged_jobs_by_time_windows() {
+    let mut problem = create_problem(
+        r"
+ID,LAT,LNG,DEMAND,DURATION,TW_START,TW_END
+job1,52.52599,13.45413,-1,5,2020-07-04T09:00:00Z,2020-07-04T12:00:00Z
+job2,52.52599,13.45413,-1,5,2020-07-04T14:00:00Z,2020-07-04T18:00:00Z
+job3,52.52599,13.45413,-1,5,2020-07-04T11:00:00Z,2020-07-04T15:00:00Z
+job4,52.52599,13.45413,-1,5,,
+job5,52.52599,13.45413,-1,5,2020-07-04T15:00:00Z,2020-07-04T16:00:00Z
+",
+    );
+    problem.plan.jobs.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let problem = merge_duplicate_locations(&problem);
+
+    assert_eq!(get_job_ids(&problem), vec!["job1", "job2"]);
+    let get_tags = |id: &str| {
+        let job = problem.plan.jobs.iter().find(|job| job.id == id).unwrap();
+        job.deliveries.as_ref().unwrap().iter().map(|task| task.tag.clone().unwrap()).collect::<Vec<_>>()
+    };
+    assert_eq!(get_tags("job1"), vec!["job1", "job3", "job4"]);
+    assert_eq!(get_tags("job2"), vec!["job2", "job5"]);
+}
@@ -0,0 +1,165 @@
+use super::*;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::thread;
+use vrp_pragmatic::format::problem::deserialize_problem;
+
+fn get_problem() -> Problem {
+    let file = File::open("../examples/data/pragmatic/simple.basic.problem.json").expect("cannot read problem");
+    deserialize_problem(BufReader::new(file)).expect("cannot deserialize problem")
+}
+
+/// Creates table response where duration between locations is `from * 100 + to` using their indices
+/// in the list of unique problem locations, distance is ten times bigger.
+fn create_table_response(request_line: &str, locations: &[Location]) -> String {
+    let path = request_line.split_whitespace().nth(1).unwrap();
+    let (path, query) = path.split_at(path.find('?').unwrap());
+    let coordinates = path
+        .rsplit('/')
+        .next()
+        .unwrap()
+        .split(';')
+        .map(|coordinate| {
+            locations
+                .iter()
+                .position(|location| get_osrm_coordinates(std::slice::from_ref(location)) == coordinate)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let get_indices = |name: &str| {
+        query
+            .trim_start_matches('?')
+            .split('&')
+            .find(|param| param.starts_with(name))
+            .unwrap()
+            .trim_start_matches(name)
+            .split(';')
+            .map(|idx| coordinates[idx.parse::<usize>().unwrap()])
+            .collect::<Vec<_>>()
+    };
+    let (sources, destinations) = (get_indices("sources="), get_indices("destinations="));
+
+    let rows = |multiplier: usize| {
+        sources
+            .iter()
+            .map(|from| {
+                let row = destinations.iter().map(|to| ((from * 100 + to) * multiplier).to_string());
+                format!("[{}]", row.collect::<Vec<_>>().join(","))
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+
+    format!(r#"{{"code":"Ok","durations":[{}],"distances":[{}]}}"#, rows(1), rows(10))
+}
+
+fn run_osrm_server(expected_requests: usize) -> (String, thread::JoinHandle<Vec<String>>) {
+    let locations = get_unique_locations(&get_problem());
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+
+    let server = thread::spawn(move || {
+        (0..expected_requests)
+            .map(|_| {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                let body = create_table_response(request_line.as_str(), locations.as_slice());
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+
+                request_line
+            })
+            .collect()
+    });
+
+    (url, server)
+}
+
+fn assert_matrix(matrix: &Matrix, size: usize) {
+    assert_eq!(matrix.profile, "normal_car");
+    assert_eq!(matrix.travel_times.len(), size * size);
+    assert!(matrix.error_codes.is_none());
+    (0..size).flat_map(|from| (0..size).map(move |to| (from, to))).for_each(|(from, to)| {
+        assert_eq!(matrix.travel_times[from * size + to], (from * 100 + to) as i64);
+        assert_eq!(matrix.distances[from * size + to], (from * 100 + to) as i64 * 10);
+    });
+}
+
+#[test]
+fn can_parse_table_response() {
+    let body = r#"{"code":"Ok","durations":[[0,1.6],[null,0]],"distances":[[0,10.2],[null,0]]}"#;
+
+    let (durations, distances) = parse_table_response(body.as_bytes(), 2, 2).unwrap();
+    let (durations, distances, error_codes) = create_matrix_values(durations, distances);
+
+    assert_eq!(durations, vec![0, 2, 0, 0]);
+    assert_eq!(distances, vec![0, 10, 0, 0]);
+    assert_eq!(error_codes, Some(vec![0, 0, 1, 0]));
+}
+
+#[test]
+fn can_detect_invalid_table_response() {
+    let error = r#"{"code":"InvalidQuery","message":"Query string malformed"}"#;
+    let wrong_size = r#"{"code":"Ok","durations":[[0]],"distances":[[0]]}"#;
+    let not_utf8 = &[0xff, 0xfe, 0xfd];
+
+    assert_eq!(
+        parse_table_response(error.as_bytes(), 2, 2),
+        Err("OSRM returned 'InvalidQuery' code: 'Query string malformed'".to_string())
+    );
+    assert_eq!(
+        parse_table_response(wrong_size.as_bytes(), 1, 2),
+        Err("OSRM response has unexpected amount of durations: 1".to_string())
+    );
+    assert!(parse_table_response(not_utf8, 1, 1).unwrap_err().starts_with("cannot parse OSRM response"));
+}
+
+#[test]
+fn can_create_osrm_matrices() {
+    let size = get_unique_locations(&get_problem()).len();
+    let (url, server) = run_osrm_server(1);
+
+    let matrices = create_osrm_matrices(&get_problem(), url.as_str()).unwrap();
+
+    let requests = server.join().unwrap();
+    assert!(requests[0].starts_with("GET /table/v1/car/"));
+    assert!(requests[0].contains("annotations=duration,distance"));
+    assert_eq!(matrices.len(), 1);
+    assert_matrix(&matrices[0], size);
+}
+
+#[test]
+fn can_create_osrm_matrices_using_multiple_table_requests() {
+    let size = get_unique_locations(&get_problem()).len();
+    let blocks = (0..size).step_by(2).count();
+    let (url, server) = run_osrm_server(blocks * blocks);
+
+    let matrices = create_osrm_matrices_with_table_size(&get_problem(), url.as_str(), 4).unwrap();
+
+    let requests = server.join().unwrap();
+    assert!(size > 2);
+    assert_eq!(requests.len(), blocks * blocks);
+    assert!(requests.iter().all(|request| request.split('?').next().unwrap().split(';').count() <= 4));
+    assert_eq!(matrices.len(), 1);
+    assert_matrix(&matrices[0], size);
+}
+
+#[test]
+fn can_return_error_when_server_is_not_available() {
+    let url = {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        format!("http://{}", listener.local_addr().unwrap())
+    };
+
+    let result = create_osrm_matrices(&get_problem(), url.as_str());
+
+    assert!(result.unwrap_err().starts_with("cannot send request to"));
+}
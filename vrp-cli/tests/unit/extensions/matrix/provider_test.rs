@@ -0,0 +1,34 @@
+use super::*;
+
+fn get_locations() -> Vec<Location> {
+    vec![Location::new(52.5, 13.3), Location::new(52.6, 13.4)]
+}
+
+#[test]
+fn can_format_locations_for_osrm() {
+    assert_eq!(format_locations(&get_locations(), "osrm"), Ok("13.3,52.5;13.4,52.6".to_string()));
+}
+
+#[test]
+fn can_format_locations_for_google() {
+    assert_eq!(
+        format_locations(&get_locations(), "google"),
+        Ok("origins=52.5,13.3|52.6,13.4&destinations=52.5,13.3|52.6,13.4".to_string())
+    );
+}
+
+#[test]
+fn can_format_locations_for_here() {
+    let body = format_locations(&get_locations(), "here").unwrap();
+    let body = serde_json::from_str::<serde_json::Value>(body.as_str()).unwrap();
+
+    assert_eq!(body["origins"][1]["lat"], 52.6);
+    assert_eq!(body["origins"][1]["lng"], 13.4);
+    assert_eq!(body["regionDefinition"]["type"], "world");
+    assert_eq!(body["matrixAttributes"][0], "travelTimes");
+}
+
+#[test]
+fn can_return_error_for_unknown_provider() {
+    assert_eq!(format_locations(&get_locations(), "unknown"), Err("unknown routing provider: 'unknown'".to_string()));
+}
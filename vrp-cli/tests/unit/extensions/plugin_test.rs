@@ -0,0 +1,65 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_core::solver::Builder;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+
+const PLUGIN_CODE: i32 = 42;
+
+extern "C" fn forbid_all(_ctx: *const PluginActivityContext) -> bool {
+    false
+}
+
+extern "C" fn check_vehicle_id(ctx: *const PluginActivityContext) -> bool {
+    let ctx = unsafe { &*ctx };
+    let vehicle_id = unsafe { std::slice::from_raw_parts(ctx.vehicle_id, ctx.vehicle_id_len) };
+
+    vehicle_id == b"vehicle_1"
+}
+
+fn get_problem() -> Problem {
+    let file = File::open("../examples/data/pragmatic/simple.basic.problem.json").expect("cannot read problem");
+    BufReader::new(file).read_pragmatic().expect("cannot read pragmatic problem")
+}
+
+fn solve_with_module(module: PluginModule) -> (usize, Vec<i32>) {
+    let mut problem = get_problem();
+    let jobs = problem.jobs.size();
+    Arc::get_mut(&mut problem.constraint).unwrap().add_module(Box::new(PluginConstraintModule::new(module, None)));
+
+    let (solution, _) = Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_max_generations(Some(10))
+        .build()
+        .and_then(|solver| solver.solve())
+        .unwrap();
+
+    (jobs, solution.unassigned.values().cloned().collect())
+}
+
+#[test]
+fn can_use_plugin_hard_activity_constraint() {
+    let (jobs, unassigned) =
+        solve_with_module(PluginModule { code: PLUGIN_CODE, hard_activity: Some(forbid_all), soft_activity: None });
+
+    assert_eq!(unassigned.len(), jobs);
+    assert!(unassigned.contains(&PLUGIN_CODE));
+}
+
+#[test]
+fn can_pass_vehicle_id_to_plugin() {
+    let (_, unassigned) = solve_with_module(PluginModule {
+        code: PLUGIN_CODE,
+        hard_activity: Some(check_vehicle_id),
+        soft_activity: None,
+    });
+
+    assert!(unassigned.is_empty());
+}
+
+#[test]
+fn can_return_error_when_plugin_is_missing() {
+    let result = add_plugin_modules(get_problem(), &["non_existing_plugin.so"]);
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,95 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+
+fn read_problem_and_matrix() -> (Problem, Matrix) {
+    let problem = File::open("../examples/data/pragmatic/simple.basic.problem.json").expect("cannot read problem");
+    let matrix = File::open("../examples/data/pragmatic/simple.basic.matrix.json").expect("cannot read matrix");
+
+    (
+        deserialize_problem(BufReader::new(problem)).ok().unwrap(),
+        deserialize_matrix(BufReader::new(matrix)).ok().unwrap(),
+    )
+}
+
+fn get_job_ids(problem: &Problem) -> Vec<String> {
+    problem.plan.jobs.iter().map(|job| job.id.clone()).collect()
+}
+
+#[test]
+fn can_reduce_problem_by_region() {
+    let (problem, matrix) = read_problem_and_matrix();
+    let method = ReduceMethod::Region { min: Location::new(52.525, 13.45), max: Location::new(52.53, 13.46) };
+
+    let (reduced, matrices) = reduce_problem(&problem, &[matrix], &method).unwrap();
+
+    assert_eq!(get_job_ids(&reduced), vec!["job1".to_string()]);
+    assert_eq!(reduced.fleet.vehicles.len(), problem.fleet.vehicles.len());
+    assert_eq!(matrices.len(), 1);
+    assert_eq!(matrices[0].travel_times, vec![0, 906, 948, 0]);
+    assert_eq!(matrices[0].distances.len(), 4);
+    assert!((reduced, matrices).read_pragmatic().is_ok());
+}
+
+#[test]
+fn can_reduce_problem_by_sampling() {
+    let (problem, matrix) = read_problem_and_matrix();
+    let method = ReduceMethod::Sample { jobs: 2, seed: Some(42) };
+
+    let (reduced, matrices) = reduce_problem(&problem, std::slice::from_ref(&matrix), &method).unwrap();
+    let (same_reduced, _) = reduce_problem(&problem, &[matrix], &method).unwrap();
+
+    assert_eq!(get_job_ids(&reduced).len(), 2);
+    assert_eq!(get_job_ids(&reduced), get_job_ids(&same_reduced));
+    let size = get_unique_locations(&reduced).len();
+    assert_eq!(matrices[0].travel_times.len(), size * size);
+    assert!((reduced, matrices).read_pragmatic().is_ok());
+}
+
+#[test]
+fn can_update_relations_and_forbidden_arcs() {
+    let (mut problem, _) = read_problem_and_matrix();
+    let relation = |jobs: Vec<&str>| Relation {
+        type_field: RelationType::Any,
+        jobs: jobs.into_iter().map(|id| id.to_string()).collect(),
+        vehicle_id: "vehicle_1".to_string(),
+        shift_index: None,
+    };
+    problem.plan.relations = Some(vec![relation(vec!["departure", "job1", "job2"]), relation(vec!["job3"])]);
+    problem.plan.forbidden_arcs = Some(vec![
+        ForbiddenArc { from: "job1".to_string(), to: "job2".to_string() },
+        ForbiddenArc { from: "job2".to_string(), to: "job1".to_string() },
+    ]);
+    let method = ReduceMethod::Region { min: Location::new(52.525, 13.45), max: Location::new(52.53, 13.46) };
+
+    let (reduced, _) = reduce_problem(&problem, &[], &method).unwrap();
+
+    let relations = reduced.plan.relations.expect("no relations");
+    assert_eq!(relations.len(), 1);
+    assert_eq!(relations[0].jobs, vec!["departure".to_string(), "job1".to_string()]);
+    assert!(reduced.plan.forbidden_arcs.is_none());
+}
+
+#[test]
+fn can_return_error_when_no_jobs_left() {
+    let (problem, _) = read_problem_and_matrix();
+    let method = ReduceMethod::Region { min: Location::new(0., 0.), max: Location::new(1., 1.) };
+
+    let result = reduce_problem(&problem, &[], &method);
+
+    assert_eq!(result.err(), Some("no jobs left in reduced problem".to_string()));
+}
+
+#[test]
+fn can_return_error_when_matrix_does_not_match() {
+    let (problem, mut matrix) = read_problem_and_matrix();
+    matrix.travel_times.pop();
+
+    let result = reduce_problem(&problem, &[matrix], &ReduceMethod::Sample { jobs: 1, seed: None });
+
+    assert_eq!(
+        result.err(),
+        Some("routing matrix for profile 'normal_car' does not match problem: expected 4 locations".to_string())
+    );
+}
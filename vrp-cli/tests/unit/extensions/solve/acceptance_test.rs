@@ -0,0 +1,53 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+use vrp_core::solver::Builder;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+
+fn create_config(
+    max_unassigned: Option<usize>,
+    max_cost: Option<f64>,
+    min_utilization: Option<f64>,
+) -> AcceptanceConfig {
+    AcceptanceConfig { max_unassigned, max_cost, min_utilization }
+}
+
+#[test]
+fn can_accept_solution_without_thresholds() {
+    let report = create_acceptance_report(&AcceptanceConfig::default(), 10, 100., 0.1);
+
+    assert!(report.is_accepted());
+}
+
+#[test]
+fn can_accept_solution_within_thresholds() {
+    let report = create_acceptance_report(&create_config(Some(1), Some(100.), Some(0.5)), 1, 100., 0.5);
+
+    assert!(report.is_accepted());
+}
+
+#[test]
+fn can_report_violated_thresholds() {
+    let report = create_acceptance_report(&create_config(Some(0), Some(100.), Some(0.9)), 2, 150., 0.5);
+
+    assert!(!report.is_accepted());
+    assert_eq!(
+        report.violations,
+        vec!["unassigned jobs: 2 > 0".to_string(), "cost: 150 > 100".to_string(), "utilization: 0.5 < 0.9".to_string()]
+    );
+}
+
+#[test]
+fn can_check_acceptance_of_solved_problem() {
+    let file = File::open("../examples/data/pragmatic/simple.basic.problem.json").expect("cannot read problem");
+    let problem = Arc::new(BufReader::new(file).read_pragmatic().expect("cannot create problem"));
+    let (solution, cost) =
+        Builder::default().with_max_generations(Some(10)).with_problem(problem).build().unwrap().solve().unwrap();
+
+    let report = check_acceptance(&create_config(Some(0), None, None), &solution, cost);
+
+    assert!(report.is_accepted());
+    assert_eq!(report.unassigned, 0);
+    assert!(report.utilization > 0. && report.utilization <= 1.);
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+#[test]
+fn can_get_batch_files_sorted_by_name() {
+    let files = get_batch_files(Path::new("../examples/data/scientific/solomon")).unwrap();
+
+    let names = files.iter().map(|path| path.file_name().unwrap().to_str().unwrap()).collect::<Vec<_>>();
+    assert!(!names.is_empty());
+    assert!(names.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert!(names.contains(&"C101.25.txt"));
+}
+
+#[test]
+fn can_return_error_for_unknown_directory() {
+    assert!(get_batch_files(Path::new("../examples/data/unknown")).is_err());
+}
+
+#[test]
+fn can_write_batch_summary() {
+    let results = vec![
+        BatchResult {
+            name: "a.json".to_string(),
+            cost: Some(10.5),
+            tours: Some(2),
+            unassigned: Some(0),
+            elapsed: 1.,
+            error: None,
+        },
+        BatchResult {
+            name: "b.json".to_string(),
+            cost: None,
+            tours: None,
+            unassigned: None,
+            elapsed: 0.,
+            error: Some("cannot read".to_string()),
+        },
+    ];
+    let mut buffer = vec![];
+
+    write_batch_summary(BufWriter::new(&mut buffer), &results).unwrap();
+
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "name,cost,tours,unassigned,elapsed,error\na.json,10.5,2,0,1.0,\nb.json,,,,0.0,cannot read\n"
+    );
+}
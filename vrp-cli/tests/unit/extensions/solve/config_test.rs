@@ -9,8 +9,71 @@ fn can_read_config() {
 
     assert!(config.population.is_some());
     assert!(config.termination.is_some());
+    assert_eq!(config.random.and_then(|random| random.seed), Some(42));
 
     let MutationConfig::RuinRecreate { ruins, recreates } = config.mutation.expect("cannot get mutation");
     assert_eq!(ruins.len(), 7);
-    assert_eq!(recreates.len(), 6);
+    assert_eq!(recreates.len(), 9);
+}
+
+#[test]
+fn can_read_preset_from_config() {
+    let config = read_config(BufReader::new(r#"{ "preset": "thorough" }"#.as_bytes())).unwrap();
+
+    assert_eq!(config.preset, Some(Preset::Thorough));
+    assert!(config.population.is_none());
+}
+
+#[test]
+fn can_parse_preset_name() {
+    assert_eq!("quick".parse::<Preset>(), Ok(Preset::Quick));
+    assert_eq!("normal".parse::<Preset>(), Ok(Preset::Normal));
+    assert_eq!("thorough".parse::<Preset>(), Ok(Preset::Thorough));
+    assert!("unknown".parse::<Preset>().is_err());
+}
+
+#[test]
+fn can_read_script_from_config() {
+    let config = read_config(BufReader::new(r#"{ "script": { "path": "rules.rhai" } }"#.as_bytes())).unwrap();
+
+    assert_eq!(config.script().map(|script| script.path.as_str()), Some("rules.rhai"));
+}
+
+#[test]
+fn can_create_builder_with_random_seed() {
+    let config = read_config(BufReader::new(r#"{ "random": { "seed": 7 } }"#.as_bytes())).unwrap();
+
+    assert_eq!(config.random.as_ref().and_then(|random| random.seed), Some(7));
+    assert!(create_builder_from_config(&config).is_ok());
+}
+
+#[test]
+fn can_create_mutation_from_preset() {
+    assert!(create_mutation_from_preset(&Preset::Quick).is_some());
+    assert!(create_mutation_from_preset(&Preset::Normal).is_none());
+    assert!(create_mutation_from_preset(&Preset::Thorough).is_some());
+
+    vec![Preset::Quick, Preset::Normal, Preset::Thorough].iter().for_each(|preset| {
+        let config = Config { preset: Some(preset.clone()), ..Config::default() };
+        assert!(create_builder_from_config(&config).is_ok());
+    });
+}
+
+#[test]
+fn can_read_cheapest_recreate_with_cache_from_config() {
+    let config = r#"{ "mutation": { "type": "ruin-recreate", "ruins": [], "recreates": [
+        { "type": "cheapest", "weight": 100 }, { "type": "cheapest", "weight": 10, "cache_size": 1000 }
+    ]}}"#;
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+
+    let MutationConfig::RuinRecreate { recreates, .. } = config.mutation.as_ref().expect("cannot get mutation");
+    let cache_sizes = recreates
+        .iter()
+        .map(|recreate| match recreate {
+            RecreateMethod::Cheapest { cache_size, .. } => *cache_size,
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(cache_sizes, vec![None, Some(1000)]);
+    assert!(create_builder_from_config(&config).is_ok());
 }
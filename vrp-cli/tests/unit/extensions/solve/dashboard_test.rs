@@ -0,0 +1,62 @@
+use super::*;
+
+fn create_event(generation: usize, elapsed: f64) -> ProgressEvent {
+    ProgressEvent {
+        generation,
+        elapsed,
+        speed: 12.345,
+        best_cost: 100.,
+        worst_cost: 125.5,
+        tours: 2,
+        unassigned: 1,
+        ruin_usage: vec![1, 3],
+        recreate_usage: vec![],
+    }
+}
+
+#[test]
+fn can_render_dashboard() {
+    let lines = render_dashboard(&create_event(10, 1.25));
+
+    assert_eq!(
+        lines,
+        vec![
+            "generation:     10 (12.35 gen/sec)",
+            "elapsed:        1.2s",
+            "best cost:      100.00",
+            "worst cost:     125.50",
+            "tours:          2",
+            "unassigned:     1",
+            "ruin usage:     #0 25% #1 75%",
+            "recreate usage: -",
+        ]
+    );
+}
+
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn can_throttle_and_redraw_dashboard() {
+    let buffer = SharedBuffer::default();
+    let hook = create_dashboard_hook(buffer.clone());
+
+    hook(&create_event(1, 0.));
+    hook(&create_event(2, 0.1));
+    hook(&create_event(3, 0.5));
+
+    let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+    assert_eq!(output.matches("generation:").count(), 2);
+    assert!(!output.contains("generation:     2 "));
+    assert_eq!(output.matches("\x1b[8A\x1b[J").count(), 1);
+}
@@ -0,0 +1,55 @@
+use super::*;
+use crate::extensions::solve::config::read_config;
+use crate::extensions::solve::zones::Strategy;
+
+#[test]
+fn can_read_options() {
+    let options = r#"{
+        "maxGenerations": 100, "maxTime": 10, "costVariation": [200, 0.1], "seed": 42,
+        "parallelism": 2, "mode": "quick", "lowMemory": true, "sparseIndex": [1000, 100],
+        "strategy": "cluster-first"
+    }"#;
+
+    let options = read_options(BufReader::new(options.as_bytes())).unwrap();
+
+    assert_eq!(
+        options,
+        SolveOptions {
+            max_generations: Some(100),
+            max_time: Some(10),
+            cost_variation: Some((200, 0.1)),
+            seed: Some(42),
+            parallelism: Some(2),
+            mode: Some(Preset::Quick),
+            low_memory: Some(true),
+            sparse_index: Some((1000, 100)),
+            strategy: Some(Strategy::ClusterFirst),
+        }
+    );
+}
+
+#[test]
+fn can_read_empty_options() {
+    let options = read_options(BufReader::new("{}".as_bytes())).unwrap();
+
+    assert_eq!(options, SolveOptions::default());
+}
+
+#[test]
+fn can_create_builder_from_options() {
+    let config = read_config(BufReader::new(r#"{ "termination": { "max_generations": 10 } }"#.as_bytes())).unwrap();
+    let options =
+        SolveOptions { max_time: Some(1), seed: Some(1), mode: Some(Preset::Quick), ..SolveOptions::default() };
+
+    assert!(create_builder_from_options(Some(&config), &options).is_ok());
+    assert!(create_builder_from_options(None, &options).is_ok());
+}
+
+#[test]
+fn can_reject_zero_parallelism() {
+    let options = SolveOptions { parallelism: Some(0), ..SolveOptions::default() };
+
+    let result = create_builder_from_options(None, &options);
+
+    assert_eq!(result.err(), Some("parallelism should be greater than zero".to_string()));
+}
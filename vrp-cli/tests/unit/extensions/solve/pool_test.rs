@@ -0,0 +1,72 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use std::thread;
+use vrp_core::solver::Solver;
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem};
+
+fn get_problem() -> Problem {
+    let file = File::open("../examples/data/pragmatic/simple.basic.problem.json").expect("cannot read problem");
+    deserialize_problem(BufReader::new(file)).expect("cannot deserialize problem")
+}
+
+fn get_matrix(scale: i64) -> Matrix {
+    let file = File::open("../examples/data/pragmatic/simple.basic.matrix.json").expect("cannot read matrix");
+    let mut matrix = deserialize_matrix(BufReader::new(file)).expect("cannot deserialize matrix");
+    matrix.travel_times.iter_mut().for_each(|value| *value *= scale);
+    matrix.distances.iter_mut().for_each(|value| *value *= scale);
+
+    matrix
+}
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn can_share_solver_between_threads() {
+    assert_send_sync::<Solver>();
+    assert_send_sync::<SolverPool>();
+}
+
+#[test]
+fn can_solve_with_different_matrices_concurrently() {
+    let pool = Arc::new(SolverPool::new(
+        get_problem(),
+        Arc::new(|| Builder::default().with_max_generations(Some(10)).with_max_time(None).with_cost_variation(None)),
+    ));
+
+    let handles = (1..3)
+        .map(|scale| {
+            let pool = pool.clone();
+            thread::spawn(move || pool.solve(vec![get_matrix(scale)]))
+        })
+        .collect::<Vec<_>>();
+
+    let solutions = handles.into_iter().map(|handle| handle.join().unwrap().unwrap()).collect::<Vec<_>>();
+
+    assert_eq!(solutions.len(), 2);
+    assert!(solutions.iter().all(|solution| solution.unassigned.is_empty()));
+    assert!(solutions[0].statistic.distance < solutions[1].statistic.distance);
+}
+
+#[test]
+fn can_reuse_prepared_problem_for_the_same_matrices() {
+    let pool = SolverPool::new(get_problem(), Arc::new(Builder::default));
+
+    let first = pool.prepare(vec![get_matrix(1)]).unwrap().problem();
+    let second = pool.prepare(vec![get_matrix(1)]).unwrap().problem();
+    let other = pool.prepare(vec![get_matrix(2)]).unwrap().problem();
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert!(!Arc::ptr_eq(&first, &other));
+}
+
+#[test]
+fn can_evict_least_recently_prepared_problem() {
+    let pool = SolverPool::new_with_cache_size(get_problem(), Arc::new(Builder::default), 1);
+
+    let first = pool.prepare(vec![get_matrix(1)]).unwrap().problem();
+    pool.prepare(vec![get_matrix(2)]).unwrap();
+    let second = pool.prepare(vec![get_matrix(1)]).unwrap().problem();
+
+    assert!(!Arc::ptr_eq(&first, &second));
+}
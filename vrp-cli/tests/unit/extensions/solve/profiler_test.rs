@@ -0,0 +1,55 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_core::solver::Builder;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+
+fn create_profile(name: &str, evaluations: usize, evaluation_time: f64, state_time: f64) -> ConstraintModuleProfile {
+    ConstraintModuleProfile { name: name.to_string(), evaluations, evaluation_time, state_time }
+}
+
+#[test]
+fn can_create_constraint_profile_report() {
+    let profiles = vec![create_profile("Slow", 10, 0.006, 0.002), create_profile("Fast", 5, 0.001, 0.001)];
+
+    let report = create_constraint_profile_report(profiles.as_slice());
+
+    assert_eq!(
+        report,
+        vec![
+            "constraint profile: #1 Slow, evaluations: 10, evaluation time: 6.000ms, state time: 2.000ms, share: 80.0%",
+            "constraint profile: #2 Fast, evaluations: 5, evaluation time: 1.000ms, state time: 1.000ms, share: 20.0%",
+        ]
+    );
+}
+
+#[test]
+fn can_create_constraint_profile_report_without_time() {
+    let report = create_constraint_profile_report(&[create_profile("Module", 0, 0., 0.)]);
+
+    assert_eq!(
+        report,
+        vec![
+            "constraint profile: #1 Module, evaluations: 0, evaluation time: 0.000ms, state time: 0.000ms, share: 0.0%"
+        ]
+    );
+}
+
+#[test]
+fn can_profile_constraints_during_search() {
+    let file = File::open("../examples/data/pragmatic/simple.basic.problem.json").expect("cannot read problem");
+    let problem = BufReader::new(file).read_pragmatic().expect("cannot read pragmatic problem");
+    let (problem, profiler) = enable_constraint_profiling(problem).unwrap();
+
+    Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_max_generations(Some(10))
+        .build()
+        .and_then(|solver| solver.solve())
+        .unwrap();
+
+    let profiles = profiler.get_profiles();
+    assert!(!profiles.is_empty());
+    assert!(profiles.iter().any(|profile| profile.evaluations > 0));
+    assert!(profiles.windows(2).all(|pair| pair[0].total_time() >= pair[1].total_time()));
+}
@@ -0,0 +1,52 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::solution::{deserialize_solution, UnassignedJobReason};
+
+fn read_simple_solution() -> Solution {
+    let solution = File::open("../examples/data/pragmatic/simple.basic.solution.json").expect("cannot read solution");
+
+    deserialize_solution(BufReader::new(solution)).ok().unwrap()
+}
+
+#[test]
+fn can_create_route_summary() {
+    let mut solution = read_simple_solution();
+    solution.unassigned = vec![UnassignedJob {
+        job_id: "job4".to_string(),
+        reasons: vec![
+            UnassignedJobReason { code: 1, description: "cannot serve required skill".to_string() },
+            UnassignedJobReason { code: 2, description: "cannot be visited within time window".to_string() },
+        ],
+    }];
+
+    let rows = create_route_summary(&solution);
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].kind, "route");
+    assert_eq!(rows[0].vehicle_id, Some("vehicle_1".to_string()));
+    assert_eq!(rows[0].shift_index, Some(0));
+    assert_eq!(rows[0].stops, Some(3));
+    assert_eq!(rows[0].distance, Some(11357));
+    assert_eq!(rows[0].duration, Some(2277));
+    assert_eq!(rows[0].load_peak, Some("2".to_string()));
+    assert_eq!(rows[0].job_id, None);
+    assert_eq!(rows[1].kind, "unassigned");
+    assert_eq!(rows[1].vehicle_id, None);
+    assert_eq!(rows[1].job_id, Some("job4".to_string()));
+    assert_eq!(rows[1].reasons, Some("cannot serve required skill;cannot be visited within time window".to_string()));
+}
+
+#[test]
+fn can_write_route_summary() {
+    let solution = read_simple_solution();
+    let mut buffer = vec![];
+
+    write_route_summary(BufWriter::new(&mut buffer), &solution).unwrap();
+
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "kind,vehicle_id,type_id,shift_index,stops,distance,duration,cost,load_peak,job_id,reasons\n\
+         route,vehicle_1,vehicle,0,3,11357,2277,35.214662000000004,2,,\n"
+    );
+}
@@ -0,0 +1,81 @@
+use super::*;
+use std::fs::File;
+use vrp_core::solver::Builder;
+use vrp_pragmatic::format::problem::deserialize_problem;
+
+fn get_problem() -> Problem {
+    let file = File::open("../examples/data/pragmatic/simple.basic.problem.json").expect("cannot read problem");
+    deserialize_problem(BufReader::new(file)).expect("cannot deserialize problem")
+}
+
+fn get_vehicle_ids(problem: &Problem) -> Vec<String> {
+    problem.fleet.vehicles.first().unwrap().vehicle_ids.clone()
+}
+
+fn create_change(type_id: &str, delta: i32) -> FleetChange {
+    FleetChange { type_id: type_id.to_string(), delta }
+}
+
+#[test]
+fn can_read_scenarios() {
+    let scenarios = read_scenarios(BufReader::new(
+        r#"{ "scenarios": [ { "name": "plus one", "changes": [ { "typeId": "vehicle", "delta": 1 } ] } ] }"#.as_bytes(),
+    ))
+    .unwrap();
+
+    assert_eq!(scenarios.scenarios.len(), 1);
+    assert_eq!(scenarios.scenarios[0].name, "plus one");
+    assert_eq!(scenarios.scenarios[0].changes[0].type_id, "vehicle");
+    assert_eq!(scenarios.scenarios[0].changes[0].delta, 1);
+}
+
+#[test]
+fn can_add_vehicles() {
+    let problem = get_problem();
+    let original = get_vehicle_ids(&problem);
+
+    let result = apply_fleet_changes(&problem, &[create_change("vehicle", 2)]).unwrap();
+
+    let ids = get_vehicle_ids(&result);
+    assert_eq!(ids.len(), original.len() + 2);
+    assert_eq!(&ids[..original.len()], original.as_slice());
+    assert_eq!(ids.last().unwrap(), &format!("vehicle_scenario_{}", original.len() + 2));
+}
+
+#[test]
+fn can_remove_vehicles() {
+    let problem = apply_fleet_changes(&get_problem(), &[create_change("vehicle", 2)]).unwrap();
+    let original = get_vehicle_ids(&problem);
+
+    let result = apply_fleet_changes(&problem, &[create_change("vehicle", -1)]).unwrap();
+
+    assert_eq!(get_vehicle_ids(&result), original[..original.len() - 1].to_vec());
+}
+
+#[test]
+fn can_detect_invalid_changes() {
+    let problem = get_problem();
+    let amount = get_vehicle_ids(&problem).len() as i32;
+
+    assert!(apply_fleet_changes(&problem, &[create_change("unknown", 1)]).is_err());
+    assert!(apply_fleet_changes(&problem, &[create_change("vehicle", -amount)]).is_err());
+}
+
+#[test]
+fn can_solve_scenarios() {
+    let problem = get_problem();
+    let scenarios = Scenarios {
+        scenarios: vec![Scenario { name: "extra".to_string(), changes: vec![create_change("vehicle", 1)] }],
+    };
+
+    let report = solve_scenarios(&problem, None, &scenarios, &|| {
+        Builder::default().with_max_generations(Some(10)).with_max_time(None).with_cost_variation(None)
+    })
+    .unwrap();
+
+    assert_eq!(report.baseline.name, "baseline");
+    assert_eq!(report.baseline.marginal_cost, 0.);
+    assert_eq!(report.scenarios.len(), 1);
+    assert_eq!(report.scenarios[0].name, "extra");
+    assert_eq!(report.scenarios[0].marginal_cost, report.scenarios[0].cost - report.baseline.cost);
+}
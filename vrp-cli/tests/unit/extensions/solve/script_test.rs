@@ -0,0 +1,90 @@
+use super::rhai_impl::*;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::{Arc, Mutex};
+use vrp_core::solver::{Builder, Logger};
+use vrp_pragmatic::format::problem::PragmaticProblem;
+
+fn create_logger() -> Logger {
+    Arc::new(|_| {})
+}
+
+fn solve_with_script(script: &str, logger: Logger) -> Result<(), String> {
+    let file = File::open("../examples/data/pragmatic/simple.basic.problem.json").expect("cannot read problem");
+    let problem = BufReader::new(file).read_pragmatic().expect("cannot read pragmatic problem");
+    let problem = add_script_module(problem, script, logger)?;
+
+    Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_max_generations(Some(10))
+        .build()
+        .and_then(|solver| solver.solve())
+        .map(|_| ())
+}
+
+#[test]
+fn can_create_module_with_activity_and_route_penalties() {
+    let module = create_script_module(
+        r#"
+        fn activity_penalty(ctx) { 1.0 }
+        fn route_penalty(ctx) { 2 }
+        "#,
+        create_logger(),
+    )
+    .unwrap();
+
+    assert_eq!(vrp_core::construction::constraints::ConstraintModule::get_constraints(&module).count(), 2);
+}
+
+#[test]
+fn can_detect_invalid_scripts() {
+    assert!(create_script_module("fn activity_penalty(ctx) { 1.0 ", create_logger()).is_err());
+    assert!(create_script_module("fn other(ctx) { 1.0 }", create_logger()).is_err());
+    assert!(create_script_module("fn route_penalty() { 1.0 }", create_logger()).is_err());
+}
+
+#[test]
+fn can_solve_with_script_penalties() {
+    let result = solve_with_script(
+        r#"
+        fn activity_penalty(ctx) {
+            let next = if ctx.next == () { 0.0 } else { ctx.next.tw_start * 0.0 };
+            if ctx.target.location == ctx.prev.location { 10.0 } else { ctx.target.duration * 0.01 + next }
+        }
+        fn route_penalty(ctx) {
+            if ctx.vehicle_id == "vehicle_1" && ctx.jobs > 10 { 100 } else { 0 }
+        }
+        "#,
+        create_logger(),
+    );
+
+    assert!(result.is_ok());
+}
+
+fn assert_disabled_script(script: &str, expected: &str) {
+    let messages = Arc::new(Mutex::new(Vec::<String>::new()));
+    let logger: Logger = {
+        let messages = messages.clone();
+        Arc::new(move |msg| messages.lock().unwrap().push(msg))
+    };
+
+    let result = solve_with_script(script, logger);
+
+    let messages = messages.lock().unwrap();
+    assert!(result.is_ok());
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].starts_with(format!("script is disabled: {}", expected).as_str()));
+}
+
+#[test]
+fn can_disable_script_exceeding_operations_limit() {
+    assert_disabled_script(
+        "fn activity_penalty(ctx) { let x = 0; loop { x += 1; } }",
+        "cannot evaluate script function 'activity_penalty'",
+    );
+}
+
+#[test]
+fn can_disable_script_returning_not_a_number() {
+    assert_disabled_script(r#"fn route_penalty(ctx) { "penalty" }"#, "script function 'route_penalty' returned");
+}
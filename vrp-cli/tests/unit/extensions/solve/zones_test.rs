@@ -0,0 +1,88 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::problem::deserialize_problem;
+
+fn get_problem(vehicle_ids: Vec<&str>, capacity: i32) -> Problem {
+    let file = File::open("../examples/data/pragmatic/simple.basic.problem.json").expect("cannot read problem");
+    let mut problem = deserialize_problem(BufReader::new(file)).expect("cannot deserialize problem");
+
+    let vehicle = problem.fleet.vehicles.first_mut().unwrap();
+    vehicle.vehicle_ids = vehicle_ids.into_iter().map(|id| id.to_string()).collect();
+    vehicle.capacity = vec![capacity];
+
+    problem
+}
+
+fn get_assignments(problem: &Problem) -> Vec<(String, Vec<String>)> {
+    problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter())
+        .map(|relation| (relation.vehicle_id.clone(), relation.jobs.clone()))
+        .collect()
+}
+
+#[test]
+fn can_parse_strategy() {
+    assert_eq!("default".parse::<Strategy>(), Ok(Strategy::Default));
+    assert_eq!("cluster-first".parse::<Strategy>(), Ok(Strategy::ClusterFirst));
+    assert!("unknown".parse::<Strategy>().is_err());
+}
+
+#[test]
+fn can_assign_all_jobs_to_one_vehicle() {
+    let problem = get_problem(vec!["v1", "v2"], 10);
+
+    let assignments = get_assignments(&assign_zones(&problem).unwrap());
+
+    assert_eq!(assignments.len(), 1);
+    assert_eq!(assignments[0].0, "v1");
+    assert_eq!(assignments[0].1.len(), 3);
+}
+
+#[test]
+fn can_split_jobs_by_capacity() {
+    let problem = get_problem(vec!["v1", "v2"], 2);
+
+    let assignments = get_assignments(&assign_zones(&problem).unwrap());
+
+    assert_eq!(
+        assignments.iter().map(|(id, jobs)| (id.as_str(), jobs.len())).collect::<Vec<_>>(),
+        vec![("v1", 2), ("v2", 1)]
+    );
+    assert!(problem.plan.relations.is_none());
+}
+
+#[test]
+fn can_keep_existing_relations() {
+    let mut problem = get_problem(vec!["v1", "v2"], 10);
+    problem.plan.relations = Some(vec![Relation {
+        type_field: RelationType::Any,
+        jobs: vec!["job1".to_string()],
+        vehicle_id: "v1".to_string(),
+        shift_index: None,
+    }]);
+
+    let assignments = get_assignments(&assign_zones(&problem).unwrap());
+
+    assert_eq!(
+        assignments,
+        vec![("v1".to_string(), vec!["job1".to_string()]), ("v2".to_string(), assignments[1].1.clone())]
+    );
+    assert_eq!(assignments[1].1.len(), 2);
+    assert!(!assignments[1].1.contains(&"job1".to_string()));
+}
+
+#[test]
+fn can_skip_jobs_with_missing_skills() {
+    let mut problem = get_problem(vec!["v1"], 10);
+    problem.plan.jobs[0].skills = Some(vec!["unknown".to_string()]);
+
+    let assignments = get_assignments(&assign_zones(&problem).unwrap());
+
+    assert_eq!(assignments.len(), 1);
+    assert_eq!(assignments[0].1.len(), 2);
+    assert!(!assignments[0].1.contains(&problem.plan.jobs[0].id));
+}
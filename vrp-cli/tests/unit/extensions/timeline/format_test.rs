@@ -0,0 +1,40 @@
+use super::*;
+
+#[test]
+fn can_format_time() {
+    // 2020-07-04T13:05:00Z
+    let timestamp = 1593867900.;
+
+    let cases = vec![
+        (None, None, "2020-07-04 13:05"),
+        (Some("en-US"), None, "07/04/2020 01:05 PM"),
+        (Some("de-DE"), Some("+02:00"), "04.07.2020 15:05"),
+        (Some("en-GB"), Some("-05:30"), "04/07/2020 07:35"),
+    ];
+
+    cases.into_iter().for_each(|(locale, timezone, expected)| {
+        assert_eq!(TimeFormat::new(locale, timezone).unwrap().time(timestamp), Ok(expected.to_string()));
+    });
+}
+
+#[test]
+fn can_reject_time_out_of_range() {
+    assert!(TimeFormat::default().time(1e20).is_err());
+}
+
+#[test]
+fn can_format_duration() {
+    let format = TimeFormat::default();
+
+    assert_eq!(format.duration(0.), "0s");
+    assert_eq!(format.duration(59.), "59s");
+    assert_eq!(format.duration(65.), "1m 05s");
+    assert_eq!(format.duration(3900.), "1h 05m");
+}
+
+#[test]
+fn can_reject_invalid_settings() {
+    assert!(TimeFormat::new(Some("xx-XX"), None).is_err());
+    assert!(TimeFormat::new(None, Some("Europe/Berlin")).is_err());
+    assert!(TimeFormat::new(None, Some("+25:00")).is_err());
+}
@@ -0,0 +1,123 @@
+use super::*;
+use std::io::BufReader;
+use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+const PROBLEM: &str = r#"
+{
+  "plan": {
+    "jobs": [
+      {
+        "id": "job1",
+        "deliveries": [
+          {
+            "places": [
+              { "location": { "lat": 1.0, "lng": 0.0 }, "duration": 10.0, "times": [["1970-01-01T00:00:50Z", "1970-01-01T00:10:00Z"]] }
+            ],
+            "demand": [1]
+          }
+        ]
+      }
+    ]
+  },
+  "fleet": {
+    "vehicles": [
+      {
+        "typeId": "vehicle",
+        "vehicleIds": ["v1"],
+        "profile": "car",
+        "costs": { "fixed": 10.0, "distance": 1.0, "time": 1.0 },
+        "shifts": [
+          {
+            "start": { "time": "1970-01-01T00:00:00Z", "location": { "lat": 0.0, "lng": 0.0 } },
+            "breaks": [ { "time": ["1970-01-01T00:00:00Z", "1970-01-01T00:10:00Z"], "duration": 20.0 } ]
+          }
+        ],
+        "capacity": [10]
+      }
+    ],
+    "profiles": [ { "name": "car", "type": "car" } ]
+  }
+}
+"#;
+
+const SOLUTION: &str = r#"
+{
+  "statistic": { "cost": 0.0, "distance": 0, "duration": 0, "times": { "driving": 0, "serving": 0, "waiting": 0, "break": 0 } },
+  "tours": [
+    {
+      "vehicleId": "v1",
+      "typeId": "vehicle",
+      "shiftIndex": 0,
+      "stops": [
+        {
+          "location": { "lat": 0.0, "lng": 0.0 },
+          "time": { "arrival": "1970-01-01T00:00:00Z", "departure": "1970-01-01T00:00:00Z" },
+          "distance": 0,
+          "load": [1],
+          "activities": [ { "jobId": "departure", "type": "departure" } ]
+        },
+        {
+          "location": { "lat": 1.0, "lng": 0.0 },
+          "time": { "arrival": "1970-01-01T00:00:05Z", "departure": "1970-01-01T00:01:20Z" },
+          "distance": 5,
+          "load": [0],
+          "activities": [
+            {
+              "jobId": "job1",
+              "type": "delivery",
+              "location": { "lat": 1.0, "lng": 0.0 },
+              "time": { "start": "1970-01-01T00:00:05Z", "end": "1970-01-01T00:01:00Z" }
+            },
+            {
+              "jobId": "break",
+              "type": "break",
+              "location": { "lat": 1.0, "lng": 0.0 },
+              "time": { "start": "1970-01-01T00:01:00Z", "end": "1970-01-01T00:01:20Z" }
+            }
+          ]
+        },
+        {
+          "location": { "lat": 0.0, "lng": 0.0 },
+          "time": { "arrival": "1970-01-01T00:01:25Z", "departure": "1970-01-01T00:01:25Z" },
+          "distance": 10,
+          "load": [0],
+          "activities": [ { "jobId": "arrival", "type": "arrival" } ]
+        }
+      ],
+      "statistic": { "cost": 0.0, "distance": 0, "duration": 0, "times": { "driving": 0, "serving": 0, "waiting": 0, "break": 0 } }
+    }
+  ],
+  "unassigned": []
+}
+"#;
+
+fn segment(segment_type: &str, job_id: Option<&str>, start: &str, end: &str) -> Segment {
+    Segment {
+        segment_type: segment_type.to_string(),
+        job_id: job_id.map(|job_id| job_id.to_string()),
+        start: format!("1970-01-01T00:{}Z", start),
+        end: format!("1970-01-01T00:{}Z", end),
+    }
+}
+
+#[test]
+fn can_create_timeline_with_waiting_and_break() {
+    let problem = deserialize_problem(BufReader::new(PROBLEM.as_bytes())).ok().unwrap();
+    let solution = deserialize_solution(BufReader::new(SOLUTION.as_bytes())).ok().unwrap();
+
+    let timeline = create_timeline(&problem, &solution).unwrap();
+
+    assert_eq!(timeline.tours.len(), 1);
+    assert_eq!(timeline.tours[0].vehicle_id, "v1");
+    assert_eq!(
+        timeline.tours[0].segments,
+        vec![
+            segment("driving", None, "00:00", "00:05"),
+            segment("waiting", Some("job1"), "00:05", "00:50"),
+            segment("serving", Some("job1"), "00:50", "01:00"),
+            segment("break", Some("break"), "01:00", "01:20"),
+            segment("driving", None, "01:20", "01:25"),
+        ]
+    );
+}
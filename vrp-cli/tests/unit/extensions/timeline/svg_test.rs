@@ -0,0 +1,34 @@
+use super::*;
+
+#[test]
+fn can_write_timeline_as_svg() {
+    let timeline = Timeline {
+        tours: vec![TourTimeline {
+            vehicle_id: "v1".to_string(),
+            shift_index: 0,
+            segments: vec![
+                Segment {
+                    segment_type: "driving".to_string(),
+                    job_id: None,
+                    start: "1970-01-01T00:00:00Z".to_string(),
+                    end: "1970-01-01T00:00:10Z".to_string(),
+                },
+                Segment {
+                    segment_type: "break".to_string(),
+                    job_id: Some("break".to_string()),
+                    start: "1970-01-01T00:00:10Z".to_string(),
+                    end: "1970-01-01T00:00:20Z".to_string(),
+                },
+            ],
+        }],
+    };
+    let mut buffer = String::new();
+
+    write_timeline_svg(unsafe { BufWriter::new(buffer.as_mut_vec()) }, &timeline, &TimeFormat::default()).unwrap();
+
+    assert!(buffer.starts_with("<svg"));
+    assert_eq!(buffer.matches("<rect").count(), 2);
+    assert!(buffer.contains(r##"x="160.00" y="8" width="500.00""##));
+    assert!(buffer.contains(r##"x="660.00" y="8" width="500.00" height="24" fill="#f28e2b""##));
+    assert!(buffer.contains("break break: 1970-01-01 00:00 - 1970-01-01 00:00 (10s)"));
+}
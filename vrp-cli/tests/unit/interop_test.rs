@@ -0,0 +1,445 @@
+use crate::interop::*;
+use std::ffi::{CStr, CString};
+use std::fs::read_to_string;
+use std::os::raw::c_char;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+fn to_owned_string(value: *mut c_char) -> String {
+    assert!(!value.is_null());
+    let result = unsafe { CStr::from_ptr(value) }.to_str().unwrap().to_string();
+    vrp_free_string(value);
+
+    result
+}
+
+fn create_solve_options() -> VrpSolveOptions {
+    let mut options = unsafe { std::mem::zeroed::<VrpSolveOptions>() };
+    vrp_solve_options_init(&mut options, std::mem::size_of::<VrpSolveOptions>());
+
+    options
+}
+
+fn create_problem_handle(problem: &CString) -> *mut ProblemHandle {
+    let mut error = ptr::null_mut();
+    let handle = vrp_problem_create(problem.as_ptr(), ptr::null(), 0, &mut error);
+    assert!(error.is_null());
+
+    handle
+}
+
+fn solve_problem(problem: &CString) -> String {
+    let handle = create_problem_handle(problem);
+    let config = CString::new(r#"{ "termination": { "max_generations": 10 } }"#).unwrap();
+    let mut error = ptr::null_mut();
+
+    let solution = vrp_solve(handle, config.as_ptr(), ptr::null(), &mut error);
+    vrp_problem_destroy(handle);
+
+    assert!(error.is_null());
+    to_owned_string(solution)
+}
+
+#[test]
+fn can_return_owned_solution() {
+    let problem =
+        CString::new(read_to_string("../examples/data/pragmatic/simple.basic.problem.json").unwrap()).unwrap();
+
+    let solution = solve_problem(&problem);
+
+    assert!(solution.contains("\"tours\""));
+}
+
+#[test]
+fn can_return_owned_error() {
+    let problem = CString::new("{}").unwrap();
+    let mut error = ptr::null_mut();
+
+    let locations = vrp_get_routing_locations(problem.as_ptr(), &mut error);
+
+    assert!(locations.is_null());
+    let error = to_owned_string(error);
+    assert!(error.contains(r#""code":2"#));
+    assert!(error.contains(r#""category":"deserialization""#));
+    assert!(error.contains(r#""errors":[{"code":"E0000""#));
+}
+
+#[test]
+fn can_ignore_null_error_and_string() {
+    let locations = vrp_get_routing_locations(ptr::null(), ptr::null_mut());
+
+    assert!(locations.is_null());
+    vrp_free_string(ptr::null_mut());
+}
+
+#[test]
+fn can_solve_problem_handle_multiple_times() {
+    let problem =
+        CString::new(read_to_string("../examples/data/pragmatic/simple.basic.problem.json").unwrap()).unwrap();
+    let config = CString::new(r#"{ "termination": { "max_generations": 10 } }"#).unwrap();
+    let mut error = ptr::null_mut();
+
+    let handle = vrp_problem_create(problem.as_ptr(), ptr::null(), 0, &mut error);
+    assert!(!handle.is_null());
+
+    (0..2).for_each(|seed| {
+        let mut options = create_solve_options();
+        options.has_seed = true;
+        options.seed = seed;
+        let solution = vrp_solve(handle, config.as_ptr(), &options, &mut error);
+
+        assert!(error.is_null());
+        assert!(to_owned_string(solution).contains("\"tours\""));
+    });
+
+    vrp_problem_destroy(handle);
+}
+
+#[test]
+fn can_return_error_when_problem_handle_cannot_be_created() {
+    let problem = CString::new("{}").unwrap();
+    let mut error = ptr::null_mut();
+
+    let handle = vrp_problem_create(problem.as_ptr(), ptr::null(), 0, &mut error);
+
+    assert!(handle.is_null());
+    assert!(to_owned_string(error).contains(r#""category":"deserialization""#));
+}
+
+#[test]
+fn can_get_version() {
+    let version = unsafe { CStr::from_ptr(vrp_get_version()) }.to_str().unwrap();
+
+    assert_eq!(version, env!("CARGO_PKG_VERSION"));
+}
+
+#[test]
+fn can_solve_problem_handle_with_options_struct() {
+    let problem =
+        CString::new(read_to_string("../examples/data/pragmatic/simple.basic.problem.json").unwrap()).unwrap();
+    let config = CString::new(r#"{ "termination": { "max_generations": 100 } }"#).unwrap();
+    let mut error = ptr::null_mut();
+    let handle = create_problem_handle(&problem);
+    let mut options = create_solve_options();
+    options.max_generations = 10;
+    options.has_seed = true;
+
+    let solution = vrp_solve(handle, config.as_ptr(), &options, &mut error);
+
+    assert_eq!(options.version, VRP_SOLVE_OPTIONS_VERSION);
+    assert!(error.is_null());
+    assert!(to_owned_string(solution).contains("\"tours\""));
+    vrp_problem_destroy(handle);
+}
+
+#[test]
+fn can_return_error_for_unsupported_options_version() {
+    let problem =
+        CString::new(read_to_string("../examples/data/pragmatic/simple.basic.problem.json").unwrap()).unwrap();
+    let config = CString::new("{}").unwrap();
+    let mut error = ptr::null_mut();
+    let handle = create_problem_handle(&problem);
+    let mut options = create_solve_options();
+    options.version = VRP_SOLVE_OPTIONS_VERSION + 1;
+
+    let solution = vrp_solve(handle, config.as_ptr(), &options, &mut error);
+
+    assert!(solution.is_null());
+    assert!(to_owned_string(error).contains("unsupported solve options version"));
+    vrp_problem_destroy(handle);
+}
+
+#[test]
+fn can_declare_all_exported_functions_in_header() {
+    let source = read_to_string("src/lib.rs").unwrap();
+    let header = read_to_string("include/vrp.h").unwrap();
+    let is_declared = |name: &str| header.contains(&format!(" {}(", name)) || header.contains(&format!("*{}(", name));
+
+    let names = source
+        .split("#[no_mangle]")
+        .skip(1)
+        .filter_map(|item| item.split("extern \"C\" fn ").nth(1))
+        .filter_map(|item| item.split('(').next())
+        .collect::<Vec<_>>();
+
+    let missing = names.iter().filter(|name| name.starts_with("vrp_") && !is_declared(name)).collect::<Vec<_>>();
+    let legacy = names.iter().filter(|name| !name.starts_with("vrp_") && is_declared(name)).collect::<Vec<_>>();
+
+    assert!(missing.is_empty(), "header is outdated, missing functions: {:?}", missing);
+    assert!(legacy.is_empty(), "header declares legacy functions: {:?}", legacy);
+}
+
+#[test]
+fn can_validate_problem_without_solving() {
+    let problem =
+        CString::new(read_to_string("../examples/data/pragmatic/simple.basic.problem.json").unwrap()).unwrap();
+    let mut error = ptr::null_mut();
+
+    let errors = vrp_validate_pragmatic(problem.as_ptr(), ptr::null(), 0, &mut error);
+
+    assert!(error.is_null());
+    assert_eq!(to_owned_string(errors), "[]");
+}
+
+#[test]
+fn can_return_validation_errors() {
+    let problem = read_to_string("../examples/data/pragmatic/simple.basic.problem.json").unwrap();
+    let mut problem = serde_json::from_str::<serde_json::Value>(&problem).unwrap();
+    let job = problem["plan"]["jobs"][0].clone();
+    problem["plan"]["jobs"].as_array_mut().unwrap().push(job);
+    let problem = CString::new(problem.to_string()).unwrap();
+    let mut error = ptr::null_mut();
+
+    let errors = vrp_validate_pragmatic(problem.as_ptr(), ptr::null(), 0, &mut error);
+
+    assert!(error.is_null());
+    let errors = serde_json::from_str::<serde_json::Value>(&to_owned_string(errors)).unwrap();
+    assert_eq!(errors[0]["code"], "E1100");
+}
+
+#[test]
+fn can_return_deserialization_errors_as_validation_result() {
+    let problem = CString::new("{}").unwrap();
+    let mut error = ptr::null_mut();
+
+    let errors = vrp_validate_pragmatic(problem.as_ptr(), ptr::null(), 0, &mut error);
+
+    assert!(error.is_null());
+    assert!(to_owned_string(errors).contains(r#""code":"E0000""#));
+}
+
+fn check_solution(problem: &CString, solution: &str, error: &mut *mut c_char) -> *mut c_char {
+    let solution = CString::new(solution).unwrap();
+
+    vrp_check_solution(problem.as_ptr(), ptr::null(), 0, solution.as_ptr(), 0., 0, 0., error)
+}
+
+#[test]
+fn can_check_solution() {
+    let problem =
+        CString::new(read_to_string("../examples/data/pragmatic/simple.basic.problem.json").unwrap()).unwrap();
+    let solution = solve_problem(&problem);
+    let mut error = ptr::null_mut();
+
+    let report = check_solution(&problem, &solution, &mut error);
+
+    assert!(error.is_null());
+    let report = serde_json::from_str::<serde_json::Value>(&to_owned_string(report)).unwrap();
+    assert_eq!(report["errors"], serde_json::json!([]));
+}
+
+#[test]
+fn can_report_violations_of_modified_solution() {
+    let problem =
+        CString::new(read_to_string("../examples/data/pragmatic/simple.basic.problem.json").unwrap()).unwrap();
+    let mut solution = serde_json::from_str::<serde_json::Value>(&solve_problem(&problem)).unwrap();
+    solution["tours"] = serde_json::json!([]);
+    let mut error = ptr::null_mut();
+
+    let report = check_solution(&problem, &solution.to_string(), &mut error);
+
+    assert!(error.is_null());
+    let report = serde_json::from_str::<serde_json::Value>(&to_owned_string(report)).unwrap();
+    assert!(!report["errors"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn can_return_error_when_solution_cannot_be_read() {
+    let problem =
+        CString::new(read_to_string("../examples/data/pragmatic/simple.basic.problem.json").unwrap()).unwrap();
+    let mut error = ptr::null_mut();
+
+    let report = check_solution(&problem, "{}", &mut error);
+
+    assert!(report.is_null());
+    assert!(to_owned_string(error).contains(r#""category":"deserialization""#));
+}
+
+thread_local! {
+    static WRITTEN: std::cell::RefCell<Vec<u8>> = std::cell::RefCell::new(vec![]);
+}
+
+extern "C" fn write_chunk(chunk: *const c_char, len: usize) {
+    let chunk = unsafe { std::slice::from_raw_parts(chunk as *const u8, len) };
+    WRITTEN.with(|written| written.borrow_mut().extend_from_slice(chunk));
+}
+
+#[test]
+fn can_write_solution_streamed() {
+    let problem =
+        CString::new(read_to_string("../examples/data/pragmatic/simple.basic.problem.json").unwrap()).unwrap();
+    let config = CString::new(r#"{ "termination": { "max_generations": 10 } }"#).unwrap();
+    let handle = create_problem_handle(&problem);
+    let mut error = ptr::null_mut();
+
+    let is_success = vrp_solve_streamed(handle, config.as_ptr(), ptr::null(), write_chunk, &mut error);
+    vrp_problem_destroy(handle);
+
+    assert!(is_success);
+    assert!(error.is_null());
+    let solution = WRITTEN.with(|written| String::from_utf8(written.borrow().clone()).unwrap());
+    let solution = serde_json::from_str::<serde_json::Value>(&solution).unwrap();
+    assert_eq!(solution["tours"].as_array().map(|tours| tours.is_empty()), Some(false));
+    assert!(solution["statistic"]["cost"].as_f64().unwrap() > 0.);
+}
+
+#[test]
+fn can_return_error_when_streamed_solution_has_no_problem_handle() {
+    let config = CString::new("{}").unwrap();
+    let mut error = ptr::null_mut();
+
+    let is_success = vrp_solve_streamed(ptr::null(), config.as_ptr(), ptr::null(), write_chunk, &mut error);
+
+    assert!(!is_success);
+    assert!(to_owned_string(error).contains(r#""category":"input""#));
+}
+
+fn get_profiles_problem_with_matrices() -> (CString, Vec<CString>) {
+    let read = |name: &str| {
+        CString::new(read_to_string(format!("../examples/data/pragmatic/basics/{}.json", name)).unwrap()).unwrap()
+    };
+
+    (read("profiles.basic.problem"), vec![read("profiles.basic.matrix.car"), read("profiles.basic.matrix.truck")])
+}
+
+#[test]
+fn can_pass_matrices_with_length_by_value() {
+    let (problem, matrices) = get_profiles_problem_with_matrices();
+    let matrices = matrices.iter().map(|matrix| matrix.as_ptr()).collect::<Vec<_>>();
+    let mut error = ptr::null_mut();
+
+    let handle = vrp_problem_create(problem.as_ptr(), matrices.as_ptr(), matrices.len(), &mut error);
+
+    assert!(error.is_null());
+    assert!(!handle.is_null());
+    vrp_problem_destroy(handle);
+}
+
+#[test]
+fn can_report_error_when_one_of_matrices_is_missing() {
+    let (problem, matrices) = get_profiles_problem_with_matrices();
+    let matrices = matrices.iter().map(|matrix| matrix.as_ptr()).collect::<Vec<_>>();
+    let mut error = ptr::null_mut();
+
+    let handle = vrp_problem_create(problem.as_ptr(), matrices.as_ptr(), 1, &mut error);
+
+    assert!(handle.is_null());
+    assert!(!to_owned_string(error).is_empty());
+}
+
+thread_local! {
+    static CALLBACK_RESULT: std::cell::RefCell<Option<bool>> = std::cell::RefCell::new(None);
+}
+
+extern "C" fn on_success(_: *const c_char) {
+    CALLBACK_RESULT.with(|result| *result.borrow_mut() = Some(true));
+}
+
+extern "C" fn on_failure(_: *const InteropError) {
+    CALLBACK_RESULT.with(|result| *result.borrow_mut() = Some(false));
+}
+
+#[test]
+fn can_pass_matrices_to_legacy_function_with_length_by_value() {
+    let (problem, matrices) = get_profiles_problem_with_matrices();
+    let matrices = matrices.iter().map(|matrix| matrix.as_ptr()).collect::<Vec<_>>();
+    let config = CString::new(r#"{ "termination": { "max_generations": 10 } }"#).unwrap();
+
+    solve_pragmatic_with_errors(
+        problem.as_ptr(),
+        matrices.as_ptr(),
+        matrices.len() as i32,
+        config.as_ptr(),
+        ptr::null(),
+        on_success,
+        on_failure,
+    );
+
+    assert_eq!(CALLBACK_RESULT.with(|result| *result.borrow()), Some(true));
+}
+
+#[test]
+fn can_read_options_struct_of_smaller_size() {
+    let mut options = create_solve_options();
+    options.max_generations = 10;
+    options.max_time = 20;
+    // NOTE emulates the caller which knows only fields up to `max_generations`
+    options.struct_size = &options.max_time as *const usize as usize - &options as *const VrpSolveOptions as usize;
+
+    let options = to_solve_options(&read_solve_options(&options).unwrap().unwrap());
+
+    assert_eq!(options.max_generations, Some(10));
+    assert_eq!(options.max_time, None);
+}
+
+#[test]
+fn can_reject_options_struct_with_invalid_size() {
+    let mut options = create_solve_options();
+    options.struct_size = 1;
+
+    assert!(read_solve_options(&options).is_err());
+}
+
+#[test]
+fn can_handle_null_problem_stream() {
+    let chunk = CString::new("{}").unwrap();
+    let mut error = ptr::null_mut();
+
+    vrp_problem_append(ptr::null_mut(), chunk.as_ptr(), 2);
+    vrp_matrix_begin(ptr::null_mut());
+    vrp_matrix_append(ptr::null_mut(), chunk.as_ptr(), 2);
+    vrp_problem_discard(ptr::null_mut());
+    let handle = vrp_problem_end(ptr::null_mut(), &mut error);
+
+    assert!(handle.is_null());
+    assert!(to_owned_string(error).contains("null pointer"));
+}
+
+#[test]
+fn can_solve_streamed_problem() {
+    let problem = read_to_string("../examples/data/pragmatic/simple.basic.problem.json").unwrap();
+    let (first, second) = problem.as_bytes().split_at(problem.len() / 2);
+    let config = CString::new(r#"{ "termination": { "max_generations": 10 } }"#).unwrap();
+    let mut error = ptr::null_mut();
+
+    let stream = vrp_problem_begin();
+    vrp_problem_append(stream, first.as_ptr() as *const c_char, first.len());
+    vrp_problem_append(stream, second.as_ptr() as *const c_char, second.len());
+    let handle = vrp_problem_end(stream, &mut error);
+    let solution = vrp_solve(handle, config.as_ptr(), ptr::null(), &mut error);
+    vrp_problem_destroy(handle);
+
+    assert!(error.is_null());
+    assert!(to_owned_string(solution).contains("\"tours\""));
+}
+
+static PROGRESS_CALLS: AtomicUsize = AtomicUsize::new(0);
+static CANCELLATION: AtomicPtr<CancellationHandle> = AtomicPtr::new(ptr::null_mut());
+
+extern "C" fn cancel_on_progress(_: usize, _: f64, _: usize, _: u64) {
+    PROGRESS_CALLS.fetch_add(1, Ordering::Relaxed);
+    vrp_cancel(CANCELLATION.load(Ordering::Relaxed));
+}
+
+#[test]
+fn can_pass_cancellation_and_progress_in_options_struct() {
+    let problem =
+        CString::new(read_to_string("../examples/data/pragmatic/simple.basic.problem.json").unwrap()).unwrap();
+    let config = CString::new(r#"{ "termination": { "max_generations": 100 } }"#).unwrap();
+    let handle = create_problem_handle(&problem);
+    let cancellation = vrp_cancellation_new();
+    CANCELLATION.store(cancellation, Ordering::Relaxed);
+    let mut options = create_solve_options();
+    options.cancellation = cancellation;
+    options.progress = Some(cancel_on_progress);
+    let mut error = ptr::null_mut();
+
+    let solution = vrp_solve(handle, config.as_ptr(), &options, &mut error);
+    vrp_cancellation_free(cancellation);
+    vrp_problem_destroy(handle);
+
+    assert!(error.is_null());
+    assert!(to_owned_string(solution).contains("\"tours\""));
+    let calls = PROGRESS_CALLS.load(Ordering::Relaxed);
+    assert!(calls > 0 && calls < 100, "unexpected amount of progress calls: {}", calls);
+}
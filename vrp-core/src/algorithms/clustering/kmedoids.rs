@@ -0,0 +1,160 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/clustering/kmedoids_test.rs"]
+mod kmedoids_test;
+
+use super::*;
+use crate::models::common::Profile;
+use crate::models::problem::{get_cost_between_jobs, TransportCost};
+use crate::utils::{compare_floats, Random};
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// Max amount of assignment and update iterations.
+const MAX_ITERATIONS: usize = 100;
+
+/// Splits jobs into at most `k` clusters using capacitated k-medoids algorithm: total demand of
+/// each cluster does not exceed `capacity`. Cost between jobs is a sum of routing distance and
+/// duration for given profile. The first medoid is selected randomly, others are selected as
+/// the most distant jobs from already selected ones.
+pub fn create_kmedoids_clusters<
+    Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static,
+>(
+    jobs: &[Job],
+    k: usize,
+    capacity: Capacity,
+    profile: Profile,
+    transport: &Arc<dyn TransportCost + Send + Sync>,
+    random: &(dyn Random + Send + Sync),
+) -> Clusters {
+    let demands = jobs.iter().map(|job| get_job_demand::<Capacity>(job)).collect::<Vec<_>>();
+    let (candidates, unassigned): (Vec<_>, Vec<_>) = (0..jobs.len()).partition(|&idx| demands[idx] <= capacity);
+    let unassigned = unassigned.into_iter().map(|idx| jobs[idx].clone()).collect::<Vec<_>>();
+
+    let k = k.min(candidates.len());
+    if k == 0 {
+        return Clusters { clusters: vec![], unassigned: jobs.to_vec() };
+    }
+
+    let costs = jobs
+        .iter()
+        .map(|lhs| jobs.iter().map(|rhs| get_cost_between_jobs(profile, transport, lhs, rhs)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let mut medoids = select_initial_medoids(candidates.as_slice(), k, costs.as_slice(), random);
+    let mut assignment = assign_jobs(candidates.as_slice(), medoids.as_slice(), demands.as_slice(), capacity, &costs);
+
+    for _ in 0..MAX_ITERATIONS {
+        let new_medoids = assignment
+            .0
+            .iter()
+            .zip(medoids.iter())
+            .map(|(cluster, &medoid)| get_medoid(cluster.as_slice(), costs.as_slice()).unwrap_or(medoid))
+            .collect::<Vec<_>>();
+
+        if new_medoids == medoids {
+            break;
+        }
+
+        medoids = new_medoids;
+        assignment = assign_jobs(candidates.as_slice(), medoids.as_slice(), demands.as_slice(), capacity, &costs);
+    }
+
+    let (clusters, not_assigned) = assignment;
+
+    Clusters {
+        clusters: clusters
+            .into_iter()
+            .filter(|cluster| !cluster.is_empty())
+            .map(|cluster| cluster.into_iter().map(|idx| jobs[idx].clone()).collect())
+            .collect(),
+        unassigned: unassigned.into_iter().chain(not_assigned.into_iter().map(|idx| jobs[idx].clone())).collect(),
+    }
+}
+
+fn select_initial_medoids(
+    candidates: &[usize],
+    k: usize,
+    costs: &[Vec<f64>],
+    random: &(dyn Random + Send + Sync),
+) -> Vec<usize> {
+    let first = candidates[random.uniform_int(0, candidates.len() as i32 - 1) as usize];
+
+    (1..k).fold(vec![first], |mut medoids, _| {
+        let next = candidates
+            .iter()
+            .filter(|idx| !medoids.contains(idx))
+            .map(|&idx| (idx, medoids.iter().map(|&medoid| costs[medoid][idx]).fold(f64::MAX, f64::min)))
+            .max_by(|(_, a), (_, b)| compare_floats(*a, *b))
+            .map(|(idx, _)| idx)
+            .unwrap();
+
+        medoids.push(next);
+        medoids
+    })
+}
+
+/// Assigns jobs to the closest medoids with enough capacity left. Jobs with the highest regret,
+/// the difference between cost to the second closest and the closest medoid, are assigned first.
+fn assign_jobs<
+    Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static,
+>(
+    candidates: &[usize],
+    medoids: &[usize],
+    demands: &[Capacity],
+    capacity: Capacity,
+    costs: &[Vec<f64>],
+) -> (Vec<Vec<usize>>, Vec<usize>) {
+    let mut clusters = medoids.iter().map(|&medoid| vec![medoid]).collect::<Vec<_>>();
+    let mut loads = medoids.iter().map(|&medoid| demands[medoid]).collect::<Vec<_>>();
+
+    let mut jobs = candidates
+        .iter()
+        .filter(|idx| !medoids.contains(idx))
+        .map(|&idx| {
+            let mut order = (0..medoids.len()).collect::<Vec<_>>();
+            order.sort_by(|&a, &b| compare_floats(costs[medoids[a]][idx], costs[medoids[b]][idx]));
+
+            let regret = match order.as_slice() {
+                [first, second, ..] => costs[medoids[*second]][idx] - costs[medoids[*first]][idx],
+                _ => 0.,
+            };
+
+            (idx, order, regret)
+        })
+        .collect::<Vec<_>>();
+
+    jobs.sort_by(|(a_idx, _, a), (b_idx, _, b)| match compare_floats(*b, *a) {
+        Ordering::Equal => a_idx.cmp(b_idx),
+        order => order,
+    });
+
+    let unassigned = jobs.into_iter().fold(vec![], |mut unassigned, (idx, order, _)| {
+        let cluster = order
+            .into_iter()
+            .find_map(|cluster| try_add_demand(loads[cluster], demands[idx], capacity).map(|load| (cluster, load)));
+
+        match cluster {
+            Some((cluster, load)) => {
+                loads[cluster] = load;
+                clusters[cluster].push(idx);
+            }
+            None => unassigned.push(idx),
+        }
+
+        unassigned
+    });
+
+    (clusters, unassigned)
+}
+
+/// Returns a cluster member with minimal total cost to other members.
+fn get_medoid(cluster: &[usize], costs: &[Vec<f64>]) -> Option<usize> {
+    cluster
+        .iter()
+        .map(|&idx| (idx, cluster.iter().map(|&other| costs[idx][other]).sum::<f64>()))
+        .min_by(|(a_idx, a), (b_idx, b)| match compare_floats(*a, *b) {
+            Ordering::Equal => a_idx.cmp(b_idx),
+            order => order,
+        })
+        .map(|(idx, _)| idx)
+}
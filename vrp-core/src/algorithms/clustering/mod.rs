@@ -0,0 +1,41 @@
+//! Contains capacity-aware job clustering algorithms. They can be used to split jobs into
+//! partitions, e.g. to assign a partition to a vehicle or to solve partitions separately.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/clustering/clustering_test.rs"]
+mod clustering_test;
+
+use crate::construction::constraints::get_job_demand;
+use crate::models::problem::Job;
+use std::ops::{Add, Sub};
+
+mod kmedoids;
+pub use self::kmedoids::create_kmedoids_clusters;
+
+mod sweep;
+pub use self::sweep::create_sweep_clusters;
+
+/// Represents a result of job clustering.
+pub struct Clusters {
+    /// Job clusters. Total demand of each cluster does not exceed capacity.
+    pub clusters: Vec<Vec<Job>>,
+    /// Jobs which cannot be assigned to any cluster without exceeding capacity.
+    pub unassigned: Vec<Job>,
+}
+
+/// Checks whether given demand fits into capacity and returns a new total demand.
+fn try_add_demand<
+    Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static,
+>(
+    current: Capacity,
+    demand: Capacity,
+    capacity: Capacity,
+) -> Option<Capacity> {
+    let total = current + demand;
+
+    if total > capacity {
+        None
+    } else {
+        Some(total)
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/clustering/sweep_test.rs"]
+mod sweep_test;
+
+use super::*;
+use crate::utils::compare_floats;
+use std::f64::consts::PI;
+
+/// Splits jobs into clusters using sweep algorithm: jobs are ordered by polar angle around the
+/// depot and consecutive jobs are grouped while their total demand does not exceed `capacity`.
+/// As core models have no coordinates, they are provided by `get_coordinate` function. Sweeping
+/// starts after the largest angular gap between jobs, so it does not split a dense area.
+pub fn create_sweep_clusters<
+    Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static,
+>(
+    jobs: &[Job],
+    depot: (f64, f64),
+    capacity: Capacity,
+    get_coordinate: &dyn Fn(&Job) -> Option<(f64, f64)>,
+) -> Clusters {
+    let (mut angles, unassigned) = jobs.iter().fold((vec![], vec![]), |(mut angles, mut unassigned), job| {
+        let demand = get_job_demand::<Capacity>(job);
+
+        match get_coordinate(job) {
+            Some((x, y)) if demand <= capacity => angles.push(((y - depot.1).atan2(x - depot.0), demand, job)),
+            _ => unassigned.push(job.clone()),
+        }
+
+        (angles, unassigned)
+    });
+
+    angles.sort_by(|(a, _, _), (b, _, _)| compare_floats(*a, *b));
+
+    let start = (0..angles.len())
+        .map(|idx| {
+            let next = angles[(idx + 1) % angles.len()].0;
+            let gap = next - angles[idx].0;
+            (idx, if gap > 0. { gap } else { gap + 2. * PI })
+        })
+        .max_by(|(_, a), (_, b)| compare_floats(*a, *b))
+        .map_or(0, |(idx, _)| (idx + 1) % angles.len());
+
+    angles.rotate_left(start);
+
+    let clusters = angles
+        .into_iter()
+        .fold(Vec::<(Capacity, Vec<Job>)>::new(), |mut clusters, (_, demand, job)| {
+            match clusters.last_mut().and_then(|(load, cluster)| {
+                try_add_demand(*load, demand, capacity).map(|new_load| (load, cluster, new_load))
+            }) {
+                Some((load, cluster, new_load)) => {
+                    *load = new_load;
+                    cluster.push(job.clone());
+                }
+                None => clusters.push((demand, vec![job.clone()])),
+            }
+
+            clusters
+        })
+        .into_iter()
+        .map(|(_, cluster)| cluster)
+        .collect();
+
+    Clusters { clusters, unassigned }
+}
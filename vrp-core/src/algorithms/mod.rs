@@ -0,0 +1,4 @@
+//! Contains standalone algorithms which can be used for problem pre- or post-analysis
+//! independently from the solver.
+
+pub mod clustering;
@@ -6,6 +6,7 @@ use crate::construction::constraints::*;
 use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
 use crate::models::common::Location;
 use crate::models::problem::{Actor, Job, Single};
+use crate::utils::geo::is_point_in_polygon;
 use std::ops::Deref;
 use std::slice::Iter;
 use std::sync::Arc;
@@ -55,6 +56,10 @@ impl ConstraintModule for AreaModule {
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn is_cacheable(&self) -> bool {
+        true
+    }
 }
 
 struct AreaHardRouteConstraint {
@@ -96,7 +101,7 @@ impl HardActivityConstraint for AreaHardActivityConstraint {
     ) -> Option<ActivityConstraintViolation> {
         if let Some(areas) = self.area_resolver.deref()(&route_ctx.route.actor) {
             let location = self.location_resolver.deref()(activity_ctx.target.place.location);
-            let can_serve = areas.iter().any(|area| is_location_in_area(&location, area));
+            let can_serve = areas.iter().any(|area| is_point_in_polygon(&location, area.as_slice()));
 
             if !can_serve {
                 // NOTE do not stop job insertion evaluation if it has multiple locations
@@ -120,29 +125,5 @@ fn can_serve_job_in_area(job: &Single, areas: &[Vec<(f64, f64)>], location_resol
         .iter()
         .filter_map(|place| place.location.clone())
         .map(|location| location_resolver.deref()(location))
-        .any(|location| areas.iter().any(|area| is_location_in_area(&location, area)))
-}
-
-/// Checks whether given location is inside area using ray casting algorithm.
-/// Location is interpreted as 2D point, area - as 2D polygon.
-fn is_location_in_area(location: &(f64, f64), area: &Vec<(f64, f64)>) -> bool {
-    let &(x, y) = location;
-
-    let mut is_inside = false;
-    let mut i = 0;
-    let mut j = area.len() - 1;
-
-    while i < area.len() {
-        let &(ix, iy) = area.get(i).unwrap();
-        let &(jx, jy) = area.get(j).unwrap();
-
-        if ((ix > x) != (jx > x)) && (y < (jy - iy) * (x - ix) / (jx - ix) + iy) {
-            is_inside = !is_inside;
-        }
-
-        j = i;
-        i += 1;
-    }
-
-    is_inside
+        .any(|location| areas.iter().any(|area| is_point_in_polygon(&location, area.as_slice())))
 }
@@ -39,6 +39,24 @@ pub trait DemandDimension<Capacity: Add + Sub + Ord + Copy + Default + Send + Sy
     fn get_demand(&self) -> Option<&Demand<Capacity>>;
 }
 
+/// Returns total job demand: a sum of static demand and dynamic pickup of all job parts. Dynamic
+/// delivery is skipped as it is the same load as dynamic pickup.
+pub(crate) fn get_job_demand<Capacity>(job: &Job) -> Capacity
+where
+    Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static,
+{
+    let get_single_demand = |single: &Single| {
+        single.dimens.get_demand().map_or(Capacity::default(), |demand: &Demand<Capacity>| {
+            demand.pickup.0 + demand.delivery.0 + demand.pickup.1
+        })
+    };
+
+    match job {
+        Job::Single(single) => get_single_demand(single),
+        Job::Multi(multi) => multi.jobs.iter().fold(Capacity::default(), |acc, single| acc + get_single_demand(single)),
+    }
+}
+
 /// Returns intervals between vehicle terminal and reload activities.
 pub fn route_intervals(route: &Route, is_reload: Box<dyn Fn(&TourActivity) -> bool + 'static>) -> Vec<(usize, usize)> {
     let last_idx = route.tour.total() - 1;
@@ -358,6 +376,10 @@ impl<Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + De
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn is_cacheable(&self) -> bool {
+        true
+    }
 }
 
 struct CapacitySoftRouteConstraint<Capacity: Add + Sub + Ord + Copy + Default + Send + Sync + 'static> {
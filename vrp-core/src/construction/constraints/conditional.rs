@@ -86,14 +86,16 @@ impl ConstraintModule for ConditionalJobModule {
 
     fn accept_solution_state(&self, ctx: &mut SolutionContext) {
         // analyzed required/ignored
-        let ignored: HashSet<Job> =
-            ctx.required.iter().filter(|job| self.context_transition.remove_from_required(ctx, job)).cloned().collect();
-        ctx.required.retain(|job| !ignored.contains(job));
+        let is_ignored =
+            ctx.required.iter().map(|job| self.context_transition.remove_from_required(ctx, job)).collect::<Vec<_>>();
+        let (ignored, required) = split_jobs(std::mem::take(&mut ctx.required), is_ignored);
+        ctx.required = required;
 
         // identify required inside ignored
-        let required: HashSet<Job> =
-            ctx.ignored.iter().filter(|job| self.context_transition.promote_to_required(ctx, job)).cloned().collect();
-        ctx.ignored.retain(|job| !required.contains(job));
+        let is_required =
+            ctx.ignored.iter().map(|job| self.context_transition.promote_to_required(ctx, job)).collect::<Vec<_>>();
+        let (required, not_required) = split_jobs(std::mem::take(&mut ctx.ignored), is_required);
+        ctx.ignored = not_required;
 
         ctx.required.extend(required);
         ctx.ignored.extend(ignored);
@@ -121,4 +123,16 @@ impl ConstraintModule for ConditionalJobModule {
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn is_cacheable(&self) -> bool {
+        true
+    }
+}
+
+/// Splits jobs into ones with and without flag set keeping their original order, so seeded runs
+/// are reproducible.
+fn split_jobs(jobs: Vec<Job>, flags: Vec<bool>) -> (Vec<Job>, Vec<Job>) {
+    let (flagged, other): (Vec<_>, Vec<_>) = jobs.into_iter().zip(flags).partition(|(_, flag)| *flag);
+
+    (flagged.into_iter().map(|(job, _)| job).collect(), other.into_iter().map(|(job, _)| job).collect())
 }
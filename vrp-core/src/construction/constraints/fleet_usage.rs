@@ -25,6 +25,10 @@ impl ConstraintModule for FleetUsageConstraintModule {
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn is_cacheable(&self) -> bool {
+        true
+    }
 }
 
 impl FleetUsageConstraintModule {
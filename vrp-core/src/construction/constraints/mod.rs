@@ -68,6 +68,9 @@ pub const RELOAD_INTERVALS_KEY: i32 = 14;
 mod pipeline;
 pub use self::pipeline::*;
 
+mod profiler;
+pub use self::profiler::*;
+
 mod area;
 pub use self::area::*;
 
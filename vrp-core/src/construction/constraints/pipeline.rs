@@ -2,8 +2,9 @@
 #[path = "../../../tests/unit/construction/constraints/pipeline_test.rs"]
 mod pipeline_test;
 
+use super::ConstraintProfiler;
 use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
-use crate::models::common::Cost;
+use crate::models::common::{saturating_sum, Cost};
 use crate::models::problem::Job;
 use hashbrown::HashSet;
 use std::slice::Iter;
@@ -93,16 +94,30 @@ pub trait ConstraintModule {
 
     /// Returns list of constraints to be used.
     fn get_constraints(&self) -> Iter<ConstraintVariant>;
+
+    /// Returns module name used for diagnostic purpose, e.g. by constraint profiler.
+    /// Default is type name.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Returns true if results of module constraints depend only on the job and the route, so they
+    /// can be reused while the route is not changed, e.g. by insertion cache. Modules which use the
+    /// state of the whole solution should return false. Default is false.
+    fn is_cacheable(&self) -> bool {
+        false
+    }
 }
 
 /// Provides the way to work with multiple constraints.
 pub struct ConstraintPipeline {
     modules: Vec<Box<dyn ConstraintModule + Send + Sync>>,
     state_keys: HashSet<i32>,
-    hard_route_constraints: Vec<Arc<dyn HardRouteConstraint + Send + Sync>>,
-    hard_activity_constraints: Vec<Arc<dyn HardActivityConstraint + Send + Sync>>,
-    soft_route_constraints: Vec<Arc<dyn SoftRouteConstraint + Send + Sync>>,
-    soft_activity_constraints: Vec<Arc<dyn SoftActivityConstraint + Send + Sync>>,
+    hard_route_constraints: Vec<(usize, Arc<dyn HardRouteConstraint + Send + Sync>)>,
+    hard_activity_constraints: Vec<(usize, Arc<dyn HardActivityConstraint + Send + Sync>)>,
+    soft_route_constraints: Vec<(usize, Arc<dyn SoftRouteConstraint + Send + Sync>)>,
+    soft_activity_constraints: Vec<(usize, Arc<dyn SoftActivityConstraint + Send + Sync>)>,
+    profiler: Option<Arc<ConstraintProfiler>>,
 }
 
 impl Default for ConstraintPipeline {
@@ -114,6 +129,7 @@ impl Default for ConstraintPipeline {
             hard_activity_constraints: vec![],
             soft_route_constraints: vec![],
             soft_activity_constraints: vec![],
+            profiler: None,
         }
     }
 }
@@ -121,17 +137,35 @@ impl Default for ConstraintPipeline {
 impl ConstraintPipeline {
     /// Accepts job insertion.
     pub fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_ctx: &mut RouteContext, job: &Job) {
-        self.modules.iter().for_each(|c| c.accept_insertion(solution_ctx, route_ctx, job))
+        self.modules
+            .iter()
+            .enumerate()
+            .for_each(|(idx, c)| self.profile_state(idx, || c.accept_insertion(solution_ctx, route_ctx, job)))
     }
 
     /// Accepts route state.
     pub fn accept_route_state(&self, ctx: &mut RouteContext) {
-        self.modules.iter().for_each(|c| c.accept_route_state(ctx))
+        self.modules.iter().enumerate().for_each(|(idx, c)| self.profile_state(idx, || c.accept_route_state(ctx)))
     }
 
     /// Accepts solution state.
     pub fn accept_solution_state(&self, ctx: &mut SolutionContext) {
-        self.modules.iter().for_each(|c| c.accept_solution_state(ctx))
+        self.modules.iter().enumerate().for_each(|(idx, c)| self.profile_state(idx, || c.accept_solution_state(ctx)))
+    }
+
+    /// Enables profiling of constraint modules which are already added to the pipeline and returns
+    /// profiler which collects results. Profiling adds noticeable overhead to constraint evaluations.
+    pub fn enable_profiling(&mut self) -> Arc<ConstraintProfiler> {
+        let profiler =
+            Arc::new(ConstraintProfiler::new(self.modules.iter().map(|module| module.name().to_string()).collect()));
+        self.profiler = Some(profiler.clone());
+
+        profiler
+    }
+
+    /// Returns true if all modules are cacheable, see [`ConstraintModule::is_cacheable`].
+    pub fn is_cacheable(&self) -> bool {
+        self.modules.iter().all(|module| module.is_cacheable())
     }
 
     /// Adds constraint module.
@@ -143,11 +177,12 @@ impl ConstraintPipeline {
             self.state_keys.insert(key.clone());
         });
 
+        let idx = self.modules.len();
         module.get_constraints().for_each(|c| match c {
-            ConstraintVariant::HardRoute(c) => self.hard_route_constraints.push(c.clone()),
-            ConstraintVariant::HardActivity(c) => self.hard_activity_constraints.push(c.clone()),
-            ConstraintVariant::SoftRoute(c) => self.soft_route_constraints.push(c.clone()),
-            ConstraintVariant::SoftActivity(c) => self.soft_activity_constraints.push(c.clone()),
+            ConstraintVariant::HardRoute(c) => self.hard_route_constraints.push((idx, c.clone())),
+            ConstraintVariant::HardActivity(c) => self.hard_activity_constraints.push((idx, c.clone())),
+            ConstraintVariant::SoftRoute(c) => self.soft_route_constraints.push((idx, c.clone())),
+            ConstraintVariant::SoftActivity(c) => self.soft_activity_constraints.push((idx, c.clone())),
         });
 
         self.modules.push(module);
@@ -163,7 +198,9 @@ impl ConstraintPipeline {
         route_ctx: &RouteContext,
         job: &Job,
     ) -> Option<RouteConstraintViolation> {
-        self.hard_route_constraints.iter().find_map(|c| c.evaluate_job(solution_ctx, route_ctx, job))
+        self.hard_route_constraints
+            .iter()
+            .find_map(|(idx, c)| self.profile_evaluation(*idx, || c.evaluate_job(solution_ctx, route_ctx, job)))
     }
 
     /// Checks whether all activity route constraints are fulfilled.
@@ -173,17 +210,51 @@ impl ConstraintPipeline {
         route_ctx: &RouteContext,
         activity_ctx: &ActivityContext,
     ) -> Option<ActivityConstraintViolation> {
-        self.hard_activity_constraints.iter().find_map(|c| c.evaluate_activity(route_ctx, activity_ctx))
+        self.hard_activity_constraints
+            .iter()
+            .find_map(|(idx, c)| self.profile_evaluation(*idx, || c.evaluate_activity(route_ctx, activity_ctx)))
     }
 
     /// Checks soft route constraints and aggregates associated actual and penalty costs.
     pub fn evaluate_soft_route(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> Cost {
-        self.soft_route_constraints.iter().map(|c| c.estimate_job(solution_ctx, route_ctx, job)).sum()
+        saturating_sum(
+            self.soft_route_constraints
+                .iter()
+                .map(|(idx, c)| self.profile_evaluation(*idx, || c.estimate_job(solution_ctx, route_ctx, job))),
+        )
     }
 
     /// Checks soft route constraints and aggregates associated actual and penalty costs.
     pub fn evaluate_soft_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
-        self.soft_activity_constraints.iter().map(|c| c.estimate_activity(route_ctx, activity_ctx)).sum()
+        saturating_sum(
+            self.soft_activity_constraints
+                .iter()
+                .map(|(idx, c)| self.profile_evaluation(*idx, || c.estimate_activity(route_ctx, activity_ctx))),
+        )
+    }
+
+    fn profile_evaluation<T, F: FnOnce() -> T>(&self, module_idx: usize, evaluate: F) -> T {
+        match self.profiler.as_ref() {
+            Some(profiler) => {
+                let timer = profiler.start_timer();
+                let result = evaluate();
+                profiler.record_evaluation(module_idx, timer.elapsed_nanos());
+
+                result
+            }
+            None => evaluate(),
+        }
+    }
+
+    fn profile_state<F: FnOnce()>(&self, module_idx: usize, accept: F) {
+        match self.profiler.as_ref() {
+            Some(profiler) => {
+                let timer = profiler.start_timer();
+                accept();
+                profiler.record_state(module_idx, timer.elapsed_nanos());
+            }
+            None => accept(),
+        }
     }
 }
 
@@ -0,0 +1,116 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/profiler_test.rs"]
+mod profiler_test;
+
+use crate::utils::{Clock, DefaultClock, Timer};
+use std::cmp::Ordering as CmpOrdering;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Collects amount of evaluations and time spent per constraint module.
+/// See `ConstraintPipeline::enable_profiling`.
+pub struct ConstraintProfiler {
+    modules: Vec<ModuleCounters>,
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+/// Specifies profiling results of one constraint module.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstraintModuleProfile {
+    /// A module name.
+    pub name: String,
+    /// Amount of constraint evaluations.
+    pub evaluations: usize,
+    /// Time spent on constraint evaluations in seconds.
+    pub evaluation_time: f64,
+    /// Time spent on route and solution state updates in seconds.
+    pub state_time: f64,
+}
+
+impl ConstraintModuleProfile {
+    /// Returns total time spent by the module in seconds.
+    pub fn total_time(&self) -> f64 {
+        self.evaluation_time + self.state_time
+    }
+}
+
+struct ModuleCounters {
+    name: String,
+    evaluations: AtomicUsize,
+    evaluation_nanos: AtomicU64,
+    state_nanos: AtomicU64,
+}
+
+impl ConstraintProfiler {
+    /// Creates a new instance of `ConstraintProfiler` for modules with given names.
+    pub fn new(names: Vec<String>) -> Self {
+        Self {
+            modules: names
+                .into_iter()
+                .map(|name| ModuleCounters {
+                    name: get_short_type_name(name.as_str()),
+                    evaluations: AtomicUsize::new(0),
+                    evaluation_nanos: AtomicU64::new(0),
+                    state_nanos: AtomicU64::new(0),
+                })
+                .collect(),
+            clock: Arc::new(DefaultClock::default()),
+        }
+    }
+
+    /// Returns module profiles ranked by total time spent, the slowest first.
+    pub fn get_profiles(&self) -> Vec<ConstraintModuleProfile> {
+        let mut profiles = self
+            .modules
+            .iter()
+            .map(|module| ConstraintModuleProfile {
+                name: module.name.clone(),
+                evaluations: module.evaluations.load(Ordering::Relaxed),
+                evaluation_time: module.evaluation_nanos.load(Ordering::Relaxed) as f64 / 1E9,
+                state_time: module.state_nanos.load(Ordering::Relaxed) as f64 / 1E9,
+            })
+            .collect::<Vec<_>>();
+
+        profiles.sort_by(|a, b| b.total_time().partial_cmp(&a.total_time()).unwrap_or(CmpOrdering::Equal));
+
+        profiles
+    }
+
+    pub(crate) fn start_timer(&self) -> Timer {
+        Timer::start_with_clock(self.clock.clone())
+    }
+
+    pub(crate) fn record_evaluation(&self, module_idx: usize, nanos: u128) {
+        if let Some(module) = self.modules.get(module_idx) {
+            module.evaluations.fetch_add(1, Ordering::Relaxed);
+            module.evaluation_nanos.fetch_add(nanos as u64, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn record_state(&self, module_idx: usize, nanos: u128) {
+        if let Some(module) = self.modules.get(module_idx) {
+            module.state_nanos.fetch_add(nanos as u64, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Removes module paths from type name, e.g. `a::b::Module<c::Type>` becomes `Module<Type>`.
+fn get_short_type_name(name: &str) -> String {
+    let is_delimiter = |ch: char| matches!(ch, '<' | '>' | ',' | ' ' | '(' | ')' | '[' | ']' | '&' | ';');
+
+    let (mut result, last) = name.chars().fold((String::new(), String::new()), |(mut result, mut token), ch| {
+        if is_delimiter(ch) {
+            result.push_str(token.rsplit("::").next().unwrap_or_default());
+            result.push(ch);
+            token.clear();
+        } else {
+            token.push(ch);
+        }
+
+        (result, token)
+    });
+
+    result.push_str(last.rsplit("::").next().unwrap_or_default());
+
+    result
+}
@@ -58,6 +58,10 @@ impl ConstraintModule for TransportConstraintModule {
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn is_cacheable(&self) -> bool {
+        true
+    }
 }
 
 impl TransportConstraintModule {
@@ -0,0 +1,126 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/cache_test.rs"]
+mod cache_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::Cost;
+use crate::models::problem::Job;
+use crate::models::solution::{get_route_signature, Activity};
+use hashbrown::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::RwLock;
+
+/// Keeps best insertion results per job and route between insertion steps and generations.
+///
+/// A route is identified by its signature (vehicle and activities), so entries are invalidated
+/// implicitly: once ruin or insertion touches a route, its signature changes and old entries are
+/// not used anymore, while entries of untouched routes are reused. As insertion cost is expected
+/// to depend only on the route itself, the cache is bypassed when constraint pipeline reports that
+/// some of its modules use the state of the whole solution, see [`ConstraintPipeline::is_cacheable`].
+///
+/// [`ConstraintPipeline::is_cacheable`]: ../constraints/struct.ConstraintPipeline.html#method.is_cacheable
+pub struct InsertionCache {
+    capacity: usize,
+    entries: RwLock<HashMap<(Job, u64), CacheEntry>>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+enum CacheEntry {
+    Success { cost: Cost, activities: Vec<(Activity, usize)> },
+    Failure { code: i32 },
+}
+
+impl InsertionCache {
+    /// Creates a new instance of `InsertionCache` which keeps at most `capacity` entries.
+    /// Once capacity is reached, all entries are dropped.
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, entries: RwLock::new(HashMap::new()), hits: AtomicUsize::new(0), misses: AtomicUsize::new(0) }
+    }
+
+    /// Evaluates job insertion in all available routes reusing cached results where possible.
+    /// `routes` are routes within their signatures as returned by [`get_insertion_routes`].
+    /// If constraints are not cacheable, all routes are evaluated without using the cache.
+    pub fn evaluate_job_insertion(
+        &self,
+        job: &Job,
+        ctx: &InsertionContext,
+        routes: &[(RouteContext, u64)],
+        position: InsertionPosition,
+    ) -> InsertionResult {
+        let is_cacheable = ctx.problem.constraint.is_cacheable();
+
+        routes.iter().fold(InsertionResult::make_failure(), |acc, (route_ctx, key)| {
+            let result = if is_cacheable {
+                self.evaluate_in_route(job, ctx, route_ctx, *key, position)
+            } else {
+                evaluate_job_insertion_in_route(job, ctx, route_ctx, position, None)
+            };
+
+            InsertionResult::choose_best_result(acc, result)
+        })
+    }
+
+    /// Returns amount of cache hits and misses.
+    pub fn stats(&self) -> (usize, usize) {
+        (self.hits.load(Ordering::Relaxed), self.misses.load(Ordering::Relaxed))
+    }
+
+    fn evaluate_in_route(
+        &self,
+        job: &Job,
+        ctx: &InsertionContext,
+        route_ctx: &RouteContext,
+        key: u64,
+        position: InsertionPosition,
+    ) -> InsertionResult {
+        let cache_key = (job.clone(), key);
+
+        if let Some(entry) = self.entries.read().unwrap().get(&cache_key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return match entry {
+                CacheEntry::Success { cost, activities } => InsertionResult::make_success(
+                    *cost,
+                    job.clone(),
+                    activities.iter().map(|(activity, index)| (Box::new(activity.deep_copy()), *index)).collect(),
+                    route_ctx.clone(),
+                ),
+                CacheEntry::Failure { code } => InsertionResult::make_failure_with_code(*code, Some(job.clone())),
+            };
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let result = evaluate_job_insertion_in_route(job, ctx, route_ctx, position, None);
+
+        let entry = match &result {
+            InsertionResult::Success(success) => CacheEntry::Success {
+                cost: success.cost,
+                activities: success.activities.iter().map(|(activity, index)| (activity.deep_copy(), *index)).collect(),
+            },
+            InsertionResult::Failure(failure) => CacheEntry::Failure { code: failure.constraint },
+        };
+
+        let mut entries = self.entries.write().unwrap();
+        if entries.len() >= self.capacity {
+            entries.clear();
+        }
+        entries.insert(cache_key, entry);
+
+        result
+    }
+}
+
+/// Returns routes available for insertion within their signatures: existing routes and
+/// routes of actors which are not used yet.
+pub fn get_insertion_routes(ctx: &InsertionContext) -> Vec<(RouteContext, u64)> {
+    ctx.solution
+        .routes
+        .iter()
+        .cloned()
+        .chain(ctx.solution.registry.next().map(RouteContext::new))
+        .map(|route_ctx| {
+            let key = get_route_signature(&route_ctx.route);
+            (route_ctx, key)
+        })
+        .collect()
+}
@@ -5,7 +5,7 @@ mod context_test;
 use crate::construction::constraints::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
 use crate::construction::heuristics::factories::*;
 use crate::construction::OP_START_MSG;
-use crate::models::common::{Cost, Schedule};
+use crate::models::common::{saturating_sum, Cost, CostExt, Schedule};
 use crate::models::problem::*;
 use crate::models::solution::*;
 use crate::models::{Extras, Problem, Solution};
@@ -108,7 +108,7 @@ pub struct SolutionContext {
 
 impl SolutionContext {
     pub fn get_total_cost(&self) -> Cost {
-        self.routes.iter().fold(Cost::default(), |acc, rc| acc + Self::get_route_cost(rc))
+        saturating_sum(self.routes.iter().map(Self::get_route_cost))
     }
 
     pub fn get_max_cost(&self) -> Cost {
@@ -147,7 +147,11 @@ impl SolutionContext {
         let distance = route_ctx.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
         let duration = route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
 
-        get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration)
+        get_cost(&actor.vehicle.costs, distance, duration).saturating_add(get_cost(
+            &actor.driver.costs,
+            distance,
+            duration,
+        ))
     }
 }
 
@@ -6,7 +6,7 @@ use std::sync::Arc;
 
 use crate::construction::constraints::ActivityConstraintViolation;
 use crate::construction::heuristics::*;
-use crate::models::common::Cost;
+use crate::models::common::{Cost, CostExt, UNBOUNDED_COST};
 use crate::models::problem::{Job, Multi, Single};
 use crate::models::solution::{Activity, Place, TourActivity};
 use crate::models::Problem;
@@ -96,7 +96,12 @@ fn evaluate_single(
     if result.is_success() {
         activity.place = result.place.unwrap();
         let activities = vec![(activity, result.index)];
-        InsertionResult::make_success(result.cost.unwrap() + route_costs, job.clone(), activities, route_ctx.clone())
+        InsertionResult::make_success(
+            result.cost.unwrap().saturating_add(route_costs),
+            job.clone(),
+            activities,
+            route_ctx.clone(),
+        )
     } else {
         InsertionResult::make_failure_with_code(result.violation.map_or(0, |v| v.code), Some(job.clone()))
     }
@@ -112,7 +117,7 @@ fn evaluate_multi(
     best_known_cost: Option<Cost>,
 ) -> InsertionResult {
     // 1. analyze permutations
-    let result = unwrap_from_result(multi.permutations().into_iter().try_fold(
+    let result = unwrap_from_result(multi.permutations(ctx.random.as_ref()).into_iter().try_fold(
         MultiContext::new(best_known_cost),
         |acc_res, services| {
             let mut shadow = ShadowContext::new(&ctx.problem, &route_ctx);
@@ -142,7 +147,10 @@ fn evaluate_multi(
                         activity.place = srv_res.place.unwrap();
                         let activity = shadow.insert(activity, srv_res.index);
                         let activities = concat_activities(in1.activities, (activity, srv_res.index));
-                        return MultiContext::success(in1.cost.unwrap_or(0.) + srv_res.cost.unwrap(), activities);
+                        return MultiContext::success(
+                            in1.cost.unwrap_or(0.).saturating_add(srv_res.cost.unwrap()),
+                            activities,
+                        );
                     }
 
                     MultiContext::fail(srv_res, in1)
@@ -157,7 +165,12 @@ fn evaluate_multi(
 
     if result.is_success() {
         let activities = result.activities.unwrap();
-        InsertionResult::make_success(result.cost.unwrap() + route_costs, job.clone(), activities, route_ctx.clone())
+        InsertionResult::make_success(
+            result.cost.unwrap().saturating_add(route_costs),
+            job.clone(),
+            activities,
+            route_ctx.clone(),
+        )
     } else {
         InsertionResult::make_failure_with_code(result.violation.map_or(0, |v| v.code), Some(job.clone()))
     }
@@ -223,7 +236,7 @@ fn analyze_insertion_in_route_leg<'a>(
 
             let costs = ctx.problem.constraint.evaluate_soft_activity(route_ctx, &activity_ctx);
 
-            if costs < in2.cost.unwrap_or(std::f64::MAX) {
+            if costs < in2.cost.unwrap_or(UNBOUNDED_COST) {
                 SingleContext::success(activity_ctx.index, costs, target.place.clone())
             } else {
                 SingleContext::skip(in2)
@@ -114,7 +114,7 @@ pub fn create_insertion_context_from_solution(
     solution: (Arc<Solution>, Option<Cost>),
     random: Arc<dyn Random + Send + Sync>,
 ) -> InsertionContext {
-    let jobs: Vec<Job> = solution.0.unassigned.iter().map(|(job, _)| job.clone()).collect();
+    let jobs: Vec<Job> = get_ordered_jobs(&problem, &solution.0.unassigned);
     let unassigned = Default::default();
     let locked = problem.locks.iter().fold(HashSet::new(), |mut acc, lock| {
         acc.extend(lock.details.iter().flat_map(|d| d.jobs.iter().cloned()));
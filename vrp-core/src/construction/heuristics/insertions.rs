@@ -1,12 +1,21 @@
-use crate::construction::heuristics::evaluators::{evaluate_job_insertion, InsertionPosition};
-use crate::construction::heuristics::{InsertionContext, RouteContext};
+use crate::construction::heuristics::*;
 use crate::construction::Quota;
 use crate::models::common::Cost;
 use crate::models::problem::Job;
 use crate::models::solution::TourActivity;
+use crate::models::Problem;
 use crate::utils::map_reduce;
+use hashbrown::{HashMap, HashSet};
 use std::borrow::Borrow;
 use std::ops::Deref;
+use std::sync::Arc;
+
+/// A key to keep in solution state how many times in a row each job was left unassigned.
+pub const JOB_AGING_KEY: i32 = 30;
+
+/// A key to keep in solution state jobs which failed insertion with their reason codes during
+/// the last run of insertion heuristic.
+pub const INSERTION_FAILURES_KEY: i32 = 31;
 
 /// Specifies insertion result variant.
 pub enum InsertionResult {
@@ -123,6 +132,7 @@ impl ResultSelector for BestResultSelector {
 /// there are no jobs left or it is not possible to insert due to constraint limitations.
 pub struct InsertionHeuristic {
     insertion_position: InsertionPosition,
+    cache: Option<Arc<InsertionCache>>,
 }
 
 impl Default for InsertionHeuristic {
@@ -133,7 +143,13 @@ impl Default for InsertionHeuristic {
 
 impl InsertionHeuristic {
     pub fn new(insertion_position: InsertionPosition) -> Self {
-        Self { insertion_position }
+        Self { insertion_position, cache: None }
+    }
+
+    /// Creates a new instance of `InsertionHeuristic` which reuses insertion results of
+    /// routes not changed since previous evaluation. See [`InsertionCache`] for details.
+    pub fn new_with_cache(insertion_position: InsertionPosition, cache: Arc<InsertionCache>) -> Self {
+        Self { insertion_position, cache: Some(cache) }
     }
 }
 
@@ -146,20 +162,31 @@ impl InsertionHeuristic {
         quota: &Option<Box<dyn Quota + Send + Sync>>,
     ) -> InsertionContext {
         let mut ctx = ctx;
+        let mut failures = vec![];
 
         prepare_ctx(&mut ctx);
 
         while !ctx.solution.required.is_empty() && !quota.as_ref().map_or(false, |q| q.is_reached()) {
             let jobs = job_selector.select(&mut ctx).collect::<Vec<Job>>();
-            let result = job_reducer.reduce(
-                &ctx,
-                jobs,
-                Box::new(|job| evaluate_job_insertion(&job, &ctx, self.insertion_position)),
-            );
-            insert(result, &mut ctx);
+            let result = match &self.cache {
+                Some(cache) => {
+                    let routes = get_insertion_routes(&ctx);
+                    job_reducer.reduce(
+                        &ctx,
+                        jobs,
+                        Box::new(|job| cache.evaluate_job_insertion(job, &ctx, &routes, self.insertion_position)),
+                    )
+                }
+                None => job_reducer.reduce(
+                    &ctx,
+                    jobs,
+                    Box::new(|job| evaluate_job_insertion(&job, &ctx, self.insertion_position)),
+                ),
+            };
+            insert(result, &mut ctx, &mut failures);
         }
 
-        finalize_ctx(&mut ctx);
+        finalize_ctx(&mut ctx, failures);
 
         ctx
     }
@@ -198,16 +225,65 @@ impl InsertionResult {
 }
 
 fn prepare_ctx(ctx: &mut InsertionContext) {
-    ctx.solution.required.extend(ctx.solution.unassigned.drain().map(|(job, _)| job));
+    let unassigned = std::mem::take(&mut ctx.solution.unassigned);
+    ctx.solution.required.extend(get_ordered_jobs(&ctx.problem, &unassigned));
     ctx.problem.constraint.accept_solution_state(&mut ctx.solution);
 }
 
-fn finalize_ctx(ctx: &mut InsertionContext) {
+/// Returns jobs from the map in the order they are defined in the problem instead of hash map
+/// iteration order which depends on job addresses, so seeded runs are reproducible. Jobs which
+/// are not defined in the problem are returned last.
+pub(crate) fn get_ordered_jobs<T>(problem: &Problem, jobs: &HashMap<Job, T>) -> Vec<Job> {
+    let mut ordered = problem.jobs.all().filter(|job| jobs.contains_key(job)).collect::<Vec<_>>();
+
+    if ordered.len() < jobs.len() {
+        let known = ordered.iter().cloned().collect::<HashSet<_>>();
+        ordered.extend(jobs.keys().filter(|job| !known.contains(*job)).cloned());
+    }
+
+    ordered
+}
+
+fn finalize_ctx(ctx: &mut InsertionContext, failures: Vec<(Job, i32)>) {
     ctx.solution.unassigned.extend(ctx.solution.required.drain(0..).map(|job| (job, 0)));
     ctx.problem.constraint.accept_solution_state(&mut ctx.solution);
+    ctx.solution.state.insert(INSERTION_FAILURES_KEY, Arc::new(failures));
+    update_job_aging(ctx);
+}
+
+fn update_job_aging(ctx: &mut InsertionContext) {
+    let aging = ctx
+        .solution
+        .unassigned
+        .keys()
+        .map(|job| (job.clone(), get_job_age(&ctx.solution, job) + 1))
+        .collect::<HashMap<_, _>>();
+
+    ctx.solution.state.insert(JOB_AGING_KEY, Arc::new(aging));
+}
+
+/// Returns jobs which failed insertion with their reason codes during the last run of insertion
+/// heuristic. Jobs left unassigned because of reached quota are not included.
+pub fn get_insertion_failures(solution_ctx: &SolutionContext) -> &[(Job, i32)] {
+    solution_ctx
+        .state
+        .get(&INSERTION_FAILURES_KEY)
+        .and_then(|failures| failures.downcast_ref::<Vec<(Job, i32)>>())
+        .map_or(&[], |failures| failures.as_slice())
+}
+
+/// Returns how many times in a row given job was left unassigned by insertion heuristic.
+pub fn get_job_age(solution_ctx: &SolutionContext, job: &Job) -> usize {
+    solution_ctx
+        .state
+        .get(&JOB_AGING_KEY)
+        .and_then(|aging| aging.downcast_ref::<HashMap<Job, usize>>())
+        .and_then(|aging| aging.get(job))
+        .cloned()
+        .unwrap_or(0)
 }
 
-fn insert(result: InsertionResult, ctx: &mut InsertionContext) {
+fn insert(result: InsertionResult, ctx: &mut InsertionContext, failures: &mut Vec<(Job, i32)>) {
     match result {
         InsertionResult::Success(mut success) => {
             let job = success.job;
@@ -229,6 +305,7 @@ fn insert(result: InsertionResult, ctx: &mut InsertionContext) {
             if let Some(job) = failure.job {
                 ctx.solution.unassigned.insert(job.clone(), failure.constraint);
                 ctx.solution.required.retain(|j| *j != job);
+                failures.push((job, failure.constraint));
             }
         }
     }
@@ -0,0 +1,100 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/marginal_test.rs"]
+mod marginal_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::Cost;
+use crate::models::problem::{Actor, Job};
+use crate::models::{Problem, Solution};
+use crate::utils::{parallel_collect, Random};
+use std::sync::Arc;
+
+/// Returns cost saved by removing each used vehicle from the solution together with amount of its
+/// jobs which cannot be served by other vehicles. Jobs of removed vehicle are reinserted into the
+/// remaining routes or unused vehicles using cheapest insertion, so the saving is an estimation:
+/// it does not include further optimization of the changed solution.
+pub fn get_vehicle_marginal_costs(
+    problem: Arc<Problem>,
+    solution: Arc<Solution>,
+    random: Arc<dyn Random + Send + Sync>,
+) -> Vec<(Arc<Actor>, Cost, usize)> {
+    let insertion_ctx = create_restored_context(problem, solution, random);
+    let total_cost = insertion_ctx.solution.get_total_cost();
+    let unassigned = insertion_ctx.solution.unassigned.len();
+
+    let actors =
+        insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route.actor.clone()).collect::<Vec<_>>();
+
+    parallel_collect(&actors, |actor| {
+        let mut insertion_ctx = insertion_ctx.deep_copy();
+
+        let route_idx = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .position(|route_ctx| Arc::ptr_eq(&route_ctx.route.actor, actor))
+            .unwrap();
+        let route_ctx = insertion_ctx.solution.routes.remove(route_idx);
+        insertion_ctx.solution.required.extend(route_ctx.route.tour.jobs());
+
+        // NOTE actor is not released in registry, so it cannot be used to serve removed jobs
+        let job_selector: Box<dyn JobSelector + Send + Sync> = Box::new(AllJobSelector::default());
+        let job_reducer: Box<dyn JobMapReducer + Send + Sync> =
+            Box::new(PairJobMapReducer::new(Box::new(BestResultSelector::default())));
+        let insertion_ctx = if get_insertion_routes(&insertion_ctx).is_empty() {
+            let required = insertion_ctx.solution.required.drain(0..).collect::<Vec<_>>();
+            insertion_ctx.solution.unassigned.extend(required.into_iter().map(|job| (job, 0)));
+            insertion_ctx
+        } else {
+            InsertionHeuristic::default().process(&job_selector, &job_reducer, insertion_ctx, &None)
+        };
+
+        let saving = total_cost - insertion_ctx.solution.get_total_cost();
+        let unserved = insertion_ctx.solution.unassigned.len().saturating_sub(unassigned);
+
+        (actor.clone(), saving, unserved)
+    })
+}
+
+/// Returns cost saved by removing each assigned job from the solution, which can be seen as the
+/// cost of serving the job. Other jobs are kept in their positions.
+pub fn get_job_marginal_costs(
+    problem: Arc<Problem>,
+    solution: Arc<Solution>,
+    random: Arc<dyn Random + Send + Sync>,
+) -> Vec<(Job, Cost)> {
+    let insertion_ctx = create_restored_context(problem, solution, random);
+    let total_cost = insertion_ctx.solution.get_total_cost();
+
+    let jobs =
+        insertion_ctx.solution.routes.iter().flat_map(|route_ctx| route_ctx.route.tour.jobs()).collect::<Vec<_>>();
+
+    parallel_collect(&jobs, |job| {
+        let mut insertion_ctx = insertion_ctx.deep_copy();
+
+        if let Some(route_ctx) =
+            insertion_ctx.solution.routes.iter_mut().find(|route_ctx| route_ctx.route.tour.contains(job))
+        {
+            route_ctx.route_mut().tour.remove(job);
+        }
+        insertion_ctx.restore();
+
+        (job.clone(), total_cost - insertion_ctx.solution.get_total_cost())
+    })
+}
+
+/// Creates insertion context from the solution with valid route states and unassigned jobs kept as is.
+fn create_restored_context(
+    problem: Arc<Problem>,
+    solution: Arc<Solution>,
+    random: Arc<dyn Random + Send + Sync>,
+) -> InsertionContext {
+    let unassigned = solution.unassigned.clone();
+
+    let mut insertion_ctx = InsertionContext::new_from_solution(problem, (solution, None), random);
+    insertion_ctx.solution.required.clear();
+    insertion_ctx.solution.unassigned.extend(unassigned);
+    insertion_ctx.restore();
+
+    insertion_ctx
+}
@@ -3,6 +3,9 @@
 //! # Design
 //!
 
+mod cache;
+pub use self::cache::*;
+
 mod context;
 pub use self::context::*;
 
@@ -13,3 +16,12 @@ mod factories;
 
 mod insertions;
 pub use self::insertions::*;
+
+mod repair;
+pub use self::repair::*;
+
+mod unassignable;
+pub use self::unassignable::*;
+
+mod marginal;
+pub use self::marginal::*;
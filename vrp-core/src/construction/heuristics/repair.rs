@@ -0,0 +1,99 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/repair_test.rs"]
+mod repair_test;
+
+use crate::construction::constraints::ConstraintPipeline;
+use crate::construction::heuristics::{ActivityContext, InsertionContext, RouteContext, SolutionContext};
+use crate::models::problem::Job;
+
+/// Contains result of route repair.
+pub struct RouteRepair {
+    /// Conditional jobs removed from the route because they violate hard constraints.
+    pub removed: Vec<Job>,
+    /// Jobs which are kept in the route, but still violate hard constraints, within violation code.
+    pub violations: Vec<(Job, i32)>,
+}
+
+/// Repairs the route after external modifications, e.g. manual edits or reading solution from
+/// external source, keeping order of its activities:
+/// * recomputes schedules and route states
+/// * removes conditional jobs (e.g. breaks or reloads), detected by `is_conditional`, which
+///   violate hard constraints at their position. Removed jobs are moved back to required ones and
+///   solution state is accepted, so the constraint pipeline can decide whether they are needed
+/// * reports residual violations of other jobs
+///
+/// A job is considered as violating constraints when it cannot be inserted into the route without
+/// it at the same position. Activities are checked against preceding ones only, so a violation is
+/// reported for the first activity where it occurs. Jobs with multiple activities are checked
+/// using route constraints only.
+pub fn repair_route(
+    insertion_ctx: &mut InsertionContext,
+    route_idx: usize,
+    is_conditional: &dyn Fn(&Job) -> bool,
+) -> RouteRepair {
+    let constraint = insertion_ctx.problem.constraint.clone();
+
+    let mut route_ctx = insertion_ctx.solution.routes[route_idx].deep_copy();
+    constraint.accept_route_state(&mut route_ctx);
+
+    let mut removed = vec![];
+    let mut violations: Vec<(Job, i32)> = vec![];
+    let mut activity_idx = 1;
+
+    while let Some(job) = route_ctx.route.tour.get(activity_idx).and_then(|activity| activity.retrieve_job()) {
+        match get_violation(&constraint, &insertion_ctx.solution, &route_ctx, &job, activity_idx) {
+            Some(_) if is_conditional(&job) => {
+                route_ctx.route_mut().tour.remove(&job);
+                constraint.accept_route_state(&mut route_ctx);
+                removed.push(job);
+            }
+            Some(code) => {
+                if !violations.iter().any(|(other, _)| *other == job) {
+                    violations.push((job, code));
+                }
+                activity_idx += 1;
+            }
+            None => activity_idx += 1,
+        }
+    }
+
+    insertion_ctx.solution.routes[route_idx] = route_ctx;
+
+    if !removed.is_empty() {
+        insertion_ctx.solution.required.extend(removed.iter().cloned());
+        constraint.accept_solution_state(&mut insertion_ctx.solution);
+    }
+
+    RouteRepair { removed, violations }
+}
+
+/// Returns violation code if the job cannot be inserted into the route without it at given position.
+fn get_violation(
+    constraint: &ConstraintPipeline,
+    solution_ctx: &SolutionContext,
+    route_ctx: &RouteContext,
+    job: &Job,
+    activity_idx: usize,
+) -> Option<i32> {
+    let mut without_job = route_ctx.deep_copy();
+    without_job.route_mut().tour.remove(job);
+    constraint.accept_route_state(&mut without_job);
+
+    if let Some(violation) = constraint.evaluate_hard_route(solution_ctx, &without_job, job) {
+        return Some(violation.code);
+    }
+
+    if job.as_multi().is_some() {
+        return None;
+    }
+
+    let activity_ctx = ActivityContext {
+        index: activity_idx - 1,
+        prev: without_job.route.tour.get(activity_idx - 1)?,
+        target: route_ctx.route.tour.get(activity_idx)?,
+        // NOTE do not check next activities as they can be affected by violations further in the tour
+        next: None,
+    };
+
+    constraint.evaluate_hard_activity(&without_job, &activity_ctx).map(|violation| violation.code)
+}
@@ -0,0 +1,31 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/unassignable_test.rs"]
+mod unassignable_test;
+
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use crate::models::Problem;
+use crate::utils::{parallel_collect, Random};
+use std::sync::Arc;
+
+/// Finds jobs which cannot be assigned even to an empty route of any available actor, within
+/// violated constraint code. Such jobs are structurally unassignable, e.g. due to unreachable
+/// location, vehicle shift, capacity or skills, so no optimization can assign them. The check is
+/// much cheaper than solving as it evaluates each job in isolation.
+pub fn find_unassignable_jobs(problem: Arc<Problem>, random: Arc<dyn Random + Send + Sync>) -> Vec<(Job, i32)> {
+    let ctx = InsertionContext::new(problem, random);
+
+    let unassignable = parallel_collect(&ctx.solution.required, |job| {
+        match evaluate_job_insertion(job, &ctx, InsertionPosition::Any) {
+            InsertionResult::Success(_) => None,
+            InsertionResult::Failure(failure) => Some((job.clone(), failure.constraint)),
+        }
+    });
+
+    ctx.solution
+        .unassigned
+        .iter()
+        .map(|(job, code)| (job.clone(), *code))
+        .chain(unassignable.into_iter().flatten())
+        .collect()
+}
@@ -1,12 +1,20 @@
 //! A core crate contains main buildings blocks for constructing heuristics and metaheuristic
 //! to solve rich ***Vehicle Routing Problem***.
 //!
+//! With default `parallel` feature disabled, the crate has no threading dependencies and the
+//! algorithm runs sequentially, so it can be used on targets without threads support.
+//!
+//! Sources of randomness and time are pluggable: solver builder accepts custom implementations of
+//! `Random` and `Clock` traits, see `Builder::with_random` and `Builder::with_clock`, so a host,
+//! e.g. an embedded device, can provide its own random generator and time source.
+//!
 
 #[cfg(test)]
 #[path = "../tests/helpers/mod.rs"]
 #[macro_use]
 pub mod helpers;
 
+pub mod algorithms;
 pub mod construction;
 pub mod models;
 pub mod solver;
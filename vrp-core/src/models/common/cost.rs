@@ -0,0 +1,50 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/common/cost_test.rs"]
+mod cost_test;
+
+use crate::models::common::Cost;
+
+/// A cost used as "no limit" sentinel, e.g. as max cost in neighbourhood queries.
+pub const UNBOUNDED_COST: Cost = std::f64::MAX;
+
+/// Provides saturating cost arithmetic: results are clamped to the finite range, so adding big-M
+/// penalties or sentinel values never produces infinity which breaks cost comparisons.
+/// NaN operands are propagated as is.
+pub trait CostExt {
+    /// Adds two costs saturating at the finite bounds.
+    fn saturating_add(self, other: Cost) -> Cost;
+
+    /// Subtracts two costs saturating at the finite bounds.
+    fn saturating_sub(self, other: Cost) -> Cost;
+
+    /// Multiplies two costs saturating at the finite bounds.
+    fn saturating_mul(self, other: Cost) -> Cost;
+}
+
+impl CostExt for Cost {
+    fn saturating_add(self, other: Cost) -> Cost {
+        saturate(saturate(self) + saturate(other))
+    }
+
+    fn saturating_sub(self, other: Cost) -> Cost {
+        saturate(saturate(self) - saturate(other))
+    }
+
+    fn saturating_mul(self, other: Cost) -> Cost {
+        saturate(saturate(self) * saturate(other))
+    }
+}
+
+/// Sums costs saturating at the finite bounds.
+pub fn saturating_sum<I: Iterator<Item = Cost>>(costs: I) -> Cost {
+    costs.fold(0., |acc, cost| acc.saturating_add(cost))
+}
+
+#[inline(always)]
+fn saturate(value: Cost) -> Cost {
+    if value.is_nan() {
+        value
+    } else {
+        value.clamp(std::f64::MIN, std::f64::MAX)
+    }
+}
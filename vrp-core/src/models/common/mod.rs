@@ -3,6 +3,9 @@
 mod primitives;
 pub use self::primitives::*;
 
+mod cost;
+pub use self::cost::*;
+
 mod domain;
 pub use self::domain::*;
 
@@ -150,6 +150,147 @@ pub fn create_matrix_transport_cost(costs: Vec<MatrixData>) -> Result<Arc<dyn Tr
     })
 }
 
+/// Specifies routing data which overrides routing information between two specific locations.
+pub struct TransportOverride {
+    /// A routing profile.
+    pub profile: Profile,
+    /// A location of departure.
+    pub from: Location,
+    /// A location of arrival.
+    pub to: Location,
+    /// Travel duration. Original value is used when omitted.
+    pub duration: Option<Duration>,
+    /// Travel distance. Original value is used when omitted.
+    pub distance: Option<Distance>,
+}
+
+/// Creates routing costs which use overridden values for specific location pairs and
+/// delegate to original costs for all others.
+pub fn create_override_transport_cost(
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    overrides: Vec<TransportOverride>,
+) -> Arc<dyn TransportCost + Send + Sync> {
+    if overrides.is_empty() {
+        transport
+    } else {
+        Arc::new(OverrideTransportCost::new(transport, overrides))
+    }
+}
+
+type OverrideKey = (Profile, Location, Location);
+
+/// A routing costs decorator which overrides routing data for specific location pairs.
+struct OverrideTransportCost {
+    inner: Arc<dyn TransportCost + Send + Sync>,
+    overrides: HashMap<OverrideKey, (Option<Duration>, Option<Distance>)>,
+}
+
+impl OverrideTransportCost {
+    /// Creates a new instance of [`OverrideTransportCost`].
+    fn new(inner: Arc<dyn TransportCost + Send + Sync>, overrides: Vec<TransportOverride>) -> Self {
+        let overrides = overrides
+            .into_iter()
+            .map(|item| ((item.profile, item.from, item.to), (item.duration, item.distance)))
+            .collect();
+
+        Self { inner, overrides }
+    }
+}
+
+impl TransportCost for OverrideTransportCost {
+    fn duration(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        self.overrides
+            .get(&(profile, from, to))
+            .and_then(|(duration, _)| *duration)
+            .unwrap_or_else(|| self.inner.duration(profile, from, to, departure))
+    }
+
+    fn distance(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        self.overrides
+            .get(&(profile, from, to))
+            .and_then(|(_, distance)| *distance)
+            .unwrap_or_else(|| self.inner.distance(profile, from, to, departure))
+    }
+}
+
+/// Specifies a travel time multiplier for a time of day band which repeats every day.
+pub struct TravelTimeMultiplier {
+    /// A routing profile.
+    pub profile: Profile,
+    /// A band start in seconds since midnight.
+    pub start: Timestamp,
+    /// A band end in seconds since midnight.
+    pub end: Timestamp,
+    /// A multiplier applied to travel duration when departure is within the band.
+    pub factor: f64,
+}
+
+/// Creates routing costs which multiply travel durations by time of day multipliers and
+/// delegate to original costs for distances and departures outside of the bands.
+pub fn create_multiplier_transport_cost(
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    multipliers: Vec<TravelTimeMultiplier>,
+) -> Arc<dyn TransportCost + Send + Sync> {
+    if multipliers.is_empty() {
+        transport
+    } else {
+        Arc::new(MultiplierTransportCost { inner: transport, multipliers })
+    }
+}
+
+/// A routing costs decorator which applies time of day multipliers to travel durations.
+struct MultiplierTransportCost {
+    inner: Arc<dyn TransportCost + Send + Sync>,
+    multipliers: Vec<TravelTimeMultiplier>,
+}
+
+impl TransportCost for MultiplierTransportCost {
+    fn duration(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        const SECONDS_PER_DAY: f64 = 86400.;
+
+        let duration = self.inner.duration(profile, from, to, departure);
+        let time_of_day = departure.rem_euclid(SECONDS_PER_DAY);
+
+        self.multipliers
+            .iter()
+            .find(|item| item.profile == profile && item.start <= time_of_day && time_of_day < item.end)
+            .map_or(duration, |item| duration * item.factor)
+    }
+
+    fn distance(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        self.inner.distance(profile, from, to, departure)
+    }
+}
+
+/// A user defined function which returns travel duration or distance between two locations
+/// for the given profile and departure time.
+pub type TransportFn = Arc<dyn Fn(Profile, Location, Location, Timestamp) -> f64 + Send + Sync>;
+
+/// Creates routing costs from user defined functions, so routing matrices are not required, e.g.
+/// manhattan distance on a grid, walking network inside a building or straight line for drones.
+pub fn create_closure_transport_cost(
+    duration: TransportFn,
+    distance: TransportFn,
+) -> Arc<dyn TransportCost + Send + Sync> {
+    Arc::new(ClosureTransportCost { duration, distance })
+}
+
+/// A routing costs which delegates to user defined functions.
+struct ClosureTransportCost {
+    duration: TransportFn,
+    distance: TransportFn,
+}
+
+impl TransportCost for ClosureTransportCost {
+    fn duration(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        (self.duration)(profile, from, to, departure)
+    }
+
+    fn distance(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        (self.distance)(profile, from, to, departure)
+    }
+}
+
 /// A time agnostic matrix routing costs.
 struct TimeAgnosticMatrixTransportCost {
     durations: Vec<Vec<Duration>>,
@@ -266,7 +407,11 @@ impl TransportCost for TimeAwareMatrixTransportCost {
     }
 }
 
-fn dominance_order<S>(a: &S, b: &S, objectives: &Vec<Box<dyn Objective<Solution = S> + Send + Sync>>) -> Ordering {
+pub(crate) fn dominance_order<S>(
+    a: &S,
+    b: &S,
+    objectives: &Vec<Box<dyn Objective<Solution = S> + Send + Sync>>,
+) -> Ordering {
     let mut less_cnt = 0;
     let mut greater_cnt = 0;
 
@@ -4,7 +4,8 @@ mod jobs_test;
 
 use crate::models::common::*;
 use crate::models::problem::{Fleet, TransportCost};
-use hashbrown::HashMap;
+use crate::utils::Random;
+use hashbrown::{HashMap, HashSet};
 use std::cell::UnsafeCell;
 use std::cmp::Ordering::Less;
 use std::hash::{Hash, Hasher};
@@ -89,8 +90,8 @@ pub struct Multi {
 /// Defines a trait to work with multi job's permutations.
 pub trait JobPermutation {
     // TODO fix all implementations to support returning reference
-    /// Returns a valid permutation.
-    fn get(&self) -> Vec<Vec<usize>>;
+    /// Returns a valid permutation using given random generator for any random choice.
+    fn get(&self, random: &dyn Random) -> Vec<Vec<usize>>;
 
     /// Validates given permutation.
     fn validate(&self, permutation: &[usize]) -> bool;
@@ -109,7 +110,7 @@ impl FixedJobPermutation {
 }
 
 impl JobPermutation for FixedJobPermutation {
-    fn get(&self) -> Vec<Vec<usize>> {
+    fn get(&self, _: &dyn Random) -> Vec<Vec<usize>> {
         self.permutations.clone()
     }
 
@@ -138,9 +139,9 @@ impl Multi {
     }
 
     /// Returns all sub-jobs permutations.
-    pub fn permutations(&self) -> Vec<Vec<Arc<Single>>> {
+    pub fn permutations(&self, random: &dyn Random) -> Vec<Vec<Arc<Single>>> {
         self.permutator
-            .get()
+            .get(random)
             .iter()
             .map(|perm| perm.iter().map(|&i| self.jobs.get(i).unwrap().clone()).collect())
             .collect()
@@ -183,12 +184,100 @@ type JobIndex = HashMap<Job, (Vec<(Job, Cost)>, Cost)>;
 pub struct Jobs {
     jobs: Vec<Job>,
     index: HashMap<Profile, JobIndex>,
+    limit: Option<usize>,
 }
 
 impl Jobs {
     /// Creates a new [`Jobs`].
     pub fn new(fleet: &Fleet, jobs: Vec<Job>, transport: &Arc<dyn TransportCost + Send + Sync>) -> Jobs {
-        Jobs { jobs: jobs.clone(), index: create_index(fleet, jobs, transport) }
+        Self::new_with_neighbours_limit(fleet, jobs, transport, None)
+    }
+
+    /// Creates a new [`Jobs`] keeping at most `limit` nearest neighbours per job in neighbourhood
+    /// index. `None` means no limit: index requires quadratic memory, but neighbour based ruin
+    /// methods can reach all jobs.
+    pub fn new_with_neighbours_limit(
+        fleet: &Fleet,
+        jobs: Vec<Job>,
+        transport: &Arc<dyn TransportCost + Send + Sync>,
+        limit: Option<usize>,
+    ) -> Jobs {
+        Jobs { jobs: jobs.clone(), index: create_index(fleet, jobs, transport, limit), limit }
+    }
+
+    /// Creates a new [`Jobs`] with `added` jobs and without `removed` ones. Neighbourhood index is
+    /// updated incrementally: neighbours are calculated only for added jobs, neighbour lists of
+    /// other jobs are kept and updated with added and removed jobs. Please note, that for sparse
+    /// index, neighbour lists can become shorter than the limit after removing jobs.
+    pub fn patch(
+        &self,
+        fleet: &Fleet,
+        added: Vec<Job>,
+        removed: &[Job],
+        transport: &Arc<dyn TransportCost + Send + Sync>,
+    ) -> Jobs {
+        let removed = removed.iter().collect::<HashSet<_>>();
+        let jobs =
+            self.jobs.iter().filter(|job| !removed.contains(job)).chain(added.iter()).cloned().collect::<Vec<_>>();
+
+        let index =
+            self.index
+                .iter()
+                .map(|(&profile, index)| {
+                    let starts = get_profile_starts(fleet, profile);
+
+                    let index = index
+                        .iter()
+                        .filter(|(job, _)| !removed.contains(job))
+                        .map(|(job, (job_costs, fleet_cost))| {
+                            let mut job_costs = job_costs
+                                .iter()
+                                .filter(|(other, _)| !removed.contains(other))
+                                .cloned()
+                                .chain(added.iter().map(|other| {
+                                    (other.clone(), get_cost_between_jobs(profile, transport, job, other))
+                                }))
+                                .collect::<Vec<_>>();
+                            sort_job_costs(&mut job_costs, self.limit);
+
+                            (job.clone(), (job_costs, *fleet_cost))
+                        })
+                        .chain(added.iter().map(|job| {
+                            (job.clone(), create_job_index_item(profile, transport, job, &jobs, &starts, self.limit))
+                        }))
+                        .collect();
+
+                    (profile, index)
+                })
+                .collect();
+
+        Jobs { jobs, index, limit: self.limit }
+    }
+
+    /// Creates a copy of [`Jobs`] which keeps at most `limit` nearest neighbours per job in
+    /// neighbourhood index.
+    pub fn with_neighbours_limit(&self, limit: usize) -> Jobs {
+        let limit = Some(self.limit.map_or(limit, |current| current.min(limit)));
+
+        let index = self
+            .index
+            .iter()
+            .map(|(&profile, index)| {
+                let index = index
+                    .iter()
+                    .map(|(job, (job_costs, fleet_cost))| {
+                        let mut job_costs = job_costs.clone();
+                        sort_job_costs(&mut job_costs, limit);
+
+                        (job.clone(), (job_costs, *fleet_cost))
+                    })
+                    .collect();
+
+                (profile, index)
+            })
+            .collect();
+
+        Jobs { jobs: self.jobs.clone(), index, limit }
     }
 
     /// Returns all jobs in original order.
@@ -264,35 +353,15 @@ fn create_index(
     fleet: &Fleet,
     jobs: Vec<Job>,
     transport: &Arc<dyn TransportCost + Send + Sync>,
+    limit: Option<usize>,
 ) -> HashMap<Profile, JobIndex> {
     fleet.profiles.iter().cloned().fold(HashMap::new(), |mut acc, profile| {
-        // get all possible start positions for given profile
-        let starts: Vec<Location> = fleet
-            .vehicles
-            .iter()
-            .filter(|v| v.profile == profile)
-            .flat_map(|v| v.details.iter().map(|d| d.start))
-            .filter(|s| s.is_some())
-            .map(|s| s.unwrap())
-            .collect();
+        let starts = get_profile_starts(fleet, profile);
 
         // create job index
         let item = jobs.iter().cloned().fold(HashMap::new(), |mut acc, job| {
-            let mut job_costs: Vec<(Job, Cost)> = jobs
-                .iter()
-                .filter(|j| **j != job)
-                .map(|j| (j.clone(), get_cost_between_jobs(profile, transport, &job, j)))
-                .collect();
-            job_costs.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Less));
-
-            let fleet_costs = starts
-                .iter()
-                .cloned()
-                .map(|s| get_cost_between_job_and_location(profile, transport, &job, s))
-                .min_by(|a, b| a.partial_cmp(b).unwrap_or(Less))
-                .unwrap_or(DEFAULT_COST);
-
-            acc.insert(job, (job_costs, fleet_costs));
+            let item = create_job_index_item(profile, transport, &job, &jobs, &starts, limit);
+            acc.insert(job, item);
             acc
         });
 
@@ -301,6 +370,52 @@ fn create_index(
     })
 }
 
+/// Returns all possible start positions for given profile.
+fn get_profile_starts(fleet: &Fleet, profile: Profile) -> Vec<Location> {
+    fleet
+        .vehicles
+        .iter()
+        .filter(|v| v.profile == profile)
+        .flat_map(|v| v.details.iter().map(|d| d.start))
+        .filter(|s| s.is_some())
+        .map(|s| s.unwrap())
+        .collect()
+}
+
+/// Creates job index item: sorted neighbours with their costs and job rank.
+fn create_job_index_item(
+    profile: Profile,
+    transport: &Arc<dyn TransportCost + Send + Sync>,
+    job: &Job,
+    jobs: &[Job],
+    starts: &[Location],
+    limit: Option<usize>,
+) -> (Vec<(Job, Cost)>, Cost) {
+    let mut job_costs: Vec<(Job, Cost)> = jobs
+        .iter()
+        .filter(|j| *j != job)
+        .map(|j| (j.clone(), get_cost_between_jobs(profile, transport, job, j)))
+        .collect();
+    sort_job_costs(&mut job_costs, limit);
+
+    let fleet_costs = starts
+        .iter()
+        .cloned()
+        .map(|s| get_cost_between_job_and_location(profile, transport, job, s))
+        .min_by(|a, b| a.partial_cmp(b).unwrap_or(Less))
+        .unwrap_or(DEFAULT_COST);
+
+    (job_costs, fleet_costs)
+}
+
+fn sort_job_costs(job_costs: &mut Vec<(Job, Cost)>, limit: Option<usize>) {
+    job_costs.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Less));
+    if let Some(limit) = limit {
+        job_costs.truncate(limit);
+        job_costs.shrink_to_fit();
+    }
+}
+
 #[inline(always)]
 fn get_cost_between_locations(
     profile: Profile,
@@ -328,7 +443,7 @@ fn get_cost_between_job_and_location(
 }
 
 /// Returns minimal cost between jobs.
-fn get_cost_between_jobs(
+pub(crate) fn get_cost_between_jobs(
     profile: Profile,
     transport: &Arc<dyn TransportCost + Send + Sync>,
     lhs: &Job,
@@ -9,3 +9,6 @@ pub use self::registry::Registry;
 mod tour;
 pub use self::tour::Tour;
 pub use self::tour::TourActivity;
+
+mod signature;
+pub use self::signature::{create_solution_signature, get_route_signature, get_solution_signature};
@@ -40,14 +40,20 @@ impl Registry {
         self.all.iter().cloned()
     }
 
-    /// Returns list of all available actors.
+    /// Returns list of all available actors in the order they are defined in the fleet.
     pub fn available<'a>(&'a self) -> impl Iterator<Item = Arc<Actor>> + 'a {
-        self.available.iter().flat_map(|(_, set)| set.iter().cloned())
+        self.all.iter().filter(move |actor| self.is_available(actor)).cloned()
     }
 
-    /// Returns next available actors from each different type.
+    /// Returns next available actors from each different type. Actors are returned in the order
+    /// they are defined in the fleet, so the result does not depend on hash set iteration order.
     pub fn next<'a>(&'a self) -> impl Iterator<Item = Arc<Actor>> + 'a {
-        self.available.iter().flat_map(|(_, set)| set.iter().take(1).cloned())
+        let mut groups = HashSet::new();
+        self.available().filter(move |actor| groups.insert(*self.index.get(actor).unwrap()))
+    }
+
+    fn is_available(&self, actor: &Arc<Actor>) -> bool {
+        self.index.get(actor).and_then(|group| self.available.get(group)).map_or(false, |set| set.contains(actor))
     }
 
     /// Creates a deep copy of registry.
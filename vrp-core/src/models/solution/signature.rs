@@ -0,0 +1,105 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/solution/signature_test.rs"]
+mod signature_test;
+
+use crate::models::common::IdDimension;
+use crate::models::problem::Job;
+use crate::models::solution::{Activity, Route};
+use crate::models::Solution;
+use std::hash::{Hash, Hasher};
+
+/// Returns a canonical signature of the solution: routes and unassigned jobs are normalized by
+/// order, so two solutions with the same tours have the same signature regardless of how routes
+/// are stored. Jobs and vehicles are identified by their ids, so the signature is stable across runs.
+pub fn get_solution_signature(solution: &Solution) -> u64 {
+    create_solution_signature(solution.routes.iter(), solution.unassigned.keys())
+}
+
+/// Creates a canonical signature from given routes and unassigned jobs.
+/// See [`get_solution_signature`] for details.
+pub fn create_solution_signature<'a>(
+    routes: impl Iterator<Item = &'a Route>,
+    unassigned: impl Iterator<Item = &'a Job>,
+) -> u64 {
+    let mut routes = routes.map(get_route_signature).collect::<Vec<_>>();
+    routes.sort_unstable();
+
+    let mut unassigned = unassigned
+        .map(|job| {
+            let mut hasher = SignatureHasher::default();
+            hash_job(job, &mut hasher);
+            hasher.finish()
+        })
+        .collect::<Vec<_>>();
+    unassigned.sort_unstable();
+
+    let mut hasher = SignatureHasher::default();
+    hasher.write_u64(routes.len() as u64);
+    routes.iter().for_each(|signature| hasher.write_u64(*signature));
+    hasher.write_u64(unassigned.len() as u64);
+    unassigned.iter().for_each(|signature| hasher.write_u64(*signature));
+
+    hasher.finish()
+}
+
+/// Returns a signature of a single route: its vehicle, time and activities.
+pub fn get_route_signature(route: &Route) -> u64 {
+    let mut hasher = SignatureHasher::default();
+
+    route.actor.vehicle.dimens.get_id().hash(&mut hasher);
+    hasher.write_u64(route.actor.detail.time.start.to_bits());
+    hasher.write_u64(route.actor.detail.time.end.to_bits());
+
+    route.tour.all_activities().for_each(|activity| hash_activity(activity, &mut hasher));
+
+    hasher.finish()
+}
+
+fn hash_activity(activity: &Activity, hasher: &mut SignatureHasher) {
+    hasher.write_u64(activity.place.location as u64);
+
+    match activity.retrieve_job() {
+        Some(job) => {
+            hash_job(&job, hasher);
+            activity.job.as_ref().and_then(|single| single.dimens.get_id()).hash(hasher);
+        }
+        None => hasher.write_u8(0),
+    }
+}
+
+fn hash_job(job: &Job, hasher: &mut SignatureHasher) {
+    job.dimens().get_id().hash(hasher);
+}
+
+/// A 64-bit FNV-1a hasher: unlike the default one, its output does not depend on platform
+/// or compiler version.
+struct SignatureHasher {
+    state: u64,
+}
+
+impl Default for SignatureHasher {
+    fn default() -> Self {
+        Self { state: 0xcbf2_9ce4_8422_2325 }
+    }
+}
+
+impl Hasher for SignatureHasher {
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        bytes.iter().for_each(|byte| {
+            self.state ^= u64::from(*byte);
+            self.state = self.state.wrapping_mul(0x0100_0000_01b3);
+        });
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64);
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+}
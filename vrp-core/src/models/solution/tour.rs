@@ -8,9 +8,17 @@ use hashbrown::HashSet;
 use std::iter::once;
 use std::ops::RangeBounds;
 use std::slice::{Iter, IterMut};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 pub type TourActivity = Box<Activity>;
 
+/// A global counter used to assign unique versions to tours.
+static TOUR_VERSION: AtomicUsize = AtomicUsize::new(0);
+
+fn next_version() -> usize {
+    TOUR_VERSION.fetch_add(1, Ordering::Relaxed) + 1
+}
+
 /// Represents a tour, a smart container for jobs with their associated activities.
 pub struct Tour {
     /// Stores activities in the order the performed.
@@ -21,11 +29,14 @@ pub struct Tour {
 
     /// Keeps track whether tour is set as closed.
     is_closed: bool,
+
+    /// A version which is changed on each modification of activities sequence.
+    version: usize,
 }
 
 impl Default for Tour {
     fn default() -> Self {
-        Tour { activities: Default::default(), jobs: Default::default(), is_closed: false }
+        Tour { activities: Default::default(), jobs: Default::default(), is_closed: false, version: next_version() }
     }
 }
 
@@ -35,6 +46,7 @@ impl Tour {
         assert!(activity.job.is_none());
         assert!(self.activities.is_empty());
         self.activities.push(activity);
+        self.version = next_version();
 
         self
     }
@@ -45,6 +57,7 @@ impl Tour {
         assert!(!self.activities.is_empty());
         self.activities.push(activity);
         self.is_closed = true;
+        self.version = next_version();
 
         self
     }
@@ -62,6 +75,7 @@ impl Tour {
 
         self.jobs.insert(activity.retrieve_job().unwrap());
         self.activities.insert(index, activity);
+        self.version = next_version();
 
         self
     }
@@ -69,6 +83,7 @@ impl Tour {
     /// Removes job within its activities from the tour.
     pub fn remove(&mut self, job: &Job) -> bool {
         self.activities.retain(|a| !a.has_same_job(job));
+        self.version = next_version();
         self.jobs.remove(job)
     }
 
@@ -94,6 +109,7 @@ impl Tour {
             .drain(range)
             .map(|a| a.retrieve_job().expect("Attempt to remove activity without job from the tour!"))
             .collect();
+        self.version = next_version();
 
         jobs.iter().for_each(|job| {
             self.remove(job);
@@ -137,9 +153,15 @@ impl Tour {
         }
     }
 
-    /// Returns all jobs.
+    /// Returns all jobs in the order of their first activity in the tour.
     pub fn jobs<'a>(&'a self) -> impl Iterator<Item = Job> + 'a {
-        self.jobs.iter().cloned()
+        // NOTE iteration order of hash set depends on job addresses, so it is not used here to keep
+        // seeded runs reproducible. Sub jobs of multi job are returned as multi job once.
+        let mut visited = HashSet::new();
+        self.activities.iter().filter_map(|activity| activity.retrieve_job()).filter(move |job| match job {
+            Job::Single(_) => true,
+            Job::Multi(_) => visited.insert(job.clone()),
+        })
     }
 
     /// Returns activity by its index in tour.
@@ -203,12 +225,19 @@ impl Tour {
         self.jobs.len()
     }
 
+    /// Returns tour version: it is changed each time when activities are added or removed, but not
+    /// when their schedules are updated. A deep copy of the tour keeps its version.
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
     /// Creates a copy of existing tour deeply copying all activities and jobs.
     pub fn deep_copy(&self) -> Tour {
         Tour {
             activities: self.activities.iter().map(|a| Box::new(a.deep_copy())).collect(),
             jobs: self.jobs.iter().cloned().collect(),
             is_closed: self.is_closed,
+            version: self.version,
         }
     }
 }
@@ -4,17 +4,27 @@ use crate::models::{Problem, Solution};
 use crate::solver::evolution::EvolutionConfig;
 use crate::solver::mutation::*;
 use crate::solver::termination::*;
-use crate::solver::Solver;
-use crate::utils::{DefaultRandom, TimeQuota};
+use crate::solver::{ImprovementHook, InsertionHeatmap, Logger, ParetoArchive, ProgressHook, Solver};
+use crate::utils::{Clock, DefaultClock, DefaultRandom, Random, TimeQuota};
 use std::ops::Deref;
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
+/// Default amount of jobs starting from which neighbourhood index is sparse in low memory mode.
+const DEFAULT_SPARSE_INDEX_THRESHOLD: usize = 5000;
+/// Default amount of neighbours per job kept in sparse neighbourhood index.
+const DEFAULT_SPARSE_INDEX_NEIGHBOURS: usize = 500;
+
 /// Provides configurable way to build solver.
 pub struct Builder {
     max_generations: Option<usize>,
     max_time: Option<usize>,
     cost_variation: Option<(usize, f64)>,
+    cancellation: Option<Arc<AtomicBool>>,
     problem: Option<Arc<Problem>>,
+    low_memory: bool,
+    sparse_index: Option<(usize, usize)>,
+    quick: bool,
     config: EvolutionConfig,
 }
 
@@ -24,7 +34,11 @@ impl Default for Builder {
             max_generations: None,
             max_time: None,
             cost_variation: None,
+            cancellation: None,
             problem: None,
+            low_memory: false,
+            sparse_index: None,
+            quick: false,
             config: EvolutionConfig {
                 mutation: Box::new(RuinAndRecreateMutation::default()),
                 termination: Box::new(MaxTime::new(300.)),
@@ -40,7 +54,16 @@ impl Default for Builder {
                 ],
                 initial_individuals: vec![],
                 random: Arc::new(DefaultRandom::default()),
+                clock: Arc::new(DefaultClock::default()),
                 logger: Arc::new(|msg| println!("{}", msg)),
+                progress_logger: None,
+                trace: None,
+                heatmap: None,
+                pareto: None,
+                on_improvement: None,
+                on_interval: None,
+                on_progress: None,
+                check_invariants: cfg!(debug_assertions),
             },
         }
     }
@@ -68,6 +91,14 @@ impl Builder {
         self
     }
 
+    /// Sets cancellation flag: solver stops refinement as soon as the flag is set and returns the
+    /// best solution found so far. It is used in addition to other termination criteria.
+    /// Default is None.
+    pub fn with_cancellation(mut self, is_cancelled: Arc<AtomicBool>) -> Self {
+        self.cancellation = Some(is_cancelled);
+        self
+    }
+
     /// Sets problem.
     pub fn with_problem(mut self, problem: Arc<Problem>) -> Self {
         self.problem = Some(problem);
@@ -75,7 +106,7 @@ impl Builder {
     }
 
     /// Sets initial methods.
-    pub fn with_initial_methods(mut self, initial_methods: Vec<(Box<dyn Recreate>, usize)>) -> Self {
+    pub fn with_initial_methods(mut self, initial_methods: Vec<(Box<dyn Recreate + Send + Sync>, usize)>) -> Self {
         self.config.initial_methods = initial_methods;
         self
     }
@@ -90,7 +121,7 @@ impl Builder {
                 InsertionContext::new_from_solution(
                     self.problem.as_ref().unwrap().clone(),
                     (solution.clone(), None),
-                    Arc::new(DefaultRandom::default()),
+                    self.config.random.clone(),
                 )
             })
             .collect();
@@ -132,33 +163,219 @@ impl Builder {
 
     /// Sets mutation algorithm.
     /// Default is ruin and recreate.
-    pub fn with_mutation(mut self, mutation: Box<dyn Mutation>) -> Self {
+    pub fn with_mutation(mut self, mutation: Box<dyn Mutation + Send + Sync>) -> Self {
         self.config.mutation = mutation;
         self
     }
 
     /// Sets termination algorithm.
     /// Default is max time and max generations.
-    pub fn with_termination(mut self, termination: Box<dyn Termination>) -> Self {
+    pub fn with_termination(mut self, termination: Box<dyn Termination + Send + Sync>) -> Self {
         self.config.termination = termination;
         self
     }
 
+    /// Sets operator trace which records ruin and recreate decisions. The trace can be dumped
+    /// at any time using shared reference. Recording happens only in debug builds.
+    /// Default is none.
+    pub fn with_operator_trace(mut self, trace: Arc<OperatorTrace>) -> Self {
+        self.config.trace = Some(trace);
+        self
+    }
+
+    /// Sets insertion heatmap which aggregates insertion failures of all solutions produced
+    /// during the run.
+    /// Default is none.
+    pub fn with_insertion_heatmap(mut self, heatmap: Arc<InsertionHeatmap>) -> Self {
+        self.config.heatmap = Some(heatmap);
+        self
+    }
+
+    /// Sets pareto archive which keeps non-dominated solutions over total cost, amount of tours
+    /// and amount of unassigned jobs found during the run.
+    /// Default is none.
+    pub fn with_pareto_archive(mut self, pareto: Arc<ParetoArchive>) -> Self {
+        self.config.pareto = Some(pareto);
+        self
+    }
+
+    /// Sets a hook which is called every time a new best solution is accepted, e.g. to update
+    /// live dashboards. The hook is called from the solver thread, so it should return quickly.
+    /// Default is none.
+    pub fn with_improvement_hook(mut self, hook: ImprovementHook) -> Self {
+        self.config.on_improvement = Some(hook);
+        self
+    }
+
+    /// Sets a hook which is called with the best known solution every `interval` generations,
+    /// e.g. to save intermediate results of long running solves. Interval should be greater than zero.
+    /// Default is none.
+    pub fn with_interval_hook(mut self, interval: usize, hook: ImprovementHook) -> Self {
+        self.config.on_interval = Some((interval, hook));
+        self
+    }
+
+    /// Sets a hook which is called with solver progress after each generation, e.g. to show
+    /// it on a dashboard. Additionally, enables tracking of mutation operators usage.
+    /// Default is none.
+    pub fn with_progress_hook(mut self, hook: ProgressHook) -> Self {
+        self.config.on_progress = Some(hook);
+        self
+    }
+
+    /// Sets random generator used by solver, e.g. `DefaultRandom::new_with_seed` to get
+    /// reproducible runs. Should be called before initial solutions are set.
+    /// Default is `DefaultRandom`.
+    pub fn with_random(mut self, random: Arc<dyn Random + Send + Sync>) -> Self {
+        self.config.random = random;
+        self
+    }
+
+    /// Sets a clock used by solver to measure elapsed time, e.g. for max time termination, so a
+    /// host can provide its own time source.
+    /// Default is `DefaultClock` which uses system time.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        self.config.clock = clock;
+        self
+    }
+
+    /// Sets a logger which receives messages about solver state, e.g. population statistics.
+    /// Default prints messages to standard output.
+    pub fn with_logger(mut self, logger: Logger) -> Self {
+        self.config.logger = logger;
+        self
+    }
+
+    /// Sets a logger which receives generation number, best cost, amount of unassigned jobs and
+    /// elapsed time after each generation of refinement loop.
+    /// Default is none.
+    pub fn with_progress_logger(mut self, logger: Logger) -> Self {
+        self.config.progress_logger = Some(logger);
+        self
+    }
+
+    /// Enables or disables solution invariant checks after each generation, see `check_invariants`.
+    /// Checks are performed only when debug assertions are enabled, e.g. in debug builds.
+    /// Default is true when debug assertions are enabled.
+    pub fn with_invariant_checks(mut self, enabled: bool) -> Self {
+        self.config.check_invariants = enabled;
+        self
+    }
+
+    /// Enables low memory mode for large problems. In this mode, population keeps a minimal
+    /// amount of individuals, initial solution is built using cheapest insertion only and
+    /// insertion heatmap, operator trace and pareto archive are not collected. For large problems,
+    /// neighbourhood index is sparse, see `with_sparse_index`. It reduces memory footprint at the
+    /// cost of search diversity, so solution quality is expected to be lower for the same time budget.
+    /// Default is false.
+    pub fn with_low_memory_mode(mut self, enabled: bool) -> Self {
+        self.low_memory = enabled;
+        self
+    }
+
+    /// Sets limits of sparse neighbourhood index used in low memory mode as amount of jobs starting
+    /// from which the index is sparse and amount of nearest neighbours kept per job.
+    /// Default is 5000 jobs and 500 neighbours.
+    pub fn with_sparse_index(mut self, limits: Option<(usize, usize)>) -> Self {
+        self.sparse_index = limits;
+        self
+    }
+
+    /// Enables quick mode for time-critical solves, e.g. interactive use. In this mode, initial
+    /// solution is built using cheapest insertion only and refined by a few ruin and recreate
+    /// generations: unless other termination criteria are specified, solver stops after 100
+    /// generations or 1 second.
+    /// Default is false.
+    pub fn with_quick_mode(mut self, enabled: bool) -> Self {
+        self.quick = enabled;
+        self
+    }
+
     /// Builds solver with parameters specified.
     pub fn build(self) -> Result<Solver, String> {
-        let problem = self.problem.ok_or_else(|| "problem is not specified".to_string())?;
+        let mut problem = self.problem.ok_or_else(|| "problem is not specified".to_string())?;
         let mut config = self.config;
 
-        let (criterias, quota): (Vec<Box<dyn Termination>>, _) =
+        let (sparse_threshold, sparse_neighbours) =
+            self.sparse_index.unwrap_or((DEFAULT_SPARSE_INDEX_THRESHOLD, DEFAULT_SPARSE_INDEX_NEIGHBOURS));
+        if sparse_neighbours == 0 {
+            return Err("amount of neighbours in sparse index should be greater than zero".to_string());
+        }
+
+        if matches!(config.on_interval, Some((0, _))) {
+            return Err("hook interval should be greater than zero".to_string());
+        }
+
+        if self.low_memory {
+            if problem.jobs.size() > sparse_threshold {
+                config.logger.deref()(format!(
+                    "configured to keep {} nearest neighbours per job in low memory mode",
+                    sparse_neighbours
+                ));
+                problem = Arc::new(Problem {
+                    fleet: problem.fleet.clone(),
+                    jobs: Arc::new(problem.jobs.with_neighbours_limit(sparse_neighbours)),
+                    locks: problem.locks.clone(),
+                    constraint: problem.constraint.clone(),
+                    activity: problem.activity.clone(),
+                    transport: problem.transport.clone(),
+                    objective: problem.objective.clone(),
+                    extras: problem.extras.clone(),
+                });
+            }
+
+            config.logger.deref()(
+                "configured to use low memory mode: minimal population, cheapest initial method, \
+                 no heatmap, operator trace and pareto archive"
+                    .to_string(),
+            );
+            config.population_size = 2;
+            config.offspring_size = 1;
+            config.elite_size = 1;
+            config.initial_size = 1;
+            config.initial_methods = vec![(Box::new(RecreateWithCheapest::default()), 1)];
+            config.heatmap = None;
+            config.trace = None;
+            config.pareto = None;
+        }
+
+        if self.quick {
+            config.logger.deref()("configured to use quick mode".to_string());
+            config.population_size = 2;
+            config.offspring_size = 1;
+            config.elite_size = 1;
+            config.initial_size = 1;
+            config.initial_methods = vec![(Box::new(RecreateWithCheapest::default()), 1)];
+        }
+
+        let (mut criterias, quota): (Vec<Box<dyn Termination + Send + Sync>>, _) =
             match (self.max_generations, self.max_time, self.cost_variation) {
+                (None, None, None) if self.quick => {
+                    config.logger.deref()(
+                        "configured to use quick max-generations (100) and max-time (1sec)".to_string(),
+                    );
+                    (
+                        vec![
+                            Box::new(MaxGeneration::new(100)),
+                            Box::new(MaxTime::new_with_clock(1., config.clock.clone())),
+                        ],
+                        create_time_quota(1, &config.clock),
+                    )
+                }
                 (None, None, None) => {
                     config.logger.deref()(
                         "configured to use default max-generations (2000) and max-time (300secs)".to_string(),
                     );
-                    (vec![Box::new(MaxGeneration::new(2000)), Box::new(MaxTime::new(300.))], None)
+                    (
+                        vec![
+                            Box::new(MaxGeneration::new(2000)),
+                            Box::new(MaxTime::new_with_clock(300., config.clock.clone())),
+                        ],
+                        None,
+                    )
                 }
                 _ => {
-                    let mut criterias: Vec<Box<dyn Termination>> = vec![];
+                    let mut criterias: Vec<Box<dyn Termination + Send + Sync>> = vec![];
 
                     if let Some(limit) = self.max_generations {
                         config.logger.deref()(format!("configured to use max-generations: {}", limit));
@@ -167,8 +384,8 @@ impl Builder {
 
                     let quota = if let Some(limit) = self.max_time {
                         config.logger.deref()(format!("configured to use max-time: {}s", limit));
-                        criterias.push(Box::new(MaxTime::new(limit as f64)));
-                        create_time_quota(limit)
+                        criterias.push(Box::new(MaxTime::new_with_clock(limit as f64, config.clock.clone())));
+                        create_time_quota(limit, &config.clock)
                     } else {
                         None
                     };
@@ -185,6 +402,10 @@ impl Builder {
                 }
             };
 
+        if let Some(is_cancelled) = self.cancellation {
+            criterias.push(Box::new(Cancellation::new(is_cancelled)));
+        }
+
         config.termination = Box::new(CompositeTermination::new(criterias));
         config.quota = quota;
 
@@ -192,6 +413,6 @@ impl Builder {
     }
 }
 
-fn create_time_quota(limit: usize) -> Option<Box<dyn Quota + Sync + Send>> {
-    Some(Box::new(TimeQuota::new(limit as f64)))
+fn create_time_quota(limit: usize, clock: &Arc<dyn Clock + Send + Sync>) -> Option<Box<dyn Quota + Sync + Send>> {
+    Some(Box::new(TimeQuota::new_with_clock(limit as f64, clock.clone())))
 }
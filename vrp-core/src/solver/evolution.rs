@@ -1,22 +1,27 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/evolution_test.rs"]
+mod evolution_test;
+
 use crate::construction::heuristics::InsertionContext;
 use crate::construction::Quota;
 use crate::models::common::{MultiObjective, Objective};
 use crate::models::Problem;
-use crate::solver::mutation::{Mutation, Recreate};
+use crate::solver::mutation::*;
 use crate::solver::population::DominancePopulation;
 use crate::solver::termination::Termination;
-use crate::solver::Logger;
-use crate::solver::{Population, RefinementContext};
-use crate::utils::{Random, Timer};
+use crate::solver::{check_invariants, ImprovementHook, Logger, ProgressEvent, ProgressHook};
+use crate::solver::{InsertionHeatmap, ParetoArchive, Population, RefinementContext};
+use crate::utils::{Clock, Random, Timer};
+use std::cmp::Ordering;
 use std::ops::Deref;
 use std::sync::Arc;
 
 /// A configuration which controls evolution execution.
 pub struct EvolutionConfig {
     /// A mutation applied to population.
-    pub mutation: Box<dyn Mutation>,
+    pub mutation: Box<dyn Mutation + Send + Sync>,
     /// A termination defines when evolution should stop.
-    pub termination: Box<dyn Termination>,
+    pub termination: Box<dyn Termination + Send + Sync>,
     /// A quota for evolution execution.
     pub quota: Option<Box<dyn Quota + Send + Sync>>,
 
@@ -29,14 +34,33 @@ pub struct EvolutionConfig {
     /// Initial size of population to be generated.
     pub initial_size: usize,
     /// Create methods to create initial individuals.
-    pub initial_methods: Vec<(Box<dyn Recreate>, usize)>,
+    pub initial_methods: Vec<(Box<dyn Recreate + Send + Sync>, usize)>,
     /// Initial individuals in population.
     pub initial_individuals: Vec<InsertionContext>,
 
     /// Random generator.
     pub random: Arc<dyn Random + Send + Sync>,
+    /// A clock used to measure elapsed time.
+    pub clock: Arc<dyn Clock + Send + Sync>,
     /// A logger used to log evolution progress.
     pub logger: Logger,
+    /// An optional logger which receives best solution statistics after each generation.
+    pub progress_logger: Option<Logger>,
+    /// An optional trace of mutation operator decisions.
+    pub trace: Option<Arc<OperatorTrace>>,
+    /// An optional heatmap of insertion failures.
+    pub heatmap: Option<Arc<InsertionHeatmap>>,
+    /// An optional archive of non-dominated solutions.
+    pub pareto: Option<Arc<ParetoArchive>>,
+    /// An optional hook called when a new best solution is found.
+    pub on_improvement: Option<ImprovementHook>,
+    /// An optional hook called with the best known solution every given amount of generations.
+    pub on_interval: Option<(usize, ImprovementHook)>,
+    /// An optional hook called with solver progress after each generation.
+    pub on_progress: Option<ProgressHook>,
+    /// Specifies whether solution invariants are checked after each generation. Checks are
+    /// performed only when debug assertions are enabled.
+    pub check_invariants: bool,
 }
 
 /// Runs evolution for given `problem` using evolution `config`.
@@ -44,21 +68,49 @@ pub struct EvolutionConfig {
 pub fn run_evolution(problem: Arc<Problem>, config: EvolutionConfig) -> Result<Box<dyn Population>, String> {
     let mut config = config;
 
-    let evolution_time = Timer::start();
+    let evolution_time = Timer::start_with_clock(config.clock.clone());
 
     let mut refinement_ctx = create_refinement_ctx(problem.clone(), &mut config, &evolution_time)?;
 
     // NOTE at the moment, only one solution is produced per generation
     while !config.termination.is_termination(&mut refinement_ctx) {
-        let generation_time = Timer::start();
+        let generation_time = Timer::start_with_clock(config.clock.clone());
 
         let insertion_ctx = refinement_ctx.population.select().deep_copy();
 
         let insertion_ctx = config.mutation.mutate(&mut refinement_ctx, insertion_ctx);
 
+        if cfg!(debug_assertions) && config.check_invariants {
+            if let Err(err) = check_invariants(&insertion_ctx) {
+                panic!("invariant is violated at generation {}: {}", refinement_ctx.generation, err);
+            }
+        }
+
+        if let Some(heatmap) = config.heatmap.as_ref() {
+            heatmap.record(&insertion_ctx);
+        }
+
+        if let Some(pareto) = config.pareto.as_ref() {
+            pareto.record(&insertion_ctx);
+        }
+
         log_progress(&refinement_ctx, &evolution_time, Some(&generation_time), &config.logger);
 
-        add_solution(&mut refinement_ctx, insertion_ctx);
+        add_solution(&mut refinement_ctx, insertion_ctx, &config.on_improvement);
+
+        if let Some(progress_logger) = config.progress_logger.as_ref() {
+            log_generation(&refinement_ctx, &evolution_time, progress_logger);
+        }
+
+        if let Some((interval, on_interval)) = config.on_interval.as_ref() {
+            if refinement_ctx.generation > 0 && refinement_ctx.generation % interval == 0 {
+                call_with_best(&refinement_ctx, on_interval);
+            }
+        }
+
+        if let Some(on_progress) = config.on_progress.as_ref() {
+            notify_progress(&refinement_ctx, &evolution_time, on_progress);
+        }
 
         refinement_ctx.generation += 1;
     }
@@ -98,6 +150,14 @@ fn create_refinement_ctx(
         std::mem::replace(&mut config.quota, None),
     );
 
+    if let Some(trace) = config.trace.clone() {
+        refinement_ctx.state.insert(OPERATOR_TRACE_KEY.to_string(), Box::new(trace));
+    }
+
+    if config.on_progress.is_some() {
+        refinement_ctx.state.insert(OPERATOR_USAGE_KEY.to_string(), Box::new(Arc::new(OperatorUsage::default())));
+    }
+
     std::mem::replace(&mut config.initial_individuals, vec![])
         .into_iter()
         .take(config.initial_size)
@@ -115,7 +175,7 @@ fn create_refinement_ctx(
     };
 
     let _ = indices.into_iter().enumerate().try_for_each(|(idx, method_idx)| {
-        let item_time = Timer::start();
+        let item_time = Timer::start_with_clock(config.clock.clone());
 
         if config.termination.is_termination(&mut refinement_ctx) {
             return Err(());
@@ -123,7 +183,15 @@ fn create_refinement_ctx(
 
         let insertion_ctx = config.initial_methods[method_idx].0.run(&mut refinement_ctx, empty_ctx.deep_copy());
 
-        add_solution(&mut refinement_ctx, insertion_ctx);
+        if let Some(heatmap) = config.heatmap.as_ref() {
+            heatmap.record(&insertion_ctx);
+        }
+
+        if let Some(pareto) = config.pareto.as_ref() {
+            pareto.record(&insertion_ctx);
+        }
+
+        add_solution(&mut refinement_ctx, insertion_ctx, &config.on_improvement);
 
         config.logger.deref()(format!(
             "[{}s] created {} of {} initial solutions in {}ms",
@@ -139,16 +207,62 @@ fn create_refinement_ctx(
     Ok(refinement_ctx)
 }
 
-fn add_solution(refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) {
+fn add_solution(
+    refinement_ctx: &mut RefinementContext,
+    insertion_ctx: InsertionContext,
+    on_improvement: &Option<ImprovementHook>,
+) {
     let is_quota_reached = refinement_ctx.quota.as_ref().map_or(false, |quota| quota.is_reached());
     let is_population_empty = refinement_ctx.population.size() == 0;
 
     // NOTE fix population not to accept solution with worse primary objective fitness as best
     if is_population_empty || !is_quota_reached {
+        let objective = refinement_ctx.problem.objective.clone();
+        let is_improvement = match refinement_ctx.population.best() {
+            Some(best) => objective.total_order(&insertion_ctx, best) == Ordering::Less,
+            None => true,
+        };
+
         refinement_ctx.population.add(insertion_ctx);
+
+        if let (true, Some(on_improvement)) = (is_improvement, on_improvement) {
+            call_with_best(refinement_ctx, on_improvement);
+        }
     }
 }
 
+fn call_with_best(refinement_ctx: &RefinementContext, hook: &ImprovementHook) {
+    if let Some(best) = refinement_ctx.population.best() {
+        let solution = best.solution.to_solution(refinement_ctx.problem.extras.clone());
+        hook.deref()(&solution, refinement_ctx.problem.objective.fitness(best), refinement_ctx.generation);
+    }
+}
+
+fn notify_progress(refinement_ctx: &RefinementContext, evolution_time: &Timer, hook: &ProgressHook) {
+    let best = match refinement_ctx.population.best() {
+        Some(best) => best,
+        None => return,
+    };
+
+    let objective = refinement_ctx.problem.objective.clone();
+    let worst_cost = refinement_ctx.population.all().map(|individual| objective.fitness(individual)).fold(0., f64::max);
+    let usage =
+        get_operator_usage(refinement_ctx).map_or_else(|| (vec![], vec![]), |usage| (usage.ruins(), usage.recreates()));
+    let elapsed = evolution_time.elapsed_secs_as_f64();
+
+    hook.deref()(&ProgressEvent {
+        generation: refinement_ctx.generation,
+        elapsed,
+        speed: if elapsed > 0. { refinement_ctx.generation as f64 / elapsed } else { 0. },
+        best_cost: objective.fitness(best),
+        worst_cost,
+        tours: best.solution.routes.len(),
+        unassigned: best.solution.unassigned.len(),
+        ruin_usage: usage.0,
+        recreate_usage: usage.1,
+    });
+}
+
 fn log_progress(
     refinement_ctx: &RefinementContext,
     evolution_time: &Timer,
@@ -176,6 +290,18 @@ fn log_progress(
     }
 }
 
+fn log_generation(refinement_ctx: &RefinementContext, evolution_time: &Timer, logger: &Logger) {
+    if let Some(best_individual) = refinement_ctx.population.best() {
+        logger.deref()(format!(
+            "generation: {}, best cost: {:.2}, unassigned: {}, elapsed: {}ms",
+            refinement_ctx.generation,
+            refinement_ctx.problem.objective.fitness(best_individual),
+            best_individual.solution.unassigned.len(),
+            evolution_time.elapsed_millis()
+        ));
+    }
+}
+
 fn log_individual(
     insertion_ctx: &InsertionContext,
     generation: Option<(usize, &Timer)>,
@@ -0,0 +1,55 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/heatmap_test.rs"]
+mod heatmap_test;
+
+use crate::construction::heuristics::{get_insertion_failures, InsertionContext};
+use crate::models::problem::Job;
+use hashbrown::HashMap;
+use std::sync::Mutex;
+
+/// Aggregates over the whole run how often each job failed insertion and why: each solution
+/// produced by the solver is a sample, and each insertion failure reported by insertion heuristic
+/// while building it is counted within its reason code.
+#[derive(Default)]
+pub struct InsertionHeatmap {
+    data: Mutex<HeatmapData>,
+}
+
+#[derive(Default)]
+struct HeatmapData {
+    samples: usize,
+    failures: HashMap<Job, HashMap<i32, usize>>,
+}
+
+impl InsertionHeatmap {
+    /// Records insertion failures of given solution.
+    pub fn record(&self, insertion_ctx: &InsertionContext) {
+        let mut data = self.data.lock().unwrap();
+
+        data.samples += 1;
+        get_insertion_failures(&insertion_ctx.solution).iter().for_each(|(job, code)| {
+            *data.failures.entry(job.clone()).or_insert_with(HashMap::new).entry(*code).or_insert(0) += 1;
+        });
+    }
+
+    /// Returns amount of recorded solutions.
+    pub fn samples(&self) -> usize {
+        self.data.lock().unwrap().samples
+    }
+
+    /// Returns jobs which failed insertion at least once within amount of failures per reason code.
+    pub fn failures(&self) -> Vec<(Job, Vec<(i32, usize)>)> {
+        self.data
+            .lock()
+            .unwrap()
+            .failures
+            .iter()
+            .map(|(job, codes)| {
+                let mut codes = codes.iter().map(|(code, count)| (*code, *count)).collect::<Vec<_>>();
+                codes.sort();
+
+                (job.clone(), codes)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/invariants_test.rs"]
+mod invariants_test;
+
+use crate::construction::heuristics::InsertionContext;
+use crate::models::common::IdDimension;
+use crate::models::problem::Job;
+use hashbrown::HashMap;
+
+/// A tolerance used to compare schedule timestamps.
+const SCHEDULE_TOLERANCE: f64 = 1E-6;
+
+/// Checks core invariants of the solution:
+/// * each job of the problem is present exactly once across routes, required, unassigned and
+///   ignored jobs
+/// * each tour starts with departure activity
+/// * activity schedules are monotonic: arrival is not later than departure and departure is not
+///   later than arrival at the next activity
+///
+/// Returns error with description of the first violated invariant.
+pub fn check_invariants(insertion_ctx: &InsertionContext) -> Result<(), String> {
+    check_jobs_accounted(insertion_ctx)?;
+    check_tours(insertion_ctx)
+}
+
+fn check_jobs_accounted(insertion_ctx: &InsertionContext) -> Result<(), String> {
+    let solution = &insertion_ctx.solution;
+
+    let mut counts = HashMap::<Job, usize>::new();
+    solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route.tour.jobs())
+        .chain(solution.required.iter().cloned())
+        .chain(solution.unassigned.keys().cloned())
+        .chain(solution.ignored.iter().cloned())
+        .for_each(|job| *counts.entry(job).or_insert(0) += 1);
+
+    insertion_ctx.problem.jobs.all().try_for_each(|job| match counts.remove(&job).unwrap_or(0) {
+        1 => Ok(()),
+        0 => Err(format!("job is lost: '{}'", get_job_id(&job))),
+        count => Err(format!("job is present {} times: '{}'", count, get_job_id(&job))),
+    })?;
+
+    match counts.keys().next() {
+        Some(job) => Err(format!("job is not part of the problem: '{}'", get_job_id(job))),
+        None => Ok(()),
+    }
+}
+
+fn check_tours(insertion_ctx: &InsertionContext) -> Result<(), String> {
+    insertion_ctx.solution.routes.iter().enumerate().try_for_each(|(route_idx, route_ctx)| {
+        let tour = &route_ctx.route.tour;
+
+        match tour.start() {
+            Some(start) if start.job.is_none() => {}
+            _ => return Err(format!("tour {} does not start with departure", route_idx)),
+        }
+
+        tour.all_activities().enumerate().try_for_each(|(activity_idx, activity)| {
+            if activity.schedule.arrival > activity.schedule.departure + SCHEDULE_TOLERANCE {
+                return Err(format!("activity {} in tour {} has arrival after departure", activity_idx, route_idx));
+            }
+
+            match tour.get(activity_idx + 1) {
+                Some(next) if activity.schedule.departure > next.schedule.arrival + SCHEDULE_TOLERANCE => Err(format!(
+                    "activity {} in tour {} departs after arrival at the next one",
+                    activity_idx, route_idx
+                )),
+                _ => Ok(()),
+            }
+        })
+    })
+}
+
+fn get_job_id(job: &Job) -> String {
+    job.dimens().get_id().cloned().unwrap_or_else(|| "<no id>".to_string())
+}
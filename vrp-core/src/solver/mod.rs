@@ -14,10 +14,18 @@ pub mod termination;
 
 mod builder;
 mod evolution;
+mod heatmap;
+mod invariants;
+mod pareto;
 mod population;
+mod prepared;
 
 pub use self::builder::Builder;
+pub use self::heatmap::InsertionHeatmap;
+pub use self::invariants::check_invariants;
+pub use self::pareto::{ParetoArchive, ParetoPoint};
 pub use self::population::DominancePopulation;
+pub use self::prepared::{JobCancellation, PreparedProblem, ProblemPatch};
 use std::ops::Deref;
 
 /// Contains information needed to perform refinement.
@@ -71,7 +79,37 @@ impl RefinementContext {
 }
 
 /// A logger type.
-pub type Logger = Arc<dyn Fn(String) -> ()>;
+pub type Logger = Arc<dyn Fn(String) + Send + Sync>;
+
+/// A hook which is called when a new best solution is found. It receives the solution, its cost
+/// and generation number.
+pub type ImprovementHook = Arc<dyn Fn(&Solution, Cost, usize) + Send + Sync>;
+
+/// Specifies solver progress after a generation.
+#[derive(Clone, Debug)]
+pub struct ProgressEvent {
+    /// Generation number.
+    pub generation: usize,
+    /// Elapsed time in seconds.
+    pub elapsed: f64,
+    /// Average amount of generations per second.
+    pub speed: f64,
+    /// Cost of the best solution in population.
+    pub best_cost: Cost,
+    /// Cost of the worst solution in population.
+    pub worst_cost: Cost,
+    /// Amount of tours in the best solution.
+    pub tours: usize,
+    /// Amount of unassigned jobs in the best solution.
+    pub unassigned: usize,
+    /// Usage counts of ruin groups, see `OperatorUsage`.
+    pub ruin_usage: Vec<usize>,
+    /// Usage counts of recreate methods, see `OperatorUsage`.
+    pub recreate_usage: Vec<usize>,
+}
+
+/// A hook which is called with solver progress after each generation.
+pub type ProgressHook = Arc<dyn Fn(&ProgressEvent) + Send + Sync>;
 
 /// A Vehicle Routing Problem Solver.
 pub struct Solver {
@@ -1,5 +1,6 @@
 use crate::construction::heuristics::InsertionContext;
 use crate::solver::RefinementContext;
+use hashbrown::HashSet;
 
 mod recreate;
 pub use self::recreate::*;
@@ -7,6 +8,14 @@ pub use self::recreate::*;
 mod ruin;
 pub use self::ruin::*;
 
+mod trace;
+use self::trace::{get_active_trace, get_assignments};
+pub use self::trace::{OperatorTrace, TraceEvent, OPERATOR_TRACE_KEY};
+
+mod usage;
+pub(crate) use self::usage::get_operator_usage;
+pub use self::usage::{OperatorUsage, OPERATOR_USAGE_KEY};
+
 /// Mutates given insertion context.
 pub trait Mutation {
     fn mutate(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext;
@@ -14,8 +23,8 @@ pub trait Mutation {
 
 /// A mutation which implements ruin and recreate metaheuristic.
 pub struct RuinAndRecreateMutation {
-    pub recreate: Box<dyn Recreate>,
-    pub ruin: Box<dyn Ruin>,
+    pub recreate: Box<dyn Recreate + Send + Sync>,
+    pub ruin: Box<dyn Ruin + Send + Sync>,
 }
 
 impl Default for RuinAndRecreateMutation {
@@ -26,15 +35,49 @@ impl Default for RuinAndRecreateMutation {
 
 impl RuinAndRecreateMutation {
     /// Creates a new instance of [`RuinAndRecreateMutation`].
-    pub fn new(recreate: Box<dyn Recreate>, ruin: Box<dyn Ruin>) -> Self {
+    pub fn new(recreate: Box<dyn Recreate + Send + Sync>, ruin: Box<dyn Ruin + Send + Sync>) -> Self {
         Self { recreate, ruin }
     }
 }
 
 impl Mutation for RuinAndRecreateMutation {
     fn mutate(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
-        let insertion_ctx = self.ruin.run(refinement_ctx, insertion_ctx);
+        if let Some(trace) = get_active_trace(refinement_ctx) {
+            let generation = refinement_ctx.generation;
+            let original = get_assignments(&insertion_ctx);
+
+            let insertion_ctx = self.ruin.run(refinement_ctx, insertion_ctx);
+
+            let assignments = get_assignments(&insertion_ctx);
+            let ruined = original
+                .into_iter()
+                .filter(|(job, _)| !assignments.contains_key(job))
+                .map(|(job, (actor, _))| {
+                    trace.record(generation, TraceEvent::Ruined { job: job.clone(), actor });
+                    job
+                })
+                .collect::<HashSet<_>>();
+
+            let insertion_ctx = self.recreate.run(refinement_ctx, insertion_ctx);
+
+            let assignments = get_assignments(&insertion_ctx);
+            ruined.iter().for_each(|job| {
+                if let Some((actor, index)) = assignments.get(job) {
+                    trace.record(
+                        generation,
+                        TraceEvent::Inserted { job: job.clone(), actor: actor.clone(), index: *index },
+                    );
+                }
+            });
+            insertion_ctx.solution.unassigned.iter().for_each(|(job, code)| {
+                trace.record(generation, TraceEvent::Unassigned { job: job.clone(), code: *code });
+            });
+
+            insertion_ctx
+        } else {
+            let insertion_ctx = self.ruin.run(refinement_ctx, insertion_ctx);
 
-        self.recreate.run(refinement_ctx, insertion_ctx)
+            self.recreate.run(refinement_ctx, insertion_ctx)
+        }
     }
 }
@@ -1,6 +1,7 @@
 //! Contains logic to build a feasible solution from partially ruined one.
 
 use crate::construction::heuristics::InsertionContext;
+use crate::solver::mutation::get_operator_usage;
 use crate::solver::RefinementContext;
 
 /// A trait which specifies logic to produce a new feasible solution from partial one.
@@ -24,9 +25,15 @@ pub use self::recreate_with_regret::RecreateWithRegret;
 mod recreate_with_nearest_neighbor;
 pub use self::recreate_with_nearest_neighbor::*;
 
+mod recreate_with_priority;
+pub use self::recreate_with_priority::*;
+
+mod recreate_with_time_buckets;
+pub use self::recreate_with_time_buckets::*;
+
 /// Provides the way to run one of multiple recreate methods.
 pub struct CompositeRecreate {
-    recreates: Vec<Box<dyn Recreate>>,
+    recreates: Vec<Box<dyn Recreate + Send + Sync>>,
     weights: Vec<usize>,
 }
 
@@ -44,7 +51,7 @@ impl Default for CompositeRecreate {
 }
 
 impl CompositeRecreate {
-    pub fn new(recreates: Vec<(Box<dyn Recreate>, usize)>) -> Self {
+    pub fn new(recreates: Vec<(Box<dyn Recreate + Send + Sync>, usize)>) -> Self {
         let weights = recreates.iter().map(|(_, weight)| *weight).collect();
         let recreates = recreates.into_iter().map(|(recreate, _)| recreate).collect();
         Self { recreates, weights }
@@ -54,6 +61,11 @@ impl CompositeRecreate {
 impl Recreate for CompositeRecreate {
     fn run(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
         let index = insertion_ctx.random.weighted(self.weights.as_slice());
+
+        if let Some(usage) = get_operator_usage(refinement_ctx) {
+            usage.record_recreate(index);
+        }
+
         self.recreates.get(index).unwrap().run(refinement_ctx, insertion_ctx)
     }
 }
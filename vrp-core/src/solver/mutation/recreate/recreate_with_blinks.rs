@@ -2,8 +2,6 @@
 #[path = "../../../../tests/unit/solver/mutation/recreate/recreate_with_blinks_test.rs"]
 mod recreate_with_blinks_test;
 
-extern crate rand;
-
 use crate::construction::constraints::{Demand, DemandDimension};
 use crate::construction::heuristics::*;
 use crate::construction::heuristics::{InsertionContext, InsertionResult};
@@ -12,8 +10,7 @@ use crate::models::problem::Job;
 use crate::models::Problem;
 use crate::solver::mutation::recreate::Recreate;
 use crate::solver::RefinementContext;
-use crate::utils::compare_floats;
-use rand::prelude::*;
+use crate::utils::{compare_floats, shuffle};
 use std::cmp::Ordering;
 use std::marker::PhantomData;
 use std::ops::{Add, Sub};
@@ -73,7 +70,7 @@ impl RandomJobSelector {
 
 impl JobSelector for RandomJobSelector {
     fn select<'a>(&'a self, ctx: &'a mut InsertionContext) -> Box<dyn Iterator<Item = Job> + 'a> {
-        ctx.solution.required.shuffle(&mut rand::thread_rng());
+        shuffle(ctx.solution.required.as_mut_slice(), ctx.random.as_ref());
 
         Box::new(ctx.solution.required.iter().cloned())
     }
@@ -2,11 +2,21 @@ use crate::construction::heuristics::InsertionContext;
 use crate::construction::heuristics::*;
 use crate::solver::mutation::recreate::Recreate;
 use crate::solver::RefinementContext;
+use std::sync::Arc;
 
 /// A recreate method which is equivalent to cheapest insertion heuristic.
 pub struct RecreateWithCheapest {
     job_selector: Box<dyn JobSelector + Send + Sync>,
     job_reducer: Box<dyn JobMapReducer + Send + Sync>,
+    cache: Option<Arc<InsertionCache>>,
+}
+
+impl RecreateWithCheapest {
+    /// Creates a new instance of `RecreateWithCheapest` which reuses insertion results of
+    /// routes untouched by ruin using given cache.
+    pub fn new_with_cache(cache: Arc<InsertionCache>) -> Self {
+        Self { cache: Some(cache), ..Self::default() }
+    }
 }
 
 impl Default for RecreateWithCheapest {
@@ -14,17 +24,18 @@ impl Default for RecreateWithCheapest {
         Self {
             job_selector: Box::new(AllJobSelector::default()),
             job_reducer: Box::new(PairJobMapReducer::new(Box::new(BestResultSelector::default()))),
+            cache: None,
         }
     }
 }
 
 impl Recreate for RecreateWithCheapest {
     fn run(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
-        InsertionHeuristic::default().process(
-            &self.job_selector,
-            &self.job_reducer,
-            insertion_ctx,
-            &refinement_ctx.quota,
-        )
+        let heuristic = match &self.cache {
+            Some(cache) => InsertionHeuristic::new_with_cache(InsertionPosition::Any, cache.clone()),
+            None => InsertionHeuristic::default(),
+        };
+
+        heuristic.process(&self.job_selector, &self.job_reducer, insertion_ctx, &refinement_ctx.quota)
     }
 }
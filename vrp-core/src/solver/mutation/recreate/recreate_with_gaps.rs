@@ -1,11 +1,9 @@
-extern crate rand;
-
 use crate::construction::heuristics::InsertionContext;
 use crate::construction::heuristics::*;
 use crate::models::problem::Job;
 use crate::solver::mutation::recreate::Recreate;
 use crate::solver::RefinementContext;
-use rand::prelude::*;
+use crate::utils::shuffle;
 
 /// Returns a sub set of randomly selected jobs.
 struct GapsJobSelector {
@@ -15,7 +13,7 @@ struct GapsJobSelector {
 impl JobSelector for GapsJobSelector {
     fn select<'a>(&'a self, ctx: &'a mut InsertionContext) -> Box<dyn Iterator<Item = Job> + 'a> {
         // TODO we should prefer to always insert locked jobs
-        ctx.solution.required.shuffle(&mut rand::thread_rng());
+        shuffle(ctx.solution.required.as_mut_slice(), ctx.random.as_ref());
 
         // TODO improve formula
         let max_jobs = self.min_jobs.max(ctx.solution.required.len());
@@ -0,0 +1,132 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/recreate/recreate_with_priority_test.rs"]
+mod recreate_with_priority_test;
+
+use crate::construction::constraints::get_job_demand;
+use crate::construction::heuristics::*;
+use crate::models::common::ValueDimension;
+use crate::models::problem::{Job, Single};
+use crate::solver::mutation::recreate::Recreate;
+use crate::solver::RefinementContext;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+use std::ops::{Add, Sub};
+
+/// Specifies an order in which required jobs are processed by insertion heuristic.
+pub trait JobOrdering {
+    /// Compares two jobs: the lesser one is processed first.
+    fn compare(&self, ctx: &InsertionContext, left: &Job, right: &Job) -> Ordering;
+}
+
+/// Orders jobs by their priority (lesser value first), then by aging (jobs which were left
+/// unassigned more times in a row first), then by demand size (bigger first) and, finally,
+/// by time window tightness (jobs with less time flexibility first).
+pub struct PriorityJobOrdering<Capacity: Add + Sub + Ord + Copy + Default + Send + Sync + 'static> {
+    phantom: PhantomData<Capacity>,
+}
+
+impl<Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static> Default
+    for PriorityJobOrdering<Capacity>
+{
+    fn default() -> Self {
+        Self { phantom: PhantomData }
+    }
+}
+
+impl<Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static>
+    PriorityJobOrdering<Capacity>
+{
+    fn get_priority(job: &Job) -> i32 {
+        job.dimens().get_value::<i32>("priority").cloned().unwrap_or(1)
+    }
+
+    fn get_flexibility(job: &Job) -> f64 {
+        let get_single_flexibility = |single: &Single| {
+            single
+                .places
+                .iter()
+                .flat_map(|place| place.times.iter())
+                .map(|time| {
+                    let window = time.to_time_window(0.);
+                    window.end - window.start
+                })
+                .sum::<f64>()
+        };
+
+        match job {
+            Job::Single(job) => get_single_flexibility(job),
+            Job::Multi(job) => job.jobs.iter().map(|single| get_single_flexibility(single)).sum(),
+        }
+    }
+}
+
+impl<Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static>
+    JobOrdering for PriorityJobOrdering<Capacity>
+{
+    fn compare(&self, ctx: &InsertionContext, left: &Job, right: &Job) -> Ordering {
+        Self::get_priority(left)
+            .cmp(&Self::get_priority(right))
+            .then_with(|| get_job_age(&ctx.solution, right).cmp(&get_job_age(&ctx.solution, left)))
+            .then_with(|| get_job_demand::<Capacity>(right).cmp(&get_job_demand::<Capacity>(left)))
+            .then_with(|| compare_floats(Self::get_flexibility(left), Self::get_flexibility(right)))
+    }
+}
+
+/// Selects a limited amount of required jobs using given ordering.
+pub struct PriorityJobSelector {
+    ordering: Box<dyn JobOrdering + Send + Sync>,
+    batch_size: usize,
+}
+
+impl PriorityJobSelector {
+    /// Creates a new instance of `PriorityJobSelector` which returns at most `batch_size`
+    /// first jobs in given ordering.
+    pub fn new(ordering: Box<dyn JobOrdering + Send + Sync>, batch_size: usize) -> Self {
+        Self { ordering, batch_size: batch_size.max(1) }
+    }
+}
+
+impl JobSelector for PriorityJobSelector {
+    fn select<'a>(&'a self, ctx: &'a mut InsertionContext) -> Box<dyn Iterator<Item = Job> + 'a> {
+        let mut required = std::mem::take(&mut ctx.solution.required);
+        required.sort_by(|a, b| self.ordering.compare(ctx, a, b));
+        ctx.solution.required = required;
+
+        Box::new(ctx.solution.required.iter().take(self.batch_size).cloned())
+    }
+}
+
+/// A recreate method which inserts jobs in order of their importance: on each step, the cheapest
+/// insertion is chosen only among a few first jobs in given ordering.
+pub struct RecreateWithPriority {
+    job_selector: Box<dyn JobSelector + Send + Sync>,
+    job_reducer: Box<dyn JobMapReducer + Send + Sync>,
+}
+
+impl RecreateWithPriority {
+    /// Creates a new instance of `RecreateWithPriority`.
+    pub fn new(ordering: Box<dyn JobOrdering + Send + Sync>, batch_size: usize) -> Self {
+        Self {
+            job_selector: Box::new(PriorityJobSelector::new(ordering, batch_size)),
+            job_reducer: Box::new(PairJobMapReducer::new(Box::new(BestResultSelector::default()))),
+        }
+    }
+}
+
+impl Default for RecreateWithPriority {
+    fn default() -> Self {
+        Self::new(Box::new(PriorityJobOrdering::<i32>::default()), 3)
+    }
+}
+
+impl Recreate for RecreateWithPriority {
+    fn run(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        InsertionHeuristic::default().process(
+            &self.job_selector,
+            &self.job_reducer,
+            insertion_ctx,
+            &refinement_ctx.quota,
+        )
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/recreate/recreate_with_time_buckets_test.rs"]
+mod recreate_with_time_buckets_test;
+
+use super::JobOrdering;
+use crate::construction::heuristics::*;
+use crate::models::common::Timestamp;
+use crate::models::problem::{Job, Single};
+use crate::solver::mutation::recreate::Recreate;
+use crate::solver::RefinementContext;
+use std::cmp::Ordering;
+
+/// Orders jobs by time bucket of their earliest time window start. Jobs from the same bucket are
+/// considered equal, jobs without time windows are processed last.
+pub struct TimeBucketJobOrdering {
+    bucket_size: Timestamp,
+}
+
+impl TimeBucketJobOrdering {
+    /// Creates a new instance of `TimeBucketJobOrdering` with given bucket size in seconds.
+    pub fn new(bucket_size: Timestamp) -> Self {
+        Self { bucket_size: bucket_size.max(1.) }
+    }
+
+    /// Returns time bucket of job's earliest time window start or `None` if job has no time windows.
+    pub fn get_bucket(&self, job: &Job) -> Option<i64> {
+        let get_single_start = |single: &Single| {
+            single
+                .places
+                .iter()
+                .flat_map(|place| place.times.iter())
+                .map(|time| time.to_time_window(0.).start)
+                .fold(None, |acc: Option<Timestamp>, start| Some(acc.map_or(start, |acc| acc.min(start))))
+        };
+
+        let start = match job {
+            Job::Single(single) => get_single_start(single),
+            Job::Multi(multi) => multi
+                .jobs
+                .iter()
+                .filter_map(|single| get_single_start(single))
+                .fold(None, |acc: Option<Timestamp>, start| Some(acc.map_or(start, |acc| acc.min(start)))),
+        };
+
+        start.map(|start| (start / self.bucket_size).floor() as i64)
+    }
+}
+
+impl JobOrdering for TimeBucketJobOrdering {
+    fn compare(&self, _: &InsertionContext, left: &Job, right: &Job) -> Ordering {
+        match (self.get_bucket(left), self.get_bucket(right)) {
+            (Some(left), Some(right)) => left.cmp(&right),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
+
+/// Selects all required jobs from the earliest time bucket.
+pub struct TimeBucketJobSelector {
+    ordering: TimeBucketJobOrdering,
+}
+
+impl TimeBucketJobSelector {
+    /// Creates a new instance of `TimeBucketJobSelector` with given bucket size in seconds.
+    pub fn new(bucket_size: Timestamp) -> Self {
+        Self { ordering: TimeBucketJobOrdering::new(bucket_size) }
+    }
+}
+
+impl JobSelector for TimeBucketJobSelector {
+    fn select<'a>(&'a self, ctx: &'a mut InsertionContext) -> Box<dyn Iterator<Item = Job> + 'a> {
+        let mut required = std::mem::take(&mut ctx.solution.required);
+        // NOTE sort is stable, so jobs within the same bucket keep their relative order
+        required.sort_by(|a, b| self.ordering.compare(ctx, a, b));
+        ctx.solution.required = required;
+
+        let first_bucket = ctx.solution.required.first().map(|job| self.ordering.get_bucket(job));
+
+        Box::new(
+            ctx.solution
+                .required
+                .iter()
+                .take_while(move |job| Some(self.ordering.get_bucket(job)) == first_bucket)
+                .cloned(),
+        )
+    }
+}
+
+/// A recreate method which inserts jobs in order of their earliest time window start: on each step,
+/// the cheapest insertion is chosen only among jobs from the earliest time bucket. It improves
+/// feasibility on instances where most jobs have narrow time windows.
+pub struct RecreateWithTimeBuckets {
+    job_selector: Box<dyn JobSelector + Send + Sync>,
+    job_reducer: Box<dyn JobMapReducer + Send + Sync>,
+}
+
+impl RecreateWithTimeBuckets {
+    /// Creates a new instance of `RecreateWithTimeBuckets` with given bucket size in seconds.
+    pub fn new(bucket_size: Timestamp) -> Self {
+        Self {
+            job_selector: Box::new(TimeBucketJobSelector::new(bucket_size)),
+            job_reducer: Box::new(PairJobMapReducer::new(Box::new(BestResultSelector::default()))),
+        }
+    }
+}
+
+impl Default for RecreateWithTimeBuckets {
+    fn default() -> Self {
+        Self::new(3600.)
+    }
+}
+
+impl Recreate for RecreateWithTimeBuckets {
+    fn run(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        InsertionHeuristic::default().process(
+            &self.job_selector,
+            &self.job_reducer,
+            insertion_ctx,
+            &refinement_ctx.quota,
+        )
+    }
+}
@@ -2,15 +2,15 @@
 #[path = "../../../../tests/unit/solver/mutation/ruin/adjusted_string_removal_test.rs"]
 mod adjusted_string_removal_test;
 
-use std::sync::{Arc, RwLock};
+use std::sync::Arc;
 
 use super::{select_seed_jobs, Ruin};
 use crate::construction::heuristics::{InsertionContext, RouteContext};
-use crate::models::problem::{Actor, Job};
+use crate::models::problem::Job;
 use crate::models::solution::Tour;
+use crate::solver::mutation::trace::{get_active_trace, TraceEvent};
 use crate::solver::RefinementContext;
 use crate::utils::Random;
-use hashbrown::HashSet;
 
 /// "Adjusted string removal" ruin strategy based on "Slack Induction by String Removals for
 /// Vehicle Routing Problems" (aka SISR) by Jan Christiaens, Greet Vanden Berghe.
@@ -54,10 +54,9 @@ impl Default for AdjustedStringRemoval {
 }
 
 impl Ruin for AdjustedStringRemoval {
-    fn run(&self, _refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+    fn run(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
         let mut insertion_ctx = insertion_ctx;
-        let jobs: RwLock<HashSet<Job>> = RwLock::new(HashSet::new());
-        let actors: RwLock<HashSet<Arc<Actor>>> = RwLock::new(HashSet::new());
+        let (trace, generation) = (get_active_trace(refinement_ctx), refinement_ctx.generation);
         let routes: Vec<RouteContext> = insertion_ctx.solution.routes.clone();
 
         let problem = insertion_ctx.problem.clone();
@@ -66,36 +65,56 @@ impl Ruin for AdjustedStringRemoval {
 
         let (lsmax, ks) = self.calculate_limits(&routes, &random);
 
-        select_seed_jobs(&problem, &routes, &random)
-            .filter(|job| !jobs.read().unwrap().contains(job))
-            .take_while(|_| actors.read().unwrap().len() != ks)
-            .for_each(|job| {
-                insertion_ctx
-                    .solution
-                    .routes
-                    .iter_mut()
-                    .find(|rc| !actors.read().unwrap().contains(&rc.route.actor) && rc.route.tour.index(&job).is_some())
-                    .iter_mut()
-                    .for_each(|rc| {
-                        // Equations 8, 9: calculate cardinality of the string removed from the tour
-                        let ltmax = rc.route.tour.activity_count().min(lsmax);
-                        let lt = random.uniform_real(1.0, ltmax as f64 + 1.).floor() as usize;
-
-                        if let Some(index) = rc.route.tour.index(&job) {
-                            actors.write().unwrap().insert(rc.route.actor.clone());
-                            select_string((&rc.route.tour, index), lt, self.alpha, &random)
-                                .filter(|job| !locked.contains(job))
-                                .collect::<Vec<Job>>()
-                                .into_iter()
-                                .for_each(|job| {
-                                    rc.route_mut().tour.remove(&job);
-                                    jobs.write().unwrap().insert(job);
-                                });
-                        }
-                    });
-            });
-
-        jobs.write().unwrap().iter().for_each(|job| insertion_ctx.solution.required.push(job.clone()));
+        // NOTE select seed job per tour first as seeds are searched in tours before any string is removed
+        let mut seeds: Vec<(usize, Job)> = vec![];
+        for job in select_seed_jobs(&problem, &routes, &random) {
+            if seeds.len() == ks {
+                break;
+            }
+
+            if let Some(route_idx) = routes.iter().position(|rc| rc.route.tour.index(&job).is_some()) {
+                if seeds.iter().all(|(idx, _)| *idx != route_idx) {
+                    seeds.push((route_idx, job));
+                }
+            }
+        }
+
+        let removed = insertion_ctx
+            .solution
+            .routes
+            .iter_mut()
+            .enumerate()
+            .flat_map(|(route_idx, rc)| {
+                let job = match seeds.iter().find(|(idx, _)| *idx == route_idx) {
+                    Some((_, job)) => job,
+                    None => return vec![],
+                };
+
+                // Equations 8, 9: calculate cardinality of the string removed from the tour
+                let ltmax = rc.route.tour.activity_count().min(lsmax);
+                let lt = random.uniform_real(1.0, ltmax as f64 + 1.).floor() as usize;
+
+                if let Some(trace) = trace.as_ref() {
+                    let event =
+                        TraceEvent::StringSelected { anchor: job.clone(), actor: rc.route.actor.clone(), length: lt };
+                    trace.record(generation, event);
+                }
+
+                let removed = rc.route.tour.index(job).map_or_else(Vec::new, |index| {
+                    select_string((&rc.route.tour, index), lt, self.alpha, &random)
+                        .filter(|job| !locked.contains(job))
+                        .collect::<Vec<_>>()
+                });
+
+                removed.iter().for_each(|job| {
+                    rc.route_mut().tour.remove(job);
+                });
+
+                removed
+            })
+            .collect::<Vec<_>>();
+
+        insertion_ctx.solution.required.extend(removed);
 
         insertion_ctx
     }
@@ -1,8 +1,10 @@
 //! A various strategies to destroy parts of an existing solution.
 
 use crate::construction::heuristics::{InsertionContext, RouteContext};
+use crate::models::common::UNBOUNDED_COST;
 use crate::models::problem::Job;
 use crate::models::Problem;
+use crate::solver::mutation::get_operator_usage;
 use crate::solver::RefinementContext;
 use crate::utils::Random;
 use std::iter::{empty, once};
@@ -31,7 +33,7 @@ pub use self::worst_jobs_removal::WorstJobRemoval;
 
 /// Provides the way to run multiple ruin methods one by one on the same solution.
 pub struct CompositeRuin {
-    ruins: Vec<Vec<(Arc<dyn Ruin>, f64)>>,
+    ruins: Vec<Vec<(Arc<dyn Ruin + Send + Sync>, f64)>>,
     weights: Vec<usize>,
 }
 
@@ -70,7 +72,7 @@ impl Default for CompositeRuin {
 }
 
 impl CompositeRuin {
-    pub fn new(ruins: Vec<(Vec<(Arc<dyn Ruin>, f64)>, usize)>) -> Self {
+    pub fn new(ruins: Vec<(Vec<(Arc<dyn Ruin + Send + Sync>, f64)>, usize)>) -> Self {
         let weights = ruins.iter().map(|(_, weight)| *weight).collect();
         let ruins = ruins.into_iter().map(|(ruin, _)| ruin).collect();
 
@@ -88,6 +90,10 @@ impl Ruin for CompositeRuin {
 
         let index = insertion_ctx.random.weighted(self.weights.as_slice());
 
+        if let Some(usage) = get_operator_usage(refinement_ctx) {
+            usage.record_ruin(index);
+        }
+
         let mut insertion_ctx = self
             .ruins
             .get(index)
@@ -125,7 +131,7 @@ fn select_seed_jobs<'a>(
             routes.get(route_index).unwrap().route.actor.vehicle.profile,
             &job,
             Default::default(),
-            std::f64::MAX,
+            UNBOUNDED_COST,
         )));
     }
 
@@ -2,17 +2,14 @@
 #[path = "../../../../tests/unit/solver/mutation/ruin/worst_jobs_removal_test.rs"]
 mod worst_jobs_removal_test;
 
-extern crate rand;
-
 use super::Ruin;
 use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
-use crate::models::common::Cost;
+use crate::models::common::{Cost, UNBOUNDED_COST};
 use crate::models::problem::{Actor, Job, TransportCost};
 use crate::models::solution::TourActivity;
 use crate::solver::RefinementContext;
-use crate::utils::parallel_collect;
-use hashbrown::{HashMap, HashSet};
-use rand::prelude::*;
+use crate::utils::{parallel_collect, shuffle};
+use hashbrown::HashMap;
 use std::cmp::Ordering::Less;
 use std::iter::once;
 use std::sync::{Arc, RwLock};
@@ -50,9 +47,9 @@ impl Ruin for WorstJobRemoval {
 
         let mut route_jobs = get_route_jobs(&insertion_ctx.solution);
         let mut routes_savings = get_routes_cost_savings(&insertion_ctx);
-        let removed_jobs: RwLock<HashSet<Job>> = RwLock::new(HashSet::default());
+        let removed_jobs: RwLock<Vec<Job>> = RwLock::new(Vec::default());
 
-        routes_savings.shuffle(&mut rand::thread_rng());
+        shuffle(routes_savings.as_mut_slice(), random.as_ref());
 
         routes_savings.iter().take_while(|_| removed_jobs.read().unwrap().len() <= self.threshold).for_each(
             |(rc, savings)| {
@@ -66,7 +63,7 @@ impl Ruin for WorstJobRemoval {
                             rc.route.actor.vehicle.profile,
                             &job,
                             Default::default(),
-                            std::f64::MAX,
+                            UNBOUNDED_COST,
                         ))
                         .filter(|job| can_remove_job(job))
                         .take(remove)
@@ -75,7 +72,7 @@ impl Ruin for WorstJobRemoval {
                             if let Some(rc) = route_jobs.get_mut(&job) {
                                 // NOTE actual insertion context modification via route mut
                                 if rc.route_mut().tour.remove(&job) {
-                                    removed_jobs.write().unwrap().insert(job);
+                                    removed_jobs.write().unwrap().push(job);
                                 }
                             }
                         });
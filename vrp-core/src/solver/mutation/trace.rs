@@ -0,0 +1,133 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/mutation/trace_test.rs"]
+mod trace_test;
+
+use crate::construction::heuristics::InsertionContext;
+use crate::models::common::IdDimension;
+use crate::models::problem::{Actor, Job};
+use crate::solver::RefinementContext;
+use hashbrown::HashMap;
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+
+/// A key used to keep operator trace in refinement context state.
+pub const OPERATOR_TRACE_KEY: &str = "operator_trace";
+
+/// Specifies a single decision made by mutation operators.
+#[derive(Clone)]
+pub enum TraceEvent {
+    /// A job was removed by ruin from the tour of given actor.
+    Ruined { job: Job, actor: Arc<Actor> },
+    /// A string of given length around anchor job was selected by string removal ruin in the tour
+    /// of given actor.
+    StringSelected { anchor: Job, actor: Arc<Actor>, length: usize },
+    /// A job was inserted by recreate into the tour of given actor at given activity index.
+    Inserted { job: Job, actor: Arc<Actor>, index: usize },
+    /// A job was left unassigned by recreate with given reason code.
+    Unassigned { job: Job, code: i32 },
+}
+
+/// Records ruin and recreate decisions made within given range of generations in a bounded
+/// buffer: when capacity is reached, oldest events are dropped. Recording happens only in debug
+/// builds, so it has no overhead in release ones.
+pub struct OperatorTrace {
+    generations: Range<usize>,
+    capacity: usize,
+    events: Mutex<VecDeque<(usize, TraceEvent)>>,
+}
+
+impl OperatorTrace {
+    /// Creates a new instance of `OperatorTrace`.
+    pub fn new(generations: Range<usize>, capacity: usize) -> Self {
+        Self { generations, capacity, events: Mutex::new(VecDeque::with_capacity(capacity)) }
+    }
+
+    /// Checks whether events of given generation should be recorded.
+    pub fn is_active(&self, generation: usize) -> bool {
+        cfg!(debug_assertions) && self.capacity > 0 && self.generations.contains(&generation)
+    }
+
+    /// Records event for given generation.
+    pub fn record(&self, generation: usize, event: TraceEvent) {
+        if !self.is_active(generation) {
+            return;
+        }
+
+        let mut events = self.events.lock().unwrap();
+        if events.len() == self.capacity {
+            events.pop_front();
+        }
+        events.push_back((generation, event));
+    }
+
+    /// Returns recorded events.
+    pub fn events(&self) -> Vec<(usize, TraceEvent)> {
+        self.events.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Returns recorded events in human readable form, one line per event.
+    pub fn dump(&self) -> Vec<String> {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(generation, event)| match event {
+                TraceEvent::Ruined { job, actor } => {
+                    format!("[{}] ruined '{}' from '{}'", generation, get_job_id(job), get_actor_id(actor))
+                }
+                TraceEvent::StringSelected { anchor, actor, length } => format!(
+                    "[{}] selected string of {} around '{}' in '{}'",
+                    generation,
+                    length,
+                    get_job_id(anchor),
+                    get_actor_id(actor)
+                ),
+                TraceEvent::Inserted { job, actor, index } => format!(
+                    "[{}] inserted '{}' into '{}' at {}",
+                    generation,
+                    get_job_id(job),
+                    get_actor_id(actor),
+                    index
+                ),
+                TraceEvent::Unassigned { job, code } => {
+                    format!("[{}] unassigned '{}' with code {}", generation, get_job_id(job), code)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns operator trace from refinement context if it is active for current generation.
+pub(crate) fn get_active_trace(refinement_ctx: &RefinementContext) -> Option<Arc<OperatorTrace>> {
+    refinement_ctx
+        .state
+        .get(OPERATOR_TRACE_KEY)
+        .and_then(|trace| trace.downcast_ref::<Arc<OperatorTrace>>())
+        .filter(|trace| trace.is_active(refinement_ctx.generation))
+        .cloned()
+}
+
+/// Returns assigned jobs within their actors and activity indices.
+pub(crate) fn get_assignments(insertion_ctx: &InsertionContext) -> HashMap<Job, (Arc<Actor>, usize)> {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| {
+            let route = route_ctx.route.clone();
+            route.tour.jobs().collect::<Vec<_>>().into_iter().map(move |job| {
+                let index = route.tour.index(&job).unwrap_or(0);
+                (job, (route.actor.clone(), index))
+            })
+        })
+        .collect()
+}
+
+fn get_job_id(job: &Job) -> String {
+    job.dimens().get_id().cloned().unwrap_or_else(|| "<unknown>".to_string())
+}
+
+fn get_actor_id(actor: &Actor) -> String {
+    actor.vehicle.dimens.get_id().cloned().unwrap_or_else(|| "<unknown>".to_string())
+}
@@ -0,0 +1,48 @@
+use crate::solver::RefinementContext;
+use std::sync::{Arc, Mutex};
+
+/// A key used to keep operator usage in refinement context state.
+pub const OPERATOR_USAGE_KEY: &str = "operator_usage";
+
+/// Counts how many times each ruin group and recreate method is selected by composite operators.
+/// Operators are identified by their index in the order they are specified in composite ones.
+#[derive(Default)]
+pub struct OperatorUsage {
+    ruins: Mutex<Vec<usize>>,
+    recreates: Mutex<Vec<usize>>,
+}
+
+impl OperatorUsage {
+    /// Records usage of ruin group with given index.
+    pub fn record_ruin(&self, index: usize) {
+        increment(&self.ruins, index);
+    }
+
+    /// Records usage of recreate method with given index.
+    pub fn record_recreate(&self, index: usize) {
+        increment(&self.recreates, index);
+    }
+
+    /// Returns usage counts of ruin groups.
+    pub fn ruins(&self) -> Vec<usize> {
+        self.ruins.lock().unwrap().clone()
+    }
+
+    /// Returns usage counts of recreate methods.
+    pub fn recreates(&self) -> Vec<usize> {
+        self.recreates.lock().unwrap().clone()
+    }
+}
+
+/// Returns operator usage from refinement context if it is tracked.
+pub(crate) fn get_operator_usage(refinement_ctx: &RefinementContext) -> Option<Arc<OperatorUsage>> {
+    refinement_ctx.state.get(OPERATOR_USAGE_KEY).and_then(|usage| usage.downcast_ref::<Arc<OperatorUsage>>()).cloned()
+}
+
+fn increment(counts: &Mutex<Vec<usize>>, index: usize) {
+    let mut counts = counts.lock().unwrap();
+    if counts.len() <= index {
+        counts.resize(index + 1, 0);
+    }
+    counts[index] += 1;
+}
@@ -8,6 +8,12 @@ pub const BALANCE_ACTIVITY_KEY: i32 = 21;
 pub const BALANCE_DISTANCE_KEY: i32 = 22;
 pub const BALANCE_DURATION_KEY: i32 = 23;
 
+mod plan_stability;
+pub use self::plan_stability::{get_plan_deviation, PlanStability};
+
+mod route_memo;
+pub use self::route_memo::RouteMemo;
+
 mod total_routes;
 pub use self::total_routes::TotalRoutes;
 
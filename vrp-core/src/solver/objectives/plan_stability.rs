@@ -0,0 +1,119 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/plan_stability_test.rs"]
+mod plan_stability_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
+use crate::models::common::{Cost, Objective};
+use crate::models::problem::{Job, TargetConstraint, TargetObjective, Vehicle};
+use hashbrown::HashMap;
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// Provides functionality needed to keep a new plan close to a previous one: each job which was
+/// served by some vehicle in the previous plan should preferably stay with the same vehicle.
+pub struct PlanStability {}
+
+impl PlanStability {
+    /// Creates a module and objective which penalize deviation from the previous plan: a job
+    /// moved to a different vehicle or left unassigned. A `weight` is an extra insertion cost
+    /// added when a job is moved to a different vehicle.
+    pub fn new_minimized(previous: HashMap<Job, Arc<Vehicle>>, weight: Cost) -> (TargetConstraint, TargetObjective) {
+        let previous = Arc::new(previous);
+
+        let constraint = PlanStabilityModule {
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(PlanStabilitySoftRouteConstraint {
+                previous: previous.clone(),
+                weight,
+            }))],
+            keys: vec![],
+        };
+
+        let objective = PlanStabilityObjective { previous };
+
+        (Box::new(constraint), Box::new(objective))
+    }
+}
+
+/// Returns amount of jobs from the previous plan which are not served by the same vehicle
+/// in the given solution: moved to a different vehicle or left unassigned.
+pub fn get_plan_deviation(previous: &HashMap<Job, Arc<Vehicle>>, solution_ctx: &SolutionContext) -> usize {
+    let kept = solution_ctx
+        .routes
+        .iter()
+        .flat_map(|route_ctx| {
+            let vehicle = &route_ctx.route.actor.vehicle;
+            route_ctx.route.tour.jobs().filter(move |job| is_same_vehicle(previous, job, vehicle))
+        })
+        .count();
+
+    previous.len() - kept
+}
+
+fn is_same_vehicle(previous: &HashMap<Job, Arc<Vehicle>>, job: &Job, vehicle: &Arc<Vehicle>) -> bool {
+    previous.get(job).map_or(false, |previous| Arc::ptr_eq(previous, vehicle))
+}
+
+struct PlanStabilityModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for PlanStabilityModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_ctx: &mut RouteContext, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+
+    fn is_cacheable(&self) -> bool {
+        true
+    }
+}
+
+struct PlanStabilitySoftRouteConstraint {
+    previous: Arc<HashMap<Job, Arc<Vehicle>>>,
+    weight: Cost,
+}
+
+impl SoftRouteConstraint for PlanStabilitySoftRouteConstraint {
+    fn estimate_job(&self, _solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> Cost {
+        if self.previous.contains_key(job) && !is_same_vehicle(&self.previous, job, &route_ctx.route.actor.vehicle) {
+            self.weight
+        } else {
+            0.
+        }
+    }
+}
+
+struct PlanStabilityObjective {
+    previous: Arc<HashMap<Job, Arc<Vehicle>>>,
+}
+
+impl Objective for PlanStabilityObjective {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        let fitness_a = get_plan_deviation(&self.previous, &a.solution);
+        let fitness_b = get_plan_deviation(&self.previous, &b.solution);
+
+        fitness_a.cmp(&fitness_b)
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        get_plan_deviation(&self.previous, &solution.solution) as f64
+    }
+}
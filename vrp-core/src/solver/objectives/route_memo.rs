@@ -0,0 +1,46 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/route_memo_test.rs"]
+mod route_memo_test;
+
+use crate::construction::heuristics::RouteContext;
+use hashbrown::HashMap;
+use std::sync::RwLock;
+
+/// Memoizes per-route values which are calculated by scanning route activities, e.g. max load ratio
+/// of load balance objective, keyed by tour version, so solution-level objective evaluation
+/// recomputes them only for routes modified since the last evaluation. A value is expected to
+/// depend only on the route's activities and their states.
+///
+/// Route cost and other route summaries, e.g. total distance and duration, are not memoized: they
+/// are already kept in route state by constraint modules, so reading them is as cheap as a lookup.
+pub struct RouteMemo<T: Clone + Send + Sync> {
+    capacity: usize,
+    entries: RwLock<HashMap<usize, T>>,
+}
+
+impl<T: Clone + Send + Sync> RouteMemo<T> {
+    /// Creates a new instance of `RouteMemo` which keeps at most `capacity` values.
+    /// Once capacity is reached, all values are dropped.
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, entries: RwLock::new(HashMap::new()) }
+    }
+
+    /// Returns memoized value for given route or calculates and stores a new one.
+    pub fn get_or_calculate<F: FnOnce(&RouteContext) -> T>(&self, route_ctx: &RouteContext, func: F) -> T {
+        let version = route_ctx.route.tour.version();
+
+        if let Some(value) = self.entries.read().unwrap().get(&version) {
+            return value.clone();
+        }
+
+        let value = func(route_ctx);
+
+        let mut entries = self.entries.write().unwrap();
+        if entries.len() >= self.capacity {
+            entries.clear();
+        }
+        entries.insert(version, value.clone());
+
+        value
+    }
+}
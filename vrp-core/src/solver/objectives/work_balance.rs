@@ -3,7 +3,7 @@ use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionCo
 use crate::models::common::Objective;
 use crate::models::problem::{Job, TargetConstraint, TargetObjective};
 use crate::solver::objectives::{
-    BALANCE_ACTIVITY_KEY, BALANCE_DISTANCE_KEY, BALANCE_DURATION_KEY, BALANCE_MAX_LOAD_KEY,
+    RouteMemo, BALANCE_ACTIVITY_KEY, BALANCE_DISTANCE_KEY, BALANCE_DURATION_KEY, BALANCE_MAX_LOAD_KEY,
 };
 use crate::utils::{compare_floats, get_cv, get_mean};
 use std::cmp::Ordering;
@@ -12,6 +12,9 @@ use std::ops::{Add, Deref, Sub};
 use std::slice::Iter;
 use std::sync::Arc;
 
+/// Specifies max amount of memoized per route values.
+const MEMO_CAPACITY: usize = 10000;
+
 /// Provides functionality needed to balance work across all routes.
 pub struct WorkBalance {}
 
@@ -52,7 +55,10 @@ impl WorkBalance {
         });
         let values_func = Arc::new({
             let get_load_ratio = get_load_ratio.clone();
-            move |ctx: &SolutionContext| ctx.routes.iter().map(|rc| get_load_ratio(rc)).collect()
+            let memo = RouteMemo::new(MEMO_CAPACITY);
+            move |ctx: &SolutionContext| {
+                ctx.routes.iter().map(|rc| memo.get_or_calculate(rc, |rc| get_load_ratio(rc))).collect()
+            }
         });
 
         let objective = WorkBalanceObjectives {
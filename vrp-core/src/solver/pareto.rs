@@ -0,0 +1,158 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/pareto_test.rs"]
+mod pareto_test;
+
+use crate::construction::heuristics::InsertionContext;
+use crate::models::common::{Cost, MultiObjective, Objective};
+use crate::models::problem::dominance_order;
+use crate::models::Solution;
+use crate::solver::population::{assign_crowding_distance, non_dominated_sort};
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+use std::sync::{Arc, Mutex};
+
+/// Objective values of the solution used to compare solutions in pareto archive.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParetoPoint {
+    /// Total cost of all tours.
+    pub cost: Cost,
+    /// Amount of tours (used vehicles).
+    pub tours: usize,
+    /// Amount of unassigned jobs.
+    pub unassigned: usize,
+}
+
+/// Keeps non-dominated solutions found during the whole run over total cost, amount of tours and
+/// amount of unassigned jobs. It lets a planner pick a trade-off between these objectives instead
+/// of relying on the fixed objective hierarchy which is used to select the best solution.
+pub struct ParetoArchive {
+    capacity: usize,
+    solutions: Mutex<Vec<(ParetoPoint, Arc<Solution>)>>,
+}
+
+impl ParetoArchive {
+    /// Creates a new instance of `ParetoArchive` which keeps at most `capacity` solutions.
+    /// When capacity is exceeded, the solution from the most crowded region of the front is dropped.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0);
+
+        Self { capacity, solutions: Mutex::new(vec![]) }
+    }
+
+    /// Records solution if it is not dominated by any solution in the archive.
+    pub fn record(&self, insertion_ctx: &InsertionContext) {
+        let point = ParetoPoint {
+            cost: insertion_ctx.solution.get_total_cost(),
+            tours: insertion_ctx.solution.routes.len(),
+            unassigned: insertion_ctx.solution.unassigned.len(),
+        };
+
+        let mut solutions = self.solutions.lock().unwrap();
+
+        if solutions.iter().any(|(other, _)| is_dominated_or_equal(&point, other)) {
+            return;
+        }
+
+        solutions.retain(|(other, _)| !is_dominated_or_equal(other, &point));
+        solutions.push((point, Arc::new(insertion_ctx.solution.to_solution(insertion_ctx.problem.extras.clone()))));
+
+        while solutions.len() > self.capacity {
+            remove_most_crowded(&mut solutions);
+        }
+
+        solutions.sort_by(|(a, _), (b, _)| compare_floats(a.cost, b.cost));
+    }
+
+    /// Returns non-dominated solutions sorted by cost.
+    pub fn solutions(&self) -> Vec<(ParetoPoint, Arc<Solution>)> {
+        self.solutions.lock().unwrap().clone()
+    }
+}
+
+/// Checks whether `point` is not better than `other` in any objective.
+fn is_dominated_or_equal(point: &ParetoPoint, other: &ParetoPoint) -> bool {
+    compare_floats(other.cost, point.cost) != Ordering::Greater
+        && other.tours <= point.tours
+        && other.unassigned <= point.unassigned
+}
+
+/// Removes the solution with the smallest crowding distance, so the remaining ones stay spread along
+/// all objectives. Ties are resolved by removing the solution with the highest cost.
+fn remove_most_crowded(solutions: &mut Vec<(ParetoPoint, Arc<Solution>)>) {
+    let points = solutions.iter().map(|(point, _)| point.clone()).collect::<Vec<_>>();
+    let objective = ParetoObjective::default();
+
+    let front = non_dominated_sort(points.as_slice(), &objective);
+    let (assigned, _) = assign_crowding_distance(&front, &objective);
+
+    let idx = assigned
+        .iter()
+        .min_by(|a, b| {
+            compare_floats(a.crowding_distance, b.crowding_distance)
+                .then_with(|| compare_floats(b.solution.cost, a.solution.cost))
+        })
+        .map(|assigned| assigned.index)
+        .unwrap();
+
+    solutions.remove(idx);
+}
+
+type PointObjective = Box<dyn Objective<Solution = ParetoPoint> + Send + Sync>;
+
+/// A multi objective over total cost, amount of tours and amount of unassigned jobs.
+struct ParetoObjective {
+    objectives: Vec<PointObjective>,
+}
+
+impl Default for ParetoObjective {
+    fn default() -> Self {
+        Self {
+            objectives: vec![
+                Box::new(PointValue(|point| point.cost)),
+                Box::new(PointValue(|point| point.tours as f64)),
+                Box::new(PointValue(|point| point.unassigned as f64)),
+            ],
+        }
+    }
+}
+
+impl Objective for ParetoObjective {
+    type Solution = ParetoPoint;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        dominance_order(a, b, &self.objectives)
+    }
+
+    fn distance(&self, _a: &Self::Solution, _b: &Self::Solution) -> f64 {
+        unreachable!()
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.cost
+    }
+}
+
+impl MultiObjective for ParetoObjective {
+    fn objectives<'a>(&'a self) -> Box<dyn Iterator<Item = &'a PointObjective> + 'a> {
+        Box::new(self.objectives.iter())
+    }
+}
+
+/// A single objective which minimizes given value of the point.
+struct PointValue(fn(&ParetoPoint) -> f64);
+
+impl Objective for PointValue {
+    type Solution = ParetoPoint;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        (self.0)(solution)
+    }
+}
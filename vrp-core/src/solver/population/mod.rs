@@ -15,17 +15,18 @@
 mod population_test;
 
 use crate::models::common::Objective;
+use crate::models::solution::create_solution_signature;
 use crate::models::Problem;
 use crate::solver::{Individual, Population};
-use crate::utils::{compare_floats, Random};
-use std::cmp::Ordering::Equal;
+use crate::utils::Random;
 use std::sync::Arc;
 
 mod crowding_distance;
+pub(crate) use self::crowding_distance::assign_crowding_distance;
 use self::crowding_distance::*;
 
 mod non_dominated_sort;
-use self::non_dominated_sort::*;
+pub(crate) use self::non_dominated_sort::non_dominated_sort;
 
 mod nsga2;
 use self::nsga2::select_and_rank;
@@ -82,12 +83,8 @@ impl Population for DominancePopulation {
                 .iter()
                 .enumerate()
                 .map(|(idx, acd)| {
-                    (
-                        idx,
-                        acd.index,
-                        acd.crowding_distance,
-                        self.problem.objective.fitness(self.individuals.get(acd.index).unwrap()),
-                    )
+                    let individual = self.individuals.get(acd.index).unwrap();
+                    (idx, acd.index, get_individual_signature(individual), self.problem.objective.fitness(individual))
                 })
                 .collect::<Vec<_>>();
 
@@ -108,9 +105,8 @@ impl Population for DominancePopulation {
         best_order.sort_by(|a, b| a.0.cmp(&b.0));
 
         // deduplicate best order
-        best_order.dedup_by(|(_, _, a_cd, a_cost), (_, _, b_cd, b_cost)| {
-            compare_floats(*a_cd, *b_cd) == Equal && compare_floats(*a_cost, *b_cost) == Equal
-        });
+        let mut seen = HashSet::new();
+        best_order.retain(|(_, _, signature, cost)| seen.insert((*signature, cost.to_bits())));
 
         // deduplicate population
         let indices = best_order.iter().map(|i| i.0).collect::<HashSet<_>>();
@@ -143,3 +139,10 @@ impl Population for DominancePopulation {
         self.individuals.len()
     }
 }
+
+fn get_individual_signature(individual: &Individual) -> u64 {
+    create_solution_signature(
+        individual.solution.routes.iter().map(|route_ctx| route_ctx.route.as_ref()),
+        individual.solution.unassigned.keys(),
+    )
+}
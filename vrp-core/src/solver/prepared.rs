@@ -0,0 +1,231 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/prepared_test.rs"]
+mod prepared_test;
+
+use crate::construction::constraints::{get_job_demand, TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
+use crate::construction::heuristics::{InsertionContext, RouteContext};
+use crate::models::common::{Cost, Distance, Duration};
+use crate::models::problem::{Actor, Fleet, Job, Vehicle};
+use crate::models::{Problem, Solution};
+use crate::solver::Builder;
+use crate::utils::Random;
+use hashbrown::HashSet;
+use std::ops::{Add, Sub};
+use std::sync::Arc;
+
+/// A problem with expensive preparation (job index with neighbour lists, constraint pipeline,
+/// transport costs) done once. It can be solved many times with different solver configurations,
+/// sequentially or concurrently, without preparing it again.
+#[derive(Clone)]
+pub struct PreparedProblem {
+    problem: Arc<Problem>,
+    fleet: Arc<Fleet>,
+    unavailable: Vec<Arc<Vehicle>>,
+}
+
+/// Specifies changes of the prepared problem.
+#[derive(Clone, Default)]
+pub struct ProblemPatch {
+    /// Jobs to be added.
+    pub added_jobs: Vec<Job>,
+    /// Jobs to be removed.
+    pub removed_jobs: Vec<Job>,
+    /// Vehicles which are not available anymore.
+    pub unavailable_vehicles: Vec<Arc<Vehicle>>,
+    /// Previously unavailable vehicles which are available again.
+    pub available_vehicles: Vec<Arc<Vehicle>>,
+}
+
+/// Contains information about resources freed by cancelling an assigned job.
+pub struct JobCancellation<Capacity: Add + Sub + Ord + Copy + Default + Send + Sync + 'static> {
+    /// An actor which was supposed to serve the job.
+    pub actor: Arc<Actor>,
+    /// Freed vehicle capacity which is defined by the job's demand.
+    pub demand: Capacity,
+    /// Freed route duration.
+    pub duration: Duration,
+    /// Freed route distance.
+    pub distance: Distance,
+    /// Freed cost.
+    pub cost: Cost,
+}
+
+impl PreparedProblem {
+    /// Creates a new instance of `PreparedProblem`.
+    pub fn new(problem: Problem) -> Self {
+        Self::from(Arc::new(problem))
+    }
+
+    /// Returns prepared problem.
+    pub fn problem(&self) -> Arc<Problem> {
+        self.problem.clone()
+    }
+
+    /// Solves prepared problem using solver created by given builder.
+    /// A problem specified in the builder is replaced by the prepared one.
+    pub fn solve(&self, builder: Builder) -> Result<(Solution, Cost), String> {
+        builder.with_problem(self.problem.clone()).build()?.solve()
+    }
+
+    /// Applies changes to the prepared problem without preparing it from scratch: job index is
+    /// updated incrementally, other parts of the problem are reused. Jobs and vehicles are matched
+    /// by reference, so they should be taken from the problem. Constraints which keep a list
+    /// of jobs or vehicles are not updated.
+    pub fn apply_patch(&mut self, patch: ProblemPatch) -> Result<(), String> {
+        let problem = self.problem.as_ref();
+
+        let existing = problem.jobs.all().collect::<HashSet<_>>();
+        if patch.removed_jobs.iter().any(|job| !existing.contains(job)) {
+            return Err("cannot remove job which is not present in the problem".to_string());
+        }
+        if patch.added_jobs.iter().any(|job| existing.contains(job)) {
+            return Err("cannot add job which is already present in the problem".to_string());
+        }
+
+        let mut locked =
+            problem.locks.iter().flat_map(|lock| lock.details.iter()).flat_map(|detail| detail.jobs.iter());
+        if locked.any(|job| patch.removed_jobs.contains(job)) {
+            return Err("cannot remove locked job".to_string());
+        }
+
+        let is_known = |vehicle: &Arc<Vehicle>| self.fleet.vehicles.iter().any(|v| Arc::ptr_eq(v, vehicle));
+        if !patch.unavailable_vehicles.iter().chain(patch.available_vehicles.iter()).all(is_known) {
+            return Err("cannot change availability of vehicle which is not present in the problem".to_string());
+        }
+
+        let unavailable = self
+            .unavailable
+            .iter()
+            .chain(patch.unavailable_vehicles.iter())
+            .filter(|vehicle| !patch.available_vehicles.iter().any(|v| Arc::ptr_eq(v, vehicle)))
+            .fold(Vec::<Arc<Vehicle>>::new(), |mut acc, vehicle| {
+                if !acc.iter().any(|v| Arc::ptr_eq(v, vehicle)) {
+                    acc.push(vehicle.clone());
+                }
+                acc
+            });
+
+        let fleet = if patch.unavailable_vehicles.is_empty() && patch.available_vehicles.is_empty() {
+            problem.fleet.clone()
+        } else {
+            Arc::new(create_available_fleet(self.fleet.as_ref(), unavailable.as_slice())?)
+        };
+
+        let jobs = if patch.added_jobs.is_empty() && patch.removed_jobs.is_empty() {
+            problem.jobs.clone()
+        } else {
+            Arc::new(problem.jobs.patch(
+                self.fleet.as_ref(),
+                patch.added_jobs,
+                patch.removed_jobs.as_slice(),
+                &problem.transport,
+            ))
+        };
+
+        self.problem = Arc::new(Problem {
+            fleet,
+            jobs,
+            locks: problem.locks.clone(),
+            constraint: problem.constraint.clone(),
+            activity: problem.activity.clone(),
+            transport: problem.transport.clone(),
+            objective: problem.objective.clone(),
+            extras: problem.extras.clone(),
+        });
+        self.unavailable = unavailable;
+
+        Ok(())
+    }
+
+    /// Cancels the job which is already assigned in the solution: removes it from the prepared
+    /// problem and from its route, then repairs route schedule keeping order of other activities.
+    /// Returns changed solution and resources freed by cancellation. Freed vehicle capacity is
+    /// defined by the job's demand.
+    pub fn cancel_job<Capacity>(
+        &mut self,
+        solution: Arc<Solution>,
+        job: &Job,
+        random: Arc<dyn Random + Send + Sync>,
+    ) -> Result<(Solution, JobCancellation<Capacity>), String>
+    where
+        Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static,
+    {
+        let actor = solution
+            .routes
+            .iter()
+            .find(|route| route.tour.contains(job))
+            .map(|route| route.actor.clone())
+            .ok_or_else(|| "cannot cancel job which is not assigned in the solution".to_string())?;
+
+        self.apply_patch(ProblemPatch { removed_jobs: vec![job.clone()], ..ProblemPatch::default() })?;
+
+        let unassigned = solution.unassigned.clone();
+        let mut insertion_ctx = InsertionContext::new_from_solution(self.problem.clone(), (solution, None), random);
+        insertion_ctx.solution.required.clear();
+        insertion_ctx.solution.unassigned.extend(unassigned);
+        insertion_ctx.restore();
+
+        let get_route_metrics = |insertion_ctx: &InsertionContext| {
+            insertion_ctx.solution.routes.iter().find(|route_ctx| Arc::ptr_eq(&route_ctx.route.actor, &actor)).map_or(
+                (0., 0.),
+                |route_ctx| {
+                    (get_route_state(route_ctx, TOTAL_DURATION_KEY), get_route_state(route_ctx, TOTAL_DISTANCE_KEY))
+                },
+            )
+        };
+
+        let (duration, distance) = get_route_metrics(&insertion_ctx);
+        let cost = insertion_ctx.solution.get_total_cost();
+
+        if let Some(route_ctx) =
+            insertion_ctx.solution.routes.iter_mut().find(|route_ctx| route_ctx.route.tour.contains(job))
+        {
+            route_ctx.route_mut().tour.remove(job);
+        }
+        insertion_ctx.restore();
+
+        let (new_duration, new_distance) = get_route_metrics(&insertion_ctx);
+        let cancellation = JobCancellation {
+            actor,
+            demand: get_job_demand(job),
+            duration: duration - new_duration,
+            distance: distance - new_distance,
+            cost: cost - insertion_ctx.solution.get_total_cost(),
+        };
+
+        Ok((insertion_ctx.solution.to_solution(self.problem.extras.clone()), cancellation))
+    }
+}
+
+impl From<Arc<Problem>> for PreparedProblem {
+    fn from(problem: Arc<Problem>) -> Self {
+        Self { fleet: problem.fleet.clone(), problem, unavailable: vec![] }
+    }
+}
+
+/// Creates a fleet without unavailable vehicles. Actors are reused, so constraints and locks
+/// which refer to them keep working.
+fn create_available_fleet(fleet: &Fleet, unavailable: &[Arc<Vehicle>]) -> Result<Fleet, String> {
+    let is_available = |vehicle: &Arc<Vehicle>| !unavailable.iter().any(|v| Arc::ptr_eq(v, vehicle));
+
+    let vehicles = fleet.vehicles.iter().filter(|vehicle| is_available(vehicle)).cloned().collect::<Vec<_>>();
+    if vehicles.is_empty() {
+        return Err("at least one vehicle should be available".to_string());
+    }
+
+    let actors = fleet.actors.iter().filter(|actor| is_available(&actor.vehicle)).cloned().collect();
+    let groups = fleet
+        .groups
+        .iter()
+        .map(|(&key, actors)| {
+            (key, actors.iter().filter(|actor| is_available(&actor.vehicle)).cloned().collect::<HashSet<_>>())
+        })
+        .filter(|(_, actors)| !actors.is_empty())
+        .collect();
+
+    Ok(Fleet { drivers: fleet.drivers.clone(), vehicles, profiles: fleet.profiles.clone(), actors, groups })
+}
+
+fn get_route_state(route_ctx: &RouteContext, key: i32) -> f64 {
+    route_ctx.state.get_route_state::<f64>(key).cloned().unwrap_or(0.)
+}
@@ -0,0 +1,26 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/termination/cancellation_test.rs"]
+mod cancellation_test;
+
+use crate::solver::termination::Termination;
+use crate::solver::RefinementContext;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Stops when cancellation flag is set, e.g. by a host application from another thread.
+pub struct Cancellation {
+    is_cancelled: Arc<AtomicBool>,
+}
+
+impl Cancellation {
+    /// Creates a new instance of [`Cancellation`].
+    pub fn new(is_cancelled: Arc<AtomicBool>) -> Self {
+        Self { is_cancelled }
+    }
+}
+
+impl Termination for Cancellation {
+    fn is_termination(&self, _: &mut RefinementContext) -> bool {
+        self.is_cancelled.load(Ordering::Relaxed)
+    }
+}
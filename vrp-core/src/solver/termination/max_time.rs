@@ -1,6 +1,11 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/termination/max_time_test.rs"]
+mod max_time_test;
+
 use crate::solver::termination::Termination;
 use crate::solver::RefinementContext;
-use crate::utils::Timer;
+use crate::utils::{Clock, Timer};
+use std::sync::Arc;
 
 /// Stops when max time elapsed.
 pub struct MaxTime {
@@ -13,6 +18,11 @@ impl MaxTime {
     pub fn new(limit_in_secs: f64) -> Self {
         Self { start: Timer::start(), limit_in_secs }
     }
+
+    /// Creates a new instance of [`MaxTime`] which measures elapsed time using given clock.
+    pub fn new_with_clock(limit_in_secs: f64, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        Self { start: Timer::start_with_clock(clock), limit_in_secs }
+    }
 }
 
 impl Termination for MaxTime {
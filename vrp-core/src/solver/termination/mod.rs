@@ -8,6 +8,9 @@ pub trait Termination {
     fn is_termination(&self, refinement_ctx: &mut RefinementContext) -> bool;
 }
 
+mod cancellation;
+pub use self::cancellation::Cancellation;
+
 mod cost_variation;
 pub use self::cost_variation::CostVariation;
 
@@ -19,12 +22,12 @@ pub use self::max_time::MaxTime;
 
 /// A trait which encapsulates multiple termination criteria.
 pub struct CompositeTermination {
-    terminations: Vec<Box<dyn Termination>>,
+    terminations: Vec<Box<dyn Termination + Send + Sync>>,
 }
 
 impl CompositeTermination {
     /// Creates a new instance of [`CompositeTermination`].
-    pub fn new(terminations: Vec<Box<dyn Termination>>) -> Self {
+    pub fn new(terminations: Vec<Box<dyn Termination + Send + Sync>>) -> Self {
         Self { terminations }
     }
 }
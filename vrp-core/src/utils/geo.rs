@@ -0,0 +1,107 @@
+//! Contains geographic helpers shared by constraints, routing approximation and clustering.
+//! A point is represented as `(latitude, longitude)` pair in degrees, distances are in meters.
+
+#[cfg(test)]
+#[path = "../../tests/unit/utils/geo_test.rs"]
+mod geo_test;
+
+use std::f64::consts::PI;
+
+/// A geographic point as `(latitude, longitude)` pair in degrees.
+pub type Point = (f64, f64);
+
+/// A bounding box specified by its `(min, max)` corners.
+pub type BoundingBox = (Point, Point);
+
+/// Gets distance between two points in meters using haversine formula.
+pub fn get_haversine_distance(p1: &Point, p2: &Point) -> f64 {
+    let d_lat = degree_rad(p1.0 - p2.0);
+    let d_lng = degree_rad(p1.1 - p2.1);
+
+    let lat1 = degree_rad(p1.0);
+    let lat2 = degree_rad(p2.0);
+
+    let a =
+        (d_lat / 2.).sin() * (d_lat / 2.).sin() + (d_lng / 2.).sin() * (d_lng / 2.).sin() * (lat1).cos() * (lat2).cos();
+    let c = 2. * a.sqrt().atan2((1. - a).sqrt());
+
+    let radius = wgs84_earth_radius(d_lat);
+
+    radius * c
+}
+
+/// Returns a bounding box of given points or `None` if there are no points.
+pub fn get_bounding_box<'a>(points: impl Iterator<Item = &'a Point>) -> Option<BoundingBox> {
+    points.fold(None, |acc, &(lat, lng)| match acc {
+        Some(((lat_min, lng_min), (lat_max, lng_max))) => {
+            Some(((lat.min(lat_min), lng.min(lng_min)), (lat.max(lat_max), lng.max(lng_max))))
+        }
+        None => Some(((lat, lng), (lat, lng))),
+    })
+}
+
+/// Returns a bounding box around the center with given half size of its side in meters.
+pub fn get_bounding_box_from_center(center: &Point, half_size: f64) -> BoundingBox {
+    let lat = degree_rad(center.0);
+    let lng = degree_rad(center.1);
+
+    let radius = wgs84_earth_radius(lat);
+    let pradius = radius * lat.cos();
+
+    let lat_min = rad_degree(lat - half_size / radius);
+    let lat_max = rad_degree(lat + half_size / radius);
+    let lng_min = rad_degree(lng - half_size / pradius);
+    let lng_max = rad_degree(lng + half_size / pradius);
+
+    ((lat_min, lng_min), (lat_max, lng_max))
+}
+
+/// Checks whether the point is inside of polygon using ray casting algorithm.
+pub fn is_point_in_polygon(point: &Point, polygon: &[Point]) -> bool {
+    if polygon.is_empty() {
+        return false;
+    }
+
+    let &(x, y) = point;
+
+    (0..polygon.len()).zip(std::iter::once(polygon.len() - 1).chain(0..polygon.len() - 1)).fold(
+        false,
+        |is_inside, (i, j)| {
+            let (ix, iy) = polygon[i];
+            let (jx, jy) = polygon[j];
+
+            if ((ix > x) != (jx > x)) && (y < (jy - iy) * (x - ix) / (jx - ix) + iy) {
+                !is_inside
+            } else {
+                is_inside
+            }
+        },
+    )
+}
+
+/// Converts degrees to radians.
+#[inline(always)]
+pub fn degree_rad(degrees: f64) -> f64 {
+    PI * degrees / 180.
+}
+
+/// Converts radians to degrees.
+#[inline(always)]
+pub fn rad_degree(radians: f64) -> f64 {
+    180. * radians / PI
+}
+
+#[inline(always)]
+fn wgs84_earth_radius(lat: f64) -> f64 {
+    // semi-axes of WGS-84 geoidal reference
+    const WGS84_A: f64 = 6378137.0; // major semiaxis [m]
+    const WGS84_B: f64 = 6356752.3; // minor semiaxis [m]
+
+    // http://en.wikipedia.org/wiki/Earth_radius
+    let an = WGS84_A * WGS84_A * lat.cos();
+    let bn = WGS84_B * WGS84_B * lat.sin();
+    let ad = WGS84_A * lat.cos();
+    let bd = WGS84_B * lat.sin();
+
+    ((an * an + bn * bn) / (ad * ad + bd * bd)).sqrt()
+}
@@ -1,5 +1,7 @@
 //! Utility helpers.
 
+pub mod geo;
+
 mod comparison;
 pub use self::comparison::compare_floats;
 pub use self::comparison::compare_shared;
@@ -14,6 +16,7 @@ mod parallel;
 pub use self::parallel::*;
 
 mod random;
+pub use self::random::shuffle;
 pub use self::random::DefaultRandom;
 pub use self::random::Random;
 
@@ -24,7 +27,7 @@ mod time_quota;
 pub use self::time_quota::TimeQuota;
 
 mod timing;
-pub use self::timing::Timer;
+pub use self::timing::{Clock, DefaultClock, Timer};
 
 /// A trait to get tuple from collection items.
 /// See https://stackoverflow.com/questions/38863781/how-to-create-a-tuple-from-a-vector
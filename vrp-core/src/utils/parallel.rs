@@ -1,11 +1,36 @@
+pub use self::actual::init_thread_pool;
 pub use self::actual::map_reduce;
 pub use self::actual::parallel_collect;
+pub use self::actual::run_with_threads;
 
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
 mod actual {
     extern crate rayon;
     use rayon::prelude::*;
 
+    /// Limits amount of threads used by parallel operations. Can be called only once and only
+    /// before any parallel operation is run, otherwise an error is returned.
+    pub fn init_thread_pool(threads: usize) -> Result<(), String> {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .map_err(|err| format!("cannot initialize thread pool: '{}'", err))
+    }
+
+    /// Runs action in a separate thread pool with given amount of threads, so parallel operations
+    /// called within the action do not use more threads.
+    pub fn run_with_threads<F, R>(threads: usize, action: F) -> Result<R, String>
+    where
+        F: FnOnce() -> R + Send,
+        R: Send,
+    {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .map(|pool| pool.install(action))
+            .map_err(|err| format!("cannot create thread pool: '{}'", err))
+    }
+
     /// Maps collection and collects results into vector in parallel.
     pub fn parallel_collect<T, F, R>(source: &[T], map_op: F) -> Vec<R>
     where
@@ -29,8 +54,22 @@ mod actual {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(any(not(feature = "parallel"), target_arch = "wasm32"))]
 mod actual {
+    /// Does nothing as all operations are run synchronously.
+    pub fn init_thread_pool(_threads: usize) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Runs action synchronously.
+    pub fn run_with_threads<F, R>(_threads: usize, action: F) -> Result<R, String>
+    where
+        F: FnOnce() -> R + Send,
+        R: Send,
+    {
+        Ok(action())
+    }
+
     /// Map collections and collects results into vector synchronously.
     pub fn parallel_collect<T, F, R>(source: &[T], map_op: F) -> Vec<R>
     where
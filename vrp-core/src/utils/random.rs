@@ -1,6 +1,12 @@
+#[cfg(test)]
+#[path = "../../tests/unit/utils/random_test.rs"]
+mod random_test;
+
 extern crate rand;
 
-use self::rand::Rng;
+use self::rand::rngs::StdRng;
+use self::rand::{Rng, SeedableRng};
+use std::sync::Mutex;
 
 /// Provides the way to use randomized values in generic way.
 pub trait Random {
@@ -43,12 +49,56 @@ pub trait Random {
     }
 }
 
-pub struct DefaultRandom {}
+/// Shuffles items in place using given random generator, so the result is reproducible when
+/// the generator is seeded.
+pub fn shuffle<T>(items: &mut [T], random: &dyn Random) {
+    (1..items.len()).rev().for_each(|idx| {
+        let other = random.uniform_int(0, idx as i32) as usize;
+        items.swap(idx, other);
+    });
+}
+
+/// A default random implementation. Uses thread local generator unless seed is specified.
+pub struct DefaultRandom {
+    seeded: Option<Mutex<StdRng>>,
+}
+
+impl DefaultRandom {
+    /// Creates a new instance of `DefaultRandom` which produces the same sequence of values
+    /// for the same seed.
+    pub fn new_with_seed(seed: u64) -> Self {
+        Self { seeded: Some(Mutex::new(StdRng::seed_from_u64(seed))) }
+    }
+}
+
+impl Random for DefaultRandom {
+    fn uniform_int(&self, min: i32, max: i32) -> i32 {
+        if min == max {
+            return min;
+        }
+
+        assert!(min < max);
+        match self.seeded.as_ref() {
+            Some(rng) => rng.lock().unwrap().gen_range(min, max + 1),
+            None => rand::thread_rng().gen_range(min, max + 1),
+        }
+    }
+
+    fn uniform_real(&self, min: f64, max: f64) -> f64 {
+        if (min - max).abs() < f64::EPSILON {
+            return min;
+        }
 
-impl Random for DefaultRandom {}
+        assert!(min < max);
+        match self.seeded.as_ref() {
+            Some(rng) => rng.lock().unwrap().gen_range(min, max),
+            None => rand::thread_rng().gen_range(min, max),
+        }
+    }
+}
 
 impl Default for DefaultRandom {
     fn default() -> Self {
-        Self {}
+        Self { seeded: None }
     }
 }
@@ -1,5 +1,6 @@
 use crate::construction::Quota;
-use crate::utils::Timer;
+use crate::utils::{Clock, Timer};
+use std::sync::Arc;
 
 /// A time quota.
 pub struct TimeQuota {
@@ -12,6 +13,11 @@ impl TimeQuota {
     pub fn new(limit_in_secs: f64) -> Self {
         Self { start: Timer::start(), limit_in_secs }
     }
+
+    /// Creates a new instance of [`TimeQuota`] which measures elapsed time using given clock.
+    pub fn new_with_clock(limit_in_secs: f64, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        Self { start: Timer::start_with_clock(clock), limit_in_secs }
+    }
 }
 
 impl Quota for TimeQuota {
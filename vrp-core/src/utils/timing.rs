@@ -1,62 +1,84 @@
-/// Implements performance timer functionality, mostly exists due to problem
-/// with `Instant` on wasm32 arch.
-pub type Timer = actual::Timer;
+use std::sync::Arc;
 
-#[cfg(not(target_arch = "wasm32"))]
-mod actual {
-    use std::time::Instant;
+/// Provides current time to the solver, so it can be replaced on targets where system clock is
+/// not available or should not be used, e.g. embedded devices or deterministic simulations.
+pub trait Clock {
+    /// Returns current time in seconds since some fixed, but arbitrary point.
+    fn now(&self) -> f64;
+}
+
+/// A default clock which uses system time.
+pub type DefaultClock = actual::DefaultClock;
+
+/// Implements performance timer functionality which measures elapsed time using given clock.
+#[derive(Clone)]
+pub struct Timer {
+    clock: Arc<dyn Clock + Send + Sync>,
+    start: f64,
+}
 
-    #[derive(Clone)]
-    pub struct Timer {
-        start: Instant,
+impl Timer {
+    /// Starts a new timer using default clock.
+    pub fn start() -> Self {
+        Self::start_with_clock(Arc::new(DefaultClock::default()))
     }
 
-    impl Timer {
-        pub fn start() -> Self {
-            Self { start: Instant::now() }
-        }
+    /// Starts a new timer using given clock.
+    pub fn start_with_clock(clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        let start = clock.now();
+        Self { clock, start }
+    }
 
-        pub fn elapsed_secs(&self) -> u64 {
-            (Instant::now() - self.start).as_secs()
-        }
+    pub fn elapsed_secs(&self) -> u64 {
+        self.elapsed_secs_as_f64() as u64
+    }
 
-        pub fn elapsed_secs_as_f64(&self) -> f64 {
-            (Instant::now() - self.start).as_secs_f64()
-        }
+    pub fn elapsed_secs_as_f64(&self) -> f64 {
+        self.clock.now() - self.start
+    }
 
-        pub fn elapsed_millis(&self) -> u128 {
-            (Instant::now() - self.start).as_millis()
-        }
+    pub fn elapsed_millis(&self) -> u128 {
+        (self.elapsed_secs_as_f64() * 1E3) as u128
+    }
+
+    pub fn elapsed_nanos(&self) -> u128 {
+        (self.elapsed_secs_as_f64() * 1E9) as u128
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(not(target_arch = "wasm32"))]
 mod actual {
+    use super::Clock;
+    use std::time::Instant;
 
-    #[derive(Clone)]
-    pub struct Timer {
-        start: f64,
+    pub struct DefaultClock {
+        origin: Instant,
     }
 
-    impl Timer {
-        pub fn start() -> Self {
-            Self { start: now() }
+    impl Default for DefaultClock {
+        fn default() -> Self {
+            Self { origin: Instant::now() }
         }
+    }
 
-        pub fn elapsed_secs(&self) -> u64 {
-            self.elapsed_secs_as_f64().round() as u64
+    impl Clock for DefaultClock {
+        fn now(&self) -> f64 {
+            self.origin.elapsed().as_secs_f64()
         }
+    }
+}
 
-        pub fn elapsed_secs_as_f64(&self) -> f64 {
-            (now() - self.start) / 1000.
-        }
+/// NOTE `Instant` is not supported on wasm32 arch.
+#[cfg(target_arch = "wasm32")]
+mod actual {
+    use super::Clock;
 
-        pub fn elapsed_millis(&self) -> u128 {
-            (now() - self.start) as u128
-        }
-    }
+    #[derive(Default)]
+    pub struct DefaultClock {}
 
-    fn now() -> f64 {
-        js_sys::Date::new_0().get_time() as f64
+    impl Clock for DefaultClock {
+        fn now(&self) -> f64 {
+            js_sys::Date::new_0().get_time() as f64 / 1000.
+        }
     }
 }
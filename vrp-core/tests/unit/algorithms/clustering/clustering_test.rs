@@ -0,0 +1,7 @@
+use super::*;
+
+#[test]
+fn can_try_add_demand() {
+    assert_eq!(try_add_demand(2, 3, 5), Some(5));
+    assert_eq!(try_add_demand(2, 4, 5), None);
+}
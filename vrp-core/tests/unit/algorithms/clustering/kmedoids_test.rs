@@ -0,0 +1,69 @@
+use super::*;
+use crate::helpers::construction::constraints::create_simple_demand;
+use crate::helpers::models::problem::*;
+use crate::utils::DefaultRandom;
+
+fn create_jobs(locations: &[(usize, i32)]) -> Vec<Job> {
+    locations
+        .iter()
+        .map(|&(location, demand)| {
+            SingleBuilder::default()
+                .id(format!("job{}", location).as_str())
+                .location(Some(location))
+                .demand(create_simple_demand(demand))
+                .build_as_job_ref()
+        })
+        .collect()
+}
+
+fn get_sorted_ids(clusters: &[Vec<Job>]) -> Vec<Vec<String>> {
+    let mut ids = clusters
+        .iter()
+        .map(|cluster| {
+            let mut ids = cluster.iter().map(|job| get_job_id(job).clone()).collect::<Vec<_>>();
+            ids.sort();
+            ids
+        })
+        .collect::<Vec<_>>();
+    ids.sort();
+    ids
+}
+
+parameterized_test! {can_create_kmedoids_clusters, (locations, k, capacity, expected_clusters, expected_unassigned), {
+    can_create_kmedoids_clusters_impl(locations, k, capacity, expected_clusters, expected_unassigned);
+}}
+
+can_create_kmedoids_clusters! {
+    case01: (vec![(0, 1), (1, 1), (2, 1), (10, 1), (11, 1), (12, 1)], 2, 3,
+             vec![vec!["job0", "job1", "job2"], vec!["job10", "job11", "job12"]], 0),
+    case02: (vec![(0, 1), (1, 1), (2, 1), (10, 1), (11, 1), (12, 1)], 2, 10,
+             vec![vec!["job0", "job1", "job2"], vec!["job10", "job11", "job12"]], 0),
+    case03: (vec![(0, 1), (1, 1), (2, 1), (10, 1), (11, 1), (12, 1)], 2, 2, vec![], 2),
+    case04: (vec![(0, 1), (1, 5), (2, 1)], 1, 3, vec![vec!["job0", "job2"]], 1),
+    case05: (vec![(0, 1), (1, 1)], 3, 3, vec![vec!["job0"], vec!["job1"]], 0),
+    case06: (vec![(0, 1), (1, 1)], 0, 3, vec![], 2),
+}
+
+fn can_create_kmedoids_clusters_impl(
+    locations: Vec<(usize, i32)>,
+    k: usize,
+    capacity: i32,
+    expected_clusters: Vec<Vec<&str>>,
+    expected_unassigned: usize,
+) {
+    let jobs = create_jobs(locations.as_slice());
+    let transport = TestTransportCost::new_shared();
+
+    let result = create_kmedoids_clusters(jobs.as_slice(), k, capacity, 0, &transport, &DefaultRandom::default());
+
+    assert_eq!(result.unassigned.len(), expected_unassigned);
+    assert!(result.clusters.iter().all(|cluster| cluster.iter().map(get_job_demand::<i32>).sum::<i32>() <= capacity));
+    assert_eq!(result.clusters.iter().map(|cluster| cluster.len()).sum::<usize>() + expected_unassigned, jobs.len());
+    if !expected_clusters.is_empty() {
+        let expected = expected_clusters
+            .iter()
+            .map(|cluster| cluster.iter().map(|id| id.to_string()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(get_sorted_ids(result.clusters.as_slice()), expected);
+    }
+}
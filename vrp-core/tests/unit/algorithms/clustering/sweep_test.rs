@@ -0,0 +1,50 @@
+use super::*;
+use crate::helpers::construction::constraints::create_simple_demand;
+use crate::helpers::models::problem::*;
+
+fn get_coordinate(job: &Job) -> Option<(f64, f64)> {
+    let location = job.to_single().places.first().unwrap().location?;
+    let angle = location as f64 * PI / 8.;
+
+    Some((angle.cos(), angle.sin()))
+}
+
+fn create_job(id: &str, location: Option<usize>, demand: i32) -> Job {
+    SingleBuilder::default().id(id).location(location).demand(create_simple_demand(demand)).build_as_job_ref()
+}
+
+parameterized_test! {can_create_sweep_clusters, (jobs, capacity, expected_clusters, expected_unassigned), {
+    can_create_sweep_clusters_impl(jobs, capacity, expected_clusters, expected_unassigned);
+}}
+
+can_create_sweep_clusters! {
+    case01: (vec![("j0", Some(0), 1), ("j1", Some(1), 1), ("j2", Some(2), 1), ("j3", Some(3), 1)], 2,
+             vec![vec!["j0", "j1"], vec!["j2", "j3"]], vec![]),
+    case02: (vec![("j3", Some(3), 1), ("j0", Some(0), 1), ("j2", Some(2), 1), ("j1", Some(1), 1)], 3,
+             vec![vec!["j0", "j1", "j2"], vec!["j3"]], vec![]),
+    case03: (vec![("j0", Some(0), 1), ("j1", Some(1), 3), ("j2", Some(2), 1), ("j3", None, 1)], 2,
+             vec![vec!["j0", "j2"]], vec!["j1", "j3"]),
+    case04: (vec![("j15", Some(15), 1), ("j0", Some(0), 1), ("j1", Some(1), 1), ("j6", Some(6), 1)], 3,
+             vec![vec!["j15", "j0", "j1"], vec!["j6"]], vec![]),
+    case05: (vec![], 3, vec![], vec![]),
+}
+
+fn can_create_sweep_clusters_impl(
+    jobs: Vec<(&str, Option<usize>, i32)>,
+    capacity: i32,
+    expected_clusters: Vec<Vec<&str>>,
+    expected_unassigned: Vec<&str>,
+) {
+    let jobs = jobs.into_iter().map(|(id, location, demand)| create_job(id, location, demand)).collect::<Vec<_>>();
+
+    let result = create_sweep_clusters(jobs.as_slice(), (0., 0.), capacity, &get_coordinate);
+
+    let clusters = result
+        .clusters
+        .iter()
+        .map(|cluster| cluster.iter().map(|job| get_job_id(job).as_str()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let unassigned = result.unassigned.iter().map(|job| get_job_id(job).as_str()).collect::<Vec<_>>();
+    assert_eq!(clusters, expected_clusters);
+    assert_eq!(unassigned, expected_unassigned);
+}
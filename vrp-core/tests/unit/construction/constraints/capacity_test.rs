@@ -126,3 +126,19 @@ fn can_evaluate_demand_on_activity_impl(
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn can_get_job_demand() {
+    let pickup = Job::Single(test_single_with_simple_demand(create_simple_demand(2)));
+    let delivery = Job::Single(test_single_with_simple_demand(create_simple_demand(-3)));
+    let without_demand = Job::Multi(test_multi_job_with_locations(vec![vec![Some(0)], vec![Some(1)]]));
+    let pickup_delivery = MultiBuilder::default()
+        .job(SingleBuilder::default().demand(Demand { pickup: (0, 2), delivery: (0, 0) }).build())
+        .job(SingleBuilder::default().demand(Demand { pickup: (0, 0), delivery: (0, 2) }).build())
+        .build();
+
+    assert_eq!(get_job_demand::<i32>(&pickup), 2);
+    assert_eq!(get_job_demand::<i32>(&delivery), 3);
+    assert_eq!(get_job_demand::<i32>(&without_demand), 0);
+    assert_eq!(get_job_demand::<i32>(&pickup_delivery), 2);
+}
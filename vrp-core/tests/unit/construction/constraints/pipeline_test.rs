@@ -1,5 +1,6 @@
 use crate::construction::constraints::*;
 use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
 use crate::helpers::models::solution::{test_actor, test_tour_activity_without_job};
 use crate::models::common::Cost;
 use crate::models::problem::Job;
@@ -111,3 +112,42 @@ fn can_estimate_hard_activity_constraints() {
 
     assert_eq!(result, 12.0);
 }
+
+#[test]
+fn can_profile_constraint_evaluations() {
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Box::new(TestConstraintModule {
+        state_keys: vec![1, 2],
+        constraints: vec![ConstraintVariant::HardActivity(Arc::new(TestHardActivityConstraint { violation: None }))],
+    }));
+    pipeline.add_module(Box::new(TestConstraintModule {
+        state_keys: vec![3, 4],
+        constraints: vec![ConstraintVariant::SoftActivity(Arc::new(TestSoftActivityConstraint { cost: 7.0 }))],
+    }));
+    let profiler = pipeline.enable_profiling();
+    let route_ctx = RouteContext::new(test_actor());
+    let (prev, target) = (test_tour_activity_without_job(), test_tour_activity_without_job());
+    let activity_ctx = ActivityContext { index: 0, prev: &prev, target: &target, next: None };
+
+    (0..3).for_each(|_| {
+        pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+    });
+    pipeline.evaluate_soft_activity(&route_ctx, &activity_ctx);
+
+    let mut profiles = profiler.get_profiles();
+    profiles.sort_by_key(|profile| profile.evaluations);
+
+    assert_eq!(profiles.len(), 2);
+    assert!(profiles.iter().all(|profile| profile.name == "TestConstraintModule"));
+    assert_eq!(profiles.iter().map(|profile| profile.evaluations).collect::<Vec<_>>(), vec![1, 3]);
+}
+
+#[test]
+fn can_report_cacheable_only_when_all_modules_are_cacheable() {
+    let mut pipeline = create_constraint_pipeline_with_transport();
+    assert!(pipeline.is_cacheable());
+
+    pipeline.add_module(Box::new(TestConstraintModule { state_keys: vec![], constraints: vec![] }));
+
+    assert!(!pipeline.is_cacheable());
+}
@@ -0,0 +1,34 @@
+use super::*;
+
+#[test]
+fn can_get_short_type_name() {
+    assert_eq!(get_short_type_name("vrp_core::construction::constraints::Module"), "Module");
+    assert_eq!(get_short_type_name("a::b::Module<c::Type, u32>"), "Module<Type, u32>");
+    assert_eq!(get_short_type_name("Module"), "Module");
+}
+
+#[test]
+fn can_rank_profiles_by_total_time() {
+    let profiler = ConstraintProfiler::new(vec!["a::Fast".to_string(), "a::Slow".to_string()]);
+
+    profiler.record_evaluation(0, 1000);
+    profiler.record_evaluation(1, 2000);
+    profiler.record_evaluation(1, 2000);
+    profiler.record_state(1, 1000);
+    profiler.record_evaluation(2, 1000);
+
+    let profiles = profiler.get_profiles();
+
+    assert_eq!(
+        profiles,
+        vec![
+            ConstraintModuleProfile {
+                name: "Slow".to_string(),
+                evaluations: 2,
+                evaluation_time: 4E-6,
+                state_time: 1E-6
+            },
+            ConstraintModuleProfile { name: "Fast".to_string(), evaluations: 1, evaluation_time: 1E-6, state_time: 0. },
+        ]
+    );
+}
@@ -0,0 +1,83 @@
+use super::*;
+use crate::construction::constraints::{ConstraintModule, ConstraintVariant};
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::construction::heuristics::{create_insertion_context, create_test_insertion_context};
+use crate::helpers::models::problem::test_single_with_location;
+use crate::helpers::models::solution::{create_test_registry, test_tour_activity_with_location};
+use crate::utils::compare_floats;
+use std::cmp::Ordering::Equal;
+use std::slice::Iter;
+
+struct SolutionStateModule {}
+
+impl ConstraintModule for SolutionStateModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: &mut RouteContext, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        [].iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        [].iter()
+    }
+}
+
+fn get_cost(result: &InsertionResult) -> Cost {
+    match result {
+        InsertionResult::Success(success) => success.cost,
+        InsertionResult::Failure(_) => unreachable!(),
+    }
+}
+
+#[test]
+fn can_reuse_cached_result_for_same_route() {
+    let ctx = create_test_insertion_context(create_test_registry());
+    let job = Job::Single(test_single_with_location(Some(5)));
+    let cache = InsertionCache::new(100);
+    let routes = get_insertion_routes(&ctx);
+
+    let first = cache.evaluate_job_insertion(&job, &ctx, &routes, InsertionPosition::Any);
+    let (hits, misses) = cache.stats();
+    let second = cache.evaluate_job_insertion(&job, &ctx, &routes, InsertionPosition::Any);
+    let expected = evaluate_job_insertion(&job, &ctx, InsertionPosition::Any);
+
+    assert!(misses > 0);
+    assert_eq!(cache.stats(), (hits + routes.len(), misses));
+    assert_eq!(compare_floats(get_cost(&first), get_cost(&expected)), Equal);
+    assert_eq!(compare_floats(get_cost(&second), get_cost(&expected)), Equal);
+}
+
+#[test]
+fn can_invalidate_cached_result_when_route_is_changed() {
+    let mut ctx = create_test_insertion_context(create_test_registry());
+    let job = Job::Single(test_single_with_location(Some(5)));
+    let cache = InsertionCache::new(100);
+
+    cache.evaluate_job_insertion(&job, &ctx, &get_insertion_routes(&ctx), InsertionPosition::Any);
+    let (_, misses) = cache.stats();
+    ctx.solution.routes.first_mut().unwrap().route_mut().tour.insert_last(test_tour_activity_with_location(3));
+    cache.evaluate_job_insertion(&job, &ctx, &get_insertion_routes(&ctx), InsertionPosition::Any);
+
+    assert_eq!(cache.stats().1, misses + 1);
+}
+
+#[test]
+fn can_bypass_cache_when_constraints_are_not_cacheable() {
+    let registry = create_test_registry();
+    let routes = vec![RouteContext::new(registry.next().next().unwrap())];
+    let mut constraint = create_constraint_pipeline_with_transport();
+    constraint.add_module(Box::new(SolutionStateModule {}));
+    let ctx = create_insertion_context(registry, constraint, routes);
+    let job = Job::Single(test_single_with_location(Some(5)));
+    let cache = InsertionCache::new(100);
+
+    let result = cache.evaluate_job_insertion(&job, &ctx, &get_insertion_routes(&ctx), InsertionPosition::Any);
+    let expected = evaluate_job_insertion(&job, &ctx, InsertionPosition::Any);
+
+    assert_eq!(cache.stats(), (0, 0));
+    assert_eq!(compare_floats(get_cost(&result), get_cost(&expected)), Equal);
+}
@@ -0,0 +1,61 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::problem::*;
+use crate::models::problem::{Jobs, ObjectiveCost};
+use crate::utils::DefaultRandom;
+
+fn create_problem_and_solution() -> (Arc<Problem>, Arc<Solution>) {
+    let fleet = Arc::new(test_fleet());
+    let transport = TestTransportCost::new_shared();
+    let jobs = vec![
+        SingleBuilder::default().id("job1").location(Some(10)).build_as_job_ref(),
+        SingleBuilder::default().id("job2").location(Some(20)).build_as_job_ref(),
+    ];
+    let problem = Arc::new(Problem {
+        fleet: fleet.clone(),
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        locks: vec![],
+        constraint: Arc::new(create_constraint_pipeline_with_transport()),
+        activity: Arc::new(TestActivityCost::default()),
+        transport,
+        objective: Arc::new(ObjectiveCost::default()),
+        extras: Arc::new(Default::default()),
+    });
+
+    let job_selector: Box<dyn JobSelector + Send + Sync> = Box::new(AllJobSelector::default());
+    let job_reducer: Box<dyn JobMapReducer + Send + Sync> =
+        Box::new(PairJobMapReducer::new(Box::new(BestResultSelector::default())));
+    let insertion_ctx = InsertionHeuristic::default().process(
+        &job_selector,
+        &job_reducer,
+        InsertionContext::new(problem.clone(), Arc::new(DefaultRandom::default())),
+        &None,
+    );
+    let solution = Arc::new(insertion_ctx.solution.to_solution(problem.extras.clone()));
+
+    (problem, solution)
+}
+
+#[test]
+fn can_get_job_marginal_costs() {
+    let (problem, solution) = create_problem_and_solution();
+
+    let mut result = get_job_marginal_costs(problem, solution, Arc::new(DefaultRandom::default()))
+        .into_iter()
+        .map(|(job, cost)| (get_job_id(&job).clone(), cost))
+        .collect::<Vec<_>>();
+    result.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    assert_eq!(result, vec![("job1".to_string(), 0.), ("job2".to_string(), 80.)]);
+}
+
+#[test]
+fn can_get_vehicle_marginal_costs() {
+    let (problem, solution) = create_problem_and_solution();
+
+    let result = get_vehicle_marginal_costs(problem, solution, Arc::new(DefaultRandom::default()));
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].1, 160.);
+    assert_eq!(result[0].2, 2);
+}
@@ -0,0 +1,65 @@
+use super::*;
+use crate::helpers::solver::generate_matrix_routes;
+use crate::models::common::{IdDimension, TimeWindow};
+use crate::utils::DefaultRandom;
+use std::sync::Arc;
+
+fn create_insertion_ctx_with_violation() -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes(3, 1);
+    let mut insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (Arc::new(solution), None),
+        Arc::new(DefaultRandom::default()),
+    );
+
+    // NOTE second job is at location 1, so it cannot be reached before time 1
+    insertion_ctx.solution.routes[0].route_mut().tour.get_mut(2).unwrap().place.time = TimeWindow::new(0., 0.5);
+
+    insertion_ctx
+}
+
+fn get_job_id(job: &Job) -> &str {
+    job.dimens().get_id().unwrap().as_str()
+}
+
+#[test]
+fn can_keep_valid_route_unchanged() {
+    let (problem, solution) = generate_matrix_routes(3, 1);
+    let mut insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (Arc::new(solution), None),
+        Arc::new(DefaultRandom::default()),
+    );
+
+    let result = repair_route(&mut insertion_ctx, 0, &|_| true);
+
+    assert!(result.removed.is_empty());
+    assert!(result.violations.is_empty());
+    assert_eq!(insertion_ctx.solution.routes[0].route.tour.job_count(), 3);
+}
+
+#[test]
+fn can_report_residual_violations() {
+    let mut insertion_ctx = create_insertion_ctx_with_violation();
+
+    let result = repair_route(&mut insertion_ctx, 0, &|_| false);
+
+    assert!(result.removed.is_empty());
+    assert_eq!(
+        result.violations.iter().map(|(job, code)| (get_job_id(job), *code)).collect::<Vec<_>>(),
+        vec![("c1", 1)]
+    );
+    assert_eq!(insertion_ctx.solution.routes[0].route.tour.job_count(), 3);
+}
+
+#[test]
+fn can_remove_violated_conditional_jobs() {
+    let mut insertion_ctx = create_insertion_ctx_with_violation();
+
+    let result = repair_route(&mut insertion_ctx, 0, &|_| true);
+
+    assert_eq!(result.removed.iter().map(get_job_id).collect::<Vec<_>>(), vec!["c1"]);
+    assert!(result.violations.is_empty());
+    assert_eq!(insertion_ctx.solution.routes[0].route.tour.job_count(), 2);
+    assert_eq!(insertion_ctx.solution.required.iter().map(get_job_id).collect::<Vec<_>>(), vec!["c1"]);
+}
@@ -0,0 +1,37 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::problem::*;
+use crate::models::common::TimeWindow;
+use crate::models::problem::{Jobs, ObjectiveCost};
+use crate::utils::DefaultRandom;
+
+#[test]
+fn can_find_unassignable_jobs() {
+    let fleet = Arc::new(test_fleet());
+    let transport = TestTransportCost::new_shared();
+    let jobs = vec![
+        SingleBuilder::default().id("job1").location(Some(10)).build_as_job_ref(),
+        SingleBuilder::default()
+            .id("job2")
+            .location(Some(10))
+            .times(vec![TimeWindow::new(2000., 3000.)])
+            .build_as_job_ref(),
+        SingleBuilder::default().id("job3").location(Some(20)).build_as_job_ref(),
+    ];
+    let problem = Arc::new(Problem {
+        fleet: fleet.clone(),
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        locks: vec![],
+        constraint: Arc::new(create_constraint_pipeline_with_transport()),
+        activity: Arc::new(TestActivityCost::default()),
+        transport,
+        objective: Arc::new(ObjectiveCost::default()),
+        extras: Arc::new(Default::default()),
+    });
+
+    let result = find_unassignable_jobs(problem, Arc::new(DefaultRandom::default()));
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(get_job_id(&result[0].0), "job2");
+    assert_eq!(result[0].1, 1);
+}
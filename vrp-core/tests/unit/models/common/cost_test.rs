@@ -0,0 +1,33 @@
+use super::*;
+use std::f64::{INFINITY, MAX, MIN, NEG_INFINITY};
+
+parameterized_test! {can_use_saturating_ops, (lhs, rhs, expected), {
+    can_use_saturating_ops_impl(lhs, rhs, expected);
+}}
+
+can_use_saturating_ops! {
+    case01: (1., 2., (3., -1., 2.)),
+    case02: (MAX, MAX, (MAX, 0., MAX)),
+    case03: (MAX, 1E12, (MAX, MAX, MAX)),
+    case04: (MIN, MAX, (0., MIN, MIN)),
+    case05: (INFINITY, 1., (MAX, MAX, MAX)),
+    case06: (NEG_INFINITY, 1E12, (MIN, MIN, MIN)),
+}
+
+fn can_use_saturating_ops_impl(lhs: Cost, rhs: Cost, expected: (Cost, Cost, Cost)) {
+    assert_eq!(lhs.saturating_add(rhs), expected.0);
+    assert_eq!(lhs.saturating_sub(rhs), expected.1);
+    assert_eq!(lhs.saturating_mul(rhs), expected.2);
+}
+
+#[test]
+fn can_propagate_nan() {
+    assert!(std::f64::NAN.saturating_add(1.).is_nan());
+}
+
+#[test]
+fn can_sum_costs_without_overflow() {
+    assert_eq!(saturating_sum(vec![1., 2., 3.].into_iter()), 6.);
+    assert_eq!(saturating_sum(vec![UNBOUNDED_COST, 1E12, UNBOUNDED_COST].into_iter()), UNBOUNDED_COST);
+    assert!(saturating_sum(vec![UNBOUNDED_COST, UNBOUNDED_COST, -1E12].into_iter()).is_finite());
+}
@@ -97,3 +97,57 @@ fn can_compare_non_dominant_relations() {
     assert_eq!(Ordering::Equal, objective.total_order(a, b));
     assert_eq!(Ordering::Equal, objective.total_order(b, a));
 }
+
+#[test]
+fn can_override_transport_cost_for_specific_pairs() {
+    let inner = create_matrix_transport_cost(vec![create_matrix_data(0, None, (10., 4), (20., 4))]).unwrap();
+    let transport = create_override_transport_cost(
+        inner,
+        vec![
+            TransportOverride { profile: 0, from: 0, to: 1, duration: Some(5.), distance: None },
+            TransportOverride { profile: 0, from: 1, to: 0, duration: None, distance: Some(7.) },
+        ],
+    );
+
+    assert_eq!(transport.duration(0, 0, 1, 0.), 5.);
+    assert_eq!(transport.distance(0, 0, 1, 0.), 20.);
+    assert_eq!(transport.duration(0, 1, 0, 0.), 10.);
+    assert_eq!(transport.distance(0, 1, 0, 0.), 7.);
+    assert_eq!(transport.duration(0, 1, 1, 0.), 10.);
+    assert_eq!(transport.distance(0, 1, 1, 0.), 20.);
+}
+
+#[test]
+fn can_multiply_travel_durations_by_time_of_day() {
+    let inner = create_matrix_transport_cost(vec![create_matrix_data(0, None, (10., 4), (20., 4))]).unwrap();
+    let transport = create_multiplier_transport_cost(
+        inner,
+        vec![
+            TravelTimeMultiplier { profile: 0, start: 7. * 3600., end: 9. * 3600., factor: 1.5 },
+            TravelTimeMultiplier { profile: 1, start: 0., end: 86400., factor: 2. },
+        ],
+    );
+
+    assert_eq!(transport.duration(0, 0, 1, 6. * 3600.), 10.);
+    assert_eq!(transport.duration(0, 0, 1, 7. * 3600.), 15.);
+    assert_eq!(transport.duration(0, 0, 1, 86400. + 8. * 3600.), 15.);
+    assert_eq!(transport.duration(0, 0, 1, 9. * 3600.), 10.);
+    assert_eq!(transport.distance(0, 0, 1, 8. * 3600.), 20.);
+}
+
+#[test]
+fn can_use_closure_transport_cost() {
+    let manhattan = |from: Location, to: Location| {
+        let (from, to) = ((from % 10, from / 10), (to % 10, to / 10));
+        ((from.0 as i64 - to.0 as i64).abs() + (from.1 as i64 - to.1 as i64).abs()) as f64
+    };
+    let transport = create_closure_transport_cost(
+        Arc::new(move |profile, from, to, _| manhattan(from, to) * (profile + 1) as f64),
+        Arc::new(move |_, from, to, _| manhattan(from, to)),
+    );
+
+    assert_eq!(transport.distance(0, 0, 23, 0.), 5.);
+    assert_eq!(transport.duration(0, 0, 23, 0.), 5.);
+    assert_eq!(transport.duration(1, 23, 0, 0.), 10.);
+    assert_eq!(transport.distance(1, 11, 11, 0.), 0.);
+}
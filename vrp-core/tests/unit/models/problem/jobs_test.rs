@@ -186,3 +186,73 @@ fn can_use_multi_job_bind_and_roots() {
 
     assert_eq!(jobs.neighbors(0, &job, 0.0, 100.0).count(), 0);
 }
+
+#[test]
+fn can_limit_amount_of_neighbours() {
+    let species = vec![
+        SingleBuilder::default().id("s0").location(Some(0)).build_as_job_ref(),
+        SingleBuilder::default().id("s1").location(Some(3)).build_as_job_ref(),
+        SingleBuilder::default().id("s2").location(Some(1)).build_as_job_ref(),
+        SingleBuilder::default().id("s3").location(Some(2)).build_as_job_ref(),
+    ];
+    let jobs = Jobs::new_with_neighbours_limit(
+        &test_fleet(),
+        species.clone(),
+        &create_only_distance_transport_cost(),
+        Some(2),
+    );
+
+    let result: Vec<String> = jobs
+        .neighbors(0, species.first().unwrap(), 0.0, u32::max_value() as f64)
+        .map(|j| get_job_id(&j).clone())
+        .collect();
+
+    assert_eq!(result, vec!["s2", "s3"]);
+}
+
+#[test]
+fn can_limit_amount_of_neighbours_of_existing_jobs() {
+    let species = vec![
+        SingleBuilder::default().id("s0").location(Some(0)).build_as_job_ref(),
+        SingleBuilder::default().id("s1").location(Some(3)).build_as_job_ref(),
+        SingleBuilder::default().id("s2").location(Some(1)).build_as_job_ref(),
+        SingleBuilder::default().id("s3").location(Some(2)).build_as_job_ref(),
+    ];
+    let jobs = Jobs::new(&test_fleet(), species.clone(), &create_only_distance_transport_cost());
+    let get_neighbours = |jobs: &Jobs| -> Vec<String> {
+        jobs.neighbors(0, species.first().unwrap(), 0.0, u32::max_value() as f64)
+            .map(|j| get_job_id(&j).clone())
+            .collect()
+    };
+
+    let sparse = jobs.with_neighbours_limit(2);
+
+    assert_eq!(get_neighbours(&jobs), vec!["s2", "s3", "s1"]);
+    assert_eq!(get_neighbours(&sparse), vec!["s2", "s3"]);
+    assert_eq!(sparse.all().count(), 4);
+}
+
+#[test]
+fn can_patch_jobs_with_the_same_index_as_created_from_scratch() {
+    let fleet = test_fleet();
+    let transport = create_only_distance_transport_cost();
+    let species = (0..5)
+        .map(|idx| SingleBuilder::default().id(format!("s{}", idx).as_str()).location(Some(idx)).build_as_job_ref())
+        .collect::<Vec<_>>();
+    let added = SingleBuilder::default().id("s5").location(Some(7)).build_as_job_ref();
+    let original = Jobs::new(&fleet, species[..4].to_vec(), &transport);
+
+    let patched = original.patch(&fleet, vec![added.clone()], &species[1..2], &transport);
+    let expected =
+        Jobs::new(&fleet, vec![species[0].clone(), species[2].clone(), species[3].clone(), added], &transport);
+
+    let get_neighbours = |jobs: &Jobs, job: &Job| {
+        jobs.neighbors(0, job, 0.0, u32::max_value() as f64).map(|j| get_job_id(&j).clone()).collect::<Vec<_>>()
+    };
+    assert_eq!(patched.size(), 4);
+    assert_eq!(patched.all().map(|job| get_job_id(&job).clone()).collect::<Vec<_>>(), vec!["s0", "s2", "s3", "s5"]);
+    expected.all().zip(patched.all()).for_each(|(expected_job, patched_job)| {
+        assert_eq!(get_neighbours(&patched, &patched_job), get_neighbours(&expected, &expected_job));
+        assert_eq!(patched.rank(0, &patched_job), expected.rank(0, &expected_job));
+    });
+}
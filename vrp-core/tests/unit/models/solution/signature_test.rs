@@ -0,0 +1,71 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_solution;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::Fleet;
+
+fn create_fleet() -> Fleet {
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .add_vehicle(test_vehicle_with_id("v2"))
+        .build()
+}
+
+fn create_route(fleet: &Fleet, vehicle: &str, jobs: &[(&str, usize)]) -> Route {
+    create_route_with_activities(
+        fleet,
+        vehicle,
+        jobs.iter()
+            .map(|(id, location)| test_tour_activity_with_job(test_single_with_id_and_location(id, Some(*location))))
+            .collect(),
+    )
+}
+
+fn create_solution(routes: Vec<Route>, unassigned: Vec<&str>) -> Solution {
+    let mut solution = create_empty_solution();
+    solution.routes = routes;
+    solution.unassigned = unassigned.into_iter().map(|id| (Job::Single(test_single_with_id(id)), 0)).collect();
+
+    solution
+}
+
+#[test]
+fn can_get_same_signature_for_different_route_order() {
+    let fleet = create_fleet();
+    let left = create_solution(
+        vec![create_route(&fleet, "v1", &[("job1", 1), ("job2", 2)]), create_route(&fleet, "v2", &[("job3", 3)])],
+        vec!["job4", "job5"],
+    );
+    let right = create_solution(
+        vec![create_route(&fleet, "v2", &[("job3", 3)]), create_route(&fleet, "v1", &[("job1", 1), ("job2", 2)])],
+        vec!["job5", "job4"],
+    );
+
+    assert_eq!(get_solution_signature(&left), get_solution_signature(&right));
+}
+
+parameterized_test! {can_get_different_signature, (left, right), {
+    can_get_different_signature_impl(left, right);
+}}
+
+can_get_different_signature! {
+    case01: ((vec![("v1", vec![("job1", 1), ("job2", 2)])], vec![]), (vec![("v1", vec![("job2", 2), ("job1", 1)])], vec![])),
+    case02: ((vec![("v1", vec![("job1", 1)])], vec![]), (vec![("v2", vec![("job1", 1)])], vec![])),
+    case03: ((vec![("v1", vec![("job1", 1)])], vec!["job2"]), (vec![("v1", vec![("job1", 1)])], vec!["job3"])),
+    case04: ((vec![("v1", vec![("job1", 1)])], vec!["job2"]), (vec![("v1", vec![("job1", 1), ("job2", 2)])], vec![])),
+}
+
+type SolutionData = (Vec<(&'static str, Vec<(&'static str, usize)>)>, Vec<&'static str>);
+
+fn can_get_different_signature_impl(left: SolutionData, right: SolutionData) {
+    let fleet = create_fleet();
+    let create = |(routes, unassigned): SolutionData| {
+        create_solution(
+            routes.into_iter().map(|(vehicle, jobs)| create_route(&fleet, vehicle, jobs.as_slice())).collect(),
+            unassigned,
+        )
+    };
+
+    assert_ne!(get_solution_signature(&create(left)), get_solution_signature(&create(right)));
+}
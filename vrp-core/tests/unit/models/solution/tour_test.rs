@@ -198,3 +198,16 @@ fn can_get_start_and_end() {
     assert_eq!(start_ptr, get_pointer(tour.start().unwrap()));
     assert_eq!(end_ptr, get_pointer(tour.end().unwrap()));
 }
+
+#[test]
+fn can_change_version_only_when_activities_are_changed() {
+    let mut tour = get_test_tour();
+    let version = tour.version();
+
+    tour.get_mut(1).unwrap().schedule.arrival = 10.;
+    assert_eq!(tour.version(), version);
+    assert_eq!(tour.deep_copy().version(), version);
+
+    tour.insert_last(test_tour_activity_with_default_job());
+    assert_ne!(tour.version(), version);
+}
@@ -0,0 +1,127 @@
+use crate::helpers::solver::generate_matrix_routes;
+use crate::solver::Builder;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn can_call_improvement_hook_on_new_best_solution() {
+    let (problem, _) = generate_matrix_routes(3, 3);
+    let improvements = Arc::new(Mutex::new(Vec::new()));
+    let hook_improvements = improvements.clone();
+
+    let (_, cost) = Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_max_generations(Some(20))
+        .with_improvement_hook(Arc::new(move |solution, cost, generation| {
+            assert!(solution.unassigned.is_empty());
+            hook_improvements.lock().unwrap().push((cost, generation));
+        }))
+        .build()
+        .unwrap()
+        .solve()
+        .unwrap();
+
+    let improvements = improvements.lock().unwrap();
+    assert!(!improvements.is_empty());
+    assert!(improvements.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+    assert_eq!(improvements.last().unwrap().0, cost);
+}
+
+#[test]
+fn can_log_progress_after_each_generation() {
+    let (problem, _) = generate_matrix_routes(3, 3);
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let logger_messages = messages.clone();
+
+    Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_max_generations(Some(10))
+        .with_progress_logger(Arc::new(move |msg| logger_messages.lock().unwrap().push(msg)))
+        .build()
+        .unwrap()
+        .solve()
+        .unwrap();
+
+    let messages = messages.lock().unwrap();
+    assert!(!messages.is_empty());
+    assert!(messages.first().unwrap().starts_with("generation: 1, best cost: "));
+    assert!(messages.iter().all(|msg| msg.contains(", unassigned: 0, elapsed: ")));
+}
+
+#[test]
+fn can_call_interval_hook_every_n_generations() {
+    let (problem, _) = generate_matrix_routes(3, 3);
+    let generations = Arc::new(Mutex::new(Vec::new()));
+    let hook_generations = generations.clone();
+
+    Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_max_generations(Some(10))
+        .with_interval_hook(3, Arc::new(move |_, _, generation| hook_generations.lock().unwrap().push(generation)))
+        .build()
+        .unwrap()
+        .solve()
+        .unwrap();
+
+    assert_eq!(*generations.lock().unwrap(), vec![3, 6, 9]);
+}
+
+#[test]
+fn can_reject_interval_hook_with_zero_interval() {
+    let (problem, _) = generate_matrix_routes(3, 3);
+
+    let result =
+        Builder::default().with_problem(Arc::new(problem)).with_interval_hook(0, Arc::new(|_, _, _| {})).build();
+
+    assert_eq!(result.err(), Some("hook interval should be greater than zero".to_string()));
+}
+
+#[test]
+fn can_call_progress_hook_after_each_generation() {
+    let (problem, _) = generate_matrix_routes(3, 3);
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let hook_events = events.clone();
+
+    Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_max_generations(Some(10))
+        .with_progress_hook(Arc::new(move |event| hook_events.lock().unwrap().push(event.clone())))
+        .build()
+        .unwrap()
+        .solve()
+        .unwrap();
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.iter().map(|event| event.generation).collect::<Vec<_>>(), (1..10).collect::<Vec<_>>());
+    assert!(events.iter().all(|event| event.best_cost <= event.worst_cost));
+    let last = events.last().unwrap();
+    assert_eq!(last.recreate_usage.iter().sum::<usize>(), 9);
+    assert!(last.ruin_usage.iter().sum::<usize>() <= 9);
+}
+
+#[test]
+fn can_use_sparse_index_limits_in_low_memory_mode() {
+    let (problem, _) = generate_matrix_routes(3, 3);
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let logger_messages = messages.clone();
+
+    Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_max_generations(Some(1))
+        .with_low_memory_mode(true)
+        .with_sparse_index(Some((5, 2)))
+        .with_logger(Arc::new(move |msg| logger_messages.lock().unwrap().push(msg)))
+        .build()
+        .unwrap();
+
+    let messages = messages.lock().unwrap();
+    assert!(messages.contains(&"configured to keep 2 nearest neighbours per job in low memory mode".to_string()));
+}
+
+#[test]
+fn can_reject_sparse_index_without_neighbours() {
+    let (problem, _) = generate_matrix_routes(3, 3);
+
+    let result = Builder::default().with_problem(Arc::new(problem)).with_sparse_index(Some((5, 0))).build();
+
+    assert_eq!(result.err(), Some("amount of neighbours in sparse index should be greater than zero".to_string()));
+}
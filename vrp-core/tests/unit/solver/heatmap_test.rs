@@ -0,0 +1,44 @@
+use super::*;
+use crate::construction::heuristics::INSERTION_FAILURES_KEY;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::problem::{get_job_id, test_single_with_id};
+use std::sync::Arc;
+
+fn create_insertion_ctx(failures: Vec<(&Job, i32)>) -> InsertionContext {
+    let mut insertion_ctx = create_empty_insertion_context();
+    let failures = failures.into_iter().map(|(job, code)| (job.clone(), code)).collect::<Vec<_>>();
+    insertion_ctx.solution.state.insert(INSERTION_FAILURES_KEY, Arc::new(failures));
+
+    insertion_ctx
+}
+
+#[test]
+fn can_aggregate_insertion_failures() {
+    let job1 = Job::Single(test_single_with_id("job1"));
+    let job2 = Job::Single(test_single_with_id("job2"));
+    let heatmap = InsertionHeatmap::default();
+
+    heatmap.record(&create_insertion_ctx(vec![(&job1, 1), (&job2, 2)]));
+    heatmap.record(&create_insertion_ctx(vec![(&job1, 1)]));
+    heatmap.record(&create_insertion_ctx(vec![(&job1, 3)]));
+    heatmap.record(&create_insertion_ctx(vec![]));
+
+    let mut failures =
+        heatmap.failures().into_iter().map(|(job, codes)| (get_job_id(&job).clone(), codes)).collect::<Vec<_>>();
+    failures.sort();
+    assert_eq!(heatmap.samples(), 4);
+    assert_eq!(failures, vec![("job1".to_string(), vec![(1, 2), (3, 1)]), ("job2".to_string(), vec![(2, 1)])]);
+}
+
+#[test]
+fn can_skip_unassigned_jobs_which_did_not_fail_insertion() {
+    let job = Job::Single(test_single_with_id("job1"));
+    let heatmap = InsertionHeatmap::default();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.unassigned.insert(job, 0);
+
+    heatmap.record(&insertion_ctx);
+
+    assert_eq!(heatmap.samples(), 1);
+    assert!(heatmap.failures().is_empty());
+}
@@ -0,0 +1,58 @@
+use super::*;
+use crate::helpers::solver::generate_matrix_routes;
+use crate::utils::DefaultRandom;
+use std::sync::Arc;
+
+fn create_insertion_ctx() -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes(3, 3);
+
+    InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (Arc::new(solution), None),
+        Arc::new(DefaultRandom::default()),
+    )
+}
+
+fn get_first_job(insertion_ctx: &InsertionContext) -> Job {
+    insertion_ctx.solution.routes.first().unwrap().route.tour.jobs().next().unwrap()
+}
+
+#[test]
+fn can_pass_valid_solution() {
+    let insertion_ctx = create_insertion_ctx();
+
+    assert_eq!(check_invariants(&insertion_ctx), Ok(()));
+}
+
+#[test]
+fn can_detect_lost_job() {
+    let mut insertion_ctx = create_insertion_ctx();
+    let job = get_first_job(&insertion_ctx);
+
+    insertion_ctx.solution.routes.first_mut().unwrap().route_mut().tour.remove(&job);
+
+    assert!(check_invariants(&insertion_ctx).unwrap_err().starts_with("job is lost"));
+}
+
+#[test]
+fn can_detect_duplicated_job() {
+    let mut insertion_ctx = create_insertion_ctx();
+    let job = get_first_job(&insertion_ctx);
+
+    insertion_ctx.solution.required.push(job);
+
+    assert!(check_invariants(&insertion_ctx).unwrap_err().starts_with("job is present 2 times"));
+}
+
+#[test]
+fn can_detect_non_monotonic_schedule() {
+    let mut insertion_ctx = create_insertion_ctx();
+
+    let route_ctx = insertion_ctx.solution.routes.first_mut().unwrap();
+    route_ctx.route_mut().tour.get_mut(1).unwrap().schedule.arrival = -10.;
+
+    assert_eq!(
+        check_invariants(&insertion_ctx),
+        Err("activity 0 in tour 0 departs after arrival at the next one".to_string())
+    );
+}
@@ -0,0 +1,69 @@
+use super::*;
+use crate::helpers::construction::constraints::create_simple_demand;
+use crate::helpers::construction::heuristics::create_test_insertion_context;
+use crate::helpers::models::problem::{get_job_id, SingleBuilder};
+use crate::helpers::models::solution::create_test_registry;
+use crate::models::common::TimeWindow;
+use hashbrown::HashMap;
+use std::sync::Arc;
+
+type JobData = (&'static str, Option<i32>, usize, i32, f64);
+
+parameterized_test! {can_sort_jobs_by_priority, (jobs, batch_size, expected), {
+    can_sort_jobs_by_priority_impl(jobs, batch_size, expected);
+}}
+
+can_sort_jobs_by_priority! {
+    case01_priority: (vec![("job1", Some(2), 0, 1, 100.), ("job2", None, 0, 1, 100.), ("job3", Some(3), 0, 1, 100.)],
+                      3, vec!["job2", "job1", "job3"]),
+    case02_aging: (vec![("job1", None, 0, 1, 100.), ("job2", None, 2, 1, 100.), ("job3", None, 1, 1, 100.)],
+                   3, vec!["job2", "job3", "job1"]),
+    case03_demand: (vec![("job1", None, 0, 1, 100.), ("job2", None, 0, 3, 100.), ("job3", None, 0, 2, 100.)],
+                    3, vec!["job2", "job3", "job1"]),
+    case04_time_windows: (vec![("job1", None, 0, 1, 300.), ("job2", None, 0, 1, 100.), ("job3", None, 0, 1, 200.)],
+                          3, vec!["job2", "job3", "job1"]),
+    case05_mixed: (vec![("job1", None, 1, 1, 100.), ("job2", Some(2), 5, 5, 10.), ("job3", None, 1, 2, 500.)],
+                   2, vec!["job3", "job1"]),
+}
+
+fn can_sort_jobs_by_priority_impl(jobs: Vec<JobData>, batch_size: usize, expected: Vec<&str>) {
+    let mut insertion_ctx = create_test_insertion_context(create_test_registry());
+    let mut aging = HashMap::new();
+    jobs.into_iter().for_each(|(id, priority, age, demand, end)| {
+        let mut single = SingleBuilder::default()
+            .id(id)
+            .demand(create_simple_demand(demand))
+            .times(vec![TimeWindow::new(0., end)])
+            .build();
+        if let Some(priority) = priority {
+            single.dimens.set_value("priority", priority);
+        }
+        let job = Job::Single(Arc::new(single));
+
+        aging.insert(job.clone(), age);
+        insertion_ctx.solution.required.push(job);
+    });
+    insertion_ctx.solution.state.insert(JOB_AGING_KEY, Arc::new(aging));
+
+    let selector = PriorityJobSelector::new(Box::new(PriorityJobOrdering::<i32>::default()), batch_size);
+    let result = selector.select(&mut insertion_ctx).map(|job| get_job_id(&job).clone()).collect::<Vec<_>>();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_track_job_aging_when_job_stays_unassigned() {
+    let mut insertion_ctx = create_test_insertion_context(create_test_registry());
+    let job = SingleBuilder::default().id("job1").times(vec![TimeWindow::new(2000., 3000.)]).build_as_job_ref();
+    let heuristic = InsertionHeuristic::default();
+    let selector: Box<dyn JobSelector + Send + Sync> = Box::new(AllJobSelector::default());
+    let reducer: Box<dyn JobMapReducer + Send + Sync> =
+        Box::new(PairJobMapReducer::new(Box::new(BestResultSelector::default())));
+
+    insertion_ctx.solution.unassigned.insert(job.clone(), 0);
+    let insertion_ctx = heuristic.process(&selector, &reducer, insertion_ctx, &None);
+    assert_eq!(get_job_age(&insertion_ctx.solution, &job), 1);
+
+    let insertion_ctx = heuristic.process(&selector, &reducer, insertion_ctx, &None);
+    assert_eq!(get_job_age(&insertion_ctx.solution, &job), 2);
+}
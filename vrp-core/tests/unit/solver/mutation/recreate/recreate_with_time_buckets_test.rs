@@ -0,0 +1,59 @@
+use super::*;
+use crate::helpers::construction::heuristics::create_test_insertion_context;
+use crate::helpers::models::problem::{get_job_id, MultiBuilder, SingleBuilder};
+use crate::helpers::models::solution::create_test_registry;
+use crate::models::common::TimeWindow;
+
+fn create_job(id: &str, times: Vec<(f64, f64)>) -> Job {
+    SingleBuilder::default()
+        .id(id)
+        .times(times.into_iter().map(|(start, end)| TimeWindow::new(start, end)).collect())
+        .build_as_job_ref()
+}
+
+parameterized_test! {can_get_time_bucket, (times, bucket_size, expected), {
+    can_get_time_bucket_impl(times, bucket_size, expected);
+}}
+
+can_get_time_bucket! {
+    case01_single_window: (vec![(3600., 7200.)], 3600., Some(1)),
+    case02_earliest_window: (vec![(7300., 9000.), (1000., 2000.)], 3600., Some(0)),
+    case03_other_bucket_size: (vec![(7300., 9000.)], 1800., Some(4)),
+    case04_no_windows: (vec![], 3600., None),
+}
+
+fn can_get_time_bucket_impl(times: Vec<(f64, f64)>, bucket_size: f64, expected: Option<i64>) {
+    let ordering = TimeBucketJobOrdering::new(bucket_size);
+
+    assert_eq!(ordering.get_bucket(&create_job("job1", times)), expected);
+}
+
+#[test]
+fn can_get_time_bucket_of_multi_job() {
+    let job = MultiBuilder::default()
+        .job(SingleBuilder::default().times(vec![TimeWindow::new(8000., 9000.)]).build())
+        .job(SingleBuilder::default().times(vec![TimeWindow::new(4000., 9000.)]).build())
+        .build();
+
+    assert_eq!(TimeBucketJobOrdering::new(3600.).get_bucket(&job), Some(1));
+}
+
+#[test]
+fn can_select_jobs_from_earliest_bucket() {
+    let mut insertion_ctx = create_test_insertion_context(create_test_registry());
+    insertion_ctx.solution.required = vec![
+        create_job("job1", vec![(7200., 9000.)]),
+        create_job("job2", vec![]),
+        create_job("job3", vec![(3700., 5000.)]),
+        create_job("job4", vec![(3600., 9000.)]),
+    ];
+
+    let selector = TimeBucketJobSelector::new(3600.);
+    let selected = selector.select(&mut insertion_ctx).map(|job| get_job_id(&job).clone()).collect::<Vec<_>>();
+
+    assert_eq!(selected, vec!["job3", "job4"]);
+    assert_eq!(
+        insertion_ctx.solution.required.iter().map(|job| get_job_id(job).as_str()).collect::<Vec<_>>(),
+        vec!["job3", "job4", "job1", "job2"]
+    );
+}
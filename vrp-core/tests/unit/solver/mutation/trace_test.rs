@@ -0,0 +1,77 @@
+use super::*;
+use crate::helpers::models::problem::test_single_with_id;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes};
+use crate::helpers::utils::random::FakeRandom;
+use crate::solver::mutation::{
+    AdjustedStringRemoval, Mutation, RandomRouteRemoval, RecreateWithCheapest, Ruin, RuinAndRecreateMutation,
+};
+use crate::utils::DefaultRandom;
+
+fn create_unassigned_event(id: &str) -> TraceEvent {
+    TraceEvent::Unassigned { job: Job::Single(test_single_with_id(id)), code: 0 }
+}
+
+#[test]
+fn can_keep_only_latest_events_within_capacity() {
+    let trace = OperatorTrace::new(0..10, 2);
+
+    trace.record(1, create_unassigned_event("job1"));
+    trace.record(2, create_unassigned_event("job2"));
+    trace.record(3, create_unassigned_event("job3"));
+
+    assert_eq!(trace.dump(), vec!["[2] unassigned 'job2' with code 0", "[3] unassigned 'job3' with code 0"]);
+}
+
+#[test]
+fn can_skip_events_outside_of_generations() {
+    let trace = OperatorTrace::new(5..6, 10);
+
+    trace.record(4, create_unassigned_event("job1"));
+    trace.record(5, create_unassigned_event("job2"));
+    trace.record(6, create_unassigned_event("job3"));
+
+    assert_eq!(trace.dump(), vec!["[5] unassigned 'job2' with code 0"]);
+}
+
+#[test]
+fn can_trace_ruin_and_recreate_decisions() {
+    let (problem, solution) = generate_matrix_routes(4, 4);
+    let insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (Arc::new(solution), None),
+        Arc::new(DefaultRandom::default()),
+    );
+    let trace = Arc::new(OperatorTrace::new(1..2, 100));
+    let mut refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    refinement_ctx.state.insert(OPERATOR_TRACE_KEY.to_string(), Box::new(trace.clone()));
+    let mutation = RuinAndRecreateMutation::new(
+        Box::new(RecreateWithCheapest::default()),
+        Box::new(RandomRouteRemoval::new(1, 1, 1.)),
+    );
+
+    mutation.mutate(&mut refinement_ctx, insertion_ctx);
+
+    let events = trace.events();
+    let ruined = events.iter().filter(|(_, event)| matches!(event, TraceEvent::Ruined { .. })).count();
+    let recreated = events.iter().filter(|(_, event)| !matches!(event, TraceEvent::Ruined { .. })).count();
+    assert_eq!(ruined, 4);
+    assert_eq!(recreated, 4);
+    assert!(events.iter().all(|(generation, _)| *generation == 1));
+}
+
+#[test]
+fn can_trace_string_removal_decisions() {
+    let (problem, solution) = generate_matrix_routes(10, 1);
+    let insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (Arc::new(solution), None),
+        Arc::new(FakeRandom::new(vec![0, 3, 1, 2], vec![1., 5.])),
+    );
+    let trace = Arc::new(OperatorTrace::new(1..2, 100));
+    let mut refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    refinement_ctx.state.insert(OPERATOR_TRACE_KEY.to_string(), Box::new(trace.clone()));
+
+    AdjustedStringRemoval::default().run(&mut refinement_ctx, insertion_ctx);
+
+    assert_eq!(trace.dump(), vec!["[1] selected string of 5 around 'c2' in '0'"]);
+}
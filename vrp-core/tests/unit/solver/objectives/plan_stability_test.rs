@@ -0,0 +1,72 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::{Fleet, Single};
+
+fn create_fleet() -> Fleet {
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .add_vehicle(test_vehicle_with_id("v2"))
+        .build()
+}
+
+fn get_vehicle(fleet: &Fleet, id: &str) -> Arc<Vehicle> {
+    fleet.vehicles.iter().find(|vehicle| get_vehicle_id(vehicle) == id).unwrap().clone()
+}
+
+fn create_route_ctx(fleet: &Fleet, vehicle: &str, jobs: &[&Arc<Single>]) -> RouteContext {
+    create_route_context_with_activities(
+        fleet,
+        vehicle,
+        jobs.iter().map(|single| test_tour_activity_with_job((*single).clone())).collect(),
+    )
+}
+
+#[test]
+fn can_count_plan_deviation() {
+    let fleet = create_fleet();
+    let (job1, job2, job3) = (test_single_with_id("job1"), test_single_with_id("job2"), test_single_with_id("job3"));
+    let previous = vec![(&job1, "v1"), (&job2, "v1"), (&job3, "v2")]
+        .into_iter()
+        .map(|(single, vehicle)| (Job::Single(single.clone()), get_vehicle(&fleet, vehicle)))
+        .collect::<HashMap<_, _>>();
+    let mut solution_ctx = create_empty_solution_context();
+    solution_ctx.routes = vec![create_route_ctx(&fleet, "v1", &[&job1]), create_route_ctx(&fleet, "v2", &[&job2])];
+
+    let deviation = get_plan_deviation(&previous, &solution_ctx);
+
+    assert_eq!(deviation, 2);
+}
+
+parameterized_test! {can_estimate_job_on_route, (job_id, vehicle, expected), {
+    can_estimate_job_on_route_impl(job_id, vehicle, expected);
+}}
+
+can_estimate_job_on_route! {
+    case01: ("job1", "v1", 0.),
+    case02: ("job1", "v2", 10.),
+    case03: ("job2", "v2", 0.),
+}
+
+fn can_estimate_job_on_route_impl(job_id: &str, vehicle: &str, expected: f64) {
+    let fleet = create_fleet();
+    let job1 = Job::Single(test_single_with_id("job1"));
+    let job = if job_id == "job1" { job1.clone() } else { Job::Single(test_single_with_id(job_id)) };
+    let previous = vec![(job1, get_vehicle(&fleet, "v1"))].into_iter().collect::<HashMap<_, _>>();
+    let (constraint, _) = PlanStability::new_minimized(previous, 10.);
+    let route_ctx = create_route_ctx(&fleet, vehicle, &[]);
+
+    let result = constraint
+        .get_constraints()
+        .filter_map(|variant| match variant {
+            ConstraintVariant::SoftRoute(soft) => {
+                Some(soft.estimate_job(&create_empty_solution_context(), &route_ctx, &job))
+            }
+            _ => None,
+        })
+        .sum::<f64>();
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,26 @@
+use super::*;
+use crate::helpers::models::problem::{test_driver, test_vehicle_with_id, FleetBuilder};
+use crate::helpers::models::solution::{create_route_context_with_activities, test_tour_activity_with_default_job};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn can_recalculate_value_only_for_modified_route() {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", vec![test_tour_activity_with_default_job()]);
+    let memo = RouteMemo::new(10);
+    let calls = AtomicUsize::new(0);
+    let calculate = |rc: &RouteContext| {
+        calls.fetch_add(1, Ordering::Relaxed);
+        rc.route.tour.activity_count()
+    };
+
+    assert_eq!(memo.get_or_calculate(&route_ctx, calculate), 1);
+    assert_eq!(memo.get_or_calculate(&route_ctx, calculate), 1);
+    assert_eq!(memo.get_or_calculate(&route_ctx.deep_copy(), calculate), 1);
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+    route_ctx.route_mut().tour.insert_last(test_tour_activity_with_default_job());
+
+    assert_eq!(memo.get_or_calculate(&route_ctx, calculate), 2);
+    assert_eq!(calls.load(Ordering::Relaxed), 2);
+}
@@ -0,0 +1,75 @@
+use super::*;
+use crate::construction::constraints::TOTAL_DISTANCE_KEY;
+use crate::construction::heuristics::RouteContext;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::problem::test_single_with_id;
+use crate::helpers::models::solution::test_actor;
+use crate::models::problem::Job;
+
+fn create_insertion_ctx(distances: Vec<f64>, unassigned: usize) -> InsertionContext {
+    let mut insertion_ctx = create_empty_insertion_context();
+
+    insertion_ctx.solution.routes = distances
+        .into_iter()
+        .map(|distance| {
+            let mut route_ctx = RouteContext::new(test_actor());
+            route_ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, distance);
+            route_ctx
+        })
+        .collect();
+    insertion_ctx.solution.unassigned =
+        (0..unassigned).map(|idx| (Job::Single(test_single_with_id(format!("job{}", idx).as_str())), 0)).collect();
+
+    insertion_ctx
+}
+
+#[test]
+fn can_keep_only_non_dominated_solutions() {
+    let archive = ParetoArchive::new(10);
+
+    archive.record(&create_insertion_ctx(vec![100.], 0));
+    archive.record(&create_insertion_ctx(vec![10., 10.], 0));
+    archive.record(&create_insertion_ctx(vec![100., 100.], 0));
+    archive.record(&create_insertion_ctx(vec![5.], 1));
+    archive.record(&create_insertion_ctx(vec![10., 10.], 0));
+
+    let result = archive.solutions().iter().map(|(point, _)| (point.tours, point.unassigned)).collect::<Vec<_>>();
+    assert_eq!(result, vec![(1, 1), (2, 0), (1, 0)]);
+}
+
+#[test]
+fn can_replace_dominated_solutions() {
+    let archive = ParetoArchive::new(10);
+
+    archive.record(&create_insertion_ctx(vec![100., 100.], 1));
+    archive.record(&create_insertion_ctx(vec![10., 10.], 2));
+    archive.record(&create_insertion_ctx(vec![10.], 0));
+
+    let result = archive.solutions().iter().map(|(point, _)| (point.tours, point.unassigned)).collect::<Vec<_>>();
+    assert_eq!(result, vec![(1, 0)]);
+}
+
+#[test]
+fn can_limit_amount_of_solutions() {
+    let archive = ParetoArchive::new(2);
+
+    archive.record(&create_insertion_ctx(vec![100.], 0));
+    archive.record(&create_insertion_ctx(vec![10., 10.], 0));
+    archive.record(&create_insertion_ctx(vec![5.], 1));
+
+    let result = archive.solutions().iter().map(|(point, _)| (point.tours, point.unassigned)).collect::<Vec<_>>();
+    assert_eq!(result, vec![(1, 1), (2, 0)]);
+}
+
+#[test]
+fn can_keep_solutions_spread_along_front_when_limit_is_reached() {
+    let archive = ParetoArchive::new(3);
+
+    archive.record(&create_insertion_ctx(vec![100.], 0));
+    archive.record(&create_insertion_ctx(vec![30., 30.], 0));
+    archive.record(&create_insertion_ctx(vec![19., 19., 19.], 0));
+    archive.record(&create_insertion_ctx(vec![3., 3., 3., 3.], 0));
+
+    let result = archive.solutions().iter().map(|(point, _)| (point.tours, point.unassigned)).collect::<Vec<_>>();
+    assert_eq!(result, vec![(4, 0), (3, 0), (1, 0)]);
+}
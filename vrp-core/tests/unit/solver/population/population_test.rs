@@ -13,14 +13,19 @@ fn create_problem() -> Arc<Problem> {
     let fleet = FleetBuilder::default()
         .add_driver(test_driver_with_costs(empty_costs()))
         .add_vehicle(test_vehicle_with_id("v1"))
+        .add_vehicle(test_vehicle_with_id("v2"))
         .build();
     create_empty_problem_with_constraint_and_fleet(create_constraint_pipeline_with_transport(), fleet)
 }
 
 fn create_individual(problem: &Arc<Problem>, fitness: f64) -> Individual {
+    create_individual_with_vehicle(problem, "v1", fitness)
+}
+
+fn create_individual_with_vehicle(problem: &Arc<Problem>, vehicle: &str, fitness: f64) -> Individual {
     let mut insertion_ctx = create_empty_insertion_context();
 
-    let mut route_ctx = create_route_context_with_activities(problem.fleet.as_ref(), "v1", vec![]);
+    let mut route_ctx = create_route_context_with_activities(problem.fleet.as_ref(), vehicle, vec![]);
 
     route_ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, fitness);
     route_ctx.state_mut().put_route_state(TOTAL_DURATION_KEY, 0.);
@@ -89,3 +94,16 @@ fn can_maintain_diversity() {
     population.add(create_individual(&problem, 200.));
     assert_eq!(get_all_fitness(&population), &[50., 100., 200.]);
 }
+
+#[test]
+fn can_keep_different_solutions_with_same_fitness() {
+    let problem = create_problem();
+    let mut population = DominancePopulation::new(problem.clone(), Arc::new(DefaultRandom::default()), 2, 1, 1);
+
+    population.add(create_individual_with_vehicle(&problem, "v1", 100.));
+    population.add(create_individual_with_vehicle(&problem, "v2", 100.));
+    assert_eq!(population.size(), 2);
+
+    population.add(create_individual_with_vehicle(&problem, "v1", 100.));
+    assert_eq!(population.size(), 2);
+}
@@ -0,0 +1,103 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::generate_matrix_routes;
+use crate::utils::DefaultRandom;
+
+fn create_builder(generations: usize) -> Builder {
+    Builder::default().with_max_generations(Some(generations)).with_max_time(None).with_cost_variation(None)
+}
+
+#[test]
+fn can_solve_prepared_problem_multiple_times() {
+    let (problem, _) = generate_matrix_routes(2, 2);
+    let prepared = PreparedProblem::new(problem);
+    let jobs = prepared.problem().jobs.clone();
+
+    let results = vec![10, 20]
+        .into_iter()
+        .map(|generations| prepared.solve(create_builder(generations)).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|(solution, _)| solution.unassigned.is_empty()));
+    assert!(Arc::ptr_eq(&jobs, &prepared.problem().jobs));
+}
+
+#[test]
+fn can_add_and_remove_jobs() {
+    let (problem, _) = generate_matrix_routes(2, 2);
+    let mut prepared = PreparedProblem::new(problem);
+    let removed = prepared.problem().jobs.all().next().unwrap();
+    let added = Job::Single(test_single_with_id_and_location("added", Some(3)));
+
+    prepared
+        .apply_patch(ProblemPatch {
+            added_jobs: vec![added.clone()],
+            removed_jobs: vec![removed],
+            ..Default::default()
+        })
+        .unwrap();
+
+    let problem = prepared.problem();
+    let ids = problem.jobs.all().map(|job| get_job_id(&job).clone()).collect::<Vec<_>>();
+    assert_eq!(ids, vec!["c1", "c2", "c3", "added"]);
+    // NOTE c3 has the same location, so it is not considered as a neighbour
+    assert_eq!(problem.jobs.neighbors(0, &added, 0., f64::MAX).count(), 2);
+    let (solution, _) = prepared.solve(create_builder(10)).unwrap();
+    assert!(solution.unassigned.is_empty());
+}
+
+#[test]
+fn can_change_vehicle_availability() {
+    let (problem, _) = generate_matrix_routes(2, 2);
+    let mut prepared = PreparedProblem::new(problem);
+    let vehicle = prepared.problem().fleet.vehicles.first().unwrap().clone();
+    let get_vehicle_ids = |prepared: &PreparedProblem| {
+        prepared.problem().fleet.actors.iter().map(|a| get_vehicle_id(&a.vehicle).clone()).collect::<Vec<_>>()
+    };
+
+    prepared.apply_patch(ProblemPatch { unavailable_vehicles: vec![vehicle.clone()], ..Default::default() }).unwrap();
+    assert_eq!(get_vehicle_ids(&prepared), vec!["1"]);
+    let (solution, _) = prepared.solve(create_builder(10)).unwrap();
+    assert_eq!(solution.routes.len(), 1);
+
+    prepared.apply_patch(ProblemPatch { available_vehicles: vec![vehicle], ..Default::default() }).unwrap();
+    assert_eq!(get_vehicle_ids(&prepared), vec!["0", "1"]);
+}
+
+#[test]
+fn can_detect_invalid_patch() {
+    let (problem, _) = generate_matrix_routes(2, 2);
+    let mut prepared = PreparedProblem::new(problem);
+    let existing = prepared.problem().jobs.all().next().unwrap();
+    let unknown = Job::Single(test_single_with_id_and_location("unknown", Some(0)));
+    let vehicles = prepared.problem().fleet.vehicles.clone();
+
+    assert!(prepared.apply_patch(ProblemPatch { removed_jobs: vec![unknown], ..Default::default() }).is_err());
+    assert!(prepared.apply_patch(ProblemPatch { added_jobs: vec![existing], ..Default::default() }).is_err());
+    assert!(prepared.apply_patch(ProblemPatch { unavailable_vehicles: vehicles, ..Default::default() }).is_err());
+    assert_eq!(prepared.problem().jobs.size(), 4);
+}
+
+#[test]
+fn can_cancel_assigned_job() {
+    let (problem, solution) = generate_matrix_routes(2, 2);
+    let mut prepared = PreparedProblem::new(problem);
+    let job = prepared.problem().jobs.all().find(|job| get_job_id(job) == "c1").unwrap();
+    let solution = Arc::new(solution);
+
+    let (solution, cancellation) =
+        prepared.cancel_job::<i32>(solution, &job, Arc::new(DefaultRandom::default())).unwrap();
+
+    assert_eq!(prepared.problem().jobs.size(), 3);
+    assert!(solution.routes.iter().all(|route| !route.tour.contains(&job)));
+    assert_eq!(solution.routes.iter().map(|route| route.tour.job_count()).sum::<usize>(), 3);
+    assert_eq!(get_vehicle_id(&cancellation.actor.vehicle), "0");
+    assert!(cancellation.distance > 0.);
+    assert!(cancellation.duration > 0.);
+    assert!(cancellation.cost > 0.);
+    assert_eq!(cancellation.demand, 0);
+
+    let result = prepared.cancel_job::<i32>(Arc::new(solution), &job, Arc::new(DefaultRandom::default()));
+    assert!(result.is_err());
+}
@@ -0,0 +1,19 @@
+use crate::helpers::models::domain::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::solver::termination::cancellation::Cancellation;
+use crate::solver::termination::Termination;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn can_detect_termination_when_cancelled() {
+    let mut refinement_ctx = create_default_refinement_ctx(create_empty_problem());
+    let is_cancelled = Arc::new(AtomicBool::new(false));
+    let termination = Cancellation::new(is_cancelled.clone());
+
+    assert!(!termination.is_termination(&mut refinement_ctx));
+
+    is_cancelled.store(true, Ordering::Relaxed);
+
+    assert!(termination.is_termination(&mut refinement_ctx));
+}
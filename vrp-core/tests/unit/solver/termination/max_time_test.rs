@@ -0,0 +1,37 @@
+use crate::helpers::models::domain::create_empty_problem;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::solver::termination::{MaxTime, Termination};
+use crate::utils::Clock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+struct FakeClock {
+    secs: AtomicU64,
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> f64 {
+        self.secs.load(Ordering::Relaxed) as f64
+    }
+}
+
+parameterized_test! {can_detect_termination_with_given_clock, (elapsed, limit, expected), {
+    can_detect_termination_with_given_clock_impl(elapsed, limit, expected);
+}}
+
+can_detect_termination_with_given_clock! {
+    case_01: (11, 10., true),
+    case_02: (9, 10., false),
+    case_03: (10, 10., false),
+}
+
+fn can_detect_termination_with_given_clock_impl(elapsed: u64, limit: f64, expected: bool) {
+    let mut refinement_ctx = create_default_refinement_ctx(create_empty_problem());
+    let clock = Arc::new(FakeClock { secs: AtomicU64::new(100) });
+    let termination = MaxTime::new_with_clock(limit, clock.clone());
+
+    clock.secs.fetch_add(elapsed, Ordering::Relaxed);
+    let result = termination.is_termination(&mut refinement_ctx);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,69 @@
+use super::*;
+
+#[test]
+fn can_calculate_haversine_distance() {
+    let distance = get_haversine_distance(&(52.52599, 13.45413), &(52.5165, 13.3808));
+
+    assert_eq!(distance.round(), 5078.);
+}
+
+#[test]
+fn can_get_bounding_box() {
+    let points = vec![(52.52599, 13.45413), (52.5225, 13.4095), (52.5165, 13.3808)];
+
+    assert_eq!(get_bounding_box(points.iter()), Some(((52.5165, 13.3808), (52.52599, 13.45413))));
+    assert_eq!(get_bounding_box(vec![].iter()), None);
+}
+
+#[test]
+fn can_get_bounding_box_from_center() {
+    let center = (52.52, 13.41);
+
+    let (min, max) = get_bounding_box_from_center(&center, 1000.);
+
+    let is_close = |distance: f64| (distance - 1000.).abs() < 5.;
+    assert!(is_close(get_haversine_distance(&(center.0, min.1), &center)));
+    assert!(is_close(get_haversine_distance(&(min.0, center.1), &center)));
+    assert!(is_close(get_haversine_distance(&center, &(max.0, center.1))));
+    assert!(is_point_in_polygon(&center, &[min, (min.0, max.1), max, (max.0, min.1)]));
+}
+
+#[test]
+fn can_check_point_in_polygon() {
+    let polygon = vec![(-1., -1.), (-1., 1.), (1., 1.), (1., -1.)];
+    assert_eq!(is_point_in_polygon(&(0., 0.), polygon.as_slice()), true);
+    assert_eq!(is_point_in_polygon(&(2., 0.), polygon.as_slice()), false);
+
+    let polygon = vec![(1., 3.), (2., 8.), (5., 4.), (5., 9.), (7., 5.), (13., 1.), (3., 1.)];
+    assert_eq!(is_point_in_polygon(&(5.5, 7.), polygon.as_slice()), true);
+    assert_eq!(is_point_in_polygon(&(4.5, 7.), polygon.as_slice()), false);
+
+    let polygon = vec![
+        (52.499148, 13.485196),
+        (52.498600, 13.480000),
+        (52.503800, 13.474680),
+        (52.510000, 13.468270),
+        (52.510788, 13.466904),
+        (52.512116, 13.465350),
+        (52.512000, 13.467000),
+        (52.513579, 13.471027),
+        (52.512938, 13.472668),
+        (52.511829, 13.474922),
+        (52.507945, 13.480124),
+        (52.509082, 13.482892),
+        (52.536026, 13.490519),
+        (52.534470, 13.499703),
+        (52.499148, 13.485196),
+    ];
+    assert_eq!(is_point_in_polygon(&(52.508956, 13.483328), polygon.as_slice()), true);
+    assert_eq!(is_point_in_polygon(&(52.505, 13.48), polygon.as_slice()), true);
+
+    let polygon =
+        vec![(52.481171, 13.4107070), (52.480248, 13.4101200), (52.480237, 13.4062790), (52.481161, 13.4062610)];
+    assert_eq!(is_point_in_polygon(&(52.480890, 13.4081030), polygon.as_slice()), true);
+}
+
+#[test]
+fn can_handle_empty_polygon() {
+    assert!(!is_point_in_polygon(&(0., 0.), &[]));
+}
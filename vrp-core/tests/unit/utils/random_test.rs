@@ -0,0 +1,32 @@
+use super::*;
+
+#[test]
+fn can_produce_same_values_with_same_seed() {
+    let get_values = |random: &DefaultRandom| {
+        (0..10).map(|_| (random.uniform_int(0, 1000), random.uniform_real(0., 1.))).collect::<Vec<_>>()
+    };
+
+    let first = get_values(&DefaultRandom::new_with_seed(42));
+    let second = get_values(&DefaultRandom::new_with_seed(42));
+    let third = get_values(&DefaultRandom::new_with_seed(7));
+
+    assert_eq!(first, second);
+    assert_ne!(first, third);
+}
+
+#[test]
+fn can_shuffle_with_same_seed() {
+    let get_shuffled = |seed: u64| {
+        let mut items = (0..20).collect::<Vec<_>>();
+        shuffle(items.as_mut_slice(), &DefaultRandom::new_with_seed(seed));
+        items
+    };
+
+    let first = get_shuffled(42);
+    let mut sorted = first.clone();
+    sorted.sort();
+
+    assert_eq!(first, get_shuffled(42));
+    assert_ne!(first, get_shuffled(7));
+    assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+}
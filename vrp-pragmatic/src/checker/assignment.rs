@@ -16,14 +16,17 @@ pub fn check_assignment(ctx: &CheckerContext) -> Result<(), String> {
 /// Checks that vehicles in each tour are used once per shift and they are known in problem.
 fn check_vehicles(ctx: &CheckerContext) -> Result<(), String> {
     let all_vehicles: HashSet<_> = ctx.problem.fleet.vehicles.iter().flat_map(|v| v.vehicle_ids.iter()).collect();
-    let mut used_vehicles = HashSet::<(String, usize)>::new();
+    let mut used_vehicles = HashSet::<(String, usize, Option<String>)>::new();
 
     ctx.solution.tours.iter().try_for_each(|tour| {
         if !all_vehicles.contains(&tour.vehicle_id) {
             return Err(format!("Used vehicle with unknown id: {}", tour.vehicle_id));
         }
 
-        if !(used_vehicles.insert((tour.vehicle_id.to_string(), tour.shift_index))) {
+        // NOTE shift split by vehicle unavailability can be used by multiple tours with the same shift index
+        let shift_start = ctx.get_vehicle_shift(tour).ok().map(|shift| shift.start.time);
+
+        if !(used_vehicles.insert((tour.vehicle_id.to_string(), tour.shift_index, shift_start))) {
             Err(format!("Vehicle with '{}' id used more than once for shift {}", tour.vehicle_id, tour.shift_index))
         } else {
             Ok(())
@@ -35,14 +38,14 @@ fn check_vehicles(ctx: &CheckerContext) -> Result<(), String> {
 
 fn check_jobs(ctx: &CheckerContext) -> Result<(), String> {
     struct JobAssignment {
-        pub tour_info: (String, usize),
+        pub tour_idx: usize,
         pub pickups: Vec<usize>,
         pub deliveries: Vec<usize>,
         pub replacements: Vec<usize>,
         pub services: Vec<usize>,
     }
-    let new_assignment = |tour_info: (String, usize)| JobAssignment {
-        tour_info,
+    let new_assignment = |tour_idx: usize| JobAssignment {
+        tour_idx,
         pickups: vec![],
         deliveries: vec![],
         replacements: vec![],
@@ -53,18 +56,16 @@ fn check_jobs(ctx: &CheckerContext) -> Result<(), String> {
     let all_jobs = ctx.problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect::<HashMap<_, _>>();
     let mut used_jobs = HashMap::<String, JobAssignment>::new();
 
-    ctx.solution.tours.iter().try_for_each(|tour| {
+    ctx.solution.tours.iter().enumerate().try_for_each(|(tour_idx, tour)| {
         tour.stops
             .iter()
             .flat_map(|stop| stop.activities.iter())
             .enumerate()
             .filter(|(_, activity)| activity_types.contains(&activity.activity_type.as_str()))
             .try_for_each(|(idx, activity)| {
-                let tour_info = (tour.vehicle_id.clone(), tour.shift_index);
-                let asgn =
-                    used_jobs.entry(activity.job_id.clone()).or_insert_with(|| new_assignment(tour_info.clone()));
+                let asgn = used_jobs.entry(activity.job_id.clone()).or_insert_with(|| new_assignment(tour_idx));
 
-                if asgn.tour_info != tour_info {
+                if asgn.tour_idx != tour_idx {
                     return Err(format!("Job served in multiple tours: '{}'", activity.job_id));
                 }
 
@@ -72,48 +72,61 @@ pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), String> {
                         },
                     )?;
 
-                let end_capacity = interval.iter().try_fold(start_delivery, |acc, (idx, (from, to))| {
-                    let from_load = Capacity::new(from.load.clone());
-                    let to_load = Capacity::new(to.load.clone());
-
-                    if from_load > capacity || to_load > capacity {
-                        return Err(format!("Load exceeds capacity in tour '{}'", tour.vehicle_id));
-                    }
-
-                    let change = to.activities.iter().try_fold::<_, _, Result<_, String>>(
-                        Capacity::default(),
-                        |acc, activity| {
-                            let activity_type = context.get_activity_type(tour, to, activity)?;
-                            let (demand_type, demand) =
-                                if activity.activity_type == "arrival" || activity.activity_type == "reload" {
-                                    (DemandType::StaticDelivery, end_pickup)
-                                } else {
-                                    get_demand(context, &activity, &activity_type)?
-                                };
-
-                            Ok(match demand_type {
-                                DemandType::StaticDelivery | DemandType::DynamicDelivery => acc - demand,
-                                DemandType::StaticPickup | DemandType::DynamicPickup => acc + demand,
-                                DemandType::None | DemandType::StaticPickupDelivery => acc,
-                            })
-                        },
-                    )?;
-
-                    let is_from_valid = from_load == acc;
-                    let is_to_valid = to_load == from_load + change;
-
-                    if is_from_valid && is_to_valid {
-                        Ok(to_load)
-                    } else {
-                        let message = match (is_from_valid, is_to_valid) {
-                            (true, false) => format!("at stop {}", idx + 1),
-                            (false, true) => format!("at stop {}", idx),
-                            _ => format!("at stops {}, {}", idx, idx + 1),
-                        };
-
-                        Err(format!("Load mismatch {} in tour '{}'", message, tour.vehicle_id))
-                    }
-                })?;
+                let end_capacity =
+                    interval.iter().try_fold::<_, _, Result<_, String>>(start_delivery, |acc, (idx, (from, to))| {
+                        let from_load = Capacity::new(from.load.clone());
+                        let to_load = Capacity::new(to.load.clone());
+
+                        if from_load > capacity || to_load > capacity {
+                            let stop_idx = if from_load > capacity { *idx } else { idx + 1 };
+                            context.check_tolerance(
+                                get_excess(&from_load, &capacity).max(get_excess(&to_load, &capacity)),
+                                context.config.load_tolerance,
+                                format!("Load exceeds capacity at stop {} in tour '{}'", stop_idx, tour.vehicle_id),
+                            )?;
+                        }
+
+                        let change = to.activities.iter().try_fold::<_, _, Result<_, String>>(
+                            Capacity::default(),
+                            |acc, activity| {
+                                let activity_type = context.get_activity_type(tour, to, activity)?;
+                                let (demand_type, demand) =
+                                    if activity.activity_type == "arrival" || activity.activity_type == "reload" {
+                                        (DemandType::StaticDelivery, end_pickup)
+                                    } else {
+                                        get_demand(context, &activity, &activity_type)?
+                                    };
+
+                                Ok(match demand_type {
+                                    DemandType::StaticDelivery | DemandType::DynamicDelivery => acc - demand,
+                                    DemandType::StaticPickup | DemandType::DynamicPickup => acc + demand,
+                                    DemandType::None | DemandType::StaticPickupDelivery => acc,
+                                })
+                            },
+                        )?;
+
+                        let is_from_valid = from_load == acc;
+                        let is_to_valid = to_load == from_load + change;
+
+                        if is_from_valid && is_to_valid {
+                            Ok(to_load)
+                        } else {
+                            let message = match (is_from_valid, is_to_valid) {
+                                (true, false) => format!("at stop {}", idx + 1),
+                                (false, true) => format!("at stop {}", idx),
+                                _ => format!("at stops {}, {}", idx, idx + 1),
+                            };
+
+                            let expected_to_load = from_load + change;
+                            context.check_tolerance(
+                                get_difference(&from_load, &acc).max(get_difference(&to_load, &expected_to_load)),
+                                context.config.load_tolerance,
+                                format!("Load mismatch {} in tour '{}'", message, tour.vehicle_id),
+                            )?;
+
+                            Ok(to_load)
+                        }
+                    })?;
 
                 Ok(end_capacity - end_pickup)
             })
@@ -163,3 +176,13 @@ fn get_demand(
 fn is_reload_stop(context: &CheckerContext, stop: &Stop) -> bool {
     context.get_stop_activity_types(stop).first().map_or(false, |a| a == "reload")
 }
+
+/// Returns the biggest amount by which left capacity exceeds right one across all dimensions.
+fn get_excess(left: &Capacity, right: &Capacity) -> i32 {
+    left.capacity.iter().zip(right.capacity.iter()).map(|(left, right)| left - right).max().unwrap_or(0)
+}
+
+/// Returns the biggest absolute difference between capacities across all dimensions.
+fn get_difference(left: &Capacity, right: &Capacity) -> i32 {
+    left.capacity.iter().zip(right.capacity.iter()).map(|(left, right)| (left - right).abs()).max().unwrap_or(0)
+}
@@ -3,17 +3,43 @@
 
 use crate::format::problem::*;
 use crate::format::solution::*;
-use crate::format::Location;
+use crate::format::{CoordIndex, Location};
 use crate::parse_time;
+use serde::Serialize;
+use std::cell::RefCell;
 use std::collections::HashMap;
 use vrp_core::models::common::TimeWindow;
 
+/// Specifies checker tolerances: violations which do not exceed them are reported as warnings
+/// instead of errors. This is useful when solution comes from a system with different rounding.
+#[derive(Clone, Debug, Default)]
+pub struct CheckerConfig {
+    /// Time window or travel duration violation in seconds.
+    pub time_tolerance: f64,
+    /// Load violation in any of capacity dimensions.
+    pub load_tolerance: i32,
+    /// Travel distance violation in meters.
+    pub distance_tolerance: f64,
+}
+
+/// Contains checker findings classified by their severity.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct CheckerReport {
+    /// Constraint violations which make solution infeasible.
+    pub errors: Vec<String>,
+    /// Soft constraint violations and violations within configured tolerance.
+    pub warnings: Vec<String>,
+}
+
 /// Stores problem and solution together and provides some helper methods.
 pub struct CheckerContext {
     pub problem: Problem,
     pub matrices: Option<Vec<Matrix>>,
     pub solution: Solution,
+    pub config: CheckerConfig,
     job_map: HashMap<String, Job>,
+    coord_index: CoordIndex,
+    warnings: RefCell<Vec<String>>,
 }
 
 /// Represents all possible activity types.
@@ -27,22 +53,78 @@ enum ActivityType {
 impl CheckerContext {
     pub fn new(problem: Problem, matrices: Option<Vec<Matrix>>, solution: Solution) -> Self {
         let job_map = problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect();
+        let coord_index = CoordIndex::new(&problem);
+        let matrices =
+            matrices.map(|matrices| with_straight_line_matrices(&problem, matrices, get_distance_factor(&problem)));
+        // NOTE tour shifts are resolved by time, so carving by unavailability keeps original shift indices valid
+        let problem = carve_vehicle_shifts(&problem).map_or(problem, |(problem, _)| problem);
+
+        Self {
+            problem,
+            matrices,
+            solution,
+            config: CheckerConfig::default(),
+            job_map,
+            coord_index,
+            warnings: RefCell::new(vec![]),
+        }
+    }
 
-        Self { problem, matrices, solution, job_map }
+    /// Sets checker tolerances.
+    pub fn with_config(self, config: CheckerConfig) -> Self {
+        Self { config, ..self }
     }
 
+    /// Checks solution feasibility. Returns all found violations separated by new line.
+    /// Warnings are not treated as violations, use [`get_report`] to get them.
+    ///
+    /// [`get_report`]: #method.get_report
     pub fn check(&self) -> Result<(), String> {
-        check_vehicle_load(&self)?;
-        check_relations(&self)?;
+        let report = self.get_report();
+
+        if report.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(report.errors.join("\n"))
+        }
+    }
+
+    /// Checks solution feasibility and returns all findings classified as errors and warnings.
+    pub fn get_report(&self) -> CheckerReport {
+        self.warnings.borrow_mut().clear();
 
         if let Err(err) = check_breaks(&self) {
             // TODO break is soft constraint and can be violated, how to improve checker?
-            println!("break is violated: {}", err);
+            self.add_warning(format!("break is violated: {}", err));
         }
 
-        check_assignment(&self)?;
+        let errors = vec![
+            check_vehicle_load(&self),
+            check_relations(&self),
+            check_forbidden_arcs(&self),
+            check_assignment(&self),
+            check_time_windows(&self),
+            check_routing(&self),
+        ]
+        .into_iter()
+        .filter_map(|result| result.err())
+        .collect::<Vec<_>>();
+
+        CheckerReport { errors, warnings: self.warnings.borrow().clone() }
+    }
+
+    /// Reports violation as error if it exceeds given tolerance, otherwise keeps it as warning.
+    fn check_tolerance<T: PartialOrd>(&self, violation: T, tolerance: T, message: String) -> Result<(), String> {
+        if violation > tolerance {
+            Err(message)
+        } else {
+            self.add_warning(message);
+            Ok(())
+        }
+    }
 
-        Ok(())
+    fn add_warning(&self, message: String) {
+        self.warnings.borrow_mut().push(message);
     }
 
     /// Gets vehicle by its id.
@@ -233,4 +315,10 @@ mod breaks;
 use crate::checker::breaks::check_breaks;
 
 mod relations;
-use crate::checker::relations::check_relations;
+use crate::checker::relations::{check_forbidden_arcs, check_relations};
+
+mod routing;
+use crate::checker::routing::check_routing;
+
+mod time_windows;
+use crate::checker::time_windows::check_time_windows;
@@ -12,7 +12,7 @@ pub fn check_relations(context: &CheckerContext) -> Result<(), String> {
     (0_usize..)
         .zip(context.problem.plan.relations.as_ref().map_or(vec![].iter(), |relations| relations.iter()))
         .try_for_each(|(idx, relation)| {
-            let tour = get_tour_by_vehicle_id(&relation.vehicle_id, relation.shift_index, &context.solution);
+            let tour = get_tour_by_relation(relation, &reserved_ids, &context.solution);
             // NOTE tour can be absent for tour relation
             let tour = if tour.is_err() {
                 return match relation.type_field {
@@ -88,13 +88,54 @@ pub fn check_relations(context: &CheckerContext) -> Result<(), String> {
     Ok(())
 }
 
-fn get_tour_by_vehicle_id(vehicle_id: &str, shift_index: Option<usize>, solution: &Solution) -> Result<Tour, String> {
-    solution
+/// Checks that no job is served directly after another one when an arc between them is forbidden.
+pub fn check_forbidden_arcs(context: &CheckerContext) -> Result<(), String> {
+    let arcs = context
+        .problem
+        .plan
+        .forbidden_arcs
+        .iter()
+        .flat_map(|arcs| arcs.iter())
+        .map(|arc| (arc.from.as_str(), arc.to.as_str()))
+        .collect::<HashSet<_>>();
+
+    if arcs.is_empty() {
+        return Ok(());
+    }
+
+    context.solution.tours.iter().try_for_each(|tour| {
+        let activity_ids = get_activity_ids(tour);
+
+        activity_ids.windows(2).try_for_each(|pair| {
+            if arcs.contains(&(pair[0].as_str(), pair[1].as_str())) {
+                Err(format!("Forbidden arc from '{}' to '{}' is used in tour '{}'", pair[0], pair[1], tour.vehicle_id))
+            } else {
+                Ok(())
+            }
+        })
+    })
+}
+
+fn get_tour_by_relation(
+    relation: &Relation,
+    reserved_ids: &HashSet<&str>,
+    solution: &Solution,
+) -> Result<Tour, String> {
+    let tours = solution
         .tours
         .iter()
-        .find(|tour| tour.vehicle_id == vehicle_id && tour.shift_index == shift_index.unwrap_or(0))
-        .cloned()
-        .ok_or_else(|| format!("Cannot find tour for '{}'", vehicle_id))
+        .filter(|tour| tour.vehicle_id == relation.vehicle_id && tour.shift_index == relation.shift_index.unwrap_or(0))
+        .collect::<Vec<_>>();
+
+    // NOTE shift split by vehicle unavailability has multiple tours, prefer the one with relation jobs
+    tours
+        .iter()
+        .find(|tour| {
+            get_activity_ids(tour).iter().any(|id| !reserved_ids.contains(id.as_str()) && relation.jobs.contains(id))
+        })
+        .or_else(|| tours.first())
+        .map(|tour| (*tour).clone())
+        .ok_or_else(|| format!("Cannot find tour for '{}'", relation.vehicle_id))
 }
 
 fn get_activity_ids(tour: &Tour) -> Vec<String> {
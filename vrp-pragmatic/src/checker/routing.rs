@@ -0,0 +1,114 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/routing_test.rs"]
+mod routing_test;
+
+use super::*;
+use crate::format::problem::get_distance_factor;
+use crate::parse_time_of_day;
+
+/// Solution reports time in whole seconds and distance in whole meters, so values of a leg can
+/// differ from routing data by one unit due to rounding.
+const ROUNDING_PRECISION: f64 = 1.;
+
+/// Checks that distances and arrival times between stops are consistent with routing matrices.
+/// Violations within configured distance and time tolerances are reported as warnings.
+/// Does nothing when matrices are not specified.
+pub fn check_routing(context: &CheckerContext) -> Result<(), String> {
+    let matrices = if let Some(matrices) = context.matrices.as_ref() { matrices } else { return Ok(()) };
+    let coord_index = &context.coord_index;
+    let distance_factor = get_distance_factor(&context.problem);
+
+    context.solution.tours.iter().try_for_each(|tour| {
+        let profile = context.get_vehicle(tour.vehicle_id.as_str())?.profile.clone();
+        let matrix = matrices
+            .iter()
+            .find(|matrix| matrix.profile == profile)
+            .ok_or_else(|| format!("Cannot find routing matrix for profile '{}'", profile))?;
+        let profile = context.problem.fleet.profiles.iter().find(|p| p.name == profile);
+        let overrides = profile.and_then(|p| p.overrides.as_ref()).map_or(&[][..], |overrides| overrides.as_slice());
+        let multipliers =
+            profile.and_then(|p| p.multipliers.as_ref()).map_or(&[][..], |multipliers| multipliers.as_slice());
+
+        (1..).zip(tour.stops.windows(2)).try_for_each(|(idx, leg)| {
+            let (from, to) = match leg {
+                [from, to] => (from, to),
+                _ => unreachable!(),
+            };
+
+            let factor = get_multiplier(multipliers, parse_time(&from.time.departure));
+            let (duration, distance) =
+                get_routing(&coord_index, matrix, overrides, (factor, distance_factor), &from.location, &to.location)?;
+
+            let actual_distance = (to.distance - from.distance) as f64;
+            let distance_violation = (actual_distance - distance).abs() - ROUNDING_PRECISION;
+            if distance_violation > 0. {
+                context.check_tolerance(
+                    distance_violation,
+                    context.config.distance_tolerance,
+                    format!(
+                        "Distance mismatch at stop {} in tour '{}': expected {}, got {}",
+                        idx, tour.vehicle_id, distance, actual_distance
+                    ),
+                )?;
+            }
+
+            let actual_duration = parse_time(&to.time.arrival) - parse_time(&from.time.departure);
+            let duration_violation = duration - actual_duration - ROUNDING_PRECISION;
+            if duration_violation > 0. {
+                context.check_tolerance(
+                    duration_violation,
+                    context.config.time_tolerance,
+                    format!(
+                        "Arrival at stop {} in tour '{}' is earlier than travel duration allows",
+                        idx, tour.vehicle_id
+                    ),
+                )?;
+            }
+
+            Ok(())
+        })
+    })
+}
+
+/// Returns travel duration and distance between two locations.
+fn get_routing(
+    coord_index: &CoordIndex,
+    matrix: &Matrix,
+    overrides: &[RoutingOverride],
+    (duration_factor, distance_factor): (f64, f64),
+    from: &Location,
+    to: &Location,
+) -> Result<(f64, f64), String> {
+    let get_index = |location: &Location| {
+        coord_index
+            .get_by_loc(location)
+            .ok_or_else(|| format!("Cannot find location ({}, {}) in the problem", location.lat, location.lng))
+    };
+
+    let size = (matrix.distances.len() as f64).sqrt().round() as usize;
+    let matrix_idx = get_index(from)? * size + get_index(to)?;
+
+    let duration = *matrix.travel_times.get(matrix_idx).ok_or_else(|| "Routing matrix is too small".to_string())?;
+    let distance = *matrix.distances.get(matrix_idx).ok_or_else(|| "Routing matrix is too small".to_string())?;
+
+    let routing_override = overrides.iter().find(|o| &o.from == from && &o.to == to);
+
+    Ok((
+        routing_override.and_then(|o| o.duration).unwrap_or(duration as f64 * duration_factor),
+        routing_override.and_then(|o| o.distance).unwrap_or(distance as f64) * distance_factor,
+    ))
+}
+
+/// Returns travel time multiplier for given departure time.
+fn get_multiplier(multipliers: &[TravelTimeMultiplier], departure: f64) -> f64 {
+    let time_of_day = departure.rem_euclid(86400.);
+
+    multipliers
+        .iter()
+        .find(|item| {
+            let start = parse_time_of_day(&item.start).unwrap_or(std::f64::MAX);
+            let end = parse_time_of_day(&item.end).unwrap_or(std::f64::MIN);
+            start <= time_of_day && time_of_day < end
+        })
+        .map_or(1., |item| item.factor)
+}
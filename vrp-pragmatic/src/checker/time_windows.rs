@@ -0,0 +1,65 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/time_windows_test.rs"]
+mod time_windows_test;
+
+use super::*;
+
+/// Checks that job activities are served within their time windows. When solution reports time
+/// window index, only that time window is checked, otherwise activity has to be served within any
+/// of job place time windows.
+pub fn check_time_windows(ctx: &CheckerContext) -> Result<(), String> {
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        tour.stops.iter().enumerate().try_for_each(|(stop_idx, stop)| {
+            stop.activities.iter().try_for_each(|activity| {
+                let activity_type = ctx.get_activity_type(tour, stop, activity)?;
+                let location = get_location(stop, activity);
+
+                let time_windows = ctx.visit_job(
+                    activity,
+                    &activity_type,
+                    |_, task| {
+                        task.places
+                            .iter()
+                            .filter(|place| same_locations(&place.location, &location))
+                            .find_map(|place| place.times.as_ref())
+                            .map(|times| times.iter().map(parse_time_window).collect::<Vec<_>>())
+                    },
+                    || None,
+                )?;
+
+                let (time_windows, tw_name) = match (time_windows, activity.time_window) {
+                    (time_windows, Some(tw_index)) => (
+                        time_windows
+                            .and_then(|time_windows| time_windows.get(tw_index).cloned())
+                            .map(|time_window| vec![time_window])
+                            .ok_or_else(|| {
+                                format!("Cannot find time window {} for job '{}'", tw_index, activity.job_id)
+                            })?,
+                        format!("time window {}", tw_index),
+                    ),
+                    (Some(time_windows), None) => (time_windows, "time windows".to_string()),
+                    (None, None) => return Ok(()),
+                };
+
+                let activity_time = get_time_window(stop, activity);
+                if time_windows.is_empty() || time_windows.iter().any(|tw| tw.intersects(&activity_time)) {
+                    Ok(())
+                } else {
+                    let violation = time_windows
+                        .iter()
+                        .map(|tw| (tw.start - activity_time.end).max(activity_time.start - tw.end))
+                        .fold(std::f64::MAX, |acc, violation| acc.min(violation));
+
+                    ctx.check_tolerance(
+                        violation,
+                        ctx.config.time_tolerance,
+                        format!(
+                            "Job '{}' is served outside of its {} at stop {} in tour '{}'",
+                            activity.job_id, tw_name, stop_idx, tour.vehicle_id
+                        ),
+                    )
+                }
+            })
+        })
+    })
+}
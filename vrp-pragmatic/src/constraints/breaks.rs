@@ -64,6 +64,10 @@ impl ConstraintModule for BreakModule {
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn is_cacheable(&self) -> bool {
+        true
+    }
 }
 
 struct BreakHardActivityConstraint {
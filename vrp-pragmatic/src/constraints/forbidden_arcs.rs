@@ -0,0 +1,105 @@
+use std::collections::{HashMap, HashSet};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::{Activity, Tour};
+
+type ForbiddenArcs = HashMap<Job, HashSet<Job>>;
+
+/// A module which prevents serving specific jobs directly after each other.
+pub struct ForbiddenArcModule {
+    arcs: Arc<ForbiddenArcs>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ForbiddenArcModule {
+    pub fn new(arcs: ForbiddenArcs, code: i32) -> Self {
+        let arcs = Arc::new(arcs);
+
+        Self {
+            arcs: arcs.clone(),
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(ForbiddenArcHardActivityConstraint {
+                arcs,
+                code,
+            }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for ForbiddenArcModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_ctx: &mut RouteContext, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        // NOTE removing a job from a tour, e.g. by ruin, joins its neighbours which can form a
+        // forbidden arc, so one of its jobs is moved back to required to be inserted again
+        let locked = &ctx.locked;
+        let removed = ctx.routes.iter_mut().fold(Vec::new(), |mut acc, rc| {
+            while let Some(job) = find_forbidden_job(self.arcs.as_ref(), &rc.route.tour, |job| locked.contains(job)) {
+                rc.route_mut().tour.remove(&job);
+                acc.push(job);
+            }
+
+            acc
+        });
+
+        ctx.required.extend(removed.into_iter());
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+
+    fn is_cacheable(&self) -> bool {
+        true
+    }
+}
+
+struct ForbiddenArcHardActivityConstraint {
+    arcs: Arc<ForbiddenArcs>,
+    code: i32,
+}
+
+impl HardActivityConstraint for ForbiddenArcHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        _route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let prev = activity_ctx.prev.as_ref();
+        let target = activity_ctx.target.as_ref();
+        let next = activity_ctx.next.map(|next| next.as_ref());
+
+        if is_forbidden(self.arcs.as_ref(), prev, Some(target)) || is_forbidden(self.arcs.as_ref(), target, next) {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+fn is_forbidden(arcs: &ForbiddenArcs, from: &Activity, to: Option<&Activity>) -> bool {
+    match (from.retrieve_job(), to.and_then(|to| to.retrieve_job())) {
+        (Some(from), Some(to)) => arcs.get(&from).map_or(false, |forbidden| forbidden.contains(&to)),
+        _ => false,
+    }
+}
+
+/// Returns a job of the first forbidden arc in the tour which is not locked, preferring the arc's end.
+fn find_forbidden_job<F: Fn(&Job) -> bool>(arcs: &ForbiddenArcs, tour: &Tour, is_locked: F) -> Option<Job> {
+    tour.all_activities()
+        .zip(tour.all_activities().skip(1))
+        .filter(|(from, to)| is_forbidden(arcs, from, Some(to)))
+        .find_map(|(from, to)| {
+            to.retrieve_job().into_iter().chain(from.retrieve_job().into_iter()).find(|job| !is_locked(job))
+        })
+}
@@ -1,37 +1,54 @@
 //! Contains implementation of extra constraints.
 
-use std::sync::Arc;
-use vrp_core::models::common::{Dimensions, IdDimension, ValueDimension};
-use vrp_core::models::problem::Single;
-use vrp_core::models::solution::{Activity, Route};
-
-fn as_single_job<F>(activity: &Activity, condition: F) -> Option<&Arc<Single>>
-where
-    F: Fn(&Arc<Single>) -> bool,
-{
-    activity.job.as_ref().and_then(|job| if condition(job) { Some(job) } else { None })
-}
-
-fn get_shift_index(dimens: &Dimensions) -> usize {
-    *dimens.get_value::<usize>("shift_index").unwrap()
-}
-
-fn get_vehicle_id_from_job(job: &Arc<Single>) -> Option<&String> {
-    job.dimens.get_value::<String>("vehicle_id")
-}
-
-fn is_correct_vehicle(route: &Route, target_id: &String, target_shift: usize) -> bool {
-    route.actor.vehicle.dimens.get_id().unwrap() == target_id
-        && get_shift_index(&route.actor.vehicle.dimens) == target_shift
+#[cfg(any(feature = "breaks", feature = "reloads"))]
+use self::conditional::*;
+
+/// Contains helpers for conditional jobs which are assigned to specific vehicle shift, e.g. breaks.
+#[cfg(any(feature = "breaks", feature = "reloads"))]
+mod conditional {
+    use std::sync::Arc;
+    use vrp_core::models::common::{Dimensions, IdDimension, ValueDimension};
+    use vrp_core::models::problem::Single;
+    use vrp_core::models::solution::{Activity, Route};
+
+    pub fn as_single_job<F>(activity: &Activity, condition: F) -> Option<&Arc<Single>>
+    where
+        F: Fn(&Arc<Single>) -> bool,
+    {
+        activity.job.as_ref().and_then(|job| if condition(job) { Some(job) } else { None })
+    }
+
+    pub fn get_shift_index(dimens: &Dimensions) -> usize {
+        *dimens.get_value::<usize>("shift_index").unwrap()
+    }
+
+    pub fn get_vehicle_id_from_job(job: &Arc<Single>) -> Option<&String> {
+        job.dimens.get_value::<String>("vehicle_id")
+    }
+
+    pub fn is_correct_vehicle(route: &Route, target_id: &String, target_shift: usize) -> bool {
+        route.actor.vehicle.dimens.get_id().unwrap() == target_id
+            && get_shift_index(&route.actor.vehicle.dimens) == target_shift
+    }
 }
 
+#[cfg(feature = "breaks")]
 mod breaks;
+#[cfg(feature = "breaks")]
 pub use self::breaks::BreakModule;
 
+mod forbidden_arcs;
+pub use self::forbidden_arcs::ForbiddenArcModule;
+
+mod positions;
+pub use self::positions::PositionModule;
+
 mod priorities;
 pub use self::priorities::PriorityModule;
 
+#[cfg(feature = "reloads")]
 mod reloads;
+#[cfg(feature = "reloads")]
 pub use self::reloads::ReloadMultiTrip;
 
 mod reachable;
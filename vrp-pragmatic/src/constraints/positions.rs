@@ -0,0 +1,85 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/positions_test.rs"]
+mod positions_test;
+
+use crate::format::problem::JobPosition;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Cost, ValueDimension};
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::TourActivity;
+
+/// A module which penalizes insertions violating preferred job positions in the tour. A job with
+/// `first` position should not be preceded by activities of other jobs, a job with `last` position
+/// should not be followed by them.
+pub struct PositionModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl PositionModule {
+    pub fn new(penalty: Cost) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(PositionSoftActivityConstraint { penalty }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for PositionModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_ctx: &mut RouteContext, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+
+    fn is_cacheable(&self) -> bool {
+        true
+    }
+}
+
+struct PositionSoftActivityConstraint {
+    penalty: Cost,
+}
+
+impl PositionSoftActivityConstraint {
+    /// Checks whether `second` activity can directly follow `first` one.
+    fn is_violated(first: &TourActivity, second: Option<&TourActivity>) -> bool {
+        match (first.retrieve_job(), second.and_then(|second| second.retrieve_job())) {
+            (Some(first), Some(second)) if first != second => {
+                get_position(&first) == Some(JobPosition::Last) || get_position(&second) == Some(JobPosition::First)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl SoftActivityConstraint for PositionSoftActivityConstraint {
+    fn estimate_activity(&self, _: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let count = |is_violated: bool| if is_violated { 1 } else { 0 };
+
+        let before = count(Self::is_violated(activity_ctx.prev, activity_ctx.next));
+        let after = count(Self::is_violated(activity_ctx.prev, Some(activity_ctx.target)))
+            + count(Self::is_violated(activity_ctx.target, activity_ctx.next));
+
+        (after - before).max(0) as f64 * self.penalty
+    }
+}
+
+fn get_position(job: &Job) -> Option<JobPosition> {
+    match job {
+        Job::Single(job) => job.dimens.get_value::<JobPosition>("position"),
+        Job::Multi(job) => job.dimens.get_value::<JobPosition>("position"),
+    }
+    .cloned()
+}
@@ -2,7 +2,7 @@ use std::slice::Iter;
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
 use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
-use vrp_core::models::common::ValueDimension;
+use vrp_core::models::common::{CostExt, ValueDimension};
 use vrp_core::models::problem::Job;
 
 /** Adds some extra penalty to jobs with priority bigger than 1. */
@@ -43,7 +43,8 @@ struct PrioritySoftRouteConstraint {}
 
 impl SoftRouteConstraint for PrioritySoftRouteConstraint {
     fn estimate_job(&self, solution_ctx: &SolutionContext, _: &RouteContext, job: &Job) -> f64 {
-        get_priority(job).map_or(0., |priority| ((priority - 1) as f64 * solution_ctx.get_max_cost().max(1E9)))
+        get_priority(job)
+            .map_or(0., |priority| ((priority - 1) as f64).saturating_mul(solution_ctx.get_max_cost().max(1E9)))
     }
 }
 
@@ -35,6 +35,10 @@ impl ConstraintModule for ReachableModule {
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn is_cacheable(&self) -> bool {
+        true
+    }
 }
 
 struct ReachableHardActivityConstraint {
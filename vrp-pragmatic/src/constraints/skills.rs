@@ -34,6 +34,10 @@ impl ConstraintModule for SkillsModule {
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn is_cacheable(&self) -> bool {
+        true
+    }
 }
 
 struct SkillsHardRouteConstraint {
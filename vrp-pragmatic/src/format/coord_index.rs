@@ -52,6 +52,11 @@ impl CoordIndex {
             });
         });
 
+        // NOTE vehicle positions are appended after all other locations
+        problem.fleet.vehicles.iter().flat_map(|vehicle| vehicle.positions.iter().flatten()).for_each(|position| {
+            index.add(&position.location);
+        });
+
         index
     }
 
@@ -77,6 +77,10 @@ const LOCKING_CONSTRAINT_CODE: i32 = 7;
 const REACHABLE_CONSTRAINT_CODE: i32 = 8;
 const PRIORITY_CONSTRAINT_CODE: i32 = 9;
 const AREA_CONSTRAINT_CODE: i32 = 10;
+const FORBIDDEN_ARC_CONSTRAINT_CODE: i32 = 11;
+
+/// A penalty added to insertion cost when preferred job position is not respected.
+const POSITION_PENALTY: f64 = 1E6;
 
 mod coord_index;
 pub use self::coord_index::CoordIndex;
@@ -0,0 +1,166 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/fleet_expansion_test.rs"]
+mod fleet_expansion_test;
+
+use serde_json::{Map, Value};
+
+/// Expands fleet shortcuts in problem json so it can be deserialized into [`Problem`]:
+/// * `fleet.vehicleDefaults`: properties applied to each vehicle type. Nested objects, e.g. costs,
+///   are merged property by property, other values are used only when vehicle type omits them
+/// * `amount`: an amount of vehicles of given type used instead of `vehicleIds`. Vehicle ids are
+///   generated from type id: `car_1`, `car_2`, .., `car_n`
+/// * `overrides`: a list of vehicle specific properties, each within `vehicleId`. Such vehicle is
+///   moved to a separate vehicle type with the same id as vehicle, which has type properties
+///   merged with overridden ones
+/// * `fleet.breakTemplates`: named breaks, each is a break or a list of breaks, which can be
+///   referenced by name within `breaks` of vehicle shifts
+///
+/// [`Problem`]: ./struct.Problem.html
+pub fn expand_fleet(problem: &mut Value) -> Result<(), String> {
+    let fleet = match problem.get_mut("fleet").and_then(|fleet| fleet.as_object_mut()) {
+        Some(fleet) => fleet,
+        None => return Ok(()),
+    };
+
+    let defaults = match fleet.remove("vehicleDefaults") {
+        Some(defaults) if defaults.is_object() => Some(defaults),
+        Some(_) => return Err("vehicleDefaults must be an object".to_string()),
+        None => None,
+    };
+
+    let templates = match fleet.remove("breakTemplates") {
+        Some(Value::Object(templates)) => Some(templates),
+        Some(_) => return Err("breakTemplates must be an object".to_string()),
+        None => None,
+    };
+
+    if let Some(vehicles) = fleet.get_mut("vehicles").and_then(|vehicles| vehicles.as_array_mut()) {
+        let mut expanded = vec![];
+
+        for mut vehicle in vehicles.drain(..) {
+            if let Some(defaults) = defaults.as_ref() {
+                merge_defaults(&mut vehicle, defaults);
+            }
+
+            match vehicle {
+                Value::Object(vehicle) => expanded.extend(expand_vehicle_type(vehicle)?),
+                vehicle => expanded.push(vehicle),
+            }
+        }
+
+        expanded.iter_mut().try_for_each(|vehicle| expand_break_templates(vehicle, templates.as_ref()))?;
+
+        *vehicles = expanded;
+    }
+
+    Ok(())
+}
+
+fn expand_vehicle_type(mut vehicle: Map<String, Value>) -> Result<Vec<Value>, String> {
+    let type_id = vehicle.get("typeId").and_then(|id| id.as_str()).unwrap_or_default().to_string();
+
+    if let Some(amount) = vehicle.remove("amount") {
+        if vehicle.contains_key("vehicleIds") {
+            return Err(format!("vehicle type '{}' has both amount and vehicleIds", type_id));
+        }
+
+        let amount = amount
+            .as_u64()
+            .ok_or_else(|| format!("amount of vehicle type '{}' must be non-negative integer", type_id))?;
+        let vehicle_ids = (1..=amount).map(|idx| Value::String(format!("{}_{}", type_id, idx))).collect();
+
+        vehicle.insert("vehicleIds".to_string(), Value::Array(vehicle_ids));
+    }
+
+    let overrides = match vehicle.remove("overrides") {
+        Some(Value::Array(overrides)) => overrides,
+        Some(_) => return Err(format!("overrides of vehicle type '{}' must be a list", type_id)),
+        None => return Ok(vec![Value::Object(vehicle)]),
+    };
+
+    let mut overridden = vec![];
+    for vehicle_override in overrides {
+        let mut vehicle_override = match vehicle_override {
+            Value::Object(vehicle_override) => vehicle_override,
+            _ => return Err(format!("override of vehicle type '{}' must be an object", type_id)),
+        };
+
+        let vehicle_id = vehicle_override
+            .remove("vehicleId")
+            .and_then(|id| id.as_str().map(|id| id.to_string()))
+            .ok_or_else(|| format!("override of vehicle type '{}' has no vehicleId", type_id))?;
+
+        let vehicle_ids = vehicle.get_mut("vehicleIds").and_then(|ids| ids.as_array_mut());
+        let position = vehicle_ids.as_ref().and_then(|ids| ids.iter().position(|id| id.as_str() == Some(&vehicle_id)));
+
+        match (vehicle_ids, position) {
+            (Some(vehicle_ids), Some(position)) => {
+                vehicle_ids.remove(position);
+            }
+            _ => return Err(format!("unknown vehicle '{}' in overrides of vehicle type '{}'", vehicle_id, type_id)),
+        }
+
+        overridden.push((vehicle_id, vehicle_override));
+    }
+
+    let has_vehicles = vehicle.get("vehicleIds").and_then(|ids| ids.as_array()).map_or(false, |ids| !ids.is_empty());
+    let vehicle = Value::Object(vehicle);
+
+    let overridden = overridden
+        .into_iter()
+        .map(|(vehicle_id, vehicle_override)| {
+            let mut vehicle_override = Value::Object(vehicle_override);
+            merge_defaults(&mut vehicle_override, &vehicle);
+
+            if let Some(vehicle_override) = vehicle_override.as_object_mut() {
+                vehicle_override.insert("typeId".to_string(), Value::String(vehicle_id.clone()));
+                vehicle_override.insert("vehicleIds".to_string(), Value::Array(vec![Value::String(vehicle_id)]));
+            }
+
+            vehicle_override
+        })
+        .collect::<Vec<_>>();
+
+    Ok(if has_vehicles { vec![vehicle] } else { vec![] }.into_iter().chain(overridden.into_iter()).collect())
+}
+
+fn expand_break_templates(vehicle: &mut Value, templates: Option<&Map<String, Value>>) -> Result<(), String> {
+    let shifts = match vehicle.get_mut("shifts").and_then(|shifts| shifts.as_array_mut()) {
+        Some(shifts) => shifts,
+        None => return Ok(()),
+    };
+
+    for shift in shifts.iter_mut() {
+        let breaks = match shift.get_mut("breaks").and_then(|breaks| breaks.as_array_mut()) {
+            Some(breaks) => breaks,
+            None => continue,
+        };
+
+        let mut expanded = vec![];
+        for vehicle_break in breaks.drain(..) {
+            match vehicle_break {
+                Value::String(name) => match templates.and_then(|templates| templates.get(&name)) {
+                    Some(Value::Array(template)) => expanded.extend(template.iter().cloned()),
+                    Some(template) => expanded.push(template.clone()),
+                    None => return Err(format!("unknown break template '{}'", name)),
+                },
+                vehicle_break => expanded.push(vehicle_break),
+            }
+        }
+
+        *breaks = expanded;
+    }
+
+    Ok(())
+}
+
+fn merge_defaults(target: &mut Value, defaults: &Value) {
+    if let (Some(target), Some(defaults)) = (target.as_object_mut(), defaults.as_object()) {
+        defaults.iter().for_each(|(key, value)| match target.get_mut(key) {
+            Some(existing) => merge_defaults(existing, value),
+            None => {
+                target.insert(key.clone(), value.clone());
+            }
+        });
+    }
+}
@@ -1,8 +1,8 @@
 use crate::extensions::{create_typed_actor_groups, MultiDimensionalCapacity};
 use crate::format::coord_index::CoordIndex;
-use crate::format::problem::reader::{add_skills, ApiProblem, ProblemProperties};
-use crate::format::problem::Matrix;
-use crate::parse_time;
+use crate::format::problem::reader::{add_skills, get_distance_factor, ApiProblem, ProblemProperties};
+use crate::format::problem::{Matrix, ShiftIndexMap, VehicleType};
+use crate::{parse_time, parse_time_of_day};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use vrp_core::construction::constraints::CapacityDimension;
@@ -13,8 +13,11 @@ use vrp_core::models::problem::*;
 pub fn create_transport_costs(
     api_problem: &ApiProblem,
     matrices: &Vec<Matrix>,
+    distance_factor: f64,
+    coord_index: &CoordIndex,
 ) -> Result<Arc<dyn TransportCost + Sync + Send>, String> {
     let fleet_profiles = get_profile_map(api_problem);
+    let override_factor = get_distance_factor(api_problem);
 
     let matrix_data = matrices
         .iter()
@@ -29,14 +32,14 @@ pub fn create_transport_costs(
                         distances.push(-1.);
                     } else {
                         durations.push(*matrix.travel_times.get(i).unwrap() as f64);
-                        distances.push(*matrix.distances.get(i).unwrap() as f64);
+                        distances.push(*matrix.distances.get(i).unwrap() as f64 * distance_factor);
                     }
                 }
                 (durations, distances)
             } else {
                 (
                     matrix.travel_times.iter().map(|d| *d as f64).collect(),
-                    matrix.distances.iter().map(|d| *d as f64).collect(),
+                    matrix.distances.iter().map(|d| *d as f64 * distance_factor).collect(),
                 )
             };
 
@@ -50,10 +53,51 @@ pub fn create_transport_costs(
         return Err("Amount of fleet profiles does not match matrix profiles".to_string());
     }
 
+    let overrides = api_problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| profile.overrides.as_ref().map(|overrides| (profile, overrides)))
+        .flat_map(|(profile, overrides)| {
+            let profile = *fleet_profiles.get(&profile.name).unwrap();
+            overrides.iter().map(move |item| TransportOverride {
+                profile,
+                from: coord_index.get_by_loc(&item.from).unwrap(),
+                to: coord_index.get_by_loc(&item.to).unwrap(),
+                duration: item.duration,
+                distance: item.distance.map(|distance| distance * override_factor),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let multipliers = api_problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| profile.multipliers.as_ref().map(|multipliers| (profile, multipliers)))
+        .flat_map(|(profile, multipliers)| {
+            let profile = *fleet_profiles.get(&profile.name).unwrap();
+            multipliers.iter().map(move |item| TravelTimeMultiplier {
+                profile,
+                start: parse_time_of_day(&item.start).unwrap(),
+                end: parse_time_of_day(&item.end).unwrap(),
+                factor: item.factor,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // NOTE overrides are applied on top of multiplied durations as they specify exact values
     create_matrix_transport_cost(matrix_data)
+        .map(|transport| create_multiplier_transport_cost(transport, multipliers))
+        .map(|transport| create_override_transport_cost(transport, overrides))
 }
 
-pub fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: &CoordIndex) -> Fleet {
+pub fn read_fleet(
+    api_problem: &ApiProblem,
+    props: &ProblemProperties,
+    coord_index: &CoordIndex,
+    shift_map: Option<&ShiftIndexMap>,
+) -> Fleet {
     let profiles = get_profile_map(api_problem);
     let mut vehicles: Vec<Arc<Vehicle>> = Default::default();
 
@@ -71,29 +115,59 @@ pub fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_ind
             areas.iter().map(|area| area.iter().map(|l| (l.lat, l.lng)).collect::<Vec<_>>()).collect::<Vec<_>>()
         });
 
+        let shift_indices = shift_map.and_then(|shift_map| shift_map.get(&vehicle.type_id));
+
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
+            let original_shift_index = shift_indices.map_or(shift_index, |indices| indices[shift_index]);
             let start = {
                 let location = coord_index.get_by_loc(&shift.start.location).unwrap();
                 let time = parse_time(&shift.start.time);
+                let time = get_gate_time(&shift.start.gate).map_or(time, |gate| time.max(gate.start));
                 (location, time)
             };
 
             let end = shift.end.as_ref().map_or(None, |end| {
                 let location = coord_index.get_by_loc(&end.location).unwrap();
                 let time = parse_time(&end.time);
+                let time = get_gate_time(&end.gate).map_or(time, |gate| time.min(gate.end));
                 Some((location, time))
             });
 
-            let details = vec![VehicleDetail {
+            let details = VehicleDetail {
                 start: Some(start.0),
                 end: end.map_or(None, |end| Some(end.0)),
                 time: Some(TimeWindow::new(start.1, end.map_or(std::f64::MAX, |end| end.1))),
-            }];
+            };
 
             vehicle.vehicle_ids.iter().for_each(|vehicle_id| {
+                let details = match get_vehicle_position(vehicle, vehicle_id, coord_index) {
+                    Some((location, time)) => {
+                        let shift_time = details.time.clone().unwrap();
+                        if time >= shift_time.end {
+                            // NOTE shift is already finished: vehicle is kept, so references to it stay
+                            // valid, but it has no time left to serve jobs
+                            VehicleDetail {
+                                start: Some(location),
+                                time: Some(TimeWindow::new(shift_time.end, shift_time.end)),
+                                ..details.clone()
+                            }
+                        } else if time >= shift_time.start {
+                            VehicleDetail {
+                                start: Some(location),
+                                time: Some(TimeWindow::new(time, shift_time.end)),
+                                ..details.clone()
+                            }
+                        } else {
+                            details.clone()
+                        }
+                    }
+                    None => details.clone(),
+                };
+
                 let mut dimens: Dimensions = Default::default();
                 dimens.set_value("type_id", vehicle.type_id.clone());
                 dimens.set_value("shift_index", shift_index);
+                dimens.set_value("original_shift_index", original_shift_index);
                 dimens.set_id(vehicle_id);
 
                 if let Some(areas) = areas.clone() {
@@ -107,7 +181,7 @@ pub fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_ind
                 }
                 add_skills(&mut dimens, &vehicle.skills);
 
-                vehicles.push(Arc::new(Vehicle { profile, costs: costs.clone(), dimens, details: details.clone() }));
+                vehicles.push(Arc::new(Vehicle { profile, costs: costs.clone(), dimens, details: vec![details] }));
             });
         }
     });
@@ -150,6 +224,23 @@ pub fn read_limits(api_problem: &ApiProblem) -> Option<TravelLimitFunc> {
     }
 }
 
+/// Returns location index and time of vehicle position, if it is specified.
+fn get_vehicle_position(
+    vehicle: &VehicleType,
+    vehicle_id: &str,
+    coord_index: &CoordIndex,
+) -> Option<(Location, Timestamp)> {
+    vehicle
+        .positions
+        .as_ref()
+        .and_then(|positions| positions.iter().find(|position| position.vehicle_id == vehicle_id))
+        .map(|position| (coord_index.get_by_loc(&position.location).unwrap(), parse_time(&position.time)))
+}
+
+fn get_gate_time(gate: &Option<Vec<String>>) -> Option<TimeWindow> {
+    gate.as_ref().map(|gate| TimeWindow::new(parse_time(gate.first().unwrap()), parse_time(gate.last().unwrap())))
+}
+
 fn get_profile_map(api_problem: &ApiProblem) -> HashMap<String, i32> {
     api_problem.fleet.profiles.iter().fold(Default::default(), |mut acc, profile| {
         if acc.get(&profile.name) == None {
@@ -1,15 +1,17 @@
 use crate::extensions::MultiDimensionalCapacity;
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::reader::{add_skills, parse_time_window, ApiProblem, JobIndex, ProblemProperties};
-use crate::format::problem::{JobTask, RelationType, VehicleBreak, VehicleBreakTime, VehicleReload, VehicleType};
+use crate::format::problem::{JobPosition, JobTask, VehicleBreak, VehicleBreakTime, VehicleReload, VehicleType};
 use crate::format::Location;
 use crate::utils::VariableJobPermutation;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use vrp_core::construction::constraints::{Demand, DemandDimension};
 use vrp_core::models::common::{Dimensions, Duration, IdDimension, TimeOffset, TimeSpan, TimeWindow, ValueDimension};
-use vrp_core::models::problem::{Actor, Fleet, Job, Jobs, Multi, Place, Single, TransportCost};
-use vrp_core::models::{Lock, LockDetail, LockOrder, LockPosition};
+use vrp_core::models::problem::{Fleet, Job, Jobs, Multi, Place, Single, TransportCost};
+use vrp_core::models::Lock;
+
+type ApiJob = crate::format::problem::Job;
 
 // TODO configure sample size
 const MULTI_JOB_SAMPLE_SIZE: usize = 3;
@@ -31,67 +33,14 @@ pub fn read_jobs_with_extra_locks(
     (Jobs::new(fleet, jobs, transport), locks)
 }
 
-pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Lock>> {
-    if api_problem.plan.relations.as_ref().map_or(true, |r| r.is_empty()) {
-        return vec![];
-    }
-
-    let relations = api_problem.plan.relations.as_ref().unwrap().iter().fold(HashMap::new(), |mut acc, r| {
-        let shift_index = r.shift_index.unwrap_or(0);
-        acc.entry((r.vehicle_id.clone(), shift_index)).or_insert(vec![]).push(r.clone());
-
-        acc
-    });
-
-    let locks = relations.into_iter().fold(vec![], |mut acc, ((vehicle_id, shift_index), rels)| {
-        let condition = create_condition(vehicle_id.clone(), shift_index);
-        let details = rels.iter().fold(vec![], |mut acc, rel| {
-            let order = match rel.type_field {
-                RelationType::Any => LockOrder::Any,
-                RelationType::Sequence => LockOrder::Sequence,
-                RelationType::Strict => LockOrder::Strict,
-            };
-
-            let position = match (rel.jobs.first().map(|s| s.as_str()), rel.jobs.last().map(|s| s.as_str())) {
-                (Some("departure"), Some("arrival")) => LockPosition::Fixed,
-                (Some("departure"), _) => LockPosition::Departure,
-                (_, Some("arrival")) => LockPosition::Arrival,
-                _ => LockPosition::Any,
-            };
-
-            let (_, _, jobs) = rel
-                .jobs
-                .iter()
-                .filter(|job| job.as_str() != "departure" && job.as_str() != "arrival")
-                .fold((0_usize, 0_usize, vec![]), |(mut break_idx, mut reload_idx, mut jobs), job| {
-                    let job = match job.as_str() {
-                        "break" => {
-                            break_idx += 1;
-                            job_index.get(format!("{}_break_{}", vehicle_id, break_idx).as_str()).cloned().unwrap()
-                        }
-                        "reload" => {
-                            reload_idx += 1;
-                            job_index.get(format!("{}_reload_{}", vehicle_id, reload_idx).as_str()).cloned().unwrap()
-                        }
-                        _ => job_index.get(job).unwrap().clone(),
-                    };
-
-                    jobs.push(job);
-
-                    (break_idx, reload_idx, jobs)
-                });
-
-            acc.push(LockDetail::new(order, position, jobs));
-
-            acc
-        });
-
-        acc.push(Arc::new(Lock::new(condition, details)));
+pub fn read_forbidden_arcs(api_problem: &ApiProblem, job_index: &JobIndex) -> HashMap<Job, HashSet<Job>> {
+    api_problem.plan.forbidden_arcs.iter().flat_map(|arcs| arcs.iter()).fold(HashMap::new(), |mut acc, arc| {
+        let from = job_index.get(&arc.from).unwrap().clone();
+        let to = job_index.get(&arc.to).unwrap().clone();
+        acc.entry(from).or_insert_with(HashSet::new).insert(to);
 
         acc
-    });
-
-    locks
+    })
 }
 
 fn read_required_jobs(
@@ -149,9 +98,9 @@ fn read_required_jobs(
         assert!(singles.len() > 0);
 
         let problem_job = if singles.len() > 1 {
-            get_multi_job(&job.id, &job.priority, &job.skills, singles, job.pickups.as_ref().map_or(0, |p| p.len()))
+            get_multi_job(job, singles, job.pickups.as_ref().map_or(0, |p| p.len()))
         } else {
-            get_single_job(&job.id, singles.into_iter().next().unwrap(), &job.priority, &job.skills)
+            get_single_job(job, singles.into_iter().next().unwrap())
         };
 
         job_index.insert(job.id.clone(), problem_job.clone());
@@ -169,11 +118,12 @@ fn read_conditional_jobs(
     let mut jobs = vec![];
     api_problem.fleet.vehicles.iter().for_each(|vehicle| {
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
-            if let Some(breaks) = &shift.breaks {
+            // NOTE problems which use disabled features are rejected by validation
+            if let (Some(breaks), true) = (&shift.breaks, cfg!(feature = "breaks")) {
                 read_breaks(coord_index, job_index, &mut jobs, vehicle, shift_index, breaks);
             }
 
-            if let Some(reloads) = &shift.reloads {
+            if let (Some(reloads), true) = (&shift.reloads, cfg!(feature = "reloads")) {
                 read_reloads(coord_index, job_index, &mut jobs, vehicle, shift_index, reloads);
             }
         }
@@ -329,27 +279,23 @@ fn get_single_with_extras(
     single
 }
 
-fn get_single_job(id: &String, single: Single, priority: &Option<i32>, skills: &Option<Vec<String>>) -> Job {
+fn get_single_job(job: &ApiJob, single: Single) -> Job {
     let mut single = single;
-    single.dimens.set_id(id.as_str());
+    single.dimens.set_id(job.id.as_str());
 
-    add_priority(&mut single.dimens, priority);
-    add_skills(&mut single.dimens, skills);
+    add_priority(&mut single.dimens, &job.priority);
+    add_skills(&mut single.dimens, &job.skills);
+    add_position(&mut single.dimens, &job.position);
 
     Job::Single(Arc::new(single))
 }
 
-fn get_multi_job(
-    id: &String,
-    priority: &Option<i32>,
-    skills: &Option<Vec<String>>,
-    singles: Vec<Single>,
-    deliveries_start_index: usize,
-) -> Job {
+fn get_multi_job(job: &ApiJob, singles: Vec<Single>, deliveries_start_index: usize) -> Job {
     let mut dimens: Dimensions = Default::default();
-    dimens.set_id(id.as_str());
-    add_priority(&mut dimens, priority);
-    add_skills(&mut dimens, skills);
+    dimens.set_id(job.id.as_str());
+    add_priority(&mut dimens, &job.priority);
+    add_skills(&mut dimens, &job.skills);
+    add_position(&mut dimens, &job.position);
 
     let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
 
@@ -367,13 +313,6 @@ fn get_multi_job(
     Job::Multi(Multi::bind(multi))
 }
 
-fn create_condition(vehicle_id: String, shift_index: usize) -> Arc<dyn Fn(&Actor) -> bool + Sync + Send> {
-    Arc::new(move |actor: &Actor| {
-        *actor.vehicle.dimens.get_id().unwrap() == vehicle_id
-            && *actor.vehicle.dimens.get_value::<usize>("shift_index").unwrap() == shift_index
-    })
-}
-
 fn add_tag(dimens: &mut Dimensions, tag: &Option<String>) {
     if let Some(tag) = tag {
         dimens.set_value("tag", tag.clone());
@@ -386,6 +325,12 @@ fn add_priority(dimens: &mut Dimensions, priority: &Option<i32>) {
     }
 }
 
+fn add_position(dimens: &mut Dimensions, position: &Option<JobPosition>) {
+    if let Some(position) = position {
+        dimens.set_value("position", *position);
+    }
+}
+
 fn empty() -> MultiDimensionalCapacity {
     MultiDimensionalCapacity::default()
 }
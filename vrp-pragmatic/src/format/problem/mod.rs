@@ -1,8 +1,20 @@
 //! Specifies logic to read problem and routing matrix from json input.
 //!
 
+mod fleet_expansion;
+pub use self::fleet_expansion::expand_fleet;
+
 mod model;
 pub use self::model::*;
 
+mod planning_horizon;
+pub(crate) use self::planning_horizon::clip_job_time_windows;
+
+mod unavailability;
+pub(crate) use self::unavailability::{carve_vehicle_shifts, get_original_shift_index, ShiftIndexMap};
+
 mod reader;
-pub use self::reader::PragmaticProblem;
+pub use self::reader::{
+    create_approx_matrices, create_custom_matrices, create_straight_line_matrices, PragmaticProblem,
+};
+pub(crate) use self::reader::{get_distance_factor, with_straight_line_matrices};
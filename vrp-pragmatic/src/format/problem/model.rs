@@ -4,6 +4,7 @@ mod model_test;
 
 extern crate serde_json;
 
+use crate::format::problem::expand_fleet;
 use crate::format::{FormatError, Location};
 use serde::{Deserialize, Serialize};
 use serde_json::Error;
@@ -96,16 +97,45 @@ pub struct Job {
     /// A set of skills required to serve a job.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skills: Option<Vec<String>>,
+
+    /// A preferred position of the job in the tour. Unlike relations, it is a soft preference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<JobPosition>,
+}
+
+/// Specifies a preferred position of the job in the tour.
+#[derive(Clone, Copy, Deserialize, Debug, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum JobPosition {
+    /// Job is preferred to be served first in the tour.
+    First,
+    /// Job is preferred to be served last in the tour.
+    Last,
+    /// Job can be served at any position.
+    Any,
+}
+
+/// Forbidden arc specifies that one job cannot be served directly after another one.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct ForbiddenArc {
+    /// An id of job which is served first.
+    pub from: String,
+    /// An id of job which cannot be served directly after the first one.
+    pub to: String,
 }
 
 /// A plan specifies work which has to be done.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Plan {
     /// List of jobs.
     pub jobs: Vec<Job>,
     /// List of relations between jobs and vehicles.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub relations: Option<Vec<Relation>>,
+    /// List of forbidden arcs between jobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forbidden_arcs: Option<Vec<ForbiddenArc>>,
 }
 
 // endregion
@@ -131,6 +161,10 @@ pub struct VehiclePlace {
     pub time: String,
     /// Vehicle location.
     pub location: Location,
+    /// Depot gate opening hours: a time window with time specified in RFC3339 format.
+    /// Vehicle cannot depart before gate is opened or arrive after it is closed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gate: Option<Vec<String>>,
 }
 
 /// Specifies vehicle shift.
@@ -244,6 +278,32 @@ pub struct VehicleType {
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+
+    /// Vehicle unavailability: a list of time windows with time specified in RFC3339 format when
+    /// vehicles of this type cannot be used, e.g. maintenance or days off. Vehicle shifts are
+    /// carved accordingly when problem is read.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unavailable: Option<Vec<Vec<String>>>,
+
+    /// Current vehicle positions, e.g. from GPS tracking, used for intra-day re-planning. Vehicle
+    /// position overrides start location and time of the shift which is active at position time,
+    /// shifts which end before it are not used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub positions: Option<Vec<VehiclePosition>>,
+}
+
+/// Specifies a current position of a vehicle.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehiclePosition {
+    /// Vehicle id.
+    pub vehicle_id: String,
+
+    /// Vehicle location.
+    pub location: Location,
+
+    /// Time when vehicle was at the location specified in RFC3339 format.
+    pub time: String,
 }
 
 /// Specifies routing profile.
@@ -260,9 +320,69 @@ pub struct Profile {
     /// Default value is 10.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<f64>,
+
+    /// A list of routing data overrides for specific location pairs which are applied on top
+    /// of routing matrix.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<Vec<RoutingOverride>>,
+
+    /// A list of travel time multipliers for time of day bands which are applied on top of
+    /// routing matrix durations, e.g. to model rush hours without time dependent matrices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multipliers: Option<Vec<TravelTimeMultiplier>>,
+
+    /// Straight line routing which is used for the profile instead of routing matrix, e.g. for
+    /// drones. When specified, routing matrix of the profile is not required and ignored.
+    #[serde(rename = "straightLine", skip_serializing_if = "Option::is_none")]
+    pub straight_line: Option<StraightLineRouting>,
+}
+
+/// Specifies routing which computes travel data analytically from great-circle distance between
+/// locations instead of using routing matrix.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StraightLineRouting {
+    /// Speed (meters per second).
+    pub speed: f64,
+
+    /// Fixed time (seconds) added to every travel between different locations, e.g. takeoff and
+    /// landing time. Default value is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_time: Option<f64>,
 }
 
-/// Specifies fleet.
+/// Specifies travel time multiplier for a time of day band. The band repeats every day.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct TravelTimeMultiplier {
+    /// Band start time of day in `HH:MM` or `HH:MM:SS` format (UTC).
+    pub start: String,
+
+    /// Band end time of day in `HH:MM` or `HH:MM:SS` format (UTC), exclusive.
+    pub end: String,
+
+    /// A multiplier applied to travel durations of departures within the band.
+    pub factor: f64,
+}
+
+/// Specifies routing data override between two locations.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct RoutingOverride {
+    /// A location of departure.
+    pub from: Location,
+
+    /// A location of arrival.
+    pub to: Location,
+
+    /// Travel duration. Matrix value is used when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<f64>,
+
+    /// Travel distance. Matrix value is used when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
+}
+
+/// Specifies fleet. In json, fleet can use shortcuts which are expanded by [`deserialize_problem`].
 #[derive(Clone, Deserialize, Debug, Serialize)]
 pub struct Fleet {
     /// Vehicle types.
@@ -275,9 +395,46 @@ pub struct Fleet {
 
 // region Configuration
 
-/// Specifies extra configuration (reserved for future).
+/// Specifies extra configuration.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Routing data configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing: Option<RoutingConfig>,
+
+    /// Planning horizon: a time window with time specified in RFC3339 format. Job time windows
+    /// are clipped to it, job time windows outside of it are rejected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub planning_horizon: Option<Vec<String>>,
+}
+
+/// Specifies routing data configuration.
 #[derive(Clone, Deserialize, Debug, Serialize)]
-pub struct Config {}
+#[serde(rename_all = "camelCase")]
+pub struct RoutingConfig {
+    /// Unit of distances in routing matrices and routing overrides. Distances are converted to
+    /// meters internally, so solution distances are always in meters. Default is meters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_unit: Option<DistanceUnit>,
+
+    /// Speed (meters per second) used for routing approximation when profile has no speed.
+    /// Default value is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_speed: Option<f64>,
+}
+
+/// Specifies distance unit.
+#[derive(Clone, Copy, Deserialize, Debug, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DistanceUnit {
+    /// Meters.
+    Meters,
+    /// Kilometers.
+    Kilometers,
+    /// Miles.
+    Miles,
+}
 
 // endregion
 
@@ -349,6 +506,37 @@ pub enum Objective {
         #[serde(skip_serializing_if = "Option::is_none")]
         options: Option<BalanceOptions>,
     },
+
+    /// An objective to minimize deviation from a previous plan: amount of jobs moved
+    /// to a different vehicle or left unassigned.
+    #[serde(rename(deserialize = "minimize-plan-deviation", serialize = "minimize-plan-deviation"))]
+    MinimizePlanDeviation {
+        /// A previous plan and deviation penalty.
+        options: PlanStabilityOptions,
+    },
+}
+
+/// Specifies plan stability objective options.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct PlanStabilityOptions {
+    /// A list of tours from the previous plan.
+    pub previous: Vec<PreviousTour>,
+    /// An extra cost added when a job is inserted into a different vehicle. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+}
+
+/// Specifies jobs served by a vehicle shift in the previous plan.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviousTour {
+    /// A vehicle id.
+    pub vehicle_id: String,
+    /// A vehicle shift index. If not specified, a first, zero indexed, shift assumed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shift_index: Option<usize>,
+    /// Ids of jobs served by the vehicle shift.
+    pub jobs: Vec<String>,
 }
 
 /// Specifies balance objective options.
@@ -387,7 +575,7 @@ pub struct Problem {
 }
 
 /// A routing matrix.
-#[derive(Clone, Deserialize, Debug, Serialize)]
+#[derive(Clone, Deserialize, Debug, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Matrix {
     /// A name of profile.
@@ -409,15 +597,22 @@ pub struct Matrix {
 
 // endregion
 
-/// Deserializes problem in json format from [`BufReader`].
+/// Deserializes problem in json format from [`BufReader`]. Fleet shortcuts, such as vehicle
+/// defaults or vehicle amount, are expanded, see [`expand_fleet`].
 pub fn deserialize_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Vec<FormatError>> {
-    serde_json::from_reader(reader).map_err(|err| {
+    let create_error = |err: String| {
         vec![FormatError::new(
             "E0000".to_string(),
             "cannot deserialize problem".to_string(),
             format!("check input json: '{}'", err),
         )]
-    })
+    };
+
+    let mut problem: serde_json::Value =
+        serde_json::from_reader(reader).map_err(|err| create_error(err.to_string()))?;
+    expand_fleet(&mut problem).map_err(create_error)?;
+
+    serde_json::from_value(problem).map_err(|err| create_error(err.to_string()))
 }
 
 /// Deserializes routing matrix in json format from [`BufReader`].
@@ -435,3 +630,8 @@ pub fn deserialize_matrix<R: Read>(reader: BufReader<R>) -> Result<Matrix, Vec<F
 pub fn serialize_problem<W: Write>(writer: BufWriter<W>, problem: &Problem) -> Result<(), Error> {
     serde_json::to_writer_pretty(writer, problem)
 }
+
+/// Serializes routing matrix in json from [`writer`].
+pub fn serialize_matrix<W: Write>(writer: BufWriter<W>, matrix: &Matrix) -> Result<(), Error> {
+    serde_json::to_writer_pretty(writer, matrix)
+}
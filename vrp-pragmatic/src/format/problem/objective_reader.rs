@@ -1,16 +1,19 @@
 use crate::extensions::MultiDimensionalCapacity;
-use crate::format::problem::reader::{ApiProblem, ProblemProperties};
-use crate::format::problem::BalanceOptions;
+use crate::format::problem::reader::{ApiProblem, JobIndex, ProblemProperties};
 use crate::format::problem::Objective::*;
+use crate::format::problem::{get_original_shift_index, BalanceOptions, PlanStabilityOptions};
 use std::sync::Arc;
 use vrp_core::construction::constraints::{ConstraintPipeline, FleetUsageConstraintModule};
-use vrp_core::models::problem::{ObjectiveCost, TargetConstraint, TargetObjective};
+use vrp_core::models::common::IdDimension;
+use vrp_core::models::problem::{Fleet, ObjectiveCost, TargetConstraint, TargetObjective};
 use vrp_core::solver::objectives::*;
 
 pub fn create_objective(
     api_problem: &ApiProblem,
     constraint: &mut ConstraintPipeline,
     props: &ProblemProperties,
+    fleet: &Fleet,
+    job_index: &JobIndex,
 ) -> Arc<ObjectiveCost> {
     Arc::new(if let Some(objectives) = &api_problem.objectives {
         let mut map_objectives = |objectives: &Vec<_>| {
@@ -49,6 +52,11 @@ pub fn create_objective(
                     constraint.add_module(module);
                     core_objectives.push(objective);
                 }
+                MinimizePlanDeviation { options } => {
+                    let (module, objective) = get_plan_stability(fleet, job_index, options);
+                    constraint.add_module(module);
+                    core_objectives.push(objective);
+                }
             });
             core_objectives
         };
@@ -67,6 +75,35 @@ fn unwrap_options(options: &Option<BalanceOptions>) -> (Option<f64>, Option<f64>
     (options.as_ref().and_then(|o| o.threshold), options.as_ref().and_then(|o| o.tolerance))
 }
 
+fn get_plan_stability(
+    fleet: &Fleet,
+    job_index: &JobIndex,
+    options: &PlanStabilityOptions,
+) -> (TargetConstraint, TargetObjective) {
+    let previous = options
+        .previous
+        .iter()
+        .filter_map(|tour| {
+            let shift_index = tour.shift_index.unwrap_or(0);
+            // NOTE shift can be split by unavailability, then its first part is used. If shift is removed
+            // completely, the jobs cannot be kept on the same vehicle and they are not tracked.
+            fleet
+                .vehicles
+                .iter()
+                .find(|vehicle| {
+                    *vehicle.dimens.get_id().unwrap() == tour.vehicle_id
+                        && get_original_shift_index(&vehicle.dimens) == shift_index
+                })
+                .map(|vehicle| (tour, vehicle))
+        })
+        .flat_map(|(tour, vehicle)| {
+            tour.jobs.iter().map(move |job_id| (job_index.get(job_id).unwrap().clone(), vehicle.clone()))
+        })
+        .collect();
+
+    PlanStability::new_minimized(previous, options.weight.unwrap_or(0.))
+}
+
 fn get_load_balance(
     props: &ProblemProperties,
     options: &Option<BalanceOptions>,
@@ -0,0 +1,39 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/planning_horizon_test.rs"]
+mod planning_horizon_test;
+
+use crate::format::problem::*;
+use crate::{format_time, parse_time};
+use vrp_core::models::common::TimeWindow;
+
+/// Clips job time windows to the planning horizon: time windows which are partially outside of it
+/// are truncated, time windows which are fully outside of it are removed. Returns `None` if there
+/// is no planning horizon.
+pub(crate) fn clip_job_time_windows(problem: &Problem) -> Option<Problem> {
+    let horizon = problem.config.as_ref().and_then(|config| config.planning_horizon.as_ref())?;
+    let horizon = TimeWindow::new(parse_time(horizon.first()?), parse_time(horizon.last()?));
+
+    let mut problem = problem.clone();
+
+    problem.plan.jobs.iter_mut().for_each(|job| {
+        job.pickups
+            .iter_mut()
+            .chain(job.deliveries.iter_mut())
+            .chain(job.replacements.iter_mut())
+            .chain(job.services.iter_mut())
+            .flat_map(|tasks| tasks.iter_mut())
+            .flat_map(|task| task.places.iter_mut())
+            .for_each(|place| {
+                place.times = place.times.as_ref().map(|times| {
+                    times
+                        .iter()
+                        .map(|tw| TimeWindow::new(parse_time(&tw[0]), parse_time(&tw[1])))
+                        .filter(|tw| tw.intersects(&horizon))
+                        .map(|tw| vec![format_time(tw.start.max(horizon.start)), format_time(tw.end.min(horizon.end))])
+                        .collect()
+                });
+            });
+    });
+
+    Some(problem)
+}
@@ -11,13 +11,22 @@ mod fleet_reader;
 #[path = "./objective_reader.rs"]
 mod objective_reader;
 
+#[cfg(feature = "relations")]
+#[path = "./relation_reader.rs"]
+mod relation_reader;
+
 use self::fleet_reader::{create_transport_costs, read_fleet, read_limits};
-use self::job_reader::{read_jobs_with_extra_locks, read_locks};
+use self::job_reader::{read_forbidden_arcs, read_jobs_with_extra_locks};
 use self::objective_reader::create_objective;
+#[cfg(feature = "relations")]
+use self::relation_reader::read_locks;
 use crate::constraints::*;
 use crate::extensions::{MultiDimensionalCapacity, OnlyVehicleActivityCost};
 use crate::format::coord_index::CoordIndex;
-use crate::format::problem::{deserialize_matrix, deserialize_problem, Matrix};
+use crate::format::problem::{
+    carve_vehicle_shifts, clip_job_time_windows, deserialize_matrix, deserialize_problem, DistanceUnit, JobPosition,
+    Matrix,
+};
 use crate::format::*;
 use crate::utils::get_approx_transportation;
 use crate::validation::ValidationContext;
@@ -32,6 +41,7 @@ use vrp_core::models::common::{Dimensions, TimeWindow, ValueDimension};
 use vrp_core::models::problem::{ActivityCost, Fleet, Job, TransportCost};
 use vrp_core::models::{Extras, Lock, Problem};
 use vrp_core::utils::compare_floats;
+use vrp_core::utils::geo::get_haversine_distance;
 
 pub type ApiProblem = crate::format::problem::Problem;
 pub type JobIndex = HashMap<String, Job>;
@@ -50,7 +60,7 @@ impl<R: Read> PragmaticProblem for (BufReader<R>, Vec<BufReader<R>>) {
             matrices.push(deserialize_matrix(matrix)?);
         }
 
-        map_to_problem(problem, matrices)
+        map_to_problem(&problem, matrices)
     }
 }
 
@@ -71,7 +81,7 @@ impl PragmaticProblem for (String, Vec<String>) {
             matrices.push(deserialize_matrix(BufReader::new(matrix.as_bytes()))?);
         }
 
-        map_to_problem(problem, matrices)
+        map_to_problem(&problem, matrices)
     }
 }
 
@@ -84,6 +94,12 @@ impl PragmaticProblem for String {
 }
 
 impl PragmaticProblem for (ApiProblem, Vec<Matrix>) {
+    fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+        map_to_problem(&self.0, self.1)
+    }
+}
+
+impl PragmaticProblem for (&ApiProblem, Vec<Matrix>) {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
         map_to_problem(self.0, self.1)
     }
@@ -97,22 +113,27 @@ impl PragmaticProblem for ApiProblem {
 
 pub struct ProblemProperties {
     has_multi_dimen_capacity: bool,
+    #[cfg(feature = "breaks")]
     has_breaks: bool,
     has_skills: bool,
     has_unreachable_locations: bool,
+    #[cfg(feature = "reloads")]
     has_reload: bool,
     has_priorities: bool,
     has_area_limits: bool,
+    has_positions: bool,
 }
 
-fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
-    const DEFAULT_SPEED: f64 = 10.;
+/// Creates routing matrices for each profile using great-circle distances between problem
+/// locations and profile speed. Locations are ordered as returned by `get_unique_locations`.
+pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
+    let default_speed = get_default_speed(problem);
     // get each speed value once
     let speeds = problem
         .fleet
         .profiles
         .iter()
-        .map(|profile| profile.speed.unwrap_or(DEFAULT_SPEED))
+        .map(|profile| profile.speed.unwrap_or(default_speed))
         .map(|speed| unsafe { std::mem::transmute(speed) })
         .collect::<HashSet<i64>>();
     let speeds = speeds.into_iter().map(|speed| unsafe { std::mem::transmute(speed) }).collect::<Vec<_>>();
@@ -125,7 +146,7 @@ fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
         .profiles
         .iter()
         .map(move |profile| {
-            let speed = profile.speed.clone().unwrap_or(DEFAULT_SPEED);
+            let speed = profile.speed.clone().unwrap_or(default_speed);
             let idx =
                 speeds.iter().position(|s| compare_floats(*s, speed) == Equal).expect("Cannot find profile speed");
 
@@ -140,32 +161,190 @@ fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
         .collect()
 }
 
+/// Creates routing matrices for each profile using user defined routing function which returns
+/// travel duration in seconds and distance in configured distance units between two locations for
+/// the profile with given name, or `None` if destination is unreachable. It allows to use custom metrics, e.g.
+/// manhattan distance on a grid or straight line for drones, without routing service. Locations
+/// are ordered as returned by `get_unique_locations`.
+pub fn create_custom_matrices<F>(problem: &ApiProblem, routing: F) -> Vec<Matrix>
+where
+    F: Fn(&str, &Location, &Location) -> Option<(f64, f64)>,
+{
+    let locations = get_unique_locations(&problem);
+
+    problem
+        .fleet
+        .profiles
+        .iter()
+        .map(|profile| {
+            let routes = locations
+                .iter()
+                .flat_map(|from| locations.iter().map(move |to| (from, to)))
+                .map(|(from, to)| routing(profile.name.as_str(), from, to))
+                .collect::<Vec<_>>();
+            let is_unreachable = routes.iter().any(|route| route.is_none());
+
+            Matrix {
+                profile: profile.name.clone(),
+                timestamp: None,
+                travel_times: routes
+                    .iter()
+                    .map(|route| route.map_or(0, |(duration, _)| duration.round() as i64))
+                    .collect(),
+                distances: routes
+                    .iter()
+                    .map(|route| route.map_or(0, |(_, distance)| distance.round() as i64))
+                    .collect(),
+                error_codes: if is_unreachable {
+                    Some(routes.iter().map(|route| if route.is_some() { 0 } else { 1 }).collect())
+                } else {
+                    None
+                },
+            }
+        })
+        .collect()
+}
+
+/// Creates routing matrices for profiles with straight line routing: distance is great-circle
+/// distance between locations and duration is the distance divided by profile speed plus fixed
+/// time of every travel between different locations. Distances are in configured distance units.
+/// Locations are ordered as returned by `get_unique_locations`.
+pub fn create_straight_line_matrices(problem: &ApiProblem) -> Vec<Matrix> {
+    get_straight_line_matrices(problem, get_distance_factor(problem))
+}
+
+/// Replaces routing matrices of profiles with straight line routing by generated ones which have
+/// distances converted with given distance factor.
+pub(crate) fn with_straight_line_matrices(
+    problem: &ApiProblem,
+    matrices: Vec<Matrix>,
+    distance_factor: f64,
+) -> Vec<Matrix> {
+    let profiles = problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| profile.straight_line.is_some())
+        .map(|profile| profile.name.as_str())
+        .collect::<HashSet<_>>();
+
+    if profiles.is_empty() {
+        return matrices;
+    }
+
+    matrices
+        .into_iter()
+        .filter(|matrix| !profiles.contains(matrix.profile.as_str()))
+        .chain(get_straight_line_matrices(problem, distance_factor).into_iter())
+        .collect()
+}
+
+fn get_straight_line_matrices(problem: &ApiProblem, distance_factor: f64) -> Vec<Matrix> {
+    let locations = get_unique_locations(&problem);
+    let distances = locations
+        .iter()
+        .flat_map(|from| {
+            locations.iter().map(move |to| get_haversine_distance(&(from.lat, from.lng), &(to.lat, to.lng)))
+        })
+        .collect::<Vec<_>>();
+
+    problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| profile.straight_line.as_ref().map(|routing| (profile, routing)))
+        .map(|(profile, routing)| {
+            let fixed_time = routing.fixed_time.unwrap_or(0.);
+            let size = locations.len();
+
+            Matrix {
+                profile: profile.name.clone(),
+                timestamp: None,
+                travel_times: distances
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, distance)| {
+                        let fixed_time = if idx / size == idx % size { 0. } else { fixed_time };
+                        (distance / routing.speed + fixed_time).round() as i64
+                    })
+                    .collect(),
+                distances: distances.iter().map(|distance| (distance / distance_factor).round() as i64).collect(),
+                error_codes: None,
+            }
+        })
+        .collect()
+}
+
+/// Returns a factor to convert routing matrix distances to meters.
+pub(crate) fn get_distance_factor(problem: &ApiProblem) -> f64 {
+    match problem.config.as_ref().and_then(|config| config.routing.as_ref()).and_then(|routing| routing.distance_unit) {
+        Some(DistanceUnit::Kilometers) => 1000.,
+        Some(DistanceUnit::Miles) => 1609.344,
+        Some(DistanceUnit::Meters) | None => 1.,
+    }
+}
+
+/// Returns speed used for routing approximation when profile has no speed specified.
+pub(crate) fn get_default_speed(problem: &ApiProblem) -> f64 {
+    const DEFAULT_SPEED: f64 = 10.;
+
+    problem
+        .config
+        .as_ref()
+        .and_then(|config| config.routing.as_ref())
+        .and_then(|routing| routing.default_speed)
+        .unwrap_or(DEFAULT_SPEED)
+}
+
 fn map_to_problem_with_approx(problem: ApiProblem) -> Result<Problem, Vec<FormatError>> {
-    let matrices = create_approx_matrices(&problem);
-    map_to_problem(problem, matrices)
+    // NOTE approximated distances are already in meters
+    let matrices = with_straight_line_matrices(&problem, create_approx_matrices(&problem), 1.);
+
+    map_to_problem_with_factor(&problem, matrices, 1.)
 }
 
-fn map_to_problem(api_problem: ApiProblem, matrices: Vec<Matrix>) -> Result<Problem, Vec<FormatError>> {
-    ValidationContext::new(&api_problem, Some(&matrices)).validate()?;
+fn map_to_problem(api_problem: &ApiProblem, matrices: Vec<Matrix>) -> Result<Problem, Vec<FormatError>> {
+    let distance_factor = get_distance_factor(api_problem);
+    let matrices = with_straight_line_matrices(api_problem, matrices, distance_factor);
+
+    map_to_problem_with_factor(api_problem, matrices, distance_factor)
+}
 
-    let problem_props = get_problem_properties(&api_problem, &matrices);
+fn map_to_problem_with_factor(
+    api_problem: &ApiProblem,
+    matrices: Vec<Matrix>,
+    distance_factor: f64,
+) -> Result<Problem, Vec<FormatError>> {
+    ValidationContext::new(api_problem, Some(&matrices)).validate()?;
 
-    let coord_index = Arc::new(CoordIndex::new(&api_problem));
-    let transport = create_transport_costs(&api_problem, &matrices).map_err(|err| {
+    // NOTE coord index is created from original problem to keep location indices consistent with matrices
+    let coord_index = Arc::new(CoordIndex::new(api_problem));
+    let transport = create_transport_costs(api_problem, &matrices, distance_factor, &coord_index).map_err(|err| {
         vec![FormatError::new(
             "E0002".to_string(),
             "cannot create transport costs".to_string(),
             format!("Check matrix routing data: '{}'", err),
         )]
     })?;
+
+    // NOTE carved shifts are renumbered, so original shift indices are kept in vehicle dimensions
+    let carved_problem = carve_vehicle_shifts(api_problem);
+    let (api_problem, shift_map) =
+        carved_problem.as_ref().map_or((api_problem, None), |(problem, shift_map)| (problem, Some(shift_map)));
+    let clipped_problem = clip_job_time_windows(api_problem);
+    let api_problem = clipped_problem.as_ref().unwrap_or(api_problem);
+
+    let problem_props = get_problem_properties(api_problem, &matrices);
     let activity = Arc::new(OnlyVehicleActivityCost::default());
-    let fleet = read_fleet(&api_problem, &problem_props, &coord_index);
+    let fleet = read_fleet(api_problem, &problem_props, &coord_index, shift_map);
 
     let mut job_index = Default::default();
     let (jobs, locks) =
-        read_jobs_with_extra_locks(&api_problem, &problem_props, &coord_index, &fleet, &transport, &mut job_index);
-    let locks = locks.into_iter().chain(read_locks(&api_problem, &job_index).into_iter()).collect();
-    let limits = read_limits(&api_problem).unwrap_or_else(|| Arc::new(|_| (None, None)));
+        read_jobs_with_extra_locks(api_problem, &problem_props, &coord_index, &fleet, &transport, &mut job_index);
+    #[cfg(feature = "relations")]
+    let locks = locks.into_iter().chain(read_locks(api_problem, &job_index).into_iter()).collect::<Vec<_>>();
+    let forbidden_arcs = read_forbidden_arcs(api_problem, &job_index);
+    let limits = read_limits(api_problem).unwrap_or_else(|| Arc::new(|_| (None, None)));
     let extras = Arc::new(create_extras(&problem_props, coord_index.clone()));
     let mut constraint = create_constraint_pipeline(
         coord_index,
@@ -174,10 +353,11 @@ fn map_to_problem(api_problem: ApiProblem, matrices: Vec<Matrix>) -> Result<Prob
         transport.clone(),
         &problem_props,
         &locks,
+        forbidden_arcs,
         limits,
     );
 
-    let objective = create_objective(&api_problem, &mut constraint, &problem_props);
+    let objective = create_objective(api_problem, &mut constraint, &problem_props, &fleet, &job_index);
 
     Ok(Problem {
         fleet: Arc::new(fleet),
@@ -198,6 +378,7 @@ fn create_constraint_pipeline(
     transport: Arc<dyn TransportCost + Send + Sync>,
     props: &ProblemProperties,
     locks: &Vec<Arc<Lock>>,
+    forbidden_arcs: HashMap<Job, HashSet<Job>>,
     limits: TravelLimitFunc,
 ) -> ConstraintPipeline {
     let mut constraint = ConstraintPipeline::default();
@@ -212,8 +393,11 @@ fn create_constraint_pipeline(
 
     add_capacity_module(&mut constraint, &props);
 
-    if props.has_breaks {
-        constraint.add_module(Box::new(BreakModule::new(BREAK_CONSTRAINT_CODE, Some(-100.), false)));
+    #[cfg(feature = "breaks")]
+    {
+        if props.has_breaks {
+            constraint.add_module(Box::new(BreakModule::new(BREAK_CONSTRAINT_CODE, Some(-100.), false)));
+        }
     }
 
     if props.has_skills {
@@ -236,29 +420,44 @@ fn create_constraint_pipeline(
         add_area_module(&mut constraint, coord_index);
     }
 
+    if !forbidden_arcs.is_empty() {
+        constraint.add_module(Box::new(ForbiddenArcModule::new(forbidden_arcs, FORBIDDEN_ARC_CONSTRAINT_CODE)));
+    }
+
+    if props.has_positions {
+        constraint.add_module(Box::new(PositionModule::new(POSITION_PENALTY)));
+    }
+
     constraint
 }
 
 fn add_capacity_module(constraint: &mut ConstraintPipeline, props: &ProblemProperties) {
-    constraint.add_module(if props.has_reload {
-        let threshold = 0.9;
-        if props.has_multi_dimen_capacity {
-            Box::new(CapacityConstraintModule::<MultiDimensionalCapacity>::new_with_multi_trip(
-                CAPACITY_CONSTRAINT_CODE,
-                Arc::new(ReloadMultiTrip::new(Box::new(|capacity| *capacity * 0.9))),
-            ))
-        } else {
-            Box::new(CapacityConstraintModule::<i32>::new_with_multi_trip(
-                CAPACITY_CONSTRAINT_CODE,
-                Arc::new(ReloadMultiTrip::new(Box::new(move |capacity| (*capacity as f64 * threshold).round() as i32))),
-            ))
+    #[cfg(feature = "reloads")]
+    {
+        if props.has_reload {
+            let threshold = 0.9;
+            constraint.add_module(if props.has_multi_dimen_capacity {
+                Box::new(CapacityConstraintModule::<MultiDimensionalCapacity>::new_with_multi_trip(
+                    CAPACITY_CONSTRAINT_CODE,
+                    Arc::new(ReloadMultiTrip::new(Box::new(|capacity| *capacity * 0.9))),
+                ))
+            } else {
+                Box::new(CapacityConstraintModule::<i32>::new_with_multi_trip(
+                    CAPACITY_CONSTRAINT_CODE,
+                    Arc::new(ReloadMultiTrip::new(Box::new(move |capacity| {
+                        (*capacity as f64 * threshold).round() as i32
+                    }))),
+                ))
+            });
+
+            return;
         }
+    }
+
+    constraint.add_module(if props.has_multi_dimen_capacity {
+        Box::new(CapacityConstraintModule::<MultiDimensionalCapacity>::new(CAPACITY_CONSTRAINT_CODE))
     } else {
-        if props.has_multi_dimen_capacity {
-            Box::new(CapacityConstraintModule::<MultiDimensionalCapacity>::new(CAPACITY_CONSTRAINT_CODE))
-        } else {
-            Box::new(CapacityConstraintModule::<i32>::new(CAPACITY_CONSTRAINT_CODE))
-        }
+        Box::new(CapacityConstraintModule::<i32>::new(CAPACITY_CONSTRAINT_CODE))
     });
 }
 
@@ -300,6 +499,7 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &Vec<Matrix>) -> P
                 .flat_map(|tasks| tasks.iter())
                 .any(|task| task.demand.as_ref().map_or(false, |d| d.len() > 1))
         });
+    #[cfg(feature = "breaks")]
     let has_breaks = api_problem
         .fleet
         .vehicles
@@ -308,6 +508,7 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &Vec<Matrix>) -> P
         .any(|shift| shift.breaks.as_ref().map_or(false, |b| b.len() > 0));
 
     let has_skills = api_problem.plan.jobs.iter().any(|job| job.skills.is_some());
+    #[cfg(feature = "reloads")]
     let has_reload = api_problem
         .fleet
         .vehicles
@@ -320,15 +521,20 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &Vec<Matrix>) -> P
         .vehicles
         .iter()
         .any(|v| v.limits.as_ref().and_then(|l| l.allowed_areas.as_ref()).map_or(false, |a| !a.is_empty()));
+    let has_positions =
+        api_problem.plan.jobs.iter().filter_map(|job| job.position).any(|position| position != JobPosition::Any);
 
     ProblemProperties {
         has_multi_dimen_capacity,
+        #[cfg(feature = "breaks")]
         has_breaks,
         has_skills,
         has_unreachable_locations,
+        #[cfg(feature = "reloads")]
         has_reload,
         has_priorities,
         has_area_limits,
+        has_positions,
     }
 }
 
@@ -0,0 +1,78 @@
+use crate::format::problem::reader::{ApiProblem, JobIndex};
+use crate::format::problem::{get_original_shift_index, RelationType};
+use std::collections::HashMap;
+use std::sync::Arc;
+use vrp_core::models::common::IdDimension;
+use vrp_core::models::problem::Actor;
+use vrp_core::models::{Lock, LockDetail, LockOrder, LockPosition};
+
+/// Reads locks from relations defined in the plan.
+pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Lock>> {
+    if api_problem.plan.relations.as_ref().map_or(true, |r| r.is_empty()) {
+        return vec![];
+    }
+
+    let relations = api_problem.plan.relations.as_ref().unwrap().iter().fold(HashMap::new(), |mut acc, r| {
+        let shift_index = r.shift_index.unwrap_or(0);
+        acc.entry((r.vehicle_id.clone(), shift_index)).or_insert(vec![]).push(r.clone());
+
+        acc
+    });
+
+    let locks = relations.into_iter().fold(vec![], |mut acc, ((vehicle_id, shift_index), rels)| {
+        let condition = create_condition(vehicle_id.clone(), shift_index);
+        let details = rels.iter().fold(vec![], |mut acc, rel| {
+            let order = match rel.type_field {
+                RelationType::Any => LockOrder::Any,
+                RelationType::Sequence => LockOrder::Sequence,
+                RelationType::Strict => LockOrder::Strict,
+            };
+
+            let position = match (rel.jobs.first().map(|s| s.as_str()), rel.jobs.last().map(|s| s.as_str())) {
+                (Some("departure"), Some("arrival")) => LockPosition::Fixed,
+                (Some("departure"), _) => LockPosition::Departure,
+                (_, Some("arrival")) => LockPosition::Arrival,
+                _ => LockPosition::Any,
+            };
+
+            let (_, _, jobs) = rel
+                .jobs
+                .iter()
+                .filter(|job| job.as_str() != "departure" && job.as_str() != "arrival")
+                .fold((0_usize, 0_usize, vec![]), |(mut break_idx, mut reload_idx, mut jobs), job| {
+                    let job = match job.as_str() {
+                        "break" => {
+                            break_idx += 1;
+                            job_index.get(format!("{}_break_{}", vehicle_id, break_idx).as_str()).cloned().unwrap()
+                        }
+                        "reload" => {
+                            reload_idx += 1;
+                            job_index.get(format!("{}_reload_{}", vehicle_id, reload_idx).as_str()).cloned().unwrap()
+                        }
+                        _ => job_index.get(job).unwrap().clone(),
+                    };
+
+                    jobs.push(job);
+
+                    (break_idx, reload_idx, jobs)
+                });
+
+            acc.push(LockDetail::new(order, position, jobs));
+
+            acc
+        });
+
+        acc.push(Arc::new(Lock::new(condition, details)));
+
+        acc
+    });
+
+    locks
+}
+
+fn create_condition(vehicle_id: String, shift_index: usize) -> Arc<dyn Fn(&Actor) -> bool + Sync + Send> {
+    Arc::new(move |actor: &Actor| {
+        *actor.vehicle.dimens.get_id().unwrap() == vehicle_id
+            && get_original_shift_index(&actor.vehicle.dimens) == shift_index
+    })
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/unavailability_test.rs"]
+mod unavailability_test;
+
+use crate::format::problem::*;
+use crate::{format_time, parse_time};
+use std::cmp::Ordering::Less;
+use std::collections::HashMap;
+use vrp_core::models::common::{Dimensions, TimeWindow, ValueDimension};
+
+/// Maps indices of carved shifts to indices of original shifts per vehicle type id.
+pub(crate) type ShiftIndexMap = HashMap<String, Vec<usize>>;
+
+/// Carves vehicle shifts using unavailable time windows of vehicle types:
+/// * shift which is fully covered by unavailable time window is removed
+/// * shift which overlaps unavailable time window is truncated
+/// * shift which contains unavailable time window is split into two shifts: the first one ends at
+///   shift end location (or start location, if shift is open) when time window starts, the second
+///   one starts at shift start location when time window ends
+///
+/// Breaks with time window are kept only within shifts which they intersect, breaks with offset
+/// and reloads are kept in all shifts. Returns `None` if there is no vehicle unavailability,
+/// otherwise returns carved problem with indices of original shifts for carved vehicle types.
+pub(crate) fn carve_vehicle_shifts(problem: &Problem) -> Option<(Problem, ShiftIndexMap)> {
+    if problem.fleet.vehicles.iter().all(|vehicle| vehicle.unavailable.is_none()) {
+        return None;
+    }
+
+    let mut problem = problem.clone();
+    let mut shift_map = ShiftIndexMap::default();
+
+    problem.fleet.vehicles.iter_mut().for_each(|vehicle| {
+        if let Some(unavailable) = vehicle.unavailable.as_ref() {
+            let mut unavailable = unavailable.iter().map(|tw| get_time_window(tw)).collect::<Vec<_>>();
+            unavailable.sort_by(|a, b| a.start.partial_cmp(&b.start).unwrap_or(Less));
+
+            let (shifts, indices): (Vec<_>, Vec<_>) = vehicle
+                .shifts
+                .iter()
+                .enumerate()
+                .flat_map(|(idx, shift)| {
+                    carve_shift(shift, unavailable.as_slice()).into_iter().map(move |shift| (shift, idx))
+                })
+                .unzip();
+
+            vehicle.shifts = shifts;
+            shift_map.insert(vehicle.type_id.clone(), indices);
+        }
+    });
+
+    Some((problem, shift_map))
+}
+
+/// Returns index of vehicle shift as it is defined in the problem, before carving by unavailability.
+pub(crate) fn get_original_shift_index(dimens: &Dimensions) -> usize {
+    *dimens.get_value::<usize>("original_shift_index").unwrap()
+}
+
+fn carve_shift(shift: &VehicleShift, unavailable: &[TimeWindow]) -> Vec<VehicleShift> {
+    let shift_start = parse_time(&shift.start.time);
+    let shift_end = shift.end.as_ref().map_or(std::f64::MAX, |end| parse_time(&end.time));
+
+    let mut parts = vec![];
+    let mut part_start = shift_start;
+
+    unavailable.iter().filter(|tw| tw.start < shift_end && tw.end > shift_start).for_each(|tw| {
+        if tw.start > part_start {
+            parts.push(TimeWindow::new(part_start, tw.start));
+        }
+        part_start = part_start.max(tw.end);
+    });
+
+    if part_start < shift_end {
+        parts.push(TimeWindow::new(part_start, shift_end));
+    }
+
+    parts
+        .into_iter()
+        .map(|part| {
+            let start = if part.start == shift_start {
+                shift.start.clone()
+            } else {
+                VehiclePlace { time: format_time(part.start), ..shift.start.clone() }
+            };
+
+            let end = if part.end == shift_end {
+                shift.end.clone()
+            } else {
+                Some(shift.end.as_ref().map_or_else(
+                    || VehiclePlace { time: format_time(part.end), location: shift.start.location.clone(), gate: None },
+                    |end| VehiclePlace { time: format_time(part.end), ..end.clone() },
+                ))
+            };
+
+            let breaks = shift.breaks.as_ref().map(|breaks| {
+                breaks
+                    .iter()
+                    .filter(|vehicle_break| match &vehicle_break.time {
+                        VehicleBreakTime::TimeWindow(tw) => get_time_window(tw).intersects(&part),
+                        VehicleBreakTime::TimeOffset(_) => true,
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>()
+            });
+            let breaks = breaks.filter(|breaks| !breaks.is_empty());
+
+            VehicleShift { start, end, breaks, reloads: shift.reloads.clone() }
+        })
+        .collect()
+}
+
+fn get_time_window(tw: &[String]) -> TimeWindow {
+    TimeWindow::new(parse_time(tw.first().unwrap()), parse_time(tw.last().unwrap()))
+}
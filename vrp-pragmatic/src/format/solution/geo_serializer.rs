@@ -1,9 +1,18 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/geo_serializer_test.rs"]
+mod geo_serializer_test;
+
 use super::Solution;
+use crate::format::coord_index::CoordIndex;
+use crate::format::solution::writer::{get_unassigned_job_id, map_code_reason};
 use crate::format::solution::{Stop, Tour};
 use serde::Serialize;
-use serde_json::Error;
+use serde_json::{json, Error, Value};
 use std::collections::HashMap;
 use std::io::{BufWriter, Write};
+use vrp_core::models::problem::Job;
+use vrp_core::models::Problem;
+use vrp_core::solver::InsertionHeatmap;
 
 #[derive(Clone, Serialize, Debug)]
 #[serde(tag = "type")]
@@ -15,7 +24,7 @@ enum Geometry {
 #[derive(Clone, Serialize, Debug)]
 #[serde(tag = "type")]
 struct Feature {
-    pub properties: HashMap<String, String>,
+    pub properties: HashMap<String, Value>,
     pub geometry: Geometry,
 }
 
@@ -25,8 +34,8 @@ struct FeatureCollection {
     pub features: Vec<Feature>,
 }
 
-fn slice_to_map(vec: &[(&str, &str)]) -> HashMap<String, String> {
-    vec.into_iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+fn slice_to_map(vec: &[(&str, &str)]) -> HashMap<String, Value> {
+    vec.into_iter().map(|(key, value)| (key.to_string(), Value::String(value.to_string()))).collect()
 }
 
 fn get_marker_symbol(stop: &Stop) -> String {
@@ -55,6 +64,8 @@ fn get_stop_point(tour_idx: usize, stop_idx: usize, stop: &Stop, color: &str) ->
             ("stop_idx", stop_idx.to_string().as_str()),
             ("arrival", stop.time.arrival.as_str()),
             ("departure", stop.time.departure.as_str()),
+            ("distance", stop.distance.to_string().as_str()),
+            ("load", stop.load.iter().map(|load| load.to_string()).collect::<Vec<_>>().join(",").as_str()),
             ("jobs_ids", stop.activities.iter().map(|a| a.job_id.clone()).collect::<Vec<_>>().join(",").as_str()),
         ]),
         geometry: Geometry::Point { coordinates: (stop.location.lng, stop.location.lat) },
@@ -100,6 +111,70 @@ pub fn serialize_solution_as_geojson<W: Write>(writer: BufWriter<W>, solution: &
     )
 }
 
+/// Serializes insertion heatmap into geo json format: each job which failed insertion at least once
+/// is represented by points at its locations with `intensity` property which is a ratio of failures
+/// to total amount of solutions produced by the solver.
+pub fn serialize_heatmap_as_geojson<W: Write>(
+    writer: BufWriter<W>,
+    problem: &Problem,
+    heatmap: &InsertionHeatmap,
+) -> Result<(), Error> {
+    let coord_index = problem
+        .extras
+        .get("coord_index")
+        .and_then(|s| s.downcast_ref::<CoordIndex>())
+        .unwrap_or_else(|| panic!("Cannot get coord index!"));
+    let samples = heatmap.samples().max(1) as f64;
+
+    let mut failures = heatmap.failures();
+    failures.sort_by_key(|(job, _)| get_unassigned_job_id(job));
+
+    let features = failures
+        .iter()
+        .flat_map(|(job, codes)| {
+            let job_id = get_unassigned_job_id(job);
+            let total = codes.iter().map(|(_, count)| *count).sum::<usize>();
+            let reasons = codes
+                .iter()
+                .map(|(code, count)| {
+                    let (code, description) = map_code_reason(*code);
+                    json!({ "code": code, "description": description, "count": count })
+                })
+                .collect::<Vec<_>>();
+
+            get_job_locations(job).into_iter().filter_map(move |location| {
+                coord_index.get_by_idx(&location).map(|location| Feature {
+                    properties: vec![
+                        ("job_id".to_string(), Value::String(job_id.clone())),
+                        ("failures".to_string(), json!(total)),
+                        ("intensity".to_string(), json!(total as f64 / samples)),
+                        ("reasons".to_string(), Value::Array(reasons.clone())),
+                    ]
+                    .into_iter()
+                    .collect(),
+                    geometry: Geometry::Point { coordinates: (location.lng, location.lat) },
+                })
+            })
+        })
+        .collect();
+
+    serde_json::to_writer_pretty(writer, &FeatureCollection { features })
+}
+
+fn get_job_locations(job: &Job) -> Vec<usize> {
+    let singles = match job {
+        Job::Single(single) => vec![single.clone()],
+        Job::Multi(multi) => multi.jobs.clone(),
+    };
+
+    let mut locations =
+        singles.iter().flat_map(|single| single.places.iter().filter_map(|place| place.location)).collect::<Vec<_>>();
+    locations.sort_unstable();
+    locations.dedup();
+
+    locations
+}
+
 fn get_color(idx: usize) -> String {
     static COLOR_LIST: ColorList = get_color_list();
 
@@ -0,0 +1,194 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/initial_reader_test.rs"]
+mod initial_reader_test;
+
+use crate::format::coord_index::CoordIndex;
+use crate::format::problem::get_original_shift_index;
+use crate::format::solution::{deserialize_solution, Activity as ApiActivity, Tour as ApiTour};
+use crate::parse_time_safe;
+use std::collections::{HashMap, HashSet};
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::construction::heuristics::*;
+use vrp_core::models::common::*;
+use vrp_core::models::problem::{Actor, Job, Single};
+use vrp_core::models::solution::{Activity, Place, Registry, Route, Tour};
+use vrp_core::models::{Problem, Solution};
+use vrp_core::utils::DefaultRandom;
+
+/// Reads initial solution from pragmatic solution json. The order of activities in tours is kept,
+/// but schedules are recomputed. Breaks and reloads which violate hard constraints at their
+/// position are dropped. Jobs which are not present in tours are considered as unassigned.
+pub fn read_init_solution<R: Read>(solution: BufReader<R>, problem: Arc<Problem>) -> Result<Solution, String> {
+    let api_solution = deserialize_solution(solution).map_err(|err| format!("cannot deserialize solution: {}", err))?;
+
+    let coord_index = problem
+        .extras
+        .get("coord_index")
+        .and_then(|s| s.downcast_ref::<CoordIndex>())
+        .ok_or_else(|| "cannot get coord index".to_string())?;
+
+    let job_index = problem.jobs.all().fold(HashMap::<String, Job>::new(), |mut acc, job| {
+        if !is_conditional(&job) {
+            let dimens = match &job {
+                Job::Single(single) => &single.dimens,
+                Job::Multi(multi) => &multi.dimens,
+            };
+            acc.insert(dimens.get_id().cloned().unwrap_or_default(), job.clone());
+        }
+        acc
+    });
+    let conditional = problem.jobs.all().filter(is_conditional).map(|job| job.to_single().clone()).collect::<Vec<_>>();
+
+    let mut registry = Registry::new(&problem.fleet);
+    let mut used_singles = HashSet::new();
+    let mut routes = vec![];
+
+    for api_tour in api_solution.tours.iter() {
+        let route_start = api_tour
+            .stops
+            .first()
+            .ok_or_else(|| format!("empty tour of vehicle '{}'", api_tour.vehicle_id))
+            .and_then(|stop| parse_time_safe(&stop.time.departure).map_err(|err| err.to_string()))?;
+
+        let actor = find_actor(&registry, api_tour, route_start).ok_or_else(|| {
+            format!("cannot find available vehicle '{}' with shift {}", api_tour.vehicle_id, api_tour.shift_index)
+        })?;
+        registry.use_actor(&actor);
+        let shift_index = *actor.vehicle.dimens.get_value::<usize>("shift_index").unwrap();
+
+        let mut tour = Tour::default();
+        tour.set_start(create_start_activity(&actor));
+        create_end_activity(&actor).map(|end| tour.set_end(end));
+
+        for stop in api_tour.stops.iter() {
+            for activity in
+                stop.activities.iter().filter(|a| a.activity_type != "departure" && a.activity_type != "arrival")
+            {
+                let location = activity.location.as_ref().unwrap_or(&stop.location);
+                let location = coord_index
+                    .get_by_loc(location)
+                    .ok_or_else(|| format!("unknown location of job '{}' activity", activity.job_id))?;
+
+                let single = find_single(activity, api_tour, shift_index, &job_index, &conditional, &used_singles)
+                    .ok_or_else(|| {
+                        format!(
+                            "cannot match '{}' activity of job '{}' in tour of vehicle '{}'",
+                            activity.activity_type, activity.job_id, api_tour.vehicle_id
+                        )
+                    })?;
+                used_singles.insert(Job::Single(single.clone()));
+
+                let place = single
+                    .places
+                    .iter()
+                    .find(|place| place.location.map_or(true, |l| l == location))
+                    .ok_or_else(|| format!("cannot match location of job '{}' activity", activity.job_id))?;
+
+                let time = activity
+                    .time_window
+                    .and_then(|idx| place.times.get(idx))
+                    .or_else(|| place.times.first())
+                    .map(|span| span.to_time_window(route_start))
+                    .unwrap_or_else(TimeWindow::max);
+
+                tour.insert_last(Box::new(Activity {
+                    place: Place { location, duration: place.duration, time },
+                    schedule: Schedule::new(0., 0.),
+                    job: Some(single),
+                }));
+            }
+        }
+
+        // NOTE multi jobs have to be assigned completely
+        let partial = tour
+            .jobs()
+            .filter(|job| {
+                job.as_multi()
+                    .map_or(false, |multi| multi.jobs.iter().any(|s| !used_singles.contains(&Job::Single(s.clone()))))
+            })
+            .collect::<Vec<_>>();
+        partial.iter().for_each(|job| {
+            tour.remove(job);
+        });
+
+        routes.push(Route { actor, tour });
+    }
+
+    let assigned = routes.iter().flat_map(|route| route.tour.jobs()).collect::<HashSet<_>>();
+    let unassigned = problem.jobs.all().filter(|job| !assigned.contains(job)).map(|job| (job, 0)).collect();
+
+    let solution = Solution { registry, routes, unassigned, extras: problem.extras.clone() };
+
+    let mut insertion_ctx = InsertionContext::new_from_solution(
+        problem.clone(),
+        (Arc::new(solution), None),
+        Arc::new(DefaultRandom::default()),
+    );
+
+    (0..insertion_ctx.solution.routes.len()).for_each(|route_idx| {
+        repair_route(&mut insertion_ctx, route_idx, &is_conditional);
+    });
+    insertion_ctx.restore();
+
+    let solution_ctx = &insertion_ctx.solution;
+    let mut solution = solution_ctx.to_solution(problem.extras.clone());
+    solution
+        .unassigned
+        .extend(solution_ctx.required.iter().chain(solution_ctx.ignored.iter()).map(|job| (job.clone(), 0)));
+
+    Ok(solution)
+}
+
+fn is_conditional(job: &Job) -> bool {
+    job.as_single().map_or(false, |single| single.dimens.get_value::<String>("vehicle_id").is_some())
+}
+
+/// Finds available actor for the tour. When original shift is split by vehicle unavailability,
+/// the part which contains tour start is preferred.
+fn find_actor(registry: &Registry, api_tour: &ApiTour, route_start: Timestamp) -> Option<Arc<Actor>> {
+    let actors = registry
+        .available()
+        .filter(|actor| {
+            let dimens = &actor.vehicle.dimens;
+            dimens.get_id().map_or(false, |id| *id == api_tour.vehicle_id)
+                && get_original_shift_index(dimens) == api_tour.shift_index
+        })
+        .collect::<Vec<_>>();
+
+    actors
+        .iter()
+        .find(|actor| actor.detail.time.start <= route_start && route_start <= actor.detail.time.end)
+        .or_else(|| actors.first())
+        .cloned()
+}
+
+fn find_single(
+    activity: &ApiActivity,
+    api_tour: &ApiTour,
+    shift_index: usize,
+    job_index: &HashMap<String, Job>,
+    conditional: &[Arc<Single>],
+    used_singles: &HashSet<Job>,
+) -> Option<Arc<Single>> {
+    let is_same_single = |single: &Arc<Single>| {
+        single.dimens.get_value::<String>("type").map_or(false, |t| *t == activity.activity_type)
+            && single.dimens.get_value::<String>("tag") == activity.job_tag.as_ref()
+            && !used_singles.contains(&Job::Single(single.clone()))
+    };
+
+    match activity.activity_type.as_str() {
+        "break" | "reload" => conditional
+            .iter()
+            .filter(|single| {
+                single.dimens.get_value::<String>("vehicle_id").map_or(false, |id| *id == api_tour.vehicle_id)
+                    && single.dimens.get_value::<usize>("shift_index").map_or(false, |idx| *idx == shift_index)
+            })
+            .find(|single| is_same_single(single))
+            .cloned(),
+        _ => match job_index.get(&activity.job_id)? {
+            Job::Single(single) => Some(single.clone()).filter(is_same_single),
+            Job::Multi(multi) => multi.jobs.iter().find(|single| is_same_single(single)).cloned(),
+        },
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/link_serializer_test.rs"]
+mod link_serializer_test;
+
+use super::{Solution, Tour};
+use crate::format::Location;
+use serde::Serialize;
+use std::io::{BufWriter, Error, Write};
+
+/// Max amount of intermediate waypoints accepted by Google Maps directions link.
+const GOOGLE_MAX_WAYPOINTS: usize = 9;
+
+/// Contains links to routing services which show the tour on a map.
+#[derive(Clone, Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TourLinks {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// A link to OSRM demo server.
+    pub osrm: String,
+    /// Links to Google Maps directions. As amount of waypoints per link is limited, a long tour
+    /// is split into several consecutive parts.
+    pub google: Vec<String>,
+}
+
+/// Creates links to routing services for each tour in the solution.
+pub fn create_tour_links(solution: &Solution) -> Vec<TourLinks> {
+    solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let locations = get_tour_locations(tour);
+
+            TourLinks {
+                vehicle_id: tour.vehicle_id.clone(),
+                shift_index: tour.shift_index,
+                osrm: get_osrm_link(locations.as_slice()),
+                google: get_google_links(locations.as_slice()),
+            }
+        })
+        .collect()
+}
+
+/// Serializes links to routing services for each tour as markdown report.
+pub fn serialize_tour_links_as_markdown<W: Write>(writer: BufWriter<W>, links: &[TourLinks]) -> Result<(), Error> {
+    let mut writer = writer;
+
+    writeln!(writer, "# Tours")?;
+
+    links.iter().try_for_each(|links| {
+        writeln!(writer)?;
+        writeln!(writer, "## {} (shift {})", links.vehicle_id, links.shift_index)?;
+        writeln!(writer)?;
+        writeln!(writer, "- [OSRM]({})", links.osrm)?;
+
+        match links.google.as_slice() {
+            [link] => writeln!(writer, "- [Google Maps]({})", link),
+            parts => parts
+                .iter()
+                .enumerate()
+                .try_for_each(|(idx, link)| writeln!(writer, "- [Google Maps, part {}]({})", idx + 1, link)),
+        }
+    })
+}
+
+/// Returns tour locations without consecutive duplicates.
+fn get_tour_locations(tour: &Tour) -> Vec<&Location> {
+    tour.stops.iter().map(|stop| &stop.location).fold(Vec::new(), |mut acc, location| {
+        match acc.last() {
+            Some(last) if last.lat == location.lat && last.lng == location.lng => {}
+            _ => acc.push(location),
+        }
+        acc
+    })
+}
+
+fn get_osrm_link(locations: &[&Location]) -> String {
+    let points = locations.iter().map(|l| format!("&loc={},{}", l.lat, l.lng)).collect::<String>();
+
+    format!("https://map.project-osrm.org/?z=12{}&hl=en&alt=0&srv=0", points)
+}
+
+fn get_google_links(locations: &[&Location]) -> Vec<String> {
+    if locations.is_empty() {
+        return vec![];
+    }
+
+    let format_location = |location: &&Location| format!("{},{}", location.lat, location.lng);
+
+    // NOTE consecutive parts share boundary location, so each part starts where the previous one ends
+    let step = GOOGLE_MAX_WAYPOINTS + 1;
+    (0..locations.len().max(2) - 1)
+        .step_by(step)
+        .map(|start| {
+            let part = &locations[start..(start + step + 1).min(locations.len())];
+            let (origin, destination) = (part.first().unwrap(), part.last().unwrap());
+            let waypoints = part.iter().skip(1).take(part.len().max(2) - 2).map(format_location).collect::<Vec<_>>();
+
+            let waypoints =
+                if waypoints.is_empty() { String::new() } else { format!("&waypoints={}", waypoints.join("%7C")) };
+
+            format!(
+                "https://www.google.com/maps/dir/?api=1&origin={}&destination={}{}&travelmode=driving",
+                format_location(origin),
+                format_location(destination),
+                waypoints
+            )
+        })
+        .collect()
+}
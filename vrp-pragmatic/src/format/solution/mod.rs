@@ -4,10 +4,17 @@ mod model;
 pub use self::model::*;
 
 mod geo_serializer;
-pub use self::geo_serializer::serialize_solution_as_geojson;
+pub use self::geo_serializer::{serialize_heatmap_as_geojson, serialize_solution_as_geojson};
+
+mod link_serializer;
+pub use self::link_serializer::{create_tour_links, serialize_tour_links_as_markdown, TourLinks};
 
 mod extensions;
 
+mod initial_reader;
+pub use self::initial_reader::read_init_solution;
+
 mod writer;
 pub use self::writer::create_solution;
+pub use self::writer::create_unassignable_jobs;
 pub use self::writer::PragmaticSolution;
@@ -1,6 +1,7 @@
 use crate::format::Location;
 use serde::{Deserialize, Serialize};
 use serde_json::Error;
+use std::collections::HashMap;
 use std::io::{BufReader, BufWriter, Read, Write};
 
 /// Timing statistic.
@@ -67,6 +68,10 @@ pub struct Activity {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename(serialize = "tag", deserialize = "tag"))]
     pub job_tag: Option<String>,
+    /// Index of job place time window used to serve the activity. Specified only when
+    /// the place has more than one time window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_window: Option<usize>,
 }
 
 /// A stop is a place where vehicle is supposed to be parked.
@@ -138,7 +143,12 @@ pub struct Iteration {
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub struct Extras {
     /// Stores information about iteration performance.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub performance: Vec<Iteration>,
+    /// Stores user defined tags of the run, e.g. experiment or region name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<HashMap<String, String>>,
 }
 
 /// A VRP solution.
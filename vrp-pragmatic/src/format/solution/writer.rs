@@ -4,6 +4,7 @@ mod writer_test;
 
 use crate::extensions::MultiDimensionalCapacity;
 use crate::format::coord_index::CoordIndex;
+use crate::format::problem::get_original_shift_index;
 use crate::format::solution::model::Timing;
 use crate::format::solution::{
     serialize_solution, serialize_solution_as_geojson, Activity, Extras, Interval, Statistic, Stop, Tour,
@@ -11,12 +12,18 @@ use crate::format::solution::{
 };
 use crate::format::*;
 use crate::format_time;
+use serde::ser::{SerializeMap, Serializer};
+use serde::Serialize;
+use std::cell::RefCell;
 use std::io::{BufWriter, Write};
+use std::sync::Arc;
 use vrp_core::construction::constraints::{route_intervals, Demand, DemandDimension};
+use vrp_core::construction::heuristics::find_unassignable_jobs;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Job, Multi};
 use vrp_core::models::solution::{Route, TourActivity};
 use vrp_core::models::{Problem, Solution};
+use vrp_core::utils::DefaultRandom;
 
 type ApiSolution = crate::format::solution::model::Solution;
 type ApiSchedule = crate::format::solution::model::Schedule;
@@ -30,6 +37,10 @@ pub trait PragmaticSolution<W: Write> {
 
     /// Serializes solution in pragmatic geo json format.
     fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
+
+    /// Serializes solution in pragmatic json format tour by tour, so the whole solution model is
+    /// not kept in memory. As total statistic is accumulated from tours, it is written after them.
+    fn write_pragmatic_json_streamed(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
 }
 
 impl<W: Write> PragmaticSolution<W> for Solution {
@@ -44,6 +55,53 @@ impl<W: Write> PragmaticSolution<W> for Solution {
         serialize_solution_as_geojson(writer, &solution).map_err(|err| err.to_string())?;
         Ok(())
     }
+
+    fn write_pragmatic_json_streamed(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+        let solution = StreamedSolution { problem, solution: self, coord_index: get_coord_index(self) };
+        solution.serialize(&mut serde_json::Serializer::pretty(writer)).map_err(|err| err.to_string())
+    }
+}
+
+/// Serializes solution creating its tours on demand.
+struct StreamedSolution<'a> {
+    problem: &'a Problem,
+    solution: &'a Solution,
+    coord_index: &'a CoordIndex,
+}
+
+/// Serializes tours one by one accumulating their statistic.
+struct StreamedTours<'a> {
+    solution: &'a StreamedSolution<'a>,
+    statistic: &'a RefCell<Statistic>,
+}
+
+impl<'a> Serialize for StreamedSolution<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let statistic = RefCell::new(Statistic::default());
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("tours", &StreamedTours { solution: self, statistic: &statistic })?;
+        map.serialize_entry("statistic", &*statistic.borrow())?;
+        map.serialize_entry("unassigned", &create_unassigned(self.solution))?;
+        if let Some(extras) = create_extras(self.solution) {
+            map.serialize_entry("extras", &extras)?;
+        }
+        map.end()
+    }
+}
+
+impl<'a> Serialize for StreamedTours<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let StreamedSolution { problem, solution, coord_index } = self.solution;
+
+        serializer.collect_seq(solution.routes.iter().map(|route| {
+            let tour = create_tour(problem, route, coord_index);
+            let statistic = self.statistic.borrow().clone() + tour.statistic.clone();
+            self.statistic.replace(statistic);
+
+            tour
+        }))
+    }
 }
 
 struct Leg {
@@ -68,11 +126,7 @@ impl Leg {
 
 /// Creates solution.
 pub fn create_solution(problem: &Problem, solution: &Solution) -> ApiSolution {
-    let coord_index = solution
-        .extras
-        .get("coord_index")
-        .and_then(|s| s.downcast_ref::<CoordIndex>())
-        .unwrap_or_else(|| panic!("Cannot get coord index!"));
+    let coord_index = get_coord_index(solution);
 
     let tours = solution.routes.iter().map(|r| create_tour(problem, r, coord_index)).collect::<Vec<Tour>>();
 
@@ -85,6 +139,14 @@ pub fn create_solution(problem: &Problem, solution: &Solution) -> ApiSolution {
     ApiSolution { statistic, tours, unassigned, extras }
 }
 
+fn get_coord_index(solution: &Solution) -> &CoordIndex {
+    solution
+        .extras
+        .get("coord_index")
+        .and_then(|s| s.downcast_ref::<CoordIndex>())
+        .unwrap_or_else(|| panic!("Cannot get coord index!"))
+}
+
 fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> Tour {
     let is_multi_dimen = has_multi_dimensional_capacity(problem.extras.as_ref());
 
@@ -94,13 +156,15 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
     let mut tour = Tour {
         vehicle_id: vehicle.dimens.get_id().unwrap().clone(),
         type_id: vehicle.dimens.get_value::<String>("type_id").unwrap().to_string(),
-        shift_index: *vehicle.dimens.get_value::<usize>("shift_index").unwrap(),
+        shift_index: get_original_shift_index(&vehicle.dimens),
         stops: vec![],
         statistic: Statistic::default(),
     };
 
     let intervals = route_intervals(route, Box::new(|a| get_activity_type(a).map_or(false, |t| t == "reload")));
 
+    let route_start = route.tour.start().unwrap().schedule.departure;
+
     let mut leg = intervals.into_iter().fold(Leg::empty(), |leg, (start_idx, end_idx)| {
         let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
             (leg.load.unwrap_or_else(|| MultiDimensionalCapacity::default()), MultiDimensionalCapacity::default()),
@@ -129,6 +193,7 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                     location: None,
                     time: None,
                     job_tag: None,
+                    time_window: None,
                 }],
             });
             (start_idx + 1, start)
@@ -153,13 +218,16 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                 let is_break = activity_type == "break";
 
                 let job_tag = act.job.as_ref().and_then(|job| job.dimens.get_value::<String>("tag").cloned());
-                let job_id = match activity_type.as_str() {
+                let (job_id, time_window) = match activity_type.as_str() {
                     "pickup" | "delivery" | "replacement" | "service" => {
                         let single = act.job.as_ref().unwrap();
                         let id = single.dimens.get_id().cloned();
-                        id.unwrap_or_else(|| Multi::roots(&single).unwrap().dimens.get_id().unwrap().clone())
+                        (
+                            id.unwrap_or_else(|| Multi::roots(&single).unwrap().dimens.get_id().unwrap().clone()),
+                            get_time_window_index(act, route_start),
+                        )
                     }
-                    _ => activity_type.clone(),
+                    _ => (activity_type.clone(), None),
                 };
 
                 let driving =
@@ -201,6 +269,7 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                     location: Some(coord_index.get_by_idx(&act.place.location).unwrap()),
                     time: Some(Interval { start: format_time(arrival), end: format_time(departure) }),
                     job_tag,
+                    time_window,
                 });
 
                 Leg {
@@ -265,37 +334,64 @@ fn calculate_load(
     current - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1
 }
 
+/// Creates a list of jobs which cannot be assigned to any vehicle even alone, within reasons.
+/// It is a fast pre-pass which does not require solving the problem.
+pub fn create_unassignable_jobs(problem: Arc<Problem>) -> Vec<UnassignedJob> {
+    let unassignable = find_unassignable_jobs(problem, Arc::new(DefaultRandom::default()));
+
+    let mut jobs = create_unassigned_jobs(unassignable.iter().map(|(job, code)| (job, code)));
+    jobs.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+
+    jobs
+}
+
 fn create_unassigned(solution: &Solution) -> Vec<UnassignedJob> {
-    solution.unassigned.iter().fold(vec![], |mut acc, unassigned| {
-        let reason = match *unassigned.1 {
-            SKILLS_CONSTRAINT_CODE => (1, "cannot serve required skill"),
-            TIME_CONSTRAINT_CODE => (2, "cannot be visited within time window"),
-            CAPACITY_CONSTRAINT_CODE => (3, "does not fit into any vehicle due to capacity"),
-            REACHABLE_CONSTRAINT_CODE => (100, "location unreachable"),
-            DISTANCE_LIMIT_CONSTRAINT_CODE => (101, "cannot be assigned due to max distance constraint of vehicle"),
-            DURATION_LIMIT_CONSTRAINT_CODE => (102, "cannot be assigned due to shift time constraint of vehicle"),
-            BREAK_CONSTRAINT_CODE => (103, "break is not assignable"),
-            LOCKING_CONSTRAINT_CODE => (104, "cannot be served due to relation lock"),
-            PRIORITY_CONSTRAINT_CODE => (105, "cannot be served due to priority"),
-            AREA_CONSTRAINT_CODE => (106, "cannot be assigned due to area constraint"),
-            _ => (0, "unknown"),
-        };
-        let dimens = match unassigned.0 {
-            Job::Single(job) => &job.dimens,
-            Job::Multi(job) => &job.dimens,
-        };
+    create_unassigned_jobs(solution.unassigned.iter())
+}
+
+fn create_unassigned_jobs<'a>(unassigned: impl Iterator<Item = (&'a Job, &'a i32)>) -> Vec<UnassignedJob> {
+    unassigned.fold(vec![], |mut acc, (job, code)| {
+        let (code, description) = map_code_reason(*code);
         acc.push(UnassignedJob {
-            job_id: dimens
-                .get_value::<String>("vehicle_id")
-                .map(|vehicle_id| format!("{}_break", vehicle_id))
-                .unwrap_or_else(|| dimens.get_id().unwrap().clone()),
-            reasons: vec![UnassignedJobReason { code: reason.0, description: reason.1.to_string() }],
+            job_id: get_unassigned_job_id(job),
+            reasons: vec![UnassignedJobReason { code, description: description.to_string() }],
         });
 
         acc
     })
 }
 
+/// Maps constraint violation code to unassigned reason code and description.
+pub(crate) fn map_code_reason(code: i32) -> (i32, &'static str) {
+    match code {
+        SKILLS_CONSTRAINT_CODE => (1, "cannot serve required skill"),
+        TIME_CONSTRAINT_CODE => (2, "cannot be visited within time window"),
+        CAPACITY_CONSTRAINT_CODE => (3, "does not fit into any vehicle due to capacity"),
+        REACHABLE_CONSTRAINT_CODE => (100, "location unreachable"),
+        DISTANCE_LIMIT_CONSTRAINT_CODE => (101, "cannot be assigned due to max distance constraint of vehicle"),
+        DURATION_LIMIT_CONSTRAINT_CODE => (102, "cannot be assigned due to shift time constraint of vehicle"),
+        BREAK_CONSTRAINT_CODE => (103, "break is not assignable"),
+        LOCKING_CONSTRAINT_CODE => (104, "cannot be served due to relation lock"),
+        PRIORITY_CONSTRAINT_CODE => (105, "cannot be served due to priority"),
+        AREA_CONSTRAINT_CODE => (106, "cannot be assigned due to area constraint"),
+        FORBIDDEN_ARC_CONSTRAINT_CODE => (107, "cannot be served due to forbidden arc"),
+        _ => (0, "unknown"),
+    }
+}
+
+/// Returns job id as it is reported in the list of unassigned jobs.
+pub(crate) fn get_unassigned_job_id(job: &Job) -> String {
+    let dimens = match job {
+        Job::Single(job) => &job.dimens,
+        Job::Multi(job) => &job.dimens,
+    };
+
+    dimens
+        .get_value::<String>("vehicle_id")
+        .map(|vehicle_id| format!("{}_break", vehicle_id))
+        .unwrap_or_else(|| dimens.get_id().unwrap().clone())
+}
+
 fn get_activity_type(activity: &TourActivity) -> Option<&String> {
     activity.job.as_ref().and_then(|single| single.dimens.get_value::<String>("type"))
 }
@@ -337,3 +433,15 @@ fn create_extras(solution: &Solution) -> Option<Extras> {
 
     None
 }
+
+/// Returns index of time window used by the activity when its job place has more than one.
+fn get_time_window_index(activity: &TourActivity, route_start: Timestamp) -> Option<usize> {
+    let single = activity.job.as_ref()?;
+
+    single
+        .places
+        .iter()
+        .filter(|place| place.times.len() > 1)
+        .filter(|place| place.location.map_or(true, |location| location == activity.place.location))
+        .find_map(|place| place.times.iter().position(|time| time.to_time_window(route_start) == activity.place.time))
+}
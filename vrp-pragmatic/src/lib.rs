@@ -27,9 +27,11 @@ pub mod checker;
 pub mod format;
 pub mod validation;
 
+pub use crate::extensions::MultiDimensionalCapacity;
+
 use crate::format::problem::Problem;
 use crate::format::{CoordIndex, Location};
-use chrono::{DateTime, ParseError, SecondsFormat, TimeZone, Utc};
+use chrono::{DateTime, NaiveTime, ParseError, SecondsFormat, TimeZone, Timelike, Utc};
 
 /// Get lists of problem.
 pub fn get_unique_locations(problem: &Problem) -> Vec<Location> {
@@ -47,3 +49,11 @@ fn parse_time(time: &String) -> f64 {
 fn parse_time_safe(time: &String) -> Result<f64, ParseError> {
     DateTime::parse_from_rfc3339(time).map(|time| time.timestamp() as f64)
 }
+
+/// Parses time of day in `HH:MM` or `HH:MM:SS` format to seconds since midnight.
+fn parse_time_of_day(time: &str) -> Option<f64> {
+    NaiveTime::parse_from_str(time, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(time, "%H:%M"))
+        .ok()
+        .map(|time| time.num_seconds_from_midnight() as f64)
+}
@@ -1,8 +1,6 @@
-extern crate rand;
-
-use rand::seq::IteratorRandom;
 use std::collections::HashSet;
 use vrp_core::models::problem::JobPermutation;
+use vrp_core::utils::Random;
 
 #[cfg(test)]
 #[path = "../../tests/unit/utils/permutations_test.rs"]
@@ -22,8 +20,8 @@ impl VariableJobPermutation {
 }
 
 impl JobPermutation for VariableJobPermutation {
-    fn get(&self) -> Vec<Vec<usize>> {
-        get_split_permutations(self.size, self.split_start_index, self.sample_size)
+    fn get(&self, random: &dyn Random) -> Vec<Vec<usize>> {
+        get_split_permutations(self.size, self.split_start_index, self.sample_size, random)
     }
 
     fn validate(&self, permutation: &[usize]) -> bool {
@@ -66,26 +64,37 @@ impl Iterator for Permutations {
     }
 }
 
-fn generate_sample_permutations(start: usize, end: usize, sample_size: usize) -> Vec<Vec<usize>> {
-    let mut rng = rand::thread_rng();
+/// Selects a sample of permutations using reservoir sampling, so all of them have the same chance.
+fn generate_sample_permutations(start: usize, end: usize, sample_size: usize, random: &dyn Random) -> Vec<Vec<usize>> {
+    get_permutations(start, end).enumerate().fold(Vec::with_capacity(sample_size), |mut sample, (idx, permutation)| {
+        if idx < sample_size {
+            sample.push(permutation);
+        } else {
+            let other = random.uniform_int(0, idx as i32) as usize;
+            if other < sample_size {
+                sample[other] = permutation;
+            }
+        }
 
-    get_permutations(start, end)
-        .choose_multiple(&mut rng, sample_size)
-        .iter()
-        .map(|permutation| permutation.iter().map(|i| *i).collect::<Vec<usize>>())
-        .collect()
+        sample
+    })
 }
 
-fn get_split_permutations(size: usize, split_start_index: usize, sample_size: usize) -> Vec<Vec<usize>> {
+fn get_split_permutations(
+    size: usize,
+    split_start_index: usize,
+    sample_size: usize,
+    random: &dyn Random,
+) -> Vec<Vec<usize>> {
     // TODO make it memory efficient somehow
 
     match split_start_index {
-        x if x == 0 || x == size => generate_sample_permutations(0, size - 1, sample_size),
+        x if x == 0 || x == size => generate_sample_permutations(0, size - 1, sample_size, random),
         _ => {
             assert!(size > split_start_index);
 
-            let first = generate_sample_permutations(0, split_start_index - 1, sample_size);
-            let second = generate_sample_permutations(split_start_index, size - 1, sample_size);
+            let first = generate_sample_permutations(0, split_start_index - 1, sample_size, random);
+            let second = generate_sample_permutations(split_start_index, size - 1, sample_size, random);
 
             first
                 .iter()
@@ -186,6 +186,74 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that forbidden arcs refer to existing jobs.
+fn check_e1108_forbidden_arcs_with_unknown_jobs(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let mut ids = ctx
+        .problem
+        .plan
+        .forbidden_arcs
+        .iter()
+        .flat_map(|arcs| arcs.iter())
+        .flat_map(|arc| vec![&arc.from, &arc.to].into_iter())
+        .filter(|id| !ctx.job_index.contains_key(*id))
+        .cloned()
+        .collect::<Vec<_>>();
+    ids.sort();
+    ids.dedup();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "forbidden arc has unknown job id".to_string(),
+            format!("remove forbidden arcs with unknown job ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that planning horizon is correct and each job place with time windows has at least one
+/// time window which intersects it.
+fn check_e1109_time_windows_within_planning_horizon(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let horizon = match ctx.problem.config.as_ref().and_then(|config| config.planning_horizon.as_ref()) {
+        Some(horizon) => horizon,
+        None => return Ok(()),
+    };
+
+    let horizon = match get_time_window_from_vec(horizon).filter(|horizon| horizon.start <= horizon.end) {
+        Some(horizon) => horizon,
+        None => {
+            return Err(FormatError::new(
+                "E1109".to_string(),
+                "invalid planning horizon".to_string(),
+                "change planning horizon to a valid time window".to_string(),
+            ))
+        }
+    };
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job)
+                .iter()
+                .flat_map(|task| task.places.iter())
+                .filter_map(|place| place.times.as_ref())
+                .any(|tws| !get_time_windows(tws).iter().flatten().any(|tw| tw.intersects(&horizon)))
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1109".to_string(),
+            "job time windows outside planning horizon".to_string(),
+            format!("change job time windows or planning horizon, jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     combine_error_results(&[
@@ -197,5 +265,7 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_forbidden_arcs_with_unknown_jobs(ctx),
+        check_e1109_time_windows_within_planning_horizon(ctx),
     ])
 }
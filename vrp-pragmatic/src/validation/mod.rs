@@ -22,12 +22,28 @@ use self::objectives::validate_objectives;
 mod vehicles;
 use self::vehicles::validate_vehicles;
 
+#[cfg(feature = "relations")]
 mod relations;
+#[cfg(feature = "relations")]
 use self::relations::validate_relations;
 
 mod routing;
 use self::routing::validate_profiles;
 
+/// Checks that relations are not used when `relations` feature is disabled.
+#[cfg(not(feature = "relations"))]
+fn validate_relations(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
+    if ctx.problem.plan.relations.as_ref().map_or(true, |relations| relations.is_empty()) {
+        Ok(())
+    } else {
+        Err(vec![FormatError::new(
+            "E1205".to_string(),
+            "relations are not supported".to_string(),
+            "remove relations from the plan or enable 'relations' feature".to_string(),
+        )])
+    }
+}
+
 impl<'a> ValidationContext<'a> {
     /// Creates an instance of `ValidationContext`.
     pub fn new(problem: &'a Problem, matrices: Option<&'a Vec<Matrix>>) -> Self {
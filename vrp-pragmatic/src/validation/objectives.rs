@@ -4,7 +4,7 @@ mod objectives_test;
 
 use super::*;
 use crate::format::problem::Objective::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Checks that objective is not empty when specified.
 fn check_e1600_empty_objective(objectives: &Vec<&Objective>) -> Result<(), FormatError> {
@@ -33,6 +33,7 @@ fn check_e1601_duplicate_objectives(objectives: &Vec<&Objective>) -> Result<(),
                 BalanceActivities { options: _ } => acc.entry("balance-activities"),
                 BalanceDistance { options: _ } => acc.entry("balance-distance"),
                 BalanceDuration { options: _ } => acc.entry("balance-duration"),
+                MinimizePlanDeviation { options: _ } => acc.entry("minimize-plan-deviation"),
             }
             .and_modify(|count| *count += 1)
             .or_insert(1_usize);
@@ -77,6 +78,49 @@ fn check_e1602_no_cost_value_objective(objectives: &Vec<&Objective>) -> Result<(
     }
 }
 
+/// Checks that previous plan of plan deviation objective refers to existing jobs and vehicles.
+fn check_e1603_invalid_previous_plan(ctx: &ValidationContext, objectives: &Vec<&Objective>) -> Result<(), FormatError> {
+    let vehicle_shifts = ctx
+        .vehicles()
+        .flat_map(|vehicle| vehicle.vehicle_ids.iter().map(move |id| (id.clone(), vehicle.shifts.len())))
+        .collect::<HashMap<_, _>>();
+
+    let mut used_jobs = HashSet::new();
+    let mut ids = objectives
+        .iter()
+        .filter_map(|objective| match objective {
+            MinimizePlanDeviation { options } => Some(options.previous.iter()),
+            _ => None,
+        })
+        .flatten()
+        .flat_map(|tour| {
+            let is_known_vehicle =
+                vehicle_shifts.get(&tour.vehicle_id).map_or(false, |shifts| tour.shift_index.unwrap_or(0) < *shifts);
+
+            Some(tour.vehicle_id.clone())
+                .filter(|_| !is_known_vehicle)
+                .into_iter()
+                .chain(
+                    tour.jobs.iter().filter(|id| !ctx.job_index.contains_key(*id) || !used_jobs.insert(*id)).cloned(),
+                )
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    ids.sort();
+    ids.dedup();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1603".to_string(),
+            "invalid previous plan in plan deviation objective".to_string(),
+            format!("remove unknown or duplicate job and vehicle ids from previous plan: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| {
         Some(&objectives.primary)
@@ -93,6 +137,7 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), Vec<FormatErro
             check_e1600_empty_objective(&objectives),
             check_e1601_duplicate_objectives(&objectives),
             check_e1602_no_cost_value_objective(&objectives),
+            check_e1603_invalid_previous_plan(ctx, &objectives),
         ])
     } else {
         Ok(())
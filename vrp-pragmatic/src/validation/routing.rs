@@ -3,6 +3,9 @@
 mod routing_test;
 
 use super::*;
+use crate::format::CoordIndex;
+use crate::parse_time_of_day;
+use std::cmp::Ordering::Less;
 
 /// Checks that no duplicated profile names specified.
 fn check_e1500_duplicated_profiles(ctx: &ValidationContext) -> Result<(), FormatError> {
@@ -28,7 +31,155 @@ fn check_e1501_empty_profiles(ctx: &ValidationContext) -> Result<(), FormatError
     }
 }
 
+/// Checks that routing overrides are correct.
+fn check_e1502_invalid_routing_overrides(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let coord_index = CoordIndex::new(ctx.problem);
+    let names = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| {
+            profile.overrides.as_ref().map_or(false, |overrides| {
+                overrides.iter().any(|item| {
+                    coord_index.get_by_loc(&item.from).is_none()
+                        || coord_index.get_by_loc(&item.to).is_none()
+                        || (item.duration.is_none() && item.distance.is_none())
+                        || item.duration.map_or(false, |duration| duration < 0.)
+                        || item.distance.map_or(false, |distance| distance < 0.)
+                })
+            })
+        })
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if names.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1502".to_string(),
+            "invalid routing overrides".to_string(),
+            format!(
+                "ensure that overrides use problem locations and have non-negative duration or distance, \
+                 profile names: '{}'",
+                names.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that profile and default routing speeds are positive.
+fn check_e1503_invalid_speed(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let is_invalid = |speed: f64| !speed.is_finite() || speed <= 0.;
+
+    let default_speed =
+        ctx.problem.config.as_ref().and_then(|config| config.routing.as_ref()).and_then(|r| r.default_speed);
+    let names = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| profile.speed.map_or(false, is_invalid))
+        .map(|profile| profile.name.clone())
+        .chain(default_speed.filter(|&speed| is_invalid(speed)).map(|_| "config.routing.defaultSpeed".to_string()))
+        .collect::<Vec<_>>();
+
+    if names.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1503".to_string(),
+            "invalid routing speed".to_string(),
+            format!("ensure that speed is a positive number, check: '{}'", names.join(", ")),
+        ))
+    }
+}
+
+/// Checks that travel time multipliers are correct.
+fn check_e1504_invalid_travel_time_multipliers(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let names = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| {
+            profile.multipliers.as_ref().map_or(false, |multipliers| {
+                let bands = multipliers
+                    .iter()
+                    .map(|item| match (parse_time_of_day(&item.start), parse_time_of_day(&item.end)) {
+                        (Some(start), Some(end)) if start < end && item.factor.is_finite() && item.factor > 0. => {
+                            Some((start, end))
+                        }
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>();
+
+                // NOTE band end is exclusive, so adjacent bands are allowed
+                multipliers.is_empty()
+                    || bands.map_or(true, |mut bands| {
+                        bands.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Less));
+                        bands.windows(2).any(|pair| pair[0].1 > pair[1].0)
+                    })
+            })
+        })
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if names.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1504".to_string(),
+            "invalid travel time multipliers".to_string(),
+            format!(
+                "ensure that multipliers have valid non-overlapping time of day bands and positive factor, \
+                 profile names: '{}'",
+                names.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that straight line routing settings are correct.
+fn check_e1505_invalid_straight_line_routing(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let names = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| {
+            profile.straight_line.as_ref().map_or(false, |routing| {
+                !routing.speed.is_finite()
+                    || routing.speed <= 0.
+                    || routing.fixed_time.map_or(false, |time| !time.is_finite() || time < 0.)
+            })
+        })
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if names.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1505".to_string(),
+            "invalid straight line routing".to_string(),
+            format!(
+                "ensure that straight line routing has positive speed and non-negative fixed time, \
+                 profile names: '{}'",
+                names.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates profiles from the fleet.
 pub fn validate_profiles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
-    combine_error_results(&[check_e1500_duplicated_profiles(ctx), check_e1501_empty_profiles(ctx)])
+    combine_error_results(&[
+        check_e1500_duplicated_profiles(ctx),
+        check_e1501_empty_profiles(ctx),
+        check_e1502_invalid_routing_overrides(ctx),
+        check_e1503_invalid_speed(ctx),
+        check_e1504_invalid_travel_time_multipliers(ctx),
+        check_e1505_invalid_straight_line_routing(ctx),
+    ])
 }
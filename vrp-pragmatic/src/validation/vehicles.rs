@@ -3,7 +3,7 @@
 mod vehicles_test;
 
 use super::*;
-use crate::validation::common::get_time_windows;
+use crate::validation::common::{get_time_window_from_vec, get_time_windows};
 use std::ops::Deref;
 use vrp_core::models::common::TimeWindow;
 
@@ -162,6 +162,147 @@ fn check_e1305_vehicle_limit_area_is_correct(ctx: &ValidationContext) -> Result<
     }
 }
 
+/// Checks that depot gate hours are correct.
+fn check_e1306_vehicle_depot_gate_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|shift, shift_time| {
+            let tws = std::iter::once(&shift.start)
+                .chain(shift.end.iter())
+                .filter_map(|place| place.gate.as_ref())
+                .map(|gate| get_time_window_from_vec(gate))
+                .collect::<Vec<_>>();
+
+            check_shift_time_windows(shift_time, tws, true)
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1306".to_string(),
+            "invalid depot gate time windows in vehicle shift".to_string(),
+            format!("ensure that depot gate conform rules, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle unavailability time windows are correct, intersect vehicle shifts and
+/// planning horizon, if it is specified.
+fn check_e1307_vehicle_unavailability_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let horizon = ctx
+        .problem
+        .config
+        .as_ref()
+        .and_then(|config| config.planning_horizon.as_ref())
+        .and_then(get_time_window_from_vec);
+
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.unavailable.as_ref().map_or(false, |unavailable| {
+                let tws = get_time_windows(unavailable);
+                let shift_times = vehicle.shifts.iter().filter_map(get_shift_time_window).collect::<Vec<_>>();
+
+                unavailable.is_empty()
+                    || !check_time_windows(&tws, true)
+                    || tws.iter().flatten().any(|tw| !shift_times.iter().any(|shift_time| tw.intersects(shift_time)))
+                    || tws.iter().flatten().any(|tw| horizon.as_ref().map_or(false, |horizon| !tw.intersects(horizon)))
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1307".to_string(),
+            "invalid vehicle unavailability time windows".to_string(),
+            format!(
+                "ensure that unavailable time windows conform rules and intersect vehicle shifts and planning horizon, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle positions are correct.
+fn check_e1308_vehicle_positions_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.positions.as_ref().map_or(false, |positions| {
+                positions.is_empty()
+                    || get_duplicates(positions.iter().map(|position| &position.vehicle_id)).is_some()
+                    || positions.iter().any(|position| {
+                        !vehicle.vehicle_ids.contains(&position.vehicle_id)
+                            || get_time_window(&position.time, &position.time).is_none()
+                    })
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1308".to_string(),
+            "invalid vehicle positions".to_string(),
+            format!(
+                "ensure that each position refers to a vehicle of its type once and has valid time, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle breaks are not used when `breaks` feature is disabled.
+fn check_e1309_vehicle_breaks_feature_is_enabled(ctx: &ValidationContext) -> Result<(), FormatError> {
+    check_shift_feature(ctx, cfg!(feature = "breaks"), |shift| shift.breaks.is_some()).map_or(Ok(()), |type_ids| {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "vehicle breaks are not supported".to_string(),
+            format!("remove breaks or enable 'breaks' feature, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    })
+}
+
+/// Checks that vehicle reloads are not used when `reloads` feature is disabled.
+fn check_e1310_vehicle_reloads_feature_is_enabled(ctx: &ValidationContext) -> Result<(), FormatError> {
+    check_shift_feature(ctx, cfg!(feature = "reloads"), |shift| shift.reloads.is_some()).map_or(Ok(()), |type_ids| {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "vehicle reloads are not supported".to_string(),
+            format!("remove reloads or enable 'reloads' feature, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    })
+}
+
+fn check_shift_feature(
+    ctx: &ValidationContext,
+    is_enabled: bool,
+    is_used: impl Fn(&VehicleShift) -> bool,
+) -> Option<Vec<String>> {
+    if is_enabled {
+        return None;
+    }
+
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.shifts.iter().any(|shift| is_used(shift)))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        None
+    } else {
+        Some(type_ids)
+    }
+}
+
 fn get_invalid_type_ids(
     ctx: &ValidationContext,
     check_shift: Box<dyn Fn(&VehicleShift, Option<TimeWindow>) -> bool>,
@@ -208,5 +349,10 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>
         check_e1303_vehicle_breaks_time_is_correct(ctx),
         check_e1304_vehicle_reload_time_is_correct(ctx),
         check_e1305_vehicle_limit_area_is_correct(ctx),
+        check_e1306_vehicle_depot_gate_is_correct(ctx),
+        check_e1307_vehicle_unavailability_is_correct(ctx),
+        check_e1308_vehicle_positions_are_correct(ctx),
+        check_e1309_vehicle_breaks_feature_is_enabled(ctx),
+        check_e1310_vehicle_reloads_feature_is_enabled(ctx),
     ])
 }
@@ -9,6 +9,7 @@ fn can_assign_break_between_jobs() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
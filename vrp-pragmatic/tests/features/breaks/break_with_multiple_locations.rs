@@ -9,12 +9,17 @@ fn can_assign_break_using_second_location() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![10., 0.]), create_delivery_job("job2", vec![20., 0.])],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 costs: create_default_vehicle_costs(),
                 shifts: vec![VehicleShift {
-                    end: Some(VehiclePlace { time: format_time(1000.).to_string(), location: vec![30., 0.].to_loc() }),
+                    end: Some(VehiclePlace {
+                        time: format_time(1000.).to_string(),
+                        location: vec![30., 0.].to_loc(),
+                        gate: None,
+                    }),
                     breaks: Some(vec![VehicleBreak {
                         time: VehicleBreakTime::TimeWindow(vec![format_time(10.), format_time(30.)]),
                         duration: 2.0,
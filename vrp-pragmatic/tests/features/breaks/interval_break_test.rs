@@ -9,6 +9,7 @@ fn can_assign_interval_break_between_jobs() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![15., 0.])],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -70,6 +71,7 @@ fn can_assign_interval_break_between_jobs() {
                                     end: "1970-01-01T00:00:06Z".to_string(),
                                 }),
                                 job_tag: None,
+                                time_window: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -80,6 +82,7 @@ fn can_assign_interval_break_between_jobs() {
                                     end: "1970-01-01T00:00:08Z".to_string(),
                                 }),
                                 job_tag: None,
+                                time_window: None,
                             }
                         ],
                     },
@@ -124,12 +127,17 @@ fn can_assign_interval_break_with_reload() {
                 create_delivery_job("job4", vec![25., 0.]),
             ],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
-                    end: Some(VehiclePlace { time: format_time(1000.).to_string(), location: vec![30., 0.].to_loc() }),
+                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc(), gate: None },
+                    end: Some(VehiclePlace {
+                        time: format_time(1000.).to_string(),
+                        location: vec![30., 0.].to_loc(),
+                        gate: None,
+                    }),
                     breaks: Some(vec![VehicleBreak {
                         time: VehicleBreakTime::TimeOffset(vec![8., 12.]),
                         duration: 2.0,
@@ -193,6 +201,7 @@ fn can_assign_interval_break_with_reload() {
                                     end: "1970-01-01T00:00:11Z".to_string(),
                                 }),
                                 job_tag: None,
+                                time_window: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -203,6 +212,7 @@ fn can_assign_interval_break_with_reload() {
                                     end: "1970-01-01T00:00:13Z".to_string(),
                                 }),
                                 job_tag: None,
+                                time_window: None,
                             }
                         ],
                     },
@@ -9,6 +9,7 @@ fn can_use_two_breaks() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![99., 0.])],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -93,6 +94,7 @@ fn can_use_two_breaks() {
                                     end: "1970-01-01T00:01:43Z".to_string(),
                                 }),
                                 job_tag: None,
+                                time_window: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -103,6 +105,7 @@ fn can_use_two_breaks() {
                                     end: "1970-01-01T00:01:45Z".to_string(),
                                 }),
                                 job_tag: None,
+                                time_window: None,
                             }
                         ],
                     },
@@ -13,6 +13,7 @@ fn get_solution(relation_type: RelationType, jobs: Vec<String>) -> Solution {
                 vehicle_id: "my_vehicle_1".to_string(),
                 shift_index: None,
             }]),
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -9,15 +9,17 @@ fn can_skip_break_when_vehicle_not_used() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![
                 VehicleType {
                     shifts: vec![VehicleShift {
-                        start: VehiclePlace { time: format_time(0.), location: vec![100., 0.].to_loc() },
+                        start: VehiclePlace { time: format_time(0.), location: vec![100., 0.].to_loc(), gate: None },
                         end: Some(VehiclePlace {
                             time: format_time(1000.).to_string(),
                             location: vec![100., 0.].to_loc(),
+                            gate: None,
                         }),
                         breaks: Some(vec![VehicleBreak {
                             time: VehicleBreakTime::TimeWindow(vec![format_time(5.), format_time(8.)]),
@@ -104,6 +106,7 @@ fn can_skip_break_when_jobs_completed() {
         plan: Plan {
             jobs: vec![create_delivery_job_with_duration("job1", vec![1., 0.], 10.)],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -189,6 +192,7 @@ fn can_skip_second_break_when_jobs_completed() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -12,20 +12,26 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                 create_delivery_job_with_times("job2", vec![10., 0.], vec![(100, 200)], 1.),
             ],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![
                     VehicleShift {
-                        start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
-                        end: Some(VehiclePlace { time: format_time(99.).to_string(), location: vec![0., 0.].to_loc() }),
+                        start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc(), gate: None },
+                        end: Some(VehiclePlace {
+                            time: format_time(99.).to_string(),
+                            location: vec![0., 0.].to_loc(),
+                            gate: None,
+                        }),
                         ..create_default_vehicle_shift()
                     },
                     VehicleShift {
-                        start: VehiclePlace { time: format_time(100.), location: vec![0., 0.].to_loc() },
+                        start: VehiclePlace { time: format_time(100.), location: vec![0., 0.].to_loc(), gate: None },
                         end: Some(VehiclePlace {
                             time: format_time(200.).to_string(),
                             location: vec![0., 0.].to_loc(),
+                            gate: None,
                         }),
                         ..create_default_vehicle_shift()
                     },
@@ -0,0 +1,123 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_shift_with_gates(start_gate: Option<(f64, f64)>, end_gate: Option<(f64, f64)>) -> VehicleShift {
+    let to_gate = |gate: Option<(f64, f64)>| gate.map(|(start, end)| vec![format_time(start), format_time(end)]);
+    let shift = create_default_vehicle_shift();
+
+    VehicleShift {
+        start: VehiclePlace { gate: to_gate(start_gate), ..shift.start.clone() },
+        end: shift.end.clone().map(|end| VehiclePlace { gate: to_gate(end_gate), ..end }),
+        ..shift
+    }
+}
+
+#[test]
+fn can_depart_when_depot_gate_is_opened() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.])],
+            relations: Option::None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_vehicle_shift_with_gates(Some((10., 100.)), None)],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 15.,
+                distance: 2,
+                duration: 3,
+                times: Timing { driving: 2, serving: 1, waiting: 0, break_time: 0 },
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:10Z", "1970-01-01T00:00:10Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (1., 0.),
+                        0,
+                        ("1970-01-01T00:00:11Z", "1970-01-01T00:00:12Z"),
+                        1
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:13Z", "1970-01-01T00:00:13Z"),
+                        2
+                    )
+                ],
+                statistic: Statistic {
+                    cost: 15.,
+                    distance: 2,
+                    duration: 3,
+                    times: Timing { driving: 2, serving: 1, waiting: 0, break_time: 0 },
+                },
+            }],
+            unassigned: vec![],
+            extras: None,
+        }
+    );
+}
+
+#[test]
+fn can_skip_job_when_depot_gate_is_closed_before_return() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![10., 0.])],
+            relations: Option::None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_vehicle_shift_with_gates(None, Some((0., 10.)))],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours.first().unwrap().stops.last().unwrap().time.arrival, "1970-01-01T00:00:03Z");
+    assert_eq!(
+        solution.unassigned,
+        vec![UnassignedJob {
+            job_id: "job2".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: 2,
+                description: "cannot be visited within time window".to_string()
+            }]
+        }]
+    );
+}
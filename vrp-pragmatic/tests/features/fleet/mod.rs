@@ -1,4 +1,10 @@
 mod basic_multi_shift;
 mod basic_open_end;
+mod depot_gate;
 mod multi_dimens;
+mod positions;
+mod routing_override;
+mod straight_line_profile;
+mod travel_time_multipliers;
+mod unavailability;
 mod unreachable_jobs;
@@ -11,6 +11,7 @@ fn can_use_two_dimensions() {
                 create_delivery_job_with_demand("job2", vec![2., 0.], vec![1, 0]),
             ],
             relations: None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -81,7 +82,11 @@ fn can_use_two_dimensions() {
 #[test]
 fn can_unassign_due_to_dimension_mismatch() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![0, 1])], relations: None },
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![0, 1])],
+            relations: None,
+            forbidden_arcs: None,
+        },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
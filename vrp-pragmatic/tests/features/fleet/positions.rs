@@ -0,0 +1,76 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_start_tour_from_vehicle_position() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![12., 0.]), create_delivery_job("job2", vec![3., 0.])],
+            relations: Option::None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                positions: Some(vec![
+                    VehiclePosition {
+                        vehicle_id: "my_vehicle_1".to_string(),
+                        location: vec![10., 0.].to_loc(),
+                        time: format_time(100.),
+                    },
+                    VehiclePosition {
+                        vehicle_id: "my_vehicle_2".to_string(),
+                        location: vec![5., 0.].to_loc(),
+                        time: format_time(2000.),
+                    },
+                ]),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.vehicle_id, "my_vehicle_1");
+    let departure = tour.stops.first().unwrap();
+    assert_eq!(departure.location, vec![10., 0.].to_loc());
+    assert_eq!(departure.time.departure, format_time(100.));
+    assert_eq!(tour.stops.last().unwrap().location, vec![0., 0.].to_loc());
+}
+
+#[test]
+fn can_keep_vehicle_with_finished_shift() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![3., 0.])],
+            relations: Option::None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                positions: Some(vec![VehiclePosition {
+                    vehicle_id: "my_vehicle_1".to_string(),
+                    location: vec![5., 0.].to_loc(),
+                    time: format_time(2000.),
+                }]),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(solution.unassigned.len(), 1);
+    assert_eq!(solution.unassigned.first().unwrap().job_id, "job1");
+}
@@ -0,0 +1,81 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_use_routing_override_for_specific_pair() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.])],
+            relations: Option::None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: vec![Profile {
+                overrides: Some(vec![RoutingOverride {
+                    from: vec![0., 0.].to_loc(),
+                    to: vec![1., 0.].to_loc(),
+                    duration: Some(5.),
+                    distance: Some(3.),
+                }]),
+                ..create_default_profiles().first().unwrap().clone()
+            }],
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 21.,
+                distance: 4,
+                duration: 7,
+                times: Timing { driving: 6, serving: 1, waiting: 0, break_time: 0 },
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (1., 0.),
+                        0,
+                        ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
+                        3
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:07Z", "1970-01-01T00:00:07Z"),
+                        4
+                    )
+                ],
+                statistic: Statistic {
+                    cost: 21.,
+                    distance: 4,
+                    duration: 7,
+                    times: Timing { driving: 6, serving: 1, waiting: 0, break_time: 0 },
+                },
+            }],
+            unassigned: vec![],
+            extras: None,
+        }
+    );
+}
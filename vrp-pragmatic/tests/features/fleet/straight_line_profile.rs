@@ -0,0 +1,51 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_mix_matrix_and_straight_line_profiles() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_skills("job1", vec![0., 10.], vec!["truck".to_string()]),
+                create_delivery_job_with_skills("job2", vec![0.001, 0.], vec!["drone".to_string()]),
+            ],
+            relations: Option::None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType { skills: Some(vec!["truck".to_string()]), ..create_default_vehicle("truck") },
+                VehicleType {
+                    profile: "drone".to_string(),
+                    skills: Some(vec!["drone".to_string()]),
+                    ..create_default_vehicle("drone")
+                },
+            ],
+            profiles: vec![
+                create_default_profiles().first().unwrap().clone(),
+                Profile {
+                    name: "drone".to_string(),
+                    profile_type: "drone".to_string(),
+                    straight_line: Some(StraightLineRouting { speed: 10., fixed_time: Some(5.) }),
+                    ..create_default_profiles().first().unwrap().clone()
+                },
+            ],
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    let get_tour = |vehicle_id: &str| solution.tours.iter().find(|tour| tour.vehicle_id == vehicle_id).unwrap();
+
+    let truck = get_tour("truck_1");
+    assert_eq!(truck.stops[1].time.arrival, "1970-01-01T00:00:10Z");
+    assert_eq!(truck.stops[1].distance, 10);
+
+    // NOTE 0.001 degree of latitude is about 111 meters which takes 11 seconds plus 5 seconds of fixed time
+    let drone = get_tour("drone_1");
+    assert_eq!(drone.stops[1].time.arrival, "1970-01-01T00:00:16Z");
+    assert_eq!(drone.stops[1].distance, 111);
+}
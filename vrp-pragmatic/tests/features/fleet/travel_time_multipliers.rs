@@ -0,0 +1,34 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_apply_travel_time_multiplier_by_time_of_day() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![10., 0.])],
+            relations: Option::None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: vec![Profile {
+                multipliers: Some(vec![TravelTimeMultiplier {
+                    start: "00:00".to_string(),
+                    end: "00:00:15".to_string(),
+                    factor: 2.,
+                }]),
+                ..create_default_profiles().first().unwrap().clone()
+            }],
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.stops[1].time.arrival, "1970-01-01T00:00:20Z");
+    assert_eq!(tour.stops[2].time.arrival, "1970-01-01T00:00:31Z");
+    assert_eq!(solution.statistic.times.driving, 30);
+    assert_eq!(solution.statistic.distance, 20);
+}
@@ -0,0 +1,95 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_serve_jobs_around_vehicle_unavailability() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![5., 0.], vec![(0, 50)], 1.),
+                create_delivery_job_with_times("job2", vec![5., 0.], vec![(300, 400)], 1.),
+                create_delivery_job_with_times("job3", vec![5., 0.], vec![(120, 180)], 1.),
+            ],
+            relations: Option::None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                unavailable: Some(vec![vec![format_time(100.), format_time(200.)]]),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let shift_indices = solution.tours.iter().map(|tour| tour.shift_index).collect::<Vec<_>>();
+    assert_eq!(shift_indices, vec![0, 0]);
+    assert!(solution.tours.iter().all(|tour| tour.stops.len() == 3));
+    assert_eq!(solution.unassigned.len(), 1);
+    assert_eq!(solution.unassigned.first().unwrap().job_id, "job3");
+}
+
+#[test]
+fn can_keep_original_shift_indices_when_shift_is_removed() {
+    let shift = create_default_vehicle_shift();
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", vec![5., 0.], vec![(2000, 3000)], 1.)],
+            #[cfg(feature = "relations")]
+            relations: Some(vec![Relation {
+                type_field: RelationType::Any,
+                jobs: vec!["job1".to_string()],
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: Some(1),
+            }]),
+            #[cfg(not(feature = "relations"))]
+            relations: None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![
+                    shift.clone(),
+                    VehicleShift {
+                        start: VehiclePlace { time: format_time(2000.), ..shift.start.clone() },
+                        end: shift.end.clone().map(|end| VehiclePlace { time: format_time(3000.), ..end }),
+                        ..shift
+                    },
+                ],
+                unavailable: Some(vec![vec![format_time(0.), format_time(1000.)]]),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![
+                MinimizeUnassignedJobs,
+                MinimizePlanDeviation {
+                    options: PlanStabilityOptions {
+                        previous: vec![PreviousTour {
+                            vehicle_id: "my_vehicle_1".to_string(),
+                            shift_index: Some(1),
+                            jobs: vec!["job1".to_string()],
+                        }],
+                        weight: Some(100.),
+                    },
+                },
+            ],
+            secondary: Some(vec![MinimizeCost]),
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].shift_index, 1);
+}
@@ -6,7 +6,11 @@ use crate::helpers::*;
 #[test]
 fn can_limit_by_area() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job("job1", vec![10., 0.])], relations: Option::None },
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![10., 0.])],
+            relations: Option::None,
+            forbidden_arcs: None,
+        },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 limits: Some(VehicleLimits {
@@ -5,7 +5,11 @@ use crate::helpers::*;
 #[test]
 fn can_limit_by_max_distance() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job("job1", vec![100., 0.])], relations: Option::None },
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![100., 0.])],
+            relations: Option::None,
+            forbidden_arcs: None,
+        },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 limits: Some(VehicleLimits { max_distance: Some(99.), shift_time: None, allowed_areas: None }),
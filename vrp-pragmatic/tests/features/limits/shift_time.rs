@@ -5,7 +5,11 @@ use crate::helpers::*;
 #[test]
 fn can_limit_one_job_by_shift_time() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job("job1", vec![100., 0.])], relations: Option::None },
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![100., 0.])],
+            relations: Option::None,
+            forbidden_arcs: None,
+        },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 limits: Some(VehicleLimits { max_distance: None, shift_time: Some(99.), allowed_areas: None }),
@@ -59,6 +63,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                 create_delivery_job_with_duration("job5", vec![5., 0.], 10.),
             ],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
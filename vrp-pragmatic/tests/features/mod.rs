@@ -1,12 +1,17 @@
 //! This module contains feature tests: minimalistic tests which check features in isolation and combination.
 
+#[cfg(feature = "breaks")]
 mod breaks;
 mod fleet;
 mod limits;
 mod multjob;
+mod objectives;
 mod pickdev;
+mod positions;
 mod priorities;
+#[cfg(feature = "relations")]
 mod relations;
+#[cfg(feature = "reloads")]
 mod reload;
 mod skills;
 mod timing;
@@ -15,6 +15,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                 ),
             ],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
@@ -115,6 +116,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                 vec![((6., 0.), 1., vec![2])],
             )],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
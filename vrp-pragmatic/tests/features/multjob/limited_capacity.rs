@@ -19,6 +19,7 @@ fn can_handle_limited_capacity() {
                 ),
             ],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
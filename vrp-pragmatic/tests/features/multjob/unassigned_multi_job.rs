@@ -12,6 +12,7 @@ fn can_unassign_multi_job_due_to_capacity() {
                 vec![((6., 0.), 1., vec![3])],
             )],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
@@ -0,0 +1 @@
+mod plan_stability;
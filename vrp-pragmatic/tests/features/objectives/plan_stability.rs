@@ -0,0 +1,71 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_previous_tour(vehicle_id: &str, jobs: Vec<&str>) -> PreviousTour {
+    PreviousTour {
+        vehicle_id: vehicle_id.to_string(),
+        shift_index: None,
+        jobs: jobs.into_iter().map(|job| job.to_string()).collect(),
+    }
+}
+
+#[test]
+fn can_keep_jobs_on_previous_vehicles() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![9., 0.])],
+            relations: None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType { vehicle_ids: vec!["my_vehicle1".to_string()], ..create_default_vehicle_type() },
+                VehicleType {
+                    type_id: "my_vehicle2".to_string(),
+                    vehicle_ids: vec!["my_vehicle2".to_string()],
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    ..create_default_vehicle_type()
+                },
+            ],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![
+                MinimizeUnassignedJobs,
+                MinimizePlanDeviation {
+                    options: PlanStabilityOptions {
+                        previous: vec![
+                            create_previous_tour("my_vehicle1", vec!["job2"]),
+                            create_previous_tour("my_vehicle2", vec!["job1"]),
+                        ],
+                        weight: Some(100.),
+                    },
+                },
+            ],
+            secondary: Some(vec![MinimizeCost]),
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    let mut assignments = solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities.iter())
+                .filter(|activity| activity.activity_type == "delivery")
+                .map(move |activity| (tour.vehicle_id.clone(), activity.job_id.clone()))
+        })
+        .collect::<Vec<_>>();
+    assignments.sort();
+    assert_eq!(
+        assignments,
+        vec![("my_vehicle1".to_string(), "job2".to_string()), ("my_vehicle2".to_string(), "job1".to_string())]
+    );
+}
@@ -0,0 +1,65 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_problem(positions: Vec<(&str, JobPosition)>) -> Problem {
+    let jobs = vec![
+        create_delivery_job("job1", vec![2., 0.]),
+        create_delivery_job("job2", vec![5., 0.]),
+        create_delivery_job("job3", vec![8., 0.]),
+    ];
+
+    Problem {
+        plan: Plan {
+            jobs: jobs
+                .into_iter()
+                .map(|job| {
+                    let position = positions.iter().find(|(id, _)| *id == job.id).map(|(_, position)| *position);
+                    Job { position, ..job }
+                })
+                .collect(),
+            relations: None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_vehicle_shift_with_locations((0., 0.), (10., 0.))],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_job_ids(solution: &Solution) -> Vec<String> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.clone())
+        .collect()
+}
+
+parameterized_test! {can_follow_position_hints, (positions, expected), {
+    can_follow_position_hints_impl(positions, expected);
+}}
+
+can_follow_position_hints! {
+    case01_no_hints: (vec![], vec!["job1", "job2", "job3"]),
+    case02_first: (vec![("job3", JobPosition::First)], vec!["job3", "job1", "job2"]),
+    case03_last: (vec![("job2", JobPosition::Last)], vec!["job1", "job3", "job2"]),
+    case04_first_and_last: (vec![("job3", JobPosition::First), ("job1", JobPosition::Last)], vec!["job3", "job2", "job1"]),
+    case05_any: (vec![("job3", JobPosition::Any)], vec!["job1", "job2", "job3"]),
+}
+
+fn can_follow_position_hints_impl(positions: Vec<(&str, JobPosition)>, expected: Vec<&str>) {
+    let problem = create_problem(positions);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert_eq!(get_job_ids(&solution), expected);
+}
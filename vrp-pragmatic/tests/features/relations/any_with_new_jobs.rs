@@ -17,6 +17,7 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                 vehicle_id: "my_vehicle_1".to_string(),
                 shift_index: None,
             }]),
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
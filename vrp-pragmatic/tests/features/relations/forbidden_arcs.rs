@@ -0,0 +1,105 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_forbidden_arc(from: &str, to: &str) -> ForbiddenArc {
+    ForbiddenArc { from: from.to_string(), to: to.to_string() }
+}
+
+fn assert_no_forbidden_arcs(solution: &Solution, arcs: &[ForbiddenArc]) {
+    solution.tours.iter().for_each(|tour| {
+        let job_ids = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities.iter())
+            .map(|activity| activity.job_id.as_str())
+            .collect::<Vec<_>>();
+
+        job_ids.windows(2).for_each(|pair| {
+            assert!(
+                !arcs.iter().any(|arc| arc.from == pair[0] && arc.to == pair[1]),
+                "forbidden arc from '{}' to '{}' is used",
+                pair[0],
+                pair[1]
+            );
+        });
+    });
+}
+
+#[test]
+fn can_avoid_forbidden_arc_between_jobs() {
+    let arcs = vec![create_forbidden_arc("job1", "job2"), create_forbidden_arc("job2", "job1")];
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+            ],
+            relations: None,
+            forbidden_arcs: Some(arcs.clone()),
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    assert_no_forbidden_arcs(&solution, &arcs);
+}
+
+#[test]
+fn can_avoid_forbidden_arcs_between_neighbour_jobs() {
+    let size = 8;
+    let arcs = (1..size)
+        .flat_map(|idx| {
+            let (from, to) = (format!("job{}", idx), format!("job{}", idx + 1));
+            vec![create_forbidden_arc(&from, &to), create_forbidden_arc(&to, &from)]
+        })
+        .collect::<Vec<_>>();
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=size).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64, 0.])).collect(),
+            relations: None,
+            forbidden_arcs: Some(arcs.clone()),
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_iterations(problem, Some(vec![matrix]), 200);
+
+    assert!(solution.unassigned.is_empty());
+    assert_no_forbidden_arcs(&solution, &arcs);
+}
+
+#[test]
+fn can_skip_job_when_only_forbidden_arcs_are_left() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+            relations: None,
+            forbidden_arcs: Some(vec![create_forbidden_arc("job1", "job2"), create_forbidden_arc("job2", "job1")]),
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution.unassigned,
+        vec![UnassignedJob {
+            job_id: "job2".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: 107,
+                description: "cannot be served due to forbidden arc".to_string()
+            }]
+        }]
+    );
+}
@@ -29,6 +29,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                     shift_index: None,
                 },
             ]),
+            forbidden_arcs: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -177,6 +178,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                     shift_index: None,
                 },
             ]),
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -1,4 +1,5 @@
 mod any_with_new_jobs;
+mod forbidden_arcs;
 mod mixed_strict_any;
 mod mixed_strict_sequence;
 mod strict_with_new_jobs;
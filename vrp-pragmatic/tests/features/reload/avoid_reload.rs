@@ -16,12 +16,17 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                 ),
             ],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
-                    end: Some(VehiclePlace { time: format_time(100.).to_string(), location: vec![0., 0.].to_loc() }),
+                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc(), gate: None },
+                    end: Some(VehiclePlace {
+                        time: format_time(100.).to_string(),
+                        location: vec![0., 0.].to_loc(),
+                        gate: None,
+                    }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
@@ -12,12 +12,17 @@ fn can_use_multi_dim_capacity() {
                 create_delivery_job_with_demand("job2", vec![2., 0.], vec![1, 1]),
             ],
             relations: None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
-                    end: Some(VehiclePlace { time: format_time(100.).to_string(), location: vec![0., 0.].to_loc() }),
+                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc(), gate: None },
+                    end: Some(VehiclePlace {
+                        time: format_time(100.).to_string(),
+                        location: vec![0., 0.].to_loc(),
+                        gate: None,
+                    }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
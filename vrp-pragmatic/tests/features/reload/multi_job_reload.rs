@@ -19,12 +19,17 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                 ),
             ],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
-                    end: Some(VehiclePlace { time: format_time(100.).to_string(), location: vec![10., 0.].to_loc() }),
+                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc(), gate: None },
+                    end: Some(VehiclePlace {
+                        time: format_time(100.).to_string(),
+                        location: vec![10., 0.].to_loc(),
+                        gate: None,
+                    }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
@@ -161,6 +166,7 @@ fn can_properly_handle_load_without_capacity_violation() {
                 create_pickup_job_with_demand("job2", vec![67., 0.], vec![2]),
             ],
             relations: None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
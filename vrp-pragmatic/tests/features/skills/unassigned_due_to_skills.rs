@@ -8,6 +8,7 @@ fn can_have_unassigned_due_to_missing_vehicle_skill() {
         plan: Plan {
             jobs: vec![create_delivery_job_with_skills("job1", vec![1., 0.], vec!["unique_skill".to_string()])],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("vehicle_without_skill")],
@@ -37,3 +38,43 @@ fn can_have_unassigned_due_to_missing_vehicle_skill() {
         }
     );
 }
+
+#[test]
+fn can_find_unassignable_jobs_without_solving() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_skills("job1", vec![1., 0.], vec!["unique_skill".to_string()]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job_with_demand("job3", vec![3., 0.], vec![100]),
+            ],
+            relations: Option::None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("vehicle_without_skill")],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let unassignable = create_unassignable_jobs(get_core_problem(problem, Some(vec![matrix])));
+
+    assert_eq!(
+        unassignable,
+        vec![
+            UnassignedJob {
+                job_id: "job1".to_string(),
+                reasons: vec![UnassignedJobReason { code: 1, description: "cannot serve required skill".to_string() }]
+            },
+            UnassignedJob {
+                job_id: "job3".to_string(),
+                reasons: vec![UnassignedJobReason {
+                    code: 3,
+                    description: "does not fit into any vehicle due to capacity".to_string()
+                }]
+            }
+        ]
+    );
+}
@@ -14,6 +14,7 @@ fn can_use_multiple_times() {
                 create_delivery_job_with_times("job5", vec![50., 0.], vec![(40, 50)], 0.),
             ],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -76,13 +77,14 @@ fn can_use_multiple_times() {
                         ("1970-01-01T00:00:50Z", "1970-01-01T00:00:50Z"),
                         50
                     ),
-                    create_stop_with_activity(
+                    create_stop_with_activity_with_time_window(
                         "job3",
                         "delivery",
                         (30., 0.),
                         0,
                         ("1970-01-01T00:01:10Z", "1970-01-01T00:01:40Z"),
-                        70
+                        70,
+                        1
                     ),
                     create_stop_with_activity(
                         "arrival",
@@ -11,6 +11,7 @@ fn can_wait_for_job_start() {
                 create_delivery_job_with_times("job2", vec![2., 0.], vec![(10, 20)], 0.),
             ],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -85,6 +86,7 @@ fn can_skip_initial_waiting() {
         plan: Plan {
             jobs: vec![create_delivery_job_with_times("job1", vec![1., 0.], vec![(10, 20)], 10.)],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
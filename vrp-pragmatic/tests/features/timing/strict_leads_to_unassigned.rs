@@ -14,6 +14,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                 create_delivery_job_with_times("job5", vec![50., 0.], vec![(0, 10)], 0.),
             ],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
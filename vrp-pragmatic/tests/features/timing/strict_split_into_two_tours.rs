@@ -14,6 +14,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                 create_delivery_job_with_times("job5", vec![50., 0.], vec![(50, 60)], 10.),
             ],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -67,13 +68,14 @@ fn can_split_into_two_tours_because_of_strict_times() {
                             ("1970-01-01T00:01:00Z", "1970-01-01T00:01:10Z"),
                             50,
                         ),
-                        create_stop_with_activity(
+                        create_stop_with_activity_with_time_window(
                             "job3",
                             "delivery",
                             (30., 0.),
                             0,
                             ("1970-01-01T00:01:30Z", "1970-01-01T00:01:50Z"),
                             70,
+                            1,
                         ),
                         create_stop_with_activity(
                             "arrival",
@@ -33,6 +33,7 @@ fn can_balance_activities_with_threshold_impl(threshold: Option<f64>, expected_l
                 create_delivery_job("job2.1", vec![2., 0.]),
             ],
             relations: None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![
@@ -15,6 +15,7 @@ fn can_balance_max_load() {
                 create_delivery_job("job6", vec![6., 0.]),
             ],
             relations: None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -73,8 +73,8 @@ pub fn default_costs_prototype() -> impl Strategy<Value = VehicleCosts> {
 pub fn default_vehicle_places_prototype() -> impl Strategy<Value = (VehiclePlace, Option<VehiclePlace>)> {
     generate_location(&DEFAULT_BOUNDING_BOX).prop_flat_map(|location| {
         Just((
-            VehiclePlace { time: default_time_plus_offset(9), location: location.clone() },
-            Some(VehiclePlace { time: default_time_plus_offset(18), location }),
+            VehiclePlace { time: default_time_plus_offset(9), location: location.clone(), gate: None },
+            Some(VehiclePlace { time: default_time_plus_offset(18), location, gate: None }),
         ))
     })
 }
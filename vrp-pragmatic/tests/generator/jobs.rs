@@ -63,6 +63,7 @@ prop_compose! {
             services: None,
             priority,
             skills,
+            position: None,
         }
     }
 }
@@ -74,7 +75,7 @@ pub fn generate_jobs(job_proto: impl Strategy<Value = Job>, range: Range<usize>)
 
 /// Generates job plan.
 pub fn generate_plan(jobs_proto: impl Strategy<Value = Vec<Job>>) -> impl Strategy<Value = Plan> {
-    jobs_proto.prop_map(|jobs| Plan { jobs, relations: None })
+    jobs_proto.prop_map(|jobs| Plan { jobs, relations: None, forbidden_arcs: None })
 }
 
 prop_compose! {
@@ -102,6 +103,7 @@ prop_compose! {
             services,
             priority,
             skills,
+            position: None,
         }
     }
 }
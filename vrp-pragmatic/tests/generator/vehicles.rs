@@ -33,6 +33,8 @@ prop_compose! {
             capacity,
             skills,
             limits,
+            unavailable: None,
+            positions: None,
         }
     }
 }
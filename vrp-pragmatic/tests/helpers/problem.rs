@@ -20,6 +20,7 @@ pub fn create_job(id: &str) -> Job {
         services: None,
         priority: None,
         skills: None,
+        position: None,
     }
 }
 
@@ -147,7 +148,7 @@ pub fn create_default_vehicle_shift() -> VehicleShift {
 
 pub fn create_default_open_vehicle_shift() -> VehicleShift {
     VehicleShift {
-        start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
+        start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc(), gate: None },
         end: None,
         breaks: None,
         reloads: None,
@@ -156,8 +157,12 @@ pub fn create_default_open_vehicle_shift() -> VehicleShift {
 
 pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64, f64)) -> VehicleShift {
     VehicleShift {
-        start: VehiclePlace { time: format_time(0.), location: vec![start.0, start.1].to_loc() },
-        end: Some(VehiclePlace { time: format_time(1000.).to_string(), location: vec![end.0, end.1].to_loc() }),
+        start: VehiclePlace { time: format_time(0.), location: vec![start.0, start.1].to_loc(), gate: None },
+        end: Some(VehiclePlace {
+            time: format_time(1000.).to_string(),
+            location: vec![end.0, end.1].to_loc(),
+            gate: None,
+        }),
         breaks: None,
         reloads: None,
     }
@@ -185,16 +190,25 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         capacity,
         skills: None,
         limits: None,
+        unavailable: None,
+        positions: None,
     }
 }
 
 pub fn create_default_profiles() -> Vec<Profile> {
-    vec![Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None }]
+    vec![Profile {
+        name: "car".to_string(),
+        profile_type: "car".to_string(),
+        speed: None,
+        overrides: None,
+        multipliers: None,
+        straight_line: None,
+    }]
 }
 
 pub fn create_empty_problem() -> Problem {
     Problem {
-        plan: Plan { jobs: vec![], relations: None },
+        plan: Plan { jobs: vec![], relations: None, forbidden_arcs: None },
         fleet: Fleet { vehicles: vec![], profiles: vec![] },
         objectives: None,
         config: None,
@@ -37,6 +37,21 @@ pub fn create_stop_with_activity_with_tag(
     create_stop_with_activity_impl(id, activity_type, location, vec![load], time, distance, Some(job_tag.to_string()))
 }
 
+pub fn create_stop_with_activity_with_time_window(
+    id: &str,
+    activity_type: &str,
+    location: (f64, f64),
+    load: i32,
+    time: (&str, &str),
+    distance: i32,
+    time_window: usize,
+) -> Stop {
+    let mut stop = create_stop_with_activity_impl(id, activity_type, location, vec![load], time, distance, None);
+    stop.activities[0].time_window = Some(time_window);
+
+    stop
+}
+
 fn create_stop_with_activity_impl(
     id: &str,
     activity_type: &str,
@@ -57,6 +72,7 @@ fn create_stop_with_activity_impl(
             location: None,
             time: None,
             job_tag,
+            time_window: None,
         }],
     }
 }
@@ -4,7 +4,7 @@ use crate::format::solution::{create_solution, Solution};
 use std::cmp::Ordering::Less;
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
-use vrp_core::models::Problem as CoreProblem;
+use vrp_core::models::{Problem as CoreProblem, Solution as CoreSolution};
 use vrp_core::solver::mutation::{Recreate, RecreateWithCheapest};
 use vrp_core::solver::RefinementContext;
 use vrp_core::solver::{Builder, DominancePopulation};
@@ -16,20 +16,23 @@ pub fn solve_with_cheapest_insertion(problem: Problem, matrices: Option<Vec<Matr
     let matrices_copy = matrices.clone();
 
     let problem = get_core_problem(problem, matrices);
+    let solution = create_solution(problem.as_ref(), &get_core_solution_with_cheapest_insertion(&problem));
+
+    assert_eq!(CheckerContext::new(problem_copy, matrices_copy, solution.clone()).check().err(), None);
+
+    sort_all_data(solution)
+}
+
+/// Returns core solution built by cheapest insertion heuristic.
+pub fn get_core_solution_with_cheapest_insertion(problem: &Arc<CoreProblem>) -> CoreSolution {
     let random = Arc::new(DefaultRandom::default());
     let population = Box::new(DominancePopulation::new(problem.clone(), random.clone(), 8, 4, 2));
     let mut refinement_ctx = RefinementContext::new(problem.clone(), population, None);
 
-    let solution = RecreateWithCheapest::default()
+    RecreateWithCheapest::default()
         .run(&mut refinement_ctx, InsertionContext::new(problem.clone(), random))
         .solution
-        .to_solution(problem.extras.clone());
-
-    let solution = create_solution(problem.as_ref(), &solution);
-
-    assert_eq!(CheckerContext::new(problem_copy, matrices_copy, solution.clone()).check().err(), None);
-
-    sort_all_data(solution)
+        .to_solution(problem.extras.clone())
 }
 
 /// Runs solver with default metaheuristic and default amount of generations.
@@ -65,7 +68,7 @@ pub fn solve_with_metaheuristic_and_iterations(
     solution
 }
 
-fn get_core_problem(problem: Problem, matrices: Option<Vec<Matrix>>) -> Arc<CoreProblem> {
+pub fn get_core_problem(problem: Problem, matrices: Option<Vec<Matrix>>) -> Arc<CoreProblem> {
     Arc::new(
         if let Some(matrices) = matrices { (problem, matrices).read_pragmatic() } else { problem.read_pragmatic() }
             .ok()
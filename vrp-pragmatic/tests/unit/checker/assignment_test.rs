@@ -138,9 +138,11 @@ fn check_jobs_impl(
                     services: Some(create_tasks("service", &tasks)),
                     priority: None,
                     skills: None,
+                    position: None,
                 })
                 .collect(),
             relations: None,
+            forbidden_arcs: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: vec![] },
         ..create_empty_problem()
@@ -25,12 +25,17 @@ fn can_check_breaks_impl(break_times: VehicleBreakTime, expected_result: Result<
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
             relations: None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
-                    end: Some(VehiclePlace { time: format_time(1000.).to_string(), location: vec![0., 0.].to_loc() }),
+                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc(), gate: None },
+                    end: Some(VehiclePlace {
+                        time: format_time(1000.).to_string(),
+                        location: vec![0., 0.].to_loc(),
+                        gate: None,
+                    }),
                     breaks: Some(vec![VehicleBreak { time: break_times, duration: 0.0, locations: None }]),
                     reloads: None,
                 }],
@@ -84,6 +89,7 @@ fn can_check_breaks_impl(break_times: VehicleBreakTime, expected_result: Result<
                             location: None,
                             time: None,
                             job_tag: None,
+                            time_window: None,
                         },
                         Activity {
                             job_id: "break".to_string(),
@@ -91,6 +97,7 @@ fn can_check_breaks_impl(break_times: VehicleBreakTime, expected_result: Result<
                             location: None,
                             time: None,
                             job_tag: None,
+                            time_window: None,
                         },
                     ],
                 },
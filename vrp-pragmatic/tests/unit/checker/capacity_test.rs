@@ -15,12 +15,12 @@ can_check_load! {
     case04: ( vec![1, 1, 3, 1, 1, 1, 0], Err("Load mismatch at stop 4 in tour 'my_vehicle_1'".to_owned())),
     case05: ( vec![1, 1, 3, 1, 2, 2, 0], Err("Load mismatch at stop 5 in tour 'my_vehicle_1'".to_owned())),
 
-    case06_1: ( vec![10, 1, 3, 1, 2, 1, 0], Err("Load exceeds capacity in tour 'my_vehicle_1'".to_owned())),
-    case06_2: ( vec![1, 1, 30, 1, 2, 1, 0], Err("Load exceeds capacity in tour 'my_vehicle_1'".to_owned())),
-    case06_3: ( vec![1, 1, 3, 1, 20, 1, 0], Err("Load exceeds capacity in tour 'my_vehicle_1'".to_owned())),
+    case06_1: ( vec![10, 1, 3, 1, 2, 1, 0], Err("Load exceeds capacity at stop 0 in tour 'my_vehicle_1'".to_owned())),
+    case06_2: ( vec![1, 1, 30, 1, 2, 1, 0], Err("Load exceeds capacity at stop 2 in tour 'my_vehicle_1'".to_owned())),
+    case06_3: ( vec![1, 1, 3, 1, 20, 1, 0], Err("Load exceeds capacity at stop 4 in tour 'my_vehicle_1'".to_owned())),
 }
 
-fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>) {
+fn create_test_data(stop_loads: Vec<i32>) -> (Problem, Solution) {
     let problem = Problem {
         plan: Plan {
             jobs: vec![
@@ -31,12 +31,17 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                 create_pickup_delivery_job("job5", vec![1., 0.], vec![5., 0.]),
             ],
             relations: None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
-                    end: Some(VehiclePlace { time: format_time(1000.).to_string(), location: vec![0., 0.].to_loc() }),
+                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc(), gate: None },
+                    end: Some(VehiclePlace {
+                        time: format_time(1000.).to_string(),
+                        location: vec![0., 0.].to_loc(),
+                        gate: None,
+                    }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
@@ -87,6 +92,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                             location: None,
                             time: None,
                             job_tag: None,
+                            time_window: None,
                         },
                         Activity {
                             job_id: "job5".to_string(),
@@ -94,6 +100,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                             location: None,
                             time: None,
                             job_tag: None,
+                            time_window: None,
                         },
                     ],
                 },
@@ -111,6 +118,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                         location: None,
                         time: None,
                         job_tag: None,
+                        time_window: None,
                     }],
                 },
                 Stop {
@@ -131,6 +139,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                                 end: "1970-01-01T00:00:09Z".to_string(),
                             }),
                             job_tag: None,
+                            time_window: None,
                         },
                         Activity {
                             job_id: "job3".to_string(),
@@ -141,6 +150,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                                 end: "1970-01-01T00:00:10Z".to_string(),
                             }),
                             job_tag: None,
+                            time_window: None,
                         },
                     ],
                 },
@@ -180,7 +190,40 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
         extras: None,
     };
 
+    (problem, solution)
+}
+
+fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>) {
+    let (problem, solution) = create_test_data(stop_loads);
+
     let result = check_vehicle_load(&CheckerContext::new(problem, None, solution));
 
     assert_eq!(result, expected_result);
 }
+
+parameterized_test! {can_check_load_with_tolerance, (stop_loads, tolerance, expected_result, expected_warnings), {
+    can_check_load_with_tolerance_impl(stop_loads, tolerance, expected_result, expected_warnings);
+}}
+
+can_check_load_with_tolerance! {
+    case01: (vec![1, 2, 3, 1, 2, 1, 0], 0, Err("Load mismatch at stop 1 in tour 'my_vehicle_1'".to_owned()), 0),
+    case02: (vec![1, 2, 3, 1, 2, 1, 0], 1, Ok(()), 2),
+    case03: (vec![1, 3, 3, 1, 2, 1, 0], 1, Err("Load mismatch at stop 1 in tour 'my_vehicle_1'".to_owned()), 0),
+    case04: (vec![1, 1, 6, 1, 2, 1, 0], 1, Err("Load mismatch at stops 2, 3 in tour 'my_vehicle_1'".to_owned()), 1),
+}
+
+fn can_check_load_with_tolerance_impl(
+    stop_loads: Vec<i32>,
+    tolerance: i32,
+    expected_result: Result<(), String>,
+    expected_warnings: usize,
+) {
+    let (problem, solution) = create_test_data(stop_loads);
+    let config = CheckerConfig { load_tolerance: tolerance, ..CheckerConfig::default() };
+    let context = CheckerContext::new(problem, None, solution).with_config(config);
+
+    let result = check_vehicle_load(&context);
+
+    assert_eq!(result, expected_result);
+    assert_eq!(context.warnings.borrow().len(), expected_warnings);
+}
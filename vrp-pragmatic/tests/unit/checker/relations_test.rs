@@ -77,6 +77,7 @@ mod single {
                     create_pickup_job("job5", vec![5., 0.]),
                 ],
                 relations,
+                forbidden_arcs: None,
             },
             fleet: Fleet {
                 vehicles: vec![VehicleType {
@@ -85,10 +86,11 @@ mod single {
                     profile: "car".to_string(),
                     costs: create_default_vehicle_costs(),
                     shifts: vec![VehicleShift {
-                        start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
+                        start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc(), gate: None },
                         end: Some(VehiclePlace {
                             time: format_time(1000.).to_string(),
                             location: vec![0., 0.].to_loc(),
+                            gate: None,
                         }),
                         breaks: Some(vec![VehicleBreak {
                             time: VehicleBreakTime::TimeWindow(vec![format_time(0.), format_time(1000.)]),
@@ -105,6 +107,8 @@ mod single {
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    unavailable: None,
+                    positions: None,
                 }],
                 profiles: create_default_profiles(),
             },
@@ -154,6 +158,7 @@ mod single {
                                     location: None,
                                     time: None,
                                     job_tag: None,
+                                    time_window: None,
                                 },
                                 Activity {
                                     job_id: "break".to_string(),
@@ -161,6 +166,7 @@ mod single {
                                     location: None,
                                     time: None,
                                     job_tag: None,
+                                    time_window: None,
                                 },
                             ],
                         },
@@ -229,3 +235,95 @@ mod single {
         assert_eq!(result, expected_result);
     }
 }
+
+mod forbidden_arcs {
+    use super::*;
+
+    parameterized_test! {can_check_forbidden_arcs, (arcs, expected_result), {
+        can_check_forbidden_arcs_impl(arcs, expected_result);
+    }}
+
+    can_check_forbidden_arcs! {
+        case01: (vec![("job1", "job3")], Ok(())),
+        case02: (vec![("job2", "job1")], Ok(())),
+        case03: (vec![("job1", "job2")], Err(())),
+        case04: (vec![("job3", "job1"), ("job2", "job3")], Err(())),
+    }
+
+    fn can_check_forbidden_arcs_impl(arcs: Vec<(&str, &str)>, expected_result: Result<(), ()>) {
+        let problem = Problem {
+            plan: Plan {
+                jobs: vec![
+                    create_delivery_job("job1", vec![1., 0.]),
+                    create_delivery_job("job2", vec![2., 0.]),
+                    create_delivery_job("job3", vec![3., 0.]),
+                ],
+                relations: None,
+                forbidden_arcs: Some(
+                    arcs.into_iter()
+                        .map(|(from, to)| ForbiddenArc { from: from.to_string(), to: to.to_string() })
+                        .collect(),
+                ),
+            },
+            fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+            ..create_empty_problem()
+        };
+        let solution = Solution {
+            statistic: Default::default(),
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        3,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0,
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (1., 0.),
+                        2,
+                        ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                        1,
+                    ),
+                    create_stop_with_activity(
+                        "job2",
+                        "delivery",
+                        (2., 0.),
+                        1,
+                        ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
+                        2,
+                    ),
+                    create_stop_with_activity(
+                        "job3",
+                        "delivery",
+                        (3., 0.),
+                        0,
+                        ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
+                        3,
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:09Z", "1970-01-01T00:00:09Z"),
+                        6,
+                    ),
+                ],
+                statistic: Default::default(),
+            }],
+            unassigned: vec![],
+            extras: None,
+        };
+
+        let result = check_forbidden_arcs(&CheckerContext::new(problem, None, solution)).map_err(|_| ());
+
+        assert_eq!(result, expected_result);
+    }
+}
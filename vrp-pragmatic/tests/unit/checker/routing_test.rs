@@ -0,0 +1,132 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_matrix(profile: &str) -> Matrix {
+    create_matrix_with_travel_time(profile, 1)
+}
+
+fn create_matrix_with_travel_time(profile: &str, travel_time: i64) -> Matrix {
+    Matrix {
+        profile: profile.to_string(),
+        timestamp: None,
+        travel_times: vec![0, travel_time, travel_time, 0],
+        distances: vec![0, 5, 5, 0],
+        error_codes: None,
+    }
+}
+
+parameterized_test! {can_check_routing, (matrices, job_stop, end_distance, multiplier, expected_result), {
+    can_check_routing_impl(matrices, job_stop, end_distance, multiplier, expected_result);
+}}
+
+can_check_routing! {
+    case01: (Some(vec![create_matrix("car")]), (5, "1970-01-01T00:00:05Z"), 10, None, Ok(())),
+    case02: (None, (3, "1970-01-01T00:00:05Z"), 10, None, Ok(())),
+    case03: (Some(vec![create_matrix("car")]), (3, "1970-01-01T00:00:05Z"), 10, None,
+             Err("Distance mismatch at stop 1 in tour 'my_vehicle_1': expected 5, got 3".to_owned())),
+    case04: (Some(vec![create_matrix("car")]), (5, "1970-01-01T00:00:05Z"), 12, None,
+             Err("Distance mismatch at stop 2 in tour 'my_vehicle_1': expected 5, got 7".to_owned())),
+    case05: (Some(vec![create_matrix("car")]), (5, "1970-01-01T00:00:02Z"), 10, None,
+             Err("Arrival at stop 1 in tour 'my_vehicle_1' is earlier than travel duration allows".to_owned())),
+    case06: (Some(vec![create_matrix("truck")]), (5, "1970-01-01T00:00:05Z"), 10, None,
+             Err("Cannot find routing matrix for profile 'car'".to_owned())),
+    case07: (Some(vec![create_matrix_with_travel_time("car", 4)]), (5, "1970-01-01T00:00:05Z"), 10, Some(0.25), Ok(())),
+    case08: (Some(vec![create_matrix_with_travel_time("car", 4)]), (5, "1970-01-01T00:00:05Z"), 10, None,
+             Err("Arrival at stop 1 in tour 'my_vehicle_1' is earlier than travel duration allows".to_owned())),
+}
+
+fn can_check_routing_impl(
+    matrices: Option<Vec<Matrix>>,
+    job_stop: (i32, &str),
+    end_distance: i32,
+    multiplier: Option<f64>,
+    expected_result: Result<(), String>,
+) {
+    let (problem, solution) = create_test_data(job_stop, end_distance, multiplier);
+
+    let result = check_routing(&CheckerContext::new(problem, matrices, solution));
+
+    assert_eq!(result, expected_result);
+}
+
+parameterized_test! {can_check_routing_with_tolerance, (travel_time, end_distance, config, expected), {
+    can_check_routing_with_tolerance_impl(travel_time, end_distance, config, expected);
+}}
+
+can_check_routing_with_tolerance! {
+    case01: (1, 12, CheckerConfig::default(), None),
+    case02: (1, 12, CheckerConfig { distance_tolerance: 1., ..CheckerConfig::default() }, Some(1)),
+    case03: (3, 10, CheckerConfig::default(), None),
+    case04: (3, 10, CheckerConfig { time_tolerance: 1., ..CheckerConfig::default() }, Some(2)),
+}
+
+fn can_check_routing_with_tolerance_impl(
+    travel_time: i64,
+    end_distance: i32,
+    config: CheckerConfig,
+    expected: Option<usize>,
+) {
+    let (problem, solution) = create_test_data((5, "1970-01-01T00:00:05Z"), end_distance, None);
+    let matrices = Some(vec![create_matrix_with_travel_time("car", travel_time)]);
+    let ctx = CheckerContext::new(problem, matrices, solution).with_config(config);
+
+    let result = check_routing(&ctx);
+
+    assert_eq!(result.ok().map(|_| ctx.warnings.borrow().len()), expected);
+}
+
+fn create_test_data(job_stop: (i32, &str), end_distance: i32, multiplier: Option<f64>) -> (Problem, Solution) {
+    let (job_distance, job_arrival) = job_stop;
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None, forbidden_arcs: None },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: vec![Profile {
+                multipliers: multiplier.map(|factor| {
+                    vec![TravelTimeMultiplier { start: "00:00".to_string(), end: "01:00".to_string(), factor }]
+                }),
+                ..create_default_profiles().first().unwrap().clone()
+            }],
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        statistic: Statistic::default(),
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    1,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:04Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    0,
+                    (job_arrival, "1970-01-01T00:00:06Z"),
+                    job_distance,
+                ),
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:07Z", "1970-01-01T00:00:07Z"),
+                    end_distance,
+                ),
+            ],
+            statistic: Statistic::default(),
+        }],
+        unassigned: vec![],
+        extras: None,
+    };
+
+    (problem, solution)
+}
@@ -0,0 +1,107 @@
+use super::*;
+use crate::helpers::*;
+
+parameterized_test! {can_check_time_windows, (time_window, expected_result), {
+    can_check_time_windows_impl(time_window, expected_result);
+}}
+
+can_check_time_windows! {
+    case01: (None, Ok(())),
+    case02: (Some(1), Ok(())),
+    case03: (Some(0), Err("Job 'job1' is served outside of its time window 0 at stop 1 in tour 'my_vehicle_1'".to_owned())),
+    case04: (Some(2), Err("Cannot find time window 2 for job 'job1'".to_owned())),
+}
+
+fn create_test_data(times: Vec<(i32, i32)>, time_window: Option<usize>) -> (Problem, Solution) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", vec![1., 0.], times, 1.)],
+            relations: None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let mut job_stop =
+        create_stop_with_activity("job1", "delivery", (1., 0.), 0, ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"), 1);
+    job_stop.activities[0].time_window = time_window;
+    let solution = Solution {
+        statistic: Statistic::default(),
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    1,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:04Z"),
+                    0,
+                ),
+                job_stop,
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:07Z", "1970-01-01T00:00:07Z"),
+                    2,
+                ),
+            ],
+            statistic: Statistic::default(),
+        }],
+        unassigned: vec![],
+        extras: None,
+    };
+
+    (problem, solution)
+}
+
+fn can_check_time_windows_impl(time_window: Option<usize>, expected_result: Result<(), String>) {
+    let (problem, solution) = create_test_data(vec![(0, 2), (5, 8)], time_window);
+
+    let result = check_time_windows(&CheckerContext::new(problem, None, solution));
+
+    assert_eq!(result, expected_result);
+}
+
+parameterized_test! {can_check_time_windows_with_tolerance, (tolerance, expected_errors, expected_warnings), {
+    can_check_time_windows_with_tolerance_impl(tolerance, expected_errors, expected_warnings);
+}}
+
+can_check_time_windows_with_tolerance! {
+    case01: (0., 1, 0),
+    case02: (2., 1, 0),
+    case03: (3., 0, 1),
+}
+
+fn can_check_time_windows_with_tolerance_impl(tolerance: f64, expected_errors: usize, expected_warnings: usize) {
+    let (problem, solution) = create_test_data(vec![(0, 2), (5, 8)], Some(0));
+    let config = CheckerConfig { time_tolerance: tolerance, ..CheckerConfig::default() };
+
+    let report = CheckerContext::new(problem, None, solution).with_config(config).get_report();
+
+    assert_eq!(report.errors.len(), expected_errors);
+    assert_eq!(report.warnings.len(), expected_warnings);
+}
+
+parameterized_test! {can_check_time_windows_without_index, (times, expected_result), {
+    can_check_time_windows_without_index_impl(times, expected_result);
+}}
+
+can_check_time_windows_without_index! {
+    case01: (vec![(5, 8)], Ok(())),
+    case02: (vec![(0, 2)], Err("Job 'job1' is served outside of its time windows at stop 1 in tour 'my_vehicle_1'".to_owned())),
+    case03: (vec![(0, 2), (8, 10)],
+             Err("Job 'job1' is served outside of its time windows at stop 1 in tour 'my_vehicle_1'".to_owned())),
+}
+
+fn can_check_time_windows_without_index_impl(times: Vec<(i32, i32)>, expected_result: Result<(), String>) {
+    let (problem, solution) = create_test_data(times, None);
+
+    let result = check_time_windows(&CheckerContext::new(problem, None, solution));
+
+    assert_eq!(result, expected_result);
+}
@@ -0,0 +1,69 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::IdDimension;
+use vrp_core::models::problem::Fleet;
+
+const PENALTY: Cost = 1000.;
+
+/// Creates activity of job with given position hint or depot activity if it is `None`.
+fn create_activity(id: &str, position: Option<Option<JobPosition>>) -> TourActivity {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_id(id);
+    if let Some(Some(position)) = position {
+        single.dimens.set_value("position", position);
+    }
+
+    let mut activity = create_activity_with_job_at_location(Arc::new(single), DEFAULT_JOB_LOCATION);
+    if position.is_none() {
+        activity.job = None;
+    }
+
+    activity
+}
+
+parameterized_test! {can_estimate_position_violations, (prev, target, next, expected), {
+    can_estimate_position_violations_impl(prev, target, next, expected);
+}}
+
+can_estimate_position_violations! {
+    case01_first_after_depot: (None, Some(JobPosition::First), Some(None), 0.),
+    case02_first_after_job: (Some(None), Some(JobPosition::First), Some(None), PENALTY),
+    case03_last_before_depot: (Some(None), Some(JobPosition::Last), None, 0.),
+    case04_last_before_job: (Some(None), Some(JobPosition::Last), Some(None), PENALTY),
+    case05_job_before_first: (None, None, Some(Some(JobPosition::First)), PENALTY),
+    case06_job_after_last: (Some(Some(JobPosition::Last)), None, None, PENALTY),
+    case07_any_between_jobs: (Some(None), Some(JobPosition::Any), Some(None), 0.),
+    case08_no_hints: (Some(None), None, Some(None), 0.),
+    case09_already_violated: (Some(Some(JobPosition::Last)), None, Some(None), 0.),
+    case10_first_before_first: (Some(None), Some(JobPosition::First), Some(Some(JobPosition::First)), PENALTY),
+}
+
+fn can_estimate_position_violations_impl(
+    prev: Option<Option<JobPosition>>,
+    target: Option<JobPosition>,
+    next: Option<Option<JobPosition>>,
+    expected: Cost,
+) {
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let route_ctx = RouteContext {
+        route: Arc::new(create_route_with_activities(&fleet, "v1", vec![])),
+        state: Arc::new(RouteState::default()),
+    };
+    let (prev, target, next) =
+        (create_activity("prev", prev), create_activity("target", Some(target)), create_activity("next", next));
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Box::new(PositionModule::new(PENALTY)));
+
+    let cost = pipeline.evaluate_soft_activity(
+        &route_ctx,
+        &ActivityContext { index: 0, prev: &prev, target: &target, next: Some(&next) },
+    );
+
+    assert_eq!(cost, expected);
+}
@@ -0,0 +1,108 @@
+use super::*;
+use serde_json::json;
+
+fn create_problem(vehicles: Value) -> Value {
+    json!({ "plan": { "jobs": [] }, "fleet": { "vehicles": vehicles, "profiles": [] } })
+}
+
+fn get_vehicles(problem: &Value) -> &Vec<Value> {
+    problem["fleet"]["vehicles"].as_array().unwrap()
+}
+
+#[test]
+fn can_generate_vehicle_ids_from_amount() {
+    let mut problem = create_problem(json!([{ "typeId": "car", "amount": 3, "capacity": [10] }]));
+
+    expand_fleet(&mut problem).unwrap();
+
+    let vehicles = get_vehicles(&problem);
+    assert_eq!(vehicles.len(), 1);
+    assert_eq!(vehicles[0]["vehicleIds"], json!(["car_1", "car_2", "car_3"]));
+    assert!(vehicles[0].get("amount").is_none());
+}
+
+#[test]
+fn can_move_overridden_vehicles_to_separate_types() {
+    let mut problem = create_problem(json!([{
+        "typeId": "car",
+        "amount": 3,
+        "capacity": [10],
+        "costs": { "fixed": 10.0, "distance": 1.0, "time": 1.0 },
+        "overrides": [ { "vehicleId": "car_2", "capacity": [20], "costs": { "fixed": 20.0 } } ]
+    }]));
+
+    expand_fleet(&mut problem).unwrap();
+
+    let vehicles = get_vehicles(&problem);
+    assert_eq!(vehicles.len(), 2);
+    assert_eq!(vehicles[0]["typeId"], json!("car"));
+    assert_eq!(vehicles[0]["vehicleIds"], json!(["car_1", "car_3"]));
+    assert_eq!(vehicles[0]["capacity"], json!([10]));
+    assert!(vehicles[0].get("overrides").is_none());
+    assert_eq!(vehicles[1]["typeId"], json!("car_2"));
+    assert_eq!(vehicles[1]["vehicleIds"], json!(["car_2"]));
+    assert_eq!(vehicles[1]["capacity"], json!([20]));
+    assert_eq!(vehicles[1]["costs"], json!({ "fixed": 20.0, "distance": 1.0, "time": 1.0 }));
+}
+
+#[test]
+fn can_drop_vehicle_type_when_all_vehicles_are_overridden() {
+    let mut problem = create_problem(json!([{
+        "typeId": "car",
+        "vehicleIds": ["car_a"],
+        "capacity": [10],
+        "overrides": [ { "vehicleId": "car_a", "capacity": [20] } ]
+    }]));
+
+    expand_fleet(&mut problem).unwrap();
+
+    let vehicles = get_vehicles(&problem);
+    assert_eq!(vehicles.len(), 1);
+    assert_eq!(vehicles[0]["typeId"], json!("car_a"));
+    assert_eq!(vehicles[0]["capacity"], json!([20]));
+}
+
+#[test]
+fn can_expand_break_templates() {
+    let lunch = json!({ "time": [ "1970-01-01T00:00:10Z", "1970-01-01T00:00:20Z" ], "duration": 5.0 });
+    let rest = json!({ "time": [ 100.0, 200.0 ], "duration": 1.0 });
+    let mut problem = json!({
+        "plan": { "jobs": [] },
+        "fleet": {
+            "breakTemplates": { "lunch": lunch.clone(), "day": [ lunch.clone(), rest.clone() ] },
+            "vehicles": [
+                { "typeId": "car", "shifts": [ { "breaks": [ "lunch", rest.clone() ] }, { "breaks": [ "day" ] } ] },
+                { "typeId": "truck", "shifts": [ { "start": {} } ] }
+            ],
+            "profiles": []
+        }
+    });
+
+    expand_fleet(&mut problem).unwrap();
+
+    let vehicles = get_vehicles(&problem);
+    assert!(problem["fleet"].get("breakTemplates").is_none());
+    assert_eq!(vehicles[0]["shifts"][0]["breaks"], json!([lunch.clone(), rest.clone()]));
+    assert_eq!(vehicles[0]["shifts"][1]["breaks"], json!([lunch, rest]));
+    assert!(vehicles[1]["shifts"][0].get("breaks").is_none());
+}
+
+parameterized_test! {can_detect_invalid_fleet_shortcuts, (vehicles, expected), {
+    can_detect_invalid_fleet_shortcuts_impl(vehicles, expected);
+}}
+
+can_detect_invalid_fleet_shortcuts! {
+    case01: (json!([{ "typeId": "car", "amount": 1, "vehicleIds": ["car_1"] }]), "has both amount and vehicleIds"),
+    case02: (json!([{ "typeId": "car", "amount": -1 }]), "must be non-negative integer"),
+    case03: (json!([{ "typeId": "car", "amount": 1, "overrides": [{ "vehicleId": "car_2" }] }]), "unknown vehicle 'car_2'"),
+    case04: (json!([{ "typeId": "car", "amount": 1, "overrides": [{ "capacity": [1] }] }]), "has no vehicleId"),
+    case05: (json!([{ "typeId": "car", "shifts": [{ "breaks": ["lunch"] }] }]), "unknown break template 'lunch'"),
+}
+
+fn can_detect_invalid_fleet_shortcuts_impl(vehicles: Value, expected: &str) {
+    let mut problem = create_problem(vehicles);
+
+    let result = expand_fleet(&mut problem);
+
+    assert!(result.err().unwrap().contains(expected));
+}
@@ -62,3 +62,46 @@ fn can_deserialize_matrix() {
     assert_eq!(matrix.distances.len(), 16);
     assert_eq!(matrix.travel_times.len(), 16);
 }
+
+const FLEET_WITH_DEFAULTS: &str = r#"
+{
+  "plan": { "jobs": [] },
+  "fleet": {
+    "vehicleDefaults": {
+      "profile": "car",
+      "costs": { "fixed": 20.0, "distance": 0.002, "time": 0.003 },
+      "shifts": [ { "start": { "time": "2019-07-04T09:00:00Z", "location": { "lat": 52.5, "lng": 13.4 } } } ],
+      "capacity": [ 10 ]
+    },
+    "vehicles": [
+      { "typeId": "small", "vehicleIds": [ "small_1" ] },
+      { "typeId": "big", "vehicleIds": [ "big_1" ], "capacity": [ 20 ], "costs": { "fixed": 40.0 } }
+    ],
+    "profiles": [ { "name": "car", "type": "car" } ]
+  }
+}
+"#;
+
+#[test]
+fn can_expand_vehicle_defaults() {
+    let problem = deserialize_problem(BufReader::new(FLEET_WITH_DEFAULTS.as_bytes())).ok().unwrap();
+
+    let vehicles = &problem.fleet.vehicles;
+    assert_eq!(vehicles.len(), 2);
+    assert!(vehicles.iter().all(|vehicle| vehicle.profile == "car" && vehicle.shifts.len() == 1));
+    assert_eq!(vehicles[0].capacity, vec![10]);
+    assert_eq!(vehicles[0].costs.fixed, Some(20.));
+    assert_eq!(vehicles[1].capacity, vec![20]);
+    assert_eq!(vehicles[1].costs.fixed, Some(40.));
+    assert_eq!(vehicles[1].costs.distance, 0.002);
+}
+
+#[test]
+fn can_detect_invalid_vehicle_defaults() {
+    let problem = FLEET_WITH_DEFAULTS.replacen(r#""vehicleDefaults": {"#, r#""vehicleDefaults": 1, "unused": {"#, 1);
+
+    let errors = deserialize_problem(BufReader::new(problem.as_bytes())).err().unwrap();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code, "E0000");
+}
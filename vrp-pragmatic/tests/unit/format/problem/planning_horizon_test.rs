@@ -0,0 +1,46 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_problem(horizon: Option<(i32, i32)>, times: Vec<(i32, i32)>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", vec![1., 0.], times, 1.)],
+            relations: None,
+            forbidden_arcs: None,
+        },
+        config: horizon.map(|(start, end)| Config {
+            routing: None,
+            planning_horizon: Some(vec![format_time(start as f64), format_time(end as f64)]),
+        }),
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_skip_problem_without_planning_horizon() {
+    assert!(clip_job_time_windows(&create_problem(None, vec![(0, 100)])).is_none());
+}
+
+parameterized_test! {can_clip_job_time_windows, (horizon, times, expected), {
+    can_clip_job_time_windows_impl(horizon, times, expected);
+}}
+
+can_clip_job_time_windows! {
+    case01: ((0, 100), vec![(10, 20)], vec![(10, 20)]),
+    case02: ((0, 100), vec![(50, 200)], vec![(50, 100)]),
+    case03: ((50, 100), vec![(0, 60), (70, 80)], vec![(50, 60), (70, 80)]),
+    case04: ((50, 100), vec![(0, 10), (70, 80), (200, 300)], vec![(70, 80)]),
+}
+
+fn can_clip_job_time_windows_impl(horizon: (i32, i32), times: Vec<(i32, i32)>, expected: Vec<(i32, i32)>) {
+    let problem = create_problem(Some(horizon), times);
+
+    let problem = clip_job_time_windows(&problem).unwrap();
+
+    let times = problem.plan.jobs[0].deliveries.as_ref().unwrap()[0].places[0].times.clone().unwrap();
+    let expected = expected
+        .into_iter()
+        .map(|(start, end)| vec![format_time(start as f64), format_time(end as f64)])
+        .collect::<Vec<_>>();
+    assert_eq!(times, expected);
+}
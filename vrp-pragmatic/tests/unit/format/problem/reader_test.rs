@@ -1,4 +1,4 @@
-use super::create_approx_matrices;
+use super::{create_approx_matrices, create_custom_matrices};
 use crate::extensions::MultiDimensionalCapacity;
 use crate::format::problem::*;
 use crate::helpers::*;
@@ -78,6 +78,7 @@ fn can_read_complex_problem() {
                     services: None,
                     priority: None,
                     skills: Some(vec!["unique".to_string()]),
+                    position: None,
                 },
                 Job {
                     id: "pickup_delivery_job".to_string(),
@@ -109,6 +110,7 @@ fn can_read_complex_problem() {
                     services: None,
                     priority: None,
                     skills: None,
+                    position: None,
                 },
                 Job {
                     id: "pickup_job".to_string(),
@@ -130,9 +132,11 @@ fn can_read_complex_problem() {
                     services: None,
                     priority: None,
                     skills: Some(vec!["unique2".to_string()]),
+                    position: None,
                 },
             ],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -144,24 +148,21 @@ fn can_read_complex_problem() {
                     start: VehiclePlace {
                         time: "1970-01-01T00:00:00Z".to_string(),
                         location: vec![52.4862, 13.45148].to_loc(),
+                        gate: None,
                     },
                     end: Some(VehiclePlace {
                         time: "1970-01-01T00:01:40Z".to_string(),
                         location: vec![52.4862, 13.45148].to_loc(),
+                        gate: None,
                     }),
-                    breaks: Some(vec![VehicleBreak {
-                        time: VehicleBreakTime::TimeWindow(vec![
-                            "1970-01-01T00:00:10Z".to_string(),
-                            "1970-01-01T00:01:20Z".to_string(),
-                        ]),
-                        duration: 100.0,
-                        locations: Some(vec![vec![52.48315, 13.4330].to_loc()]),
-                    }]),
+                    breaks: None,
                     reloads: None,
                 }],
                 capacity: vec![10, 1],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
                 limits: Some(VehicleLimits { max_distance: Some(123.1), shift_time: Some(100.), allowed_areas: None }),
+                unavailable: None,
+                positions: None,
             }],
             profiles: create_default_profiles(),
         },
@@ -171,14 +172,14 @@ fn can_read_complex_problem() {
     let matrix = Matrix {
         profile: "car".to_owned(),
         timestamp: None,
-        travel_times: vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
-        distances: vec![2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2],
+        travel_times: vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+        distances: vec![2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2],
         error_codes: Option::None,
     };
 
     let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
 
-    assert_eq!(problem.jobs.all().collect::<Vec<_>>().len(), 3 + 2);
+    assert_eq!(problem.jobs.all().collect::<Vec<_>>().len(), 3);
 
     // delivery
     let job = get_single_job(0, problem.jobs.as_ref());
@@ -272,14 +273,43 @@ fn can_create_approximation_matrices() {
                 create_delivery_job("job2", vec![52.5165, 13.3808]),
             ],
             relations: None,
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                Profile { name: "car1".to_string(), profile_type: "car".to_string(), speed: Some(8.) },
-                Profile { name: "car2".to_string(), profile_type: "car".to_string(), speed: Some(10.) },
-                Profile { name: "car3".to_string(), profile_type: "car".to_string(), speed: Some(5.) },
-                Profile { name: "car4".to_string(), profile_type: "car".to_string(), speed: None },
+                Profile {
+                    name: "car1".to_string(),
+                    profile_type: "car".to_string(),
+                    speed: Some(8.),
+                    overrides: None,
+                    multipliers: None,
+                    straight_line: None,
+                },
+                Profile {
+                    name: "car2".to_string(),
+                    profile_type: "car".to_string(),
+                    speed: Some(10.),
+                    overrides: None,
+                    multipliers: None,
+                    straight_line: None,
+                },
+                Profile {
+                    name: "car3".to_string(),
+                    profile_type: "car".to_string(),
+                    speed: Some(5.),
+                    overrides: None,
+                    multipliers: None,
+                    straight_line: None,
+                },
+                Profile {
+                    name: "car4".to_string(),
+                    profile_type: "car".to_string(),
+                    speed: None,
+                    overrides: None,
+                    multipliers: None,
+                    straight_line: None,
+                },
             ],
         },
         ..create_empty_problem()
@@ -298,3 +328,103 @@ fn can_create_approximation_matrices() {
         assert_eq!(matrix.travel_times, &[0, duration, duration, 0]);
     }
 }
+
+#[test]
+fn can_use_default_speed_for_approximation_matrices() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![52.52599, 13.45413]),
+                create_delivery_job("job2", vec![52.5165, 13.3808]),
+            ],
+            relations: None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![],
+            profiles: vec![Profile {
+                name: "car".to_string(),
+                profile_type: "car".to_string(),
+                speed: None,
+                overrides: None,
+                multipliers: None,
+                straight_line: None,
+            }],
+        },
+        config: Some(Config {
+            routing: Some(RoutingConfig { distance_unit: None, default_speed: Some(5.) }),
+            planning_horizon: None,
+        }),
+        ..create_empty_problem()
+    };
+
+    let matrices = create_approx_matrices(&problem);
+
+    assert_eq!(matrices.len(), 1);
+    assert_eq!(matrices[0].distances, &[0, 5078, 5078, 0]);
+    assert_eq!(matrices[0].travel_times, &[0, 1016, 1016, 0]);
+}
+
+#[test]
+fn can_create_custom_matrices() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 2.]), create_delivery_job("job2", vec![4., 6.])],
+            relations: None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet { vehicles: vec![], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+
+    let matrices = create_custom_matrices(&problem, |_, from, to| {
+        let distance = (from.lat - to.lat).abs() + (from.lng - to.lng).abs();
+        if distance > 5. {
+            None
+        } else {
+            Some((distance * 2., distance))
+        }
+    });
+
+    assert_eq!(matrices.len(), 1);
+    assert_eq!(matrices[0].distances, &[0, 0, 0, 0]);
+    assert_eq!(matrices[0].error_codes, Some(vec![0, 1, 1, 0]));
+
+    let matrices = create_custom_matrices(&problem, |_, from, to| {
+        let distance = (from.lat - to.lat).abs() + (from.lng - to.lng).abs();
+        Some((distance * 2., distance))
+    });
+
+    assert_eq!(matrices[0].distances, &[0, 7, 7, 0]);
+    assert_eq!(matrices[0].travel_times, &[0, 14, 14, 0]);
+    assert!(matrices[0].error_codes.is_none());
+}
+
+parameterized_test! {can_normalize_matrix_distance_units, (distance_unit, expected), {
+    can_normalize_matrix_distance_units_impl(distance_unit, expected);
+}}
+
+can_normalize_matrix_distance_units! {
+    case01: (None, 2.),
+    case02: (Some(DistanceUnit::Meters), 2.),
+    case03: (Some(DistanceUnit::Kilometers), 2000.),
+    case04: (Some(DistanceUnit::Miles), 3218.688),
+}
+
+fn can_normalize_matrix_distance_units_impl(distance_unit: Option<DistanceUnit>, expected: f64) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None, forbidden_arcs: None },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        config: Some(Config {
+            routing: Some(RoutingConfig { distance_unit, default_speed: None }),
+            planning_horizon: None,
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix(vec![0, 2, 2, 0]);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+
+    assert_eq!(problem.transport.distance(0, 0, 1, 0.), expected);
+    assert_eq!(problem.transport.duration(0, 0, 1, 0.), 2.);
+}
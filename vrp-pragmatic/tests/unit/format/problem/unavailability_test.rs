@@ -0,0 +1,135 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_problem(shift: VehicleShift, unavailable: Option<Vec<(f64, f64)>>) -> Problem {
+    Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![shift],
+                unavailable: unavailable.map(|tws| {
+                    tws.into_iter().map(|(start, end)| vec![format_time(start), format_time(end)]).collect()
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_shift_times(problem: &Problem) -> Vec<(f64, Option<f64>)> {
+    problem.fleet.vehicles[0]
+        .shifts
+        .iter()
+        .map(|shift| (parse_time(&shift.start.time), shift.end.as_ref().map(|end| parse_time(&end.time))))
+        .collect()
+}
+
+#[test]
+fn can_skip_problem_without_unavailability() {
+    let problem = create_problem(create_default_vehicle_shift(), None);
+
+    assert!(carve_vehicle_shifts(&problem).is_none());
+}
+
+parameterized_test! {can_carve_vehicle_shift, (unavailable, expected), {
+    can_carve_vehicle_shift_impl(unavailable, expected);
+}}
+
+can_carve_vehicle_shift! {
+    case01: (vec![(2000., 3000.)], vec![(0., Some(1000.))]),
+    case02: (vec![(0., 1000.)], vec![]),
+    case03: (vec![(0., 200.)], vec![(200., Some(1000.))]),
+    case04: (vec![(800., 1200.)], vec![(0., Some(800.))]),
+    case05: (vec![(200., 300.)], vec![(0., Some(200.)), (300., Some(1000.))]),
+    case06: (vec![(600., 700.), (200., 300.)], vec![(0., Some(200.)), (300., Some(600.)), (700., Some(1000.))]),
+    case07: (vec![(200., 400.), (300., 500.)], vec![(0., Some(200.)), (500., Some(1000.))]),
+}
+
+fn can_carve_vehicle_shift_impl(unavailable: Vec<(f64, f64)>, expected: Vec<(f64, Option<f64>)>) {
+    let problem = create_problem(create_default_vehicle_shift(), Some(unavailable));
+
+    let (problem, _) = carve_vehicle_shifts(&problem).unwrap();
+
+    assert_eq!(get_shift_times(&problem), expected);
+}
+
+#[test]
+fn can_carve_open_vehicle_shift() {
+    let problem = create_problem(create_default_open_vehicle_shift(), Some(vec![(200., 300.)]));
+
+    let (problem, _) = carve_vehicle_shifts(&problem).unwrap();
+
+    assert_eq!(get_shift_times(&problem), vec![(0., Some(200.)), (300., None)]);
+    let first_end = problem.fleet.vehicles[0].shifts[0].end.as_ref().unwrap();
+    assert_eq!(first_end.location, vec![0., 0.].to_loc());
+}
+
+#[test]
+fn can_keep_breaks_only_within_carved_shifts() {
+    let problem = create_problem(
+        VehicleShift {
+            breaks: Some(vec![
+                VehicleBreak {
+                    time: VehicleBreakTime::TimeWindow(vec![format_time(100.), format_time(150.)]),
+                    duration: 10.,
+                    locations: None,
+                },
+                VehicleBreak {
+                    time: VehicleBreakTime::TimeWindow(vec![format_time(500.), format_time(600.)]),
+                    duration: 10.,
+                    locations: None,
+                },
+                VehicleBreak { time: VehicleBreakTime::TimeOffset(vec![50., 100.]), duration: 10., locations: None },
+            ]),
+            ..create_default_vehicle_shift()
+        },
+        Some(vec![(200., 300.)]),
+    );
+
+    let (problem, _) = carve_vehicle_shifts(&problem).unwrap();
+
+    let break_counts = problem.fleet.vehicles[0]
+        .shifts
+        .iter()
+        .map(|shift| shift.breaks.as_ref().map_or(0, |breaks| breaks.len()))
+        .collect::<Vec<_>>();
+    assert_eq!(break_counts, vec![2, 2]);
+}
+
+#[test]
+fn can_map_carved_shifts_to_original_ones() {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![
+                    create_default_vehicle_shift(),
+                    create_vehicle_shift_with_times(2000., 3000.),
+                    create_vehicle_shift_with_times(4000., 5000.),
+                ],
+                unavailable: Some(vec![
+                    vec![format_time(0.), format_time(1000.)],
+                    vec![format_time(4200.), format_time(4300.)],
+                ]),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+
+    let (problem, shift_map) = carve_vehicle_shifts(&problem).unwrap();
+
+    assert_eq!(get_shift_times(&problem), vec![(2000., Some(3000.)), (4000., Some(4200.)), (4300., Some(5000.))]);
+    assert_eq!(shift_map.get("my_vehicle"), Some(&vec![1, 2, 2]));
+}
+
+fn create_vehicle_shift_with_times(start: f64, end: f64) -> VehicleShift {
+    let shift = create_default_vehicle_shift();
+
+    VehicleShift {
+        start: VehiclePlace { time: format_time(start), ..shift.start.clone() },
+        end: shift.end.clone().map(|place| VehiclePlace { time: format_time(end), ..place }),
+        ..shift
+    }
+}
@@ -0,0 +1,118 @@
+use super::*;
+use crate::format::problem::Problem as ApiProblem;
+use crate::format::problem::*;
+use crate::format::solution::Statistic;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::problem::{Job as CoreJob, Place, Single};
+use vrp_core::solver::mutation::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::{DominancePopulation, RefinementContext};
+use vrp_core::utils::DefaultRandom;
+
+#[test]
+fn can_serialize_insertion_heatmap() {
+    let problem = ApiProblem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job_with_demand("job2", vec![2., 0.], vec![100]),
+            ],
+            relations: None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let problem = get_core_problem(problem, Some(vec![matrix]));
+    let random = Arc::new(DefaultRandom::default());
+    let population = Box::new(DominancePopulation::new(problem.clone(), random.clone(), 8, 4, 2));
+    let mut refinement_ctx = RefinementContext::new(problem.clone(), population, None);
+    let heatmap = InsertionHeatmap::default();
+    (0..2).for_each(|_| {
+        heatmap.record(
+            &RecreateWithCheapest::default()
+                .run(&mut refinement_ctx, InsertionContext::new(problem.clone(), random.clone())),
+        )
+    });
+    let mut buffer = vec![];
+
+    serialize_heatmap_as_geojson(BufWriter::new(&mut buffer), problem.as_ref(), &heatmap).unwrap();
+
+    let result = serde_json::from_slice::<Value>(buffer.as_slice()).unwrap();
+    assert_eq!(
+        result,
+        json!({
+            "type": "FeatureCollection",
+            "features": [{
+                "type": "Feature",
+                "properties": {
+                    "job_id": "job2",
+                    "failures": 2,
+                    "intensity": 1.,
+                    "reasons": [{ "code": 3, "description": "does not fit into any vehicle due to capacity", "count": 2 }]
+                },
+                "geometry": { "type": "Point", "coordinates": [0., 2.] }
+            }]
+        })
+    );
+}
+
+#[test]
+fn can_serialize_solution() {
+    let solution = Solution {
+        statistic: Statistic::default(),
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    1,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    0,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+            ],
+            statistic: Statistic::default(),
+        }],
+        unassigned: vec![],
+        extras: None,
+    };
+    let mut buffer = vec![];
+
+    serialize_solution_as_geojson(BufWriter::new(&mut buffer), &solution).unwrap();
+
+    let result = serde_json::from_slice::<Value>(buffer.as_slice()).unwrap();
+    let features = result["features"].as_array().unwrap();
+    assert_eq!(features.len(), 3);
+    assert_eq!(features[1]["geometry"], json!({ "type": "Point", "coordinates": [0., 1.] }));
+    assert_eq!(features[1]["properties"]["arrival"], json!("1970-01-01T00:00:01Z"));
+    assert_eq!(features[1]["properties"]["departure"], json!("1970-01-01T00:00:02Z"));
+    assert_eq!(features[1]["properties"]["load"], json!("0"));
+    assert_eq!(features[1]["properties"]["jobs_ids"], json!("job1"));
+    assert_eq!(features[2]["geometry"], json!({ "type": "LineString", "coordinates": [[0., 0.], [0., 1.]] }));
+    assert_eq!(features[2]["properties"]["vehicle_id"], json!("my_vehicle_1"));
+}
+
+#[test]
+fn can_get_unique_job_locations() {
+    let create_place = |location: usize| Place { location: Some(location), duration: 0., times: vec![] };
+    let job = CoreJob::Single(Arc::new(Single {
+        places: vec![create_place(1), create_place(2), create_place(1), create_place(2)],
+        dimens: Default::default(),
+    }));
+
+    assert_eq!(get_job_locations(&job), vec![1, 2]);
+}
@@ -0,0 +1,104 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use std::io::BufReader;
+use std::sync::Arc;
+use vrp_core::models::Problem as CoreProblem;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![5., 0.]),
+                create_pickup_delivery_job("job2", vec![3., 0.], vec![8., 0.]),
+                create_delivery_job("job3", vec![10., 0.]),
+            ],
+            relations: Option::None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    #[cfg(feature = "breaks")]
+                    breaks: Some(vec![VehicleBreak {
+                        time: VehicleBreakTime::TimeWindow(vec![crate::format_time(5.), crate::format_time(10.)]),
+                        duration: 2.0,
+                        locations: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle("my_vehicle")
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn read_solution(problem: &Arc<CoreProblem>, solution: &Solution) -> Result<Solution, String> {
+    let solution = serde_json::to_string(solution).unwrap();
+
+    read_init_solution(BufReader::new(solution.as_bytes()), problem.clone())
+        .map(|solution| create_solution(problem.as_ref(), &solution))
+}
+
+fn get_job_ids(solution: &Solution) -> Vec<String> {
+    let mut ids = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().flat_map(|stop| stop.activities.iter()))
+        .map(|activity| activity.job_id.clone())
+        .collect::<Vec<_>>();
+    ids.sort();
+
+    ids
+}
+
+#[test]
+fn can_read_solution_written_by_solver() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_cheapest_insertion(problem.clone(), Some(vec![matrix.clone()]));
+    let core_problem = get_core_problem(problem, Some(vec![matrix]));
+
+    let result = read_solution(&core_problem, &solution).unwrap();
+
+    assert_eq!(result.tours, solution.tours);
+    assert_eq!(result.statistic, solution.statistic);
+}
+
+#[test]
+fn can_treat_missing_jobs_as_unassigned() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+    let mut solution = solve_with_cheapest_insertion(problem.clone(), Some(vec![matrix.clone()]));
+    let core_problem = get_core_problem(problem, Some(vec![matrix]));
+    solution.tours.iter_mut().flat_map(|tour| tour.stops.iter_mut()).for_each(|stop| {
+        stop.activities.retain(|activity| activity.job_id != "job1" && activity.activity_type != "pickup")
+    });
+
+    let result = read_solution(&core_problem, &solution).unwrap();
+
+    let job_ids = get_job_ids(&result);
+    assert!(job_ids.iter().all(|id| id != "job1" && id != "job2"));
+    assert!(job_ids.contains(&"job3".to_string()));
+    let unassigned = result.unassigned.iter().map(|job| job.job_id.clone()).collect::<Vec<_>>();
+    assert!(unassigned.contains(&"job1".to_string()));
+    assert!(unassigned.contains(&"job2".to_string()));
+}
+
+#[test]
+fn can_return_error_for_unknown_vehicle_or_job() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_cheapest_insertion(problem.clone(), Some(vec![matrix.clone()]));
+    let core_problem = get_core_problem(problem, Some(vec![matrix]));
+
+    let mut unknown_vehicle = solution.clone();
+    unknown_vehicle.tours[0].vehicle_id = "unknown".to_string();
+    let mut unknown_job = solution;
+    unknown_job.tours[0].stops[1].activities[0].job_id = "unknown".to_string();
+
+    assert!(read_solution(&core_problem, &unknown_vehicle).is_err());
+    assert!(read_solution(&core_problem, &unknown_job).is_err());
+}
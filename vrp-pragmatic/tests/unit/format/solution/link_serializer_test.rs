@@ -0,0 +1,77 @@
+use super::*;
+use crate::format::solution::{Statistic, Stop};
+use crate::helpers::*;
+
+fn create_stop(idx: usize, lng: f64) -> Stop {
+    create_stop_with_activity(
+        format!("job{}", idx).as_str(),
+        "delivery",
+        (0., lng),
+        0,
+        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+        0,
+    )
+}
+
+fn create_test_solution(lngs: Vec<f64>) -> Solution {
+    Solution {
+        statistic: Statistic::default(),
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: lngs.into_iter().enumerate().map(|(idx, lng)| create_stop(idx, lng)).collect(),
+            statistic: Statistic::default(),
+        }],
+        unassigned: vec![],
+        extras: None,
+    }
+}
+
+#[test]
+fn can_create_tour_links() {
+    let solution = create_test_solution(vec![0., 1., 1., 2., 0.]);
+
+    let links = create_tour_links(&solution);
+
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].vehicle_id, "my_vehicle_1");
+    assert_eq!(
+        links[0].osrm,
+        "https://map.project-osrm.org/?z=12&loc=0,0&loc=0,1&loc=0,2&loc=0,0&hl=en&alt=0&srv=0".to_string()
+    );
+    assert_eq!(
+        links[0].google,
+        vec![
+            "https://www.google.com/maps/dir/?api=1&origin=0,0&destination=0,0&waypoints=0,1%7C0,2&travelmode=driving"
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn can_split_long_tour_into_google_links() {
+    let solution = create_test_solution((0..12).map(|idx| idx as f64).collect());
+
+    let links = create_tour_links(&solution);
+
+    assert_eq!(links[0].google.len(), 2);
+    assert!(links[0].google[0].contains("origin=0,0&destination=0,10&"));
+    assert_eq!(
+        links[0].google[1],
+        "https://www.google.com/maps/dir/?api=1&origin=0,10&destination=0,11&travelmode=driving".to_string()
+    );
+}
+
+#[test]
+fn can_serialize_tour_links_as_markdown() {
+    let links = create_tour_links(&create_test_solution((0..12).map(|idx| idx as f64).collect()));
+    let mut buffer = vec![];
+
+    serialize_tour_links_as_markdown(BufWriter::new(&mut buffer), links.as_slice()).unwrap();
+
+    let markdown = String::from_utf8(buffer).unwrap();
+    assert!(markdown.starts_with("# Tours\n\n## my_vehicle_1 (shift 0)\n\n- [OSRM](https://map.project-osrm.org/"));
+    assert!(markdown.contains("- [Google Maps, part 1](https://www.google.com/maps/dir/"));
+    assert!(markdown.contains("- [Google Maps, part 2](https://www.google.com/maps/dir/"));
+}
@@ -1,6 +1,7 @@
 use crate::format::problem::*;
 use crate::format::solution::*;
 use crate::helpers::*;
+use std::io::{BufReader, BufWriter};
 
 #[test]
 fn can_create_solution() {
@@ -8,6 +9,7 @@ fn can_create_solution() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -82,6 +84,7 @@ fn can_merge_activities_in_one_stop() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![5., 0.])],
             relations: Option::None,
+            forbidden_arcs: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -129,7 +132,8 @@ fn can_merge_activities_in_one_stop() {
                                     start: "1970-01-01T00:00:05Z".to_string(),
                                     end: "1970-01-01T00:00:06Z".to_string()
                                 }),
-                                job_tag: None
+                                job_tag: None,
+                                time_window: None,
                             },
                             Activity {
                                 job_id: "job1".to_string(),
@@ -139,7 +143,8 @@ fn can_merge_activities_in_one_stop() {
                                     start: "1970-01-01T00:00:06Z".to_string(),
                                     end: "1970-01-01T00:00:07Z".to_string()
                                 }),
-                                job_tag: None
+                                job_tag: None,
+                                time_window: None,
                             }
                         ]
                     },
@@ -164,3 +169,37 @@ fn can_merge_activities_in_one_stop() {
         }
     );
 }
+
+#[test]
+fn can_write_solution_streamed() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![5., 0.]),
+                create_delivery_job("job2", vec![-5., 0.]),
+                create_delivery_job("job3", vec![0., 5.]),
+            ],
+            relations: Option::None,
+            forbidden_arcs: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string(), "v3".to_string()],
+                capacity: vec![1],
+                ..create_default_vehicle("my_vehicle")
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let problem = get_core_problem(problem, Some(vec![matrix]));
+    let solution = get_core_solution_with_cheapest_insertion(&problem);
+    let mut buffer = String::new();
+
+    solution.write_pragmatic_json_streamed(&problem, unsafe { BufWriter::new(buffer.as_mut_vec()) }).unwrap();
+
+    let result = deserialize_solution(BufReader::new(buffer.as_bytes())).unwrap();
+    assert_eq!(result.tours.len(), 3);
+    assert_eq!(result, create_solution(&problem, &solution));
+}
@@ -1,4 +1,5 @@
 use super::*;
+use vrp_core::utils::DefaultRandom;
 
 #[test]
 fn can_generate_permutations() {
@@ -20,7 +21,7 @@ fn can_generate_permutations() {
 
 #[test]
 fn can_generate_split_permutations() {
-    let job_permutations = get_split_permutations(5, 3, 12);
+    let job_permutations = get_split_permutations(5, 3, 12, &DefaultRandom::default());
 
     assert_eq!(job_permutations.len(), 12);
     job_permutations.iter().for_each(|permutation| {
@@ -31,13 +32,20 @@ fn can_generate_split_permutations() {
         assert_eq!(right, 3);
     });
 
-    let job_permutations = get_split_permutations(3, 0, 10);
+    let job_permutations = get_split_permutations(3, 0, 10, &DefaultRandom::default());
     assert_eq!(job_permutations.len(), 6);
 
-    let job_permutations = get_split_permutations(3, 3, 10);
+    let job_permutations = get_split_permutations(3, 3, 10, &DefaultRandom::default());
     assert_eq!(job_permutations.len(), 6);
 }
 
+#[test]
+fn can_generate_same_sample_permutations_with_same_seed() {
+    let get_sample = || get_split_permutations(7, 3, 12, &DefaultRandom::new_with_seed(42));
+
+    assert_eq!(get_sample(), get_sample());
+}
+
 #[test]
 fn can_validate_permutations() {
     let permutator = VariableJobPermutation::new(5, 3, 12);
@@ -1,4 +1,5 @@
 use super::*;
+use crate::format_time;
 use crate::helpers::*;
 
 fn assert_result(code: &str, action: &str, result: Option<FormatError>) {
@@ -20,7 +21,11 @@ can_detect_reserved_ids! {
 
 fn can_detect_reserved_ids_impl(job_id: String, expected: Option<&str>) {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job(job_id.as_str(), vec![1., 0.])], relations: None },
+        plan: Plan {
+            jobs: vec![create_delivery_job(job_id.as_str(), vec![1., 0.])],
+            relations: None,
+            forbidden_arcs: None,
+        },
         fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
         ..create_empty_problem()
     };
@@ -46,8 +51,10 @@ fn can_detect_empty_job() {
                 services: None,
                 priority: None,
                 skills: None,
+                position: None,
             }],
             relations: None,
+            forbidden_arcs: None,
         },
         ..create_empty_problem()
     };
@@ -60,7 +67,11 @@ fn can_detect_empty_job() {
 #[test]
 fn can_detect_negative_duration() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job_with_duration("job1", vec![1., 0.], -10.)], relations: None },
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_duration("job1", vec![1., 0.], -10.)],
+            relations: None,
+            forbidden_arcs: None,
+        },
         ..create_empty_problem()
     };
 
@@ -72,7 +83,11 @@ fn can_detect_negative_duration() {
 #[test]
 fn can_detect_negative_demand() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![0, -1])], relations: None },
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![0, -1])],
+            relations: None,
+            forbidden_arcs: None,
+        },
         ..create_empty_problem()
     };
 
@@ -80,3 +95,59 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+#[test]
+fn can_detect_forbidden_arcs_with_unknown_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.])],
+            relations: None,
+            forbidden_arcs: Some(vec![ForbiddenArc { from: "job1".to_string(), to: "job2".to_string() }]),
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1108_forbidden_arcs_with_unknown_jobs(&ValidationContext::new(&problem, None)).err();
+
+    assert_result("E1108", "job2", result);
+}
+
+parameterized_test! {can_detect_time_windows_outside_planning_horizon, (horizon, times, expected), {
+    can_detect_time_windows_outside_planning_horizon_impl(horizon, times, expected);
+}}
+
+can_detect_time_windows_outside_planning_horizon! {
+    case01: ((0, 100), vec![(10, 20)], None),
+    case02: ((0, 100), vec![(50, 200)], None),
+    case03: ((0, 100), vec![(200, 300), (50, 60)], None),
+    case04: ((0, 100), vec![], None),
+    case05: ((0, 100), vec![(200, 300)], Some("job1")),
+    case06: ((100, 0), vec![(10, 20)], Some("planning horizon")),
+}
+
+fn can_detect_time_windows_outside_planning_horizon_impl(
+    horizon: (i32, i32),
+    times: Vec<(i32, i32)>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", vec![1., 0.], times, 1.)],
+            relations: None,
+            forbidden_arcs: None,
+        },
+        config: Some(Config {
+            routing: None,
+            planning_horizon: Some(vec![format_time(horizon.0 as f64), format_time(horizon.1 as f64)]),
+        }),
+        ..create_empty_problem()
+    };
+
+    let result = check_e1109_time_windows_within_planning_horizon(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1109", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
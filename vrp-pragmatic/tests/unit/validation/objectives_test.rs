@@ -1,6 +1,6 @@
 use super::*;
 use crate::format::problem::Objective::*;
-use crate::helpers::create_empty_problem;
+use crate::helpers::*;
 
 fn min_cost() -> Objective {
     MinimizeCost
@@ -89,3 +89,44 @@ fn can_detect_missing_cost_objective_impl(objectives: Option<Objectives>, expect
 
     assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1602".to_string()));
 }
+
+parameterized_test! {can_detect_invalid_previous_plan, (previous, expected), {
+    can_detect_invalid_previous_plan_impl(previous, expected);
+}}
+
+can_detect_invalid_previous_plan! {
+    case01: (vec![("my_vehicle_1", None, vec!["job1"])], None),
+    case02: (vec![("my_vehicle_1", None, vec!["job2"])], Some("job2")),
+    case03: (vec![("my_vehicle_2", None, vec!["job1"])], Some("my_vehicle_2")),
+    case04: (vec![("my_vehicle_1", Some(1), vec!["job1"])], Some("my_vehicle_1")),
+    case05: (vec![("my_vehicle_1", None, vec!["job1"]), ("my_vehicle_1", None, vec!["job1"])], Some("job1")),
+}
+
+fn can_detect_invalid_previous_plan_impl(previous: Vec<(&str, Option<usize>, Vec<&str>)>, expected: Option<&str>) {
+    let previous = previous
+        .into_iter()
+        .map(|(vehicle_id, shift_index, jobs)| PreviousTour {
+            vehicle_id: vehicle_id.to_string(),
+            shift_index,
+            jobs: jobs.into_iter().map(|job| job.to_string()).collect(),
+        })
+        .collect();
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None, forbidden_arcs: None },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        objectives: Some(Objectives {
+            primary: vec![MinimizePlanDeviation { options: PlanStabilityOptions { previous, weight: None } }],
+            secondary: Some(vec![min_cost()]),
+        }),
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1603_invalid_previous_plan(&ctx, &objectives);
+
+    assert_eq!(result.clone().err().map(|err| err.code), expected.map(|_| "E1603".to_string()));
+    if let Some(expected) = expected {
+        assert!(result.err().unwrap().action.contains(expected));
+    }
+}
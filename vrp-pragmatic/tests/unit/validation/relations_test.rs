@@ -35,6 +35,7 @@ fn can_detect_relation_errors_impl(job_ids: Vec<String>, vehicle_id: String, exp
                 vehicle_id,
                 shift_index: None,
             }]),
+            forbidden_arcs: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
         ..create_empty_problem()
@@ -80,6 +81,7 @@ fn can_detect_multi_place_time_window_jobs_impl(relation_type: RelationType, exp
                 vehicle_id: "vehicle_1".to_string(),
                 shift_index: None,
             }]),
+            forbidden_arcs: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
         ..create_empty_problem()
@@ -123,6 +125,7 @@ fn can_detect_multi_vehicle_assignment_impl(relations: Vec<(&str, &str)>, expect
                     })
                     .collect(),
             ),
+            forbidden_arcs: None,
         },
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("car"), create_default_vehicle("truck")],
@@ -1,5 +1,5 @@
 use super::*;
-use crate::helpers::create_empty_problem;
+use crate::helpers::*;
 
 #[test]
 fn can_detect_duplicates() {
@@ -7,8 +7,22 @@ fn can_detect_duplicates() {
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                Profile { name: "my_vehicle".to_string(), profile_type: "car".to_string(), speed: None },
-                Profile { name: "my_vehicle".to_string(), profile_type: "truck".to_string(), speed: None },
+                Profile {
+                    name: "my_vehicle".to_string(),
+                    profile_type: "car".to_string(),
+                    speed: None,
+                    overrides: None,
+                    multipliers: None,
+                    straight_line: None,
+                },
+                Profile {
+                    name: "my_vehicle".to_string(),
+                    profile_type: "truck".to_string(),
+                    speed: None,
+                    overrides: None,
+                    multipliers: None,
+                    straight_line: None,
+                },
             ],
         },
         ..create_empty_problem()
@@ -29,3 +43,159 @@ fn can_detect_empty_profiles() {
 
     assert_eq!(result.err().map(|err| err.code), Some("E1501".to_string()));
 }
+
+parameterized_test! {can_detect_invalid_routing_overrides, (from, duration, distance, expected), {
+    can_detect_invalid_routing_overrides_impl(from, duration, distance, expected);
+}}
+
+can_detect_invalid_routing_overrides! {
+    case01: ((0., 0.), Some(10.), None, None),
+    case02: ((0., 0.), None, Some(10.), None),
+    case03: ((0., 0.), Some(10.), Some(10.), None),
+
+    case04: ((0., 0.), None, None, Some(())),
+    case05: ((0., 0.), Some(-1.), None, Some(())),
+    case06: ((0., 0.), None, Some(-1.), Some(())),
+    case07: ((5., 5.), Some(10.), None, Some(())),
+}
+
+fn can_detect_invalid_routing_overrides_impl(
+    from: (f64, f64),
+    duration: Option<f64>,
+    distance: Option<f64>,
+    expected: Option<()>,
+) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None, forbidden_arcs: None },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: vec![Profile {
+                overrides: Some(vec![RoutingOverride {
+                    from: vec![from.0, from.1].to_loc(),
+                    to: vec![1., 0.].to_loc(),
+                    duration,
+                    distance,
+                }]),
+                ..create_default_profiles().first().unwrap().clone()
+            }],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1502_invalid_routing_overrides(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1502".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_speed, (profile_speed, default_speed, expected), {
+    can_detect_invalid_speed_impl(profile_speed, default_speed, expected);
+}}
+
+can_detect_invalid_speed! {
+    case01: (None, None, None),
+    case02: (Some(10.), Some(5.), None),
+    case03: (Some(0.), None, Some(())),
+    case04: (Some(-1.), None, Some(())),
+    case05: (None, Some(0.), Some(())),
+    case06: (None, Some(f64::NAN), Some(())),
+}
+
+fn can_detect_invalid_speed_impl(profile_speed: Option<f64>, default_speed: Option<f64>, expected: Option<()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![],
+            profiles: vec![Profile {
+                name: "car".to_string(),
+                profile_type: "car".to_string(),
+                speed: profile_speed,
+                overrides: None,
+                multipliers: None,
+                straight_line: None,
+            }],
+        },
+        config: Some(Config {
+            routing: Some(RoutingConfig { distance_unit: None, default_speed }),
+            planning_horizon: None,
+        }),
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+
+    let result = check_e1503_invalid_speed(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1503".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_travel_time_multipliers, (multipliers, expected), {
+    can_detect_invalid_travel_time_multipliers_impl(multipliers, expected);
+}}
+
+can_detect_invalid_travel_time_multipliers! {
+    case01: (None, None),
+    case02: (Some(vec![("07:00", "09:00", 1.3)]), None),
+    case03: (Some(vec![("07:00", "09:00", 1.3), ("09:00", "10:30:00", 1.1)]), None),
+
+    case04: (Some(vec![]), Some(())),
+    case05: (Some(vec![("09:00", "07:00", 1.3)]), Some(())),
+    case06: (Some(vec![("07:00", "09:00", 0.)]), Some(())),
+    case07: (Some(vec![("7am", "09:00", 1.3)]), Some(())),
+    case08: (Some(vec![("07:00", "09:00", 1.3), ("08:00", "10:00", 1.1)]), Some(())),
+}
+
+fn can_detect_invalid_travel_time_multipliers_impl(multipliers: Option<Vec<(&str, &str, f64)>>, expected: Option<()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![],
+            profiles: vec![Profile {
+                multipliers: multipliers.map(|multipliers| {
+                    multipliers
+                        .into_iter()
+                        .map(|(start, end, factor)| TravelTimeMultiplier {
+                            start: start.to_string(),
+                            end: end.to_string(),
+                            factor,
+                        })
+                        .collect()
+                }),
+                ..create_default_profiles().first().unwrap().clone()
+            }],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1504_invalid_travel_time_multipliers(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1504".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_straight_line_routing, (speed, fixed_time, expected), {
+    can_detect_invalid_straight_line_routing_impl(speed, fixed_time, expected);
+}}
+
+can_detect_invalid_straight_line_routing! {
+    case01: (20., None, None),
+    case02: (20., Some(60.), None),
+    case03: (20., Some(0.), None),
+
+    case04: (0., None, Some(())),
+    case05: (-1., None, Some(())),
+    case06: (f64::NAN, None, Some(())),
+    case07: (20., Some(-1.), Some(())),
+}
+
+fn can_detect_invalid_straight_line_routing_impl(speed: f64, fixed_time: Option<f64>, expected: Option<()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![],
+            profiles: vec![Profile {
+                straight_line: Some(StraightLineRouting { speed, fixed_time }),
+                ..create_default_profiles().first().unwrap().clone()
+            }],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1505_invalid_straight_line_routing(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1505".to_string()));
+}
@@ -1,5 +1,6 @@
 use super::*;
 use crate::format::Location;
+use crate::format_time;
 use crate::helpers::*;
 
 fn coord(lat: f64, lng: f64) -> Location {
@@ -38,3 +39,128 @@ fn can_detect_invalid_area_impl(allowed_areas: Option<Vec<Vec<Location>>>, expec
 
     assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1305".to_string()));
 }
+
+parameterized_test! {can_detect_invalid_depot_gate, (start_gate, end_gate, expected), {
+    can_detect_invalid_depot_gate_impl(start_gate, end_gate, expected);
+}}
+
+can_detect_invalid_depot_gate! {
+    case01: (None, None, None),
+    case02: (Some((0., 100.)), None, None),
+    case03: (None, Some((500., 1000.)), None),
+    case04: (Some((0., 100.)), Some((500., 1000.)), None),
+
+    case05: (Some((100., 0.)), None, Some(())),
+    case06: (None, Some((2000., 3000.)), Some(())),
+}
+
+fn can_detect_invalid_depot_gate_impl(
+    start_gate: Option<(f64, f64)>,
+    end_gate: Option<(f64, f64)>,
+    expected: Option<()>,
+) {
+    let to_gate = |gate: Option<(f64, f64)>| gate.map(|(start, end)| vec![format_time(start), format_time(end)]);
+    let shift = create_default_vehicle_shift();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: VehiclePlace { gate: to_gate(start_gate), ..shift.start.clone() },
+                    end: shift.end.clone().map(|end| VehiclePlace { gate: to_gate(end_gate), ..end }),
+                    ..shift
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1306_vehicle_depot_gate_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1306".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_unavailability, (unavailable, horizon, expected), {
+    can_detect_invalid_unavailability_impl(unavailable, horizon, expected);
+}}
+
+can_detect_invalid_unavailability! {
+    case01: (None, None, None),
+    case02: (Some(vec![(100., 200.)]), None, None),
+    case03: (Some(vec![(100., 200.), (150., 300.)]), None, None),
+    case04: (Some(vec![(100., 200.)]), Some((0., 500.)), None),
+
+    case05: (Some(vec![]), None, Some(())),
+    case06: (Some(vec![(200., 100.)]), None, Some(())),
+    case07: (Some(vec![(2000., 3000.)]), None, Some(())),
+    case08: (Some(vec![(100., 200.), (2000., 3000.)]), None, Some(())),
+    case09: (Some(vec![(100., 200.)]), Some((500., 1000.)), Some(())),
+}
+
+fn can_detect_invalid_unavailability_impl(
+    unavailable: Option<Vec<(f64, f64)>>,
+    horizon: Option<(f64, f64)>,
+    expected: Option<()>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                unavailable: unavailable.map(|tws| {
+                    tws.into_iter().map(|(start, end)| vec![format_time(start), format_time(end)]).collect()
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        config: horizon.map(|(start, end)| Config {
+            routing: None,
+            planning_horizon: Some(vec![format_time(start), format_time(end)]),
+        }),
+        ..create_empty_problem()
+    };
+
+    let result = check_e1307_vehicle_unavailability_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1307".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_positions, (positions, expected), {
+    can_detect_invalid_positions_impl(positions, expected);
+}}
+
+can_detect_invalid_positions! {
+    case01: (None, None),
+    case02: (Some(vec![("my_vehicle_1", "1970-01-01T00:01:40Z")]), None),
+
+    case03: (Some(vec![]), Some(())),
+    case04: (Some(vec![("my_vehicle_2", "1970-01-01T00:01:40Z")]), Some(())),
+    case05: (Some(vec![("my_vehicle_1", "invalid")]), Some(())),
+    case06: (Some(vec![("my_vehicle_1", "1970-01-01T00:01:40Z"), ("my_vehicle_1", "1970-01-01T00:01:40Z")]), Some(())),
+}
+
+fn can_detect_invalid_positions_impl(positions: Option<Vec<(&str, &str)>>, expected: Option<()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                positions: positions.map(|positions| {
+                    positions
+                        .into_iter()
+                        .map(|(vehicle_id, time)| VehiclePosition {
+                            vehicle_id: vehicle_id.to_string(),
+                            location: vec![1., 0.].to_loc(),
+                            time: time.to_string(),
+                        })
+                        .collect()
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1308_vehicle_positions_are_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1308".to_string()));
+}
@@ -0,0 +1,68 @@
+//! Compares cheapest insertion recreate with and without insertion cache on consecutive ruin and
+//! recreate steps.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::{InsertionCache, InsertionContext};
+use vrp_core::models::Problem;
+use vrp_core::solver::mutation::{AdjustedStringRemoval, Recreate, RecreateWithCheapest, Ruin};
+use vrp_core::solver::{DominancePopulation, RefinementContext};
+use vrp_core::utils::DefaultRandom;
+use vrp_scientific::solomon::SolomonProblem;
+
+const STEPS: usize = 50;
+const CACHE_SIZE: usize = 100_000;
+
+fn create_refinement_ctx(problem: Arc<Problem>) -> RefinementContext {
+    let population = DominancePopulation::new(problem.clone(), Arc::new(DefaultRandom::default()), 1, 1, 0);
+
+    RefinementContext::new(problem, Box::new(population), None)
+}
+
+fn create_insertion_ctx(problem: Arc<Problem>) -> InsertionContext {
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(DefaultRandom::new_with_seed(0)));
+
+    RecreateWithCheapest::default().run(&mut create_refinement_ctx(problem), insertion_ctx)
+}
+
+fn run_steps(recreate: &RecreateWithCheapest, problem: &Arc<Problem>, insertion_ctx: &InsertionContext) -> usize {
+    let ruin = AdjustedStringRemoval::default();
+    let mut refinement_ctx = create_refinement_ctx(problem.clone());
+    let mut insertion_ctx = insertion_ctx.deep_copy();
+    insertion_ctx.random = Arc::new(DefaultRandom::new_with_seed(0));
+
+    (0..STEPS).for_each(|_| {
+        let ruined_ctx = ruin.run(&mut refinement_ctx, insertion_ctx.deep_copy());
+        insertion_ctx = recreate.run(&mut refinement_ctx, ruined_ctx);
+    });
+
+    insertion_ctx.solution.routes.len()
+}
+
+fn bench_cheapest_recreate(c: &mut Criterion) {
+    let file = File::open("../examples/data/scientific/solomon/C101.100.txt").expect("cannot open problem file");
+    let problem = Arc::new(BufReader::new(file).read_solomon().expect("cannot read problem"));
+    let insertion_ctx = create_insertion_ctx(problem.clone());
+
+    let mut group = c.benchmark_group(format!("{} cheapest recreate steps on C101.100", STEPS));
+    group.sample_size(10);
+
+    group.bench_function("without cache", |b| {
+        let recreate = RecreateWithCheapest::default();
+        b.iter(|| run_steps(&recreate, &problem, &insertion_ctx))
+    });
+
+    group.bench_function("with cache", |b| {
+        b.iter(|| {
+            let recreate = RecreateWithCheapest::new_with_cache(Arc::new(InsertionCache::new(CACHE_SIZE)));
+            run_steps(&recreate, &problem, &insertion_ctx)
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_cheapest_recreate);
+criterion_main!(benches);
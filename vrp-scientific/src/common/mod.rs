@@ -4,4 +4,4 @@ mod text_reader;
 pub use self::text_reader::*;
 
 mod text_writer;
-pub use self::text_writer::write_text_solution;
+pub use self::text_writer::{write_dimacs_solution, write_text_solution};
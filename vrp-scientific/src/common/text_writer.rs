@@ -1,6 +1,10 @@
+#[cfg(test)]
+#[path = "../../tests/unit/common/text_writer_test.rs"]
+mod text_writer_test;
+
 use std::io::{BufWriter, Error, ErrorKind, Write};
 use vrp_core::models::common::IdDimension;
-use vrp_core::models::Solution;
+use vrp_core::models::{Problem, Solution};
 
 pub fn write_text_solution<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
     let mut writer = writer;
@@ -25,3 +29,43 @@ pub fn write_text_solution<W: Write>(writer: BufWriter<W>, solution: &Solution)
 
     Ok(())
 }
+
+/// Writes solution in DIMACS implementation challenge submission format: one line per route with
+/// customer numbers in visiting order followed by a line with total distance. Customer number is
+/// taken from job id without non-digit prefix, e.g. `c12` becomes `12`.
+pub fn write_dimacs_solution<W: Write>(
+    writer: BufWriter<W>,
+    problem: &Problem,
+    solution: &Solution,
+) -> Result<(), Error> {
+    let mut writer = writer;
+
+    if !solution.unassigned.is_empty() {
+        return Err(Error::new(ErrorKind::Other, "Cannot write dimacs solution with unassigned jobs."));
+    }
+
+    let mut distance = 0.;
+
+    for (route, idx) in solution.routes.iter().zip(1..) {
+        let customers = route
+            .tour
+            .all_activities()
+            .filter_map(|activity| activity.retrieve_job())
+            .filter_map(|job| job.dimens().get_id().cloned())
+            .map(|id| id.trim_start_matches(|c: char| !c.is_ascii_digit()).to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        writer.write_all(format!("Route #{}: {}\n", idx, customers).as_bytes())?;
+
+        let profile = route.actor.vehicle.profile;
+        let activities = route.tour.all_activities().collect::<Vec<_>>();
+        distance += activities.windows(2).fold(0., |acc, pair| {
+            let (from, to) = (pair[0], pair[1]);
+            acc + problem.transport.distance(profile, from.place.location, to.place.location, from.schedule.departure)
+        });
+    }
+
+    writer.write_all(format!("Cost {:.2}\n", distance).as_bytes())?;
+
+    Ok(())
+}
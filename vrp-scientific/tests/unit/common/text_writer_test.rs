@@ -0,0 +1,39 @@
+use super::*;
+use crate::helpers::*;
+use crate::solomon::SolomonProblem;
+use std::sync::Arc;
+use vrp_core::solver::Builder;
+
+#[test]
+fn can_write_dimacs_solution() {
+    let problem = Arc::new(
+        SolomonBuilder::new()
+            .set_title("Three customers")
+            .set_vehicle((1, 10))
+            .add_customer((0, 0, 0, 0, 0, 1000, 1))
+            .add_customer((1, 1, 0, 1, 5, 1000, 5))
+            .add_customer((2, 3, 0, 2, 0, 1002, 11))
+            .add_customer((3, 7, 0, 1, 0, 1000, 12))
+            .build()
+            .read_solomon()
+            .unwrap(),
+    );
+    let (solution, _) = Builder::default()
+        .with_problem(problem.clone())
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap()
+        .solve()
+        .unwrap();
+    let mut buffer = String::new();
+
+    write_dimacs_solution(unsafe { BufWriter::new(buffer.as_mut_vec()) }, &problem, &solution).unwrap();
+
+    let lines = buffer.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("Route #1: "));
+    let mut customers = lines[0].trim_start_matches("Route #1: ").split(' ').collect::<Vec<_>>();
+    customers.sort();
+    assert_eq!(customers, vec!["1", "2", "3"]);
+    assert_eq!(lines[1], "Cost 14.00");
+}
@@ -0,0 +1,88 @@
+//! A facade crate which exposes a stable public API of the solver.
+//!
+//! Internal crates (`vrp-core`, `vrp-pragmatic`, `vrp-scientific` and `vrp-cli`) may change their
+//! module layout between minor versions. This crate re-exports a curated set of their types and
+//! functions under paths which follow semantic versioning: an item is removed or moved here only
+//! with a major version increment.
+//!
+//! A typical usage is to read a problem in `pragmatic` format, solve it and write the solution:
+//!
+//! ```no_run
+//! use std::fs::File;
+//! use std::io::{BufReader, BufWriter};
+//! use std::sync::Arc;
+//! use vrp::prelude::*;
+//!
+//! let problem = BufReader::new(File::open("problem.json").unwrap());
+//! let problem = Arc::new(problem.read_pragmatic().expect("cannot read problem"));
+//!
+//! let (solution, _) = create_builder_from_preset(&Preset::Quick)
+//!     .with_problem(problem.clone())
+//!     .with_max_generations(Some(100))
+//!     .build()
+//!     .and_then(|solver| solver.solve())
+//!     .expect("cannot solve problem");
+//!
+//! solution.write_pragmatic_json(&problem, BufWriter::new(std::io::stdout())).unwrap();
+//! ```
+
+#[cfg(test)]
+#[path = "../tests/unit/lib_test.rs"]
+mod lib_test;
+
+/// Contains core problem and solution models which are independent from input format.
+pub mod models {
+    pub use vrp_core::models::common::Cost;
+    pub use vrp_core::models::{Problem, Solution};
+}
+
+/// Contains the solver and its builder with hooks to observe solving progress.
+pub mod solver {
+    pub use vrp_core::solver::{Builder, ImprovementHook, ProgressEvent, ProgressHook, Solver};
+}
+
+/// Contains solver config and options which are used to create the solver builder.
+pub mod config {
+    pub use vrp_cli::extensions::solve::config::{
+        create_builder_from_config, create_builder_from_preset, read_config, Config, Preset,
+    };
+    pub use vrp_cli::extensions::solve::options::{create_builder_from_options, read_options, SolveOptions};
+}
+
+/// Contains `pragmatic` format: problem and solution models, readers, writers and errors.
+pub mod pragmatic {
+    pub use vrp_pragmatic::format::FormatError;
+
+    /// Contains `pragmatic` problem model and readers.
+    pub mod problem {
+        pub use vrp_pragmatic::format::problem::{
+            deserialize_matrix, deserialize_problem, serialize_problem, Matrix, PragmaticProblem, Problem,
+        };
+    }
+
+    /// Contains `pragmatic` solution model and writers.
+    pub mod solution {
+        pub use vrp_pragmatic::format::solution::{deserialize_solution, PragmaticSolution, Solution};
+    }
+
+    /// Contains solution checker which verifies that solution satisfies problem definition.
+    pub mod checker {
+        pub use vrp_pragmatic::checker::CheckerContext;
+    }
+}
+
+/// Contains readers and writers of scientific formats.
+pub mod scientific {
+    pub use vrp_scientific::lilim::{LilimProblem, LilimSolution};
+    pub use vrp_scientific::solomon::{SolomonProblem, SolomonSolution};
+}
+
+/// Re-exports the most used types and traits.
+pub mod prelude {
+    pub use crate::config::{create_builder_from_config, create_builder_from_preset, read_config, Config, Preset};
+    pub use crate::models::{Problem, Solution};
+    pub use crate::pragmatic::problem::PragmaticProblem;
+    pub use crate::pragmatic::solution::PragmaticSolution;
+    pub use crate::pragmatic::FormatError;
+    pub use crate::solver::{Builder, Solver};
+}
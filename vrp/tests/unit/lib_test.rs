@@ -0,0 +1,27 @@
+use crate::pragmatic::checker::CheckerContext;
+use crate::pragmatic::problem::deserialize_problem;
+use crate::pragmatic::solution::deserialize_solution;
+use crate::prelude::*;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::sync::Arc;
+
+#[test]
+fn can_solve_pragmatic_problem_using_facade() {
+    let path = "../examples/data/pragmatic/simple.basic.problem.json";
+    let config = read_config(BufReader::new(r#"{ "termination": { "max_generations": 10 } }"#.as_bytes())).unwrap();
+    let problem = Arc::new(BufReader::new(File::open(path).unwrap()).read_pragmatic().unwrap());
+
+    let (solution, _) = create_builder_from_config(&config)
+        .unwrap()
+        .with_problem(problem.clone())
+        .build()
+        .and_then(|solver| solver.solve())
+        .unwrap();
+
+    let mut buffer = String::new();
+    solution.write_pragmatic_json(&problem, unsafe { BufWriter::new(buffer.as_mut_vec()) }).unwrap();
+    let solution = deserialize_solution(BufReader::new(buffer.as_bytes())).unwrap();
+    let problem = deserialize_problem(BufReader::new(File::open(path).unwrap())).unwrap();
+    assert_eq!(CheckerContext::new(problem, None, solution).check(), Ok(()));
+}